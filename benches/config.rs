@@ -0,0 +1,137 @@
+//! Measures the hot paths of a configuration with a large number of keys.
+//!
+//! Run with `cargo bench -p more-config-benches`. Each benchmark reports the average time of
+//! a single iteration.
+
+use config::{ext::*, *};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+const SECTIONS: usize = 100;
+const KEYS_PER_SECTION: usize = 100;
+const PROVIDERS: usize = 50;
+
+// prevents the compiler from optimizing away the result of a benchmark
+fn black_box<T>(value: T) -> T {
+    let result = unsafe { std::ptr::read_volatile(&value) };
+    std::mem::forget(value);
+    result
+}
+
+fn bench<T, F: FnMut() -> T>(name: &str, iterations: u32, mut action: F) {
+    for _ in 0..(iterations / 10).max(1) {
+        black_box(action());
+    }
+
+    let start = Instant::now();
+
+    for _ in 0..iterations {
+        black_box(action());
+    }
+
+    let elapsed = start.elapsed();
+    let average = Duration::from_nanos((elapsed.as_nanos() / iterations as u128) as u64);
+
+    println!(
+        "{:<40} {:>12?}/iter ({} iterations)",
+        name, average, iterations
+    );
+}
+
+fn pairs(sections: usize, keys: usize) -> Vec<(String, String)> {
+    let mut pairs = Vec::with_capacity(sections * keys);
+
+    for section in 0..sections {
+        for key in 0..keys {
+            pairs.push((
+                format!("Section{}:Key{}", section, key),
+                (section * keys + key).to_string(),
+            ));
+        }
+    }
+
+    pairs
+}
+
+fn large() -> Box<dyn ConfigurationRoot> {
+    DefaultConfigurationBuilder::new()
+        .add_in_memory(&pairs(SECTIONS, KEYS_PER_SECTION))
+        .build()
+        .unwrap()
+}
+
+// each provider overrides a different section so that lookups visit every provider
+fn layered() -> Box<dyn ConfigurationRoot> {
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    for provider in 0..PROVIDERS {
+        let mut pairs = pairs(1, KEYS_PER_SECTION);
+
+        for (key, _) in &mut pairs {
+            *key = key.replace("Section0", &format!("Section{}", provider));
+        }
+
+        builder.add_in_memory(&pairs);
+    }
+
+    builder.build().unwrap()
+}
+
+fn traverse(configuration: &dyn Configuration) -> usize {
+    configuration
+        .children()
+        .iter()
+        .map(|child| 1 + traverse(&***child))
+        .sum()
+}
+
+fn main() {
+    let data = pairs(SECTIONS, KEYS_PER_SECTION);
+
+    bench("build (10k keys)", 20, || {
+        DefaultConfigurationBuilder::new()
+            .add_in_memory(&data)
+            .build()
+            .unwrap()
+    });
+
+    let config = large();
+
+    bench("prewarm (10k keys)", 20, || {
+        let config = large();
+        config.prewarm();
+        config
+    });
+
+    bench("get", 100_000, || config.get("Section50:Key50"));
+    bench("get missing", 100_000, || config.get("Section50:Missing"));
+    bench("get_typed", 100_000, || config.get_typed("Section50:Key50"));
+
+    let layers = layered();
+
+    bench("get (50 providers)", 100_000, || {
+        layers.get("Section0:Key50")
+    });
+    bench("get_typed (50 providers)", 100_000, || {
+        layers.get_typed("Section0:Key50")
+    });
+
+    bench("section traversal (10k keys)", 20, || {
+        traverse(config.deref())
+    });
+    bench("iter_leaves (10k keys)", 20, || {
+        config.iter_leaves(None).count()
+    });
+    bench("reify (10k keys)", 20, || {
+        config.reify::<HashMap<String, HashMap<String, u32>>>()
+    });
+
+    let snapshot = config.snapshot();
+
+    bench("snapshot", 20, || config.snapshot());
+    bench("snapshot get", 100_000, || snapshot.get("Section50:Key50"));
+
+    let config = large();
+
+    bench("reload (10k keys)", 20, || config.reload().unwrap());
+}
@@ -0,0 +1,209 @@
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{Error, Ident, Lit, LitStr, Result, Token};
+
+// represents the segments of a key path, such as "Logging" / "Level"
+pub(crate) struct KeyPath {
+    segments: Punctuated<Lit, Token![/]>,
+}
+
+impl Parse for KeyPath {
+    fn parse(input: ParseStream) -> Result<Self> {
+        Ok(Self {
+            segments: Punctuated::parse_separated_nonempty(input)?,
+        })
+    }
+}
+
+// escapes the key delimiter and escape character the same as ConfigurationPath::escape
+fn escape(segment: &str) -> String {
+    let mut escaped = String::with_capacity(segment.len());
+
+    for ch in segment.chars() {
+        if ch == '\\' || ch == ':' {
+            escaped.push('\\');
+        }
+
+        escaped.push(ch);
+    }
+
+    escaped
+}
+
+pub(crate) fn expand_key(path: KeyPath) -> Result<TokenStream2> {
+    let mut segments = Vec::with_capacity(path.segments.len());
+
+    for lit in &path.segments {
+        let segment = match lit {
+            Lit::Str(text) => text.value(),
+            Lit::Int(index) => index.base10_parse::<usize>()?.to_string(),
+            _ => {
+                return Err(Error::new_spanned(
+                    lit,
+                    "expected a string literal or an array index",
+                ))
+            }
+        };
+
+        if segment.is_empty() {
+            return Err(Error::new_spanned(lit, "a key segment cannot be empty"));
+        }
+
+        segments.push(escape(&segment));
+    }
+
+    let key = segments.join(":");
+
+    Ok(quote! { #key })
+}
+
+// converts a key segment, such as ConnectionString or connectionString, to CONNECTION_STRING
+fn screaming_snake_case(segment: &str) -> String {
+    let chars: Vec<_> = segment.chars().collect();
+    let mut name = String::with_capacity(segment.len() + 4);
+
+    for (i, &ch) in chars.iter().enumerate() {
+        if !ch.is_ascii_alphanumeric() {
+            name.push('_');
+            continue;
+        }
+
+        if ch.is_ascii_uppercase() && i > 0 {
+            let previous = chars[i - 1];
+            let next_is_lower = chars.get(i + 1).map_or(false, char::is_ascii_lowercase);
+
+            if previous.is_ascii_lowercase()
+                || previous.is_ascii_digit()
+                || (previous.is_ascii_uppercase() && next_is_lower)
+            {
+                name.push('_');
+            }
+        }
+
+        name.push(ch.to_ascii_uppercase());
+    }
+
+    name
+}
+
+fn constant_name(path: &[String]) -> String {
+    let name = path
+        .iter()
+        .map(|segment| screaming_snake_case(segment))
+        .collect::<Vec<_>>()
+        .join("_");
+    let mut name = name
+        .split('_')
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("_");
+
+    if name.is_empty() || name.starts_with(|ch: char| ch.is_ascii_digit()) {
+        name.insert(0, '_');
+    }
+
+    name
+}
+
+// visits every section and value, which are keyed by the constant name
+fn visit(
+    value: &Value,
+    path: &mut Vec<String>,
+    keys: &mut BTreeMap<String, String>,
+    span: Span,
+) -> Result<()> {
+    if !path.is_empty() {
+        let name = constant_name(path);
+        let key = path
+            .iter()
+            .map(|segment| escape(segment))
+            .collect::<Vec<_>>()
+            .join(":");
+
+        if let Some(other) = keys.insert(name.clone(), key.clone()) {
+            return Err(Error::new(
+                span,
+                format!(
+                    "the keys '{}' and '{}' both map to the constant {}",
+                    other, key, name
+                ),
+            ));
+        }
+    }
+
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map {
+                path.push(key.clone());
+                visit(value, path, keys, span)?;
+                path.pop();
+            }
+        }
+        Value::Array(items) => {
+            for (index, value) in items.iter().enumerate() {
+                path.push(index.to_string());
+                visit(value, path, keys, span)?;
+                path.pop();
+            }
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+pub(crate) fn expand_keys(file: LitStr) -> Result<TokenStream2> {
+    let span = file.span();
+    let mut path = PathBuf::from(std::env::var("CARGO_MANIFEST_DIR").unwrap_or_default());
+
+    path.push(file.value());
+
+    let text = std::fs::read_to_string(&path).map_err(|error| {
+        Error::new(
+            span,
+            format!("the file '{}' could not be read: {}", path.display(), error),
+        )
+    })?;
+    let json: Value = serde_json::from_str(&text).map_err(|error| {
+        Error::new(
+            span,
+            format!("the file '{}' is not valid JSON: {}", path.display(), error),
+        )
+    })?;
+
+    if !json.is_object() {
+        return Err(Error::new(
+            span,
+            "the root of the file must be a JSON object",
+        ));
+    }
+
+    let mut keys = BTreeMap::new();
+
+    visit(&json, &mut Vec::new(), &mut keys, span)?;
+
+    let constants = keys.iter().map(|(name, key)| {
+        let ident = Ident::new(name, span);
+        let doc = format!("The `{}` configuration key.", key);
+        quote! {
+            #[doc = #doc]
+            pub const #ident: &str = #key;
+        }
+    });
+    let file = path.to_string_lossy().into_owned();
+
+    Ok(quote! {
+        /// Provides the configuration keys defined by the reference configuration file.
+        #[allow(dead_code)]
+        pub mod keys {
+            // rebuilds the keys when the reference file changes
+            const _: &[u8] = include_bytes!(#file);
+            #(#constants)*
+        }
+    })
+}
@@ -0,0 +1,350 @@
+//! Provides the `#[derive(Config)]`, `config_keys!`, and `key!` macros for the `more-config` crate.
+
+mod keys;
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{
+    parse_macro_input, Attribute, Data, DeriveInput, Error, Fields, GenericArgument, Lit, LitStr,
+    Meta, NestedMeta, Path, PathArguments, Result, Type,
+};
+
+#[derive(Default)]
+struct FieldOptions {
+    key: Option<String>,
+    default: Option<String>,
+    env: Option<String>,
+    min: Option<Lit>,
+    max: Option<Lit>,
+    non_empty: bool,
+    nested: bool,
+    validate: Option<Path>,
+}
+
+impl FieldOptions {
+    fn parse(attrs: &[Attribute]) -> Result<Self> {
+        let mut options = Self::default();
+
+        for attr in attrs.iter().filter(|attr| attr.path.is_ident("config")) {
+            let list = match attr.parse_meta()? {
+                Meta::List(list) => list,
+                meta => return Err(Error::new_spanned(meta, "expected #[config(...)]")),
+            };
+
+            for item in list.nested {
+                match item {
+                    NestedMeta::Meta(Meta::Path(path)) if path.is_ident("non_empty") => {
+                        options.non_empty = true
+                    }
+                    NestedMeta::Meta(Meta::Path(path)) if path.is_ident("nested") => {
+                        options.nested = true
+                    }
+                    NestedMeta::Meta(Meta::NameValue(pair)) => {
+                        let name = pair.path.get_ident().map(ToString::to_string);
+
+                        match (name.as_deref(), pair.lit) {
+                            (Some("key"), Lit::Str(text)) => options.key = Some(text.value()),
+                            (Some("default"), Lit::Str(text)) => {
+                                options.default = Some(text.value())
+                            }
+                            (Some("env"), Lit::Str(text)) => options.env = Some(text.value()),
+                            (Some("min"), lit @ (Lit::Int(_) | Lit::Float(_))) => {
+                                options.min = Some(lit)
+                            }
+                            (Some("max"), lit @ (Lit::Int(_) | Lit::Float(_))) => {
+                                options.max = Some(lit)
+                            }
+                            (Some("validate"), Lit::Str(text)) => {
+                                options.validate = Some(text.parse()?)
+                            }
+                            (_, lit) => {
+                                return Err(Error::new_spanned(
+                                    lit,
+                                    "unsupported or invalid config attribute",
+                                ))
+                            }
+                        }
+                    }
+                    item => return Err(Error::new_spanned(item, "unsupported config attribute")),
+                }
+            }
+        }
+
+        Ok(options)
+    }
+}
+
+// gets the type argument of the specified generic type, such as T in Option<T>
+fn type_argument<'a>(ty: &'a Type, name: &str) -> Option<&'a Type> {
+    let segment = match ty {
+        Type::Path(path) if path.qself.is_none() => path.path.segments.last()?,
+        _ => return None,
+    };
+
+    if segment.ident != name {
+        return None;
+    }
+
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) if args.args.len() == 1 => match &args.args[0] {
+            GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn pascal_case(name: &str) -> String {
+    name.trim_start_matches("r#")
+        .split('_')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+fn expand(input: DeriveInput) -> Result<TokenStream2> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(Error::new_spanned(
+                    &input.ident,
+                    "Config can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "Config can only be derived for structs",
+            ))
+        }
+    };
+    let mut idents = Vec::with_capacity(fields.len());
+    let mut bindings = Vec::with_capacity(fields.len());
+    let mut keys = Vec::with_capacity(fields.len());
+
+    for field in fields {
+        let ident = field.ident.as_ref().unwrap();
+        let options = FieldOptions::parse(&field.attrs)?;
+        let key = options
+            .key
+            .clone()
+            .unwrap_or_else(|| pascal_case(&ident.to_string()));
+        let inner = type_argument(&field.ty, "Option");
+        let ty = inner.unwrap_or(&field.ty);
+        let optional = inner.is_some();
+
+        if options.nested && (options.default.is_some() || options.env.is_some()) {
+            return Err(Error::new_spanned(
+                ident,
+                "a nested field cannot have a default value or environment variable",
+            ));
+        }
+
+        let value = if options.nested {
+            keys.push(quote! {
+                keys.extend(__private::nested_keys(#key, <#ty as ::config::Config>::keys()));
+            });
+
+            if optional {
+                quote! { __private::optional_section::<#ty>(configuration, #key)? }
+            } else {
+                quote! { __private::section::<#ty>(configuration, #key)? }
+            }
+        } else if let Some(item) = type_argument(ty, "Vec") {
+            keys.push(quote! {
+                keys.push(::config::ConfigKey {
+                    key: #key.to_owned(),
+                    default: None,
+                    env: None,
+                    required: false,
+                });
+            });
+
+            if optional {
+                quote! {{
+                    let values = __private::values::<#item>(configuration, #key)?;
+                    if values.is_empty() { None } else { Some(values) }
+                }}
+            } else {
+                quote! { __private::values::<#item>(configuration, #key)? }
+            }
+        } else {
+            let default = match &options.default {
+                Some(value) => quote! { Some(#value) },
+                _ => quote! { None },
+            };
+            let env = match &options.env {
+                Some(value) => quote! { Some(#value) },
+                _ => quote! { None },
+            };
+            let required = !optional && options.default.is_none();
+
+            keys.push(quote! {
+                keys.push(::config::ConfigKey {
+                    key: #key.to_owned(),
+                    default: #default,
+                    env: #env,
+                    required: #required,
+                });
+            });
+
+            let value = quote! { __private::value::<#ty>(configuration, #key, #env, #default)? };
+
+            if optional {
+                value
+            } else {
+                quote! { __private::required(configuration, #key, #value)? }
+            }
+        };
+
+        let mut checks = Vec::new();
+
+        if let Some(min) = &options.min {
+            checks.push(quote! {
+                if *value < #min {
+                    return Err(__private::invalid(
+                        configuration,
+                        #key,
+                        value.to_string(),
+                        format_args!("must be at least {}", #min),
+                    ));
+                }
+            });
+        }
+
+        if let Some(max) = &options.max {
+            checks.push(quote! {
+                if *value > #max {
+                    return Err(__private::invalid(
+                        configuration,
+                        #key,
+                        value.to_string(),
+                        format_args!("must be at most {}", #max),
+                    ));
+                }
+            });
+        }
+
+        if options.non_empty {
+            checks.push(quote! {
+                if value.is_empty() {
+                    return Err(__private::invalid(
+                        configuration,
+                        #key,
+                        __private::raw(configuration, #key),
+                        "must not be empty",
+                    ));
+                }
+            });
+        }
+
+        if let Some(validate) = &options.validate {
+            checks.push(quote! {
+                if let Err(message) = #validate(value) {
+                    return Err(__private::invalid(
+                        configuration,
+                        #key,
+                        __private::raw(configuration, #key),
+                        message,
+                    ));
+                }
+            });
+        }
+
+        let validation = if checks.is_empty() {
+            quote! {}
+        } else if optional {
+            quote! { if let Some(value) = &#ident { #(#checks)* } }
+        } else {
+            quote! {{ let value = &#ident; #(#checks)* }}
+        };
+
+        bindings.push(quote! {
+            let #ident = #value;
+            #validation
+        });
+        idents.push(ident);
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::config::Config for #name #ty_generics #where_clause {
+            fn keys() -> ::std::vec::Vec<::config::ConfigKey> {
+                use ::config::__private;
+                let mut keys = ::std::vec::Vec::new();
+                #(#keys)*
+                keys
+            }
+
+            fn from_config(
+                configuration: &dyn ::config::Configuration,
+            ) -> ::std::result::Result<Self, ::config::ConfigError> {
+                use ::config::__private;
+                #(#bindings)*
+                Ok(Self { #(#idents),* })
+            }
+        }
+    })
+}
+
+/// Derives the `Config` trait, which binds a struct from a configuration.
+///
+/// # Remarks
+///
+/// Fields are configured with the `#[config(...)]` attribute. Refer to the `Config` trait for
+/// the supported attributes.
+#[proc_macro_derive(Config, attributes(config))]
+pub fn derive_config(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand(input)
+        .unwrap_or_else(|error| error.to_compile_error())
+        .into()
+}
+
+/// Generates a `keys` module with a constant for each key path in a reference JSON file.
+///
+/// # Remarks
+///
+/// The path of the file is relative to the directory of the crate manifest. Each section and
+/// value in the file has a constant whose name is the key path in upper snake case; for example,
+/// `Database:ConnectionString` is `keys::DATABASE_CONNECTION_STRING`. Array elements use their
+/// index, such as `keys::SERVERS_0_HOST`. Compilation fails if the file cannot be read or two
+/// keys map to the same constant.
+#[proc_macro]
+pub fn config_keys(input: TokenStream) -> TokenStream {
+    let file = parse_macro_input!(input as LitStr);
+
+    keys::expand_keys(file)
+        .unwrap_or_else(|error| error.to_compile_error())
+        .into()
+}
+
+/// Produces a configuration key path from its segments, which are validated at compile time.
+///
+/// # Remarks
+///
+/// Segments are string literals or array indices separated by `/`; for example,
+/// `key!("Logging" / "LogLevel" / "Default")` is `"Logging:LogLevel:Default"` and
+/// `key!("Servers" / 0 / "Host")` is `"Servers:0:Host"`. A key delimiter or backslash in a
+/// segment is escaped the same as `ConfigurationPath::escape`. Compilation fails if a segment
+/// is empty.
+#[proc_macro]
+pub fn key(input: TokenStream) -> TokenStream {
+    let path = parse_macro_input!(input as keys::KeyPath);
+
+    keys::expand_key(path)
+        .unwrap_or_else(|error| error.to_compile_error())
+        .into()
+}
@@ -0,0 +1,223 @@
+use std::path::PathBuf;
+
+/// Represents a configuration source specified on the command line.
+#[derive(Debug, PartialEq)]
+pub enum Source {
+    /// Indicates a JSON file.
+    Json(PathBuf),
+
+    /// Indicates an INI file.
+    Ini(PathBuf),
+
+    /// Indicates an XML file.
+    Xml(PathBuf),
+
+    /// Indicates the environment variables with an optional prefix.
+    Env(Option<String>),
+
+    /// Indicates a single in-memory key/value pair.
+    Value(String, String),
+}
+
+/// Represents a command to run.
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    /// Prints the usage.
+    Help,
+
+    /// Prints the merged configuration tree and the provider of each value.
+    Tree(Vec<Source>),
+
+    /// Prints every provider that configures a key, in order of precedence.
+    Explain { key: String, sources: Vec<Source> },
+
+    /// Prints the differences between two sets of sources.
+    Diff { old: Vec<Source>, new: Vec<Source> },
+
+    /// Validates the configuration against a JSON schema.
+    Validate {
+        schema: PathBuf,
+        sources: Vec<Source>,
+    },
+}
+
+fn value<I: Iterator<Item = String>>(flag: &str, args: &mut I) -> Result<String, String> {
+    args.next()
+        .filter(|value| !value.starts_with("--"))
+        .ok_or_else(|| format!("The {} option requires a value.", flag))
+}
+
+fn sources<I: Iterator<Item = String>>(args: &mut I) -> Result<Vec<Source>, String> {
+    let mut sources = Vec::new();
+
+    while let Some(arg) = args.next() {
+        let source = match arg.as_str() {
+            "--json" => Source::Json(value(&arg, args)?.into()),
+            "--ini" => Source::Ini(value(&arg, args)?.into()),
+            "--xml" => Source::Xml(value(&arg, args)?.into()),
+            "--env" => Source::Env(None),
+            "--env-prefix" => Source::Env(Some(value(&arg, args)?)),
+            "--set" => {
+                let pair = value(&arg, args)?;
+
+                match pair.split_once('=') {
+                    Some((key, value)) => Source::Value(key.into(), value.into()),
+                    _ => {
+                        return Err(format!(
+                            "The value '{}' must be in the form key=value.",
+                            pair
+                        ))
+                    }
+                }
+            }
+            _ => return Err(format!("Unknown option '{}'.", arg)),
+        };
+
+        sources.push(source);
+    }
+
+    Ok(sources)
+}
+
+/// Parses the command from the specified arguments, excluding the program name.
+///
+/// # Arguments
+///
+/// * `args` - The arguments to parse
+pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Command, String> {
+    let mut args = args.into_iter();
+    let command = match args.next() {
+        Some(command) => command,
+        _ => return Ok(Command::Help),
+    };
+
+    match command.as_str() {
+        "help" | "--help" | "-h" => Ok(Command::Help),
+        "tree" => Ok(Command::Tree(sources(&mut args)?)),
+        "explain" => {
+            let key = args
+                .next()
+                .filter(|key| !key.starts_with("--"))
+                .ok_or("The explain command requires a key.")?;
+
+            Ok(Command::Explain {
+                key,
+                sources: sources(&mut args)?,
+            })
+        }
+        "diff" => {
+            let old: Vec<_> = args.by_ref().take_while(|arg| arg != "--against").collect();
+            let new = sources(&mut args)?;
+
+            if new.is_empty() {
+                return Err("The diff command requires sources after --against.".into());
+            }
+
+            Ok(Command::Diff {
+                old: sources(&mut old.into_iter())?,
+                new,
+            })
+        }
+        "validate" => {
+            let mut schema = None;
+            let mut rest = Vec::new();
+
+            while let Some(arg) = args.next() {
+                if arg == "--schema" {
+                    schema = Some(value(&arg, &mut args)?);
+                } else {
+                    rest.push(arg);
+                }
+            }
+
+            Ok(Command::Validate {
+                schema: schema
+                    .ok_or("The validate command requires the --schema option.")?
+                    .into(),
+                sources: sources(&mut rest.into_iter())?,
+            })
+        }
+        _ => Err(format!("Unknown command '{}'.", command)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(ToOwned::to_owned).collect()
+    }
+
+    #[test]
+    fn parse_should_return_sources_in_order() {
+        // arrange
+        let args = args("tree --json a.json --env-prefix APP_ --set Name=App --env");
+
+        // act
+        let command = parse(args).unwrap();
+
+        // assert
+        assert_eq!(
+            command,
+            Command::Tree(vec![
+                Source::Json("a.json".into()),
+                Source::Env(Some("APP_".into())),
+                Source::Value("Name".into(), "App".into()),
+                Source::Env(None),
+            ])
+        );
+    }
+
+    #[test]
+    fn parse_should_split_diff_sources() {
+        // arrange
+        let args = args("diff --json a.json --against --json a.json --ini b.ini");
+
+        // act
+        let command = parse(args).unwrap();
+
+        // assert
+        assert_eq!(
+            command,
+            Command::Diff {
+                old: vec![Source::Json("a.json".into())],
+                new: vec![Source::Json("a.json".into()), Source::Ini("b.ini".into())],
+            }
+        );
+    }
+
+    #[test]
+    fn parse_should_extract_schema_from_any_position() {
+        // arrange
+        let args = args("validate --xml a.xml --schema schema.json");
+
+        // act
+        let command = parse(args).unwrap();
+
+        // assert
+        assert_eq!(
+            command,
+            Command::Validate {
+                schema: "schema.json".into(),
+                sources: vec![Source::Xml("a.xml".into())],
+            }
+        );
+    }
+
+    #[test]
+    fn parse_should_fail_when_option_has_no_value() {
+        // arrange
+        let args = args("tree --json --env");
+
+        // act
+        let result = parse(args);
+
+        // assert
+        assert_eq!(
+            result,
+            Err("The --json option requires a value.".to_owned())
+        );
+    }
+}
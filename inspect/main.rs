@@ -0,0 +1,178 @@
+mod args;
+
+use args::{Command, Source};
+use config::{ext::*, *};
+use std::process::exit;
+
+const USAGE: &str = "Inspects the configuration composed from the specified sources.
+
+Usage:
+  config-inspect tree [SOURCES]
+  config-inspect explain <KEY> [SOURCES]
+  config-inspect diff [SOURCES] --against [SOURCES]
+  config-inspect validate --schema <FILE> [SOURCES]
+
+Commands:
+  tree      Prints the merged configuration and the provider of each value
+  explain   Prints every provider that configures a key, in order of precedence
+  diff      Prints the differences between two sets of sources
+  validate  Validates the configuration against a JSON schema
+
+Sources, in order of increasing precedence:
+  --json <FILE>          Adds a JSON file
+  --ini <FILE>           Adds an INI file
+  --xml <FILE>           Adds an XML file
+  --env                  Adds all environment variables
+  --env-prefix <PREFIX>  Adds the environment variables with the prefix
+  --set <KEY=VALUE>      Adds a single value
+
+The exit code is 1 when a key is not configured, the sources differ, or the
+configuration is invalid.";
+
+fn build(sources: &[Source]) -> Result<Box<dyn ConfigurationRoot>, String> {
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    for source in sources {
+        match source {
+            Source::Json(path) => builder.add_json_file(path),
+            Source::Ini(path) => builder.add_ini_file(path),
+            Source::Xml(path) => builder.add_xml_file(path),
+            Source::Env(Some(prefix)) => builder.add_env_vars_with_prefix(prefix),
+            Source::Env(_) => builder.add_env_vars(),
+            Source::Value(key, value) => builder.add_in_memory(&[(key, value)]),
+        };
+    }
+
+    builder.build().map_err(|error| error.to_string())
+}
+
+// the provider with the highest precedence that configures the key
+fn provider_of(root: &dyn ConfigurationRoot, key: &str) -> Option<(String, Value)> {
+    root.providers()
+        .rev()
+        .find_map(|provider| Some((provider.name().to_owned(), provider.get(key)?)))
+}
+
+fn display(root: &dyn ConfigurationRoot, key: &str, value: &str) -> String {
+    if root.is_sensitive(key) {
+        REDACTED.to_owned()
+    } else {
+        value.to_owned()
+    }
+}
+
+fn print_tree(
+    root: &dyn ConfigurationRoot,
+    children: &[Box<dyn ConfigurationSection>],
+    indent: &str,
+) {
+    for child in children {
+        match provider_of(root, child.path()) {
+            Some((provider, value)) => println!(
+                "{}{} = {} ({})",
+                indent,
+                child.key(),
+                display(root, child.path(), &value),
+                provider
+            ),
+            _ => println!("{}{}:", indent, child.key()),
+        }
+
+        print_tree(root, &child.children(), &format!("{}  ", indent));
+    }
+}
+
+fn run(command: Command) -> Result<bool, String> {
+    match command {
+        Command::Help => {
+            println!("{}", USAGE);
+            Ok(true)
+        }
+        Command::Tree(sources) => {
+            let root = build(&sources)?;
+
+            print_tree(root.as_ref(), &root.children(), "");
+            Ok(true)
+        }
+        Command::Explain { key, sources } => {
+            let root = build(&sources)?;
+            let mut found = false;
+
+            for provider in root.providers().rev() {
+                if let Some(value) = provider.get(&key) {
+                    let marker = if found { ' ' } else { '*' };
+
+                    println!(
+                        "{} {} = {}",
+                        marker,
+                        provider.name(),
+                        display(root.as_ref(), &key, &value)
+                    );
+                    found = true;
+                }
+            }
+
+            if !found {
+                println!("The key '{}' is not configured.", key);
+            }
+
+            Ok(found)
+        }
+        Command::Diff { old, new } => {
+            let old = build(&old)?;
+            let new = build(&new)?;
+            let entries = diff(old.as_ref().as_ref(), new.as_ref().as_ref());
+
+            for entry in &entries {
+                println!("{}", entry);
+            }
+
+            Ok(entries.is_empty())
+        }
+        Command::Validate { schema, sources } => {
+            let text = std::fs::read_to_string(&schema).map_err(|error| {
+                format!(
+                    "The schema '{}' could not be read. {}",
+                    schema.display(),
+                    error
+                )
+            })?;
+            let schema = ConfigurationSchema::from_json_schema(&text).map_err(|error| {
+                format!("The schema '{}' is invalid. {}", schema.display(), error)
+            })?;
+            let root = build(&sources)?;
+            let report = schema.validate(root.as_ref().as_ref());
+
+            for key in &report.unknown_keys {
+                println!("unknown: {}", key);
+            }
+
+            for key in &report.missing_keys {
+                println!("missing: {}", key);
+            }
+
+            for mismatch in &report.mismatches {
+                println!(
+                    "mismatch: {} expected {}, but was '{}'",
+                    mismatch.key,
+                    mismatch.expected,
+                    display(root.as_ref(), &mismatch.key, &mismatch.value)
+                );
+            }
+
+            Ok(report.is_valid())
+        }
+    }
+}
+
+fn main() {
+    match args::parse(std::env::args().skip(1)).and_then(run) {
+        Ok(true) => {}
+        Ok(false) => exit(1),
+        Err(error) => {
+            eprintln!("{}", error);
+            eprintln!("Run 'config-inspect help' for usage.");
+            exit(2);
+        }
+    }
+}
@@ -27,6 +27,11 @@ pub trait ConfigurationBinder {
     /// # Arguments
     ///
     /// * `key` - The key of the value to retrieve
+    ///
+    /// # Remarks
+    ///
+    /// If the value cannot be parsed, it is parsed again after applying the
+    /// [`ValueConversion`](crate::ValueConversion) of the configuration, if any.
     fn get_value<T: FromStr>(&self, key: impl AsRef<str>) -> Result<Option<T>, T::Err>;
 
     /// Gets an optional, typed value from the configuration.
@@ -35,6 +40,67 @@ pub trait ConfigurationBinder {
     ///
     /// * `key` - The key of the value to retrieve
     fn get_value_or_default<T: FromStr + Default>(&self, key: impl AsRef<str>) -> Result<T, T::Err>;
+
+    /// Gets the elements of the configuration as an array.
+    ///
+    /// # Remarks
+    ///
+    /// Elements are keyed by their zero-based index, such as `Servers:0` and `Servers:1`. An error
+    /// is returned if a key is not an index or an index is missing, which can happen when arrays
+    /// from multiple providers are merged, rather than binding the remaining elements by position.
+    fn as_vec<T: DeserializeOwned>(&self) -> Result<Vec<T>, Error>;
+
+    /// Gets the elements of a configuration section as an array.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the configuration section to retrieve
+    ///
+    /// # Remarks
+    ///
+    /// An empty array is returned if the section does not exist.
+    fn get_vec<T: DeserializeOwned>(&self, key: impl AsRef<str>) -> Result<Vec<T>, Error>;
+}
+
+fn parse<T: FromStr>(section: &dyn ConfigurationSection) -> Result<T, T::Err> {
+    let value = section.value();
+
+    T::from_str(value.as_str()).or_else(|error| {
+        match section.value_conversion().convert(value.as_str()) {
+            Some(converted) => T::from_str(converted).map_err(|_| error),
+            _ => Err(error),
+        }
+    })
+}
+
+fn elements<T: DeserializeOwned>(configuration: &dyn Configuration) -> Result<Vec<T>, Error> {
+    let mut children = Vec::new();
+
+    for child in configuration.children() {
+        match child.key().parse::<usize>() {
+            Ok(index) => children.push((index, child)),
+            _ => {
+                return Err(Error::Custom(format!(
+                    "'{}' is not an array index",
+                    child.path()
+                )))
+            }
+        }
+    }
+
+    children.sort_by_key(|(index, _)| *index);
+
+    let mut values = Vec::with_capacity(children.len());
+
+    for (expected, (index, child)) in children.into_iter().enumerate() {
+        if index != expected {
+            return Err(crate::de::missing_index(expected, child.as_ref()));
+        }
+
+        values.push(crate::de::from_section(child)?);
+    }
+
+    Ok(values)
 }
 
 impl ConfigurationBinder for dyn Configuration + '_ {
@@ -57,7 +123,7 @@ impl ConfigurationBinder for dyn Configuration + '_ {
     fn get_value<T: FromStr>(&self, key: impl AsRef<str>) -> Result<Option<T>, T::Err> {
         let section = self.section(key.as_ref());
         let value = if section.exists() {
-            Some(T::from_str(section.value().as_str())?)
+            Some(parse(&*section)?)
         } else {
             None
         };
@@ -68,13 +134,21 @@ impl ConfigurationBinder for dyn Configuration + '_ {
     fn get_value_or_default<T: FromStr + Default>(&self, key: impl AsRef<str>) -> Result<T, T::Err> {
         let section = self.section(key.as_ref());
         let value = if section.exists() {
-            T::from_str(section.value().as_str())?
+            parse(&*section)?
         } else {
             T::default()
         };
 
         Ok(value)
     }
+
+    fn as_vec<T: DeserializeOwned>(&self) -> Result<Vec<T>, Error> {
+        elements(self)
+    }
+
+    fn get_vec<T: DeserializeOwned>(&self, key: impl AsRef<str>) -> Result<Vec<T>, Error> {
+        elements(self.section(key.as_ref()).deref().as_ref())
+    }
 }
 
 impl<C: AsRef<dyn Configuration>> ConfigurationBinder for C {
@@ -97,7 +171,7 @@ impl<C: AsRef<dyn Configuration>> ConfigurationBinder for C {
     fn get_value<T: FromStr>(&self, key: impl AsRef<str>) -> Result<Option<T>, T::Err> {
         let section = self.as_ref().section(key.as_ref());
         let value = if section.exists() {
-            Some(T::from_str(section.value().as_str())?)
+            Some(parse(&*section)?)
         } else {
             None
         };
@@ -108,11 +182,19 @@ impl<C: AsRef<dyn Configuration>> ConfigurationBinder for C {
     fn get_value_or_default<T: FromStr + Default>(&self, key: impl AsRef<str>) -> Result<T, T::Err> {
         let section = self.as_ref().section(key.as_ref());
         let value = if section.exists() {
-            T::from_str(section.value().as_str())?
+            parse(&*section)?
         } else {
             T::default()
         };
 
         Ok(value)
     }
+
+    fn as_vec<T: DeserializeOwned>(&self) -> Result<Vec<T>, Error> {
+        elements(self.as_ref())
+    }
+
+    fn get_vec<T: DeserializeOwned>(&self, key: impl AsRef<str>) -> Result<Vec<T>, Error> {
+        elements(self.as_ref().section(key.as_ref()).deref().as_ref())
+    }
 }
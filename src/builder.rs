@@ -1,17 +1,43 @@
 use crate::{ConfigurationRoot, ConfigurationSource, ReloadError};
 use std::any::Any;
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+// the key of the ConfigurationBuilder property that holds the base path of relative files
+pub(crate) const BASE_PATH: &str = "BasePath";
+
+// the key of the ConfigurationBuilder property that holds the bootstrap configuration
+pub(crate) const BOOTSTRAP: &str = "Bootstrap";
 
 /// Defines the behavior used to build an application [`Configuration`](crate::Configuration).
 pub trait ConfigurationBuilder {
     /// Gets a read-only key/value collection that can be used to share data between the
     /// [`ConfigurationBuilder`] and each registered [`ConfigurationSource`](crate::ConfigurationSource).
+    ///
+    /// # Remarks
+    ///
+    /// The properties are the extension point for settings that apply to many sources, such as
+    /// the base path of relative files or the [`HostEnvironment`](crate::HostEnvironment).
+    /// A source reads a property when it is built, typically with
+    /// [`property`](trait.ConfigurationBuilder.html#method.property).
     fn properties(&self) -> &HashMap<String, Box<dyn Any>>;
 
     /// Gets the registered [`ConfigurationSource`](crate::ConfigurationSource) set used to obtain
     /// configuration values.
     fn sources(&self) -> &[Box<dyn ConfigurationSource>];
 
+    /// Gets the registered [`ConfigurationSource`](crate::ConfigurationSource) set that provides
+    /// default values.
+    ///
+    /// # Remarks
+    ///
+    /// Default sources are built before, and are overridden by, all other sources. The default
+    /// implementation does not have any default sources.
+    fn default_sources(&self) -> &[Box<dyn ConfigurationSource>] {
+        &[]
+    }
+
     /// Adds a new configuration source.
     ///
     /// # Arguments
@@ -23,3 +49,36 @@ pub trait ConfigurationBuilder {
     /// registered [`ConfigurationSource`](crate::ConfigurationSource) set.
     fn build(&self) -> Result<Box<dyn ConfigurationRoot>, ReloadError>;
 }
+
+impl dyn ConfigurationBuilder + '_ {
+    /// Gets a typed property shared by the configuration builder, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the property to retrieve
+    ///
+    /// # Remarks
+    ///
+    /// `None` is returned when the property does not exist or is not of the requested type.
+    pub fn property<T: Any>(&self, key: &str) -> Option<&T> {
+        self.properties()
+            .get(key)
+            .and_then(|value| value.downcast_ref::<T>())
+    }
+
+    /// Gets the path that relative file paths are resolved against, if any.
+    pub fn base_path(&self) -> Option<&Path> {
+        self.property::<PathBuf>(BASE_PATH).map(PathBuf::as_path)
+    }
+
+    /// Gets the bootstrap configuration built before the configuration of this builder, if any.
+    ///
+    /// # Remarks
+    ///
+    /// A source uses the bootstrap configuration to configure itself when it is built; for
+    /// example, to read the address of a remote configuration service.
+    pub fn bootstrap(&self) -> Option<&dyn ConfigurationRoot> {
+        self.property::<Arc<dyn ConfigurationRoot>>(BOOTSTRAP)
+            .map(AsRef::as_ref)
+    }
+}
@@ -1,14 +1,65 @@
 use crate::{
-    util::cmp_keys, Configuration, ConfigurationBuilder, ConfigurationProvider,
-    ConfigurationSource, Value,
+    ext::ConfigurationIteratorExtensions,
+    util::{cmp_keys, first_segment, strip_prefix},
+    Configuration, ConfigurationBuilder, ConfigurationPairs, ConfigurationPath,
+    ConfigurationProvider, ConfigurationSection, ConfigurationSource, Value,
 };
-use std::borrow::Borrow;
-use std::rc::Rc;
-use tokens::ChangeToken;
+use std::sync::{Arc, Weak};
+#[cfg(feature = "async")]
+use tokens::{Callback, Registration, State};
+use tokens::{ChangeToken, NeverChangeToken};
+
+// the link to a chained configuration, which is resolved on each access
+#[derive(Clone)]
+enum Link {
+    Strong(Arc<dyn Configuration>),
+    Weak(Weak<dyn Configuration>),
+}
+
+impl Link {
+    fn resolve(&self) -> Option<Arc<dyn Configuration>> {
+        match self {
+            Self::Strong(configuration) => Some(configuration.clone()),
+            Self::Weak(configuration) => configuration.upgrade(),
+        }
+    }
+}
+
+// a change token that also changes when a weakly referenced configuration is dropped, which
+// requires the configuration to be thread-safe
+#[cfg(feature = "async")]
+struct LivenessChangeToken {
+    configuration: Weak<dyn Configuration>,
+    inner: Box<dyn ChangeToken>,
+}
+
+#[cfg(feature = "async")]
+impl ChangeToken for LivenessChangeToken {
+    fn changed(&self) -> bool {
+        self.configuration.strong_count() == 0 || self.inner.changed()
+    }
+
+    fn must_poll(&self) -> bool {
+        true
+    }
+
+    fn register(&self, callback: Callback, state: State) -> Registration {
+        self.inner.register(callback, state)
+    }
+}
 
 /// Represents a chained [`ConfigurationProvider`](crate::ConfigurationProvider).
+///
+/// # Remarks
+///
+/// A provider that weakly references its [`Configuration`](crate::Configuration) does not keep
+/// it alive. Once the configuration has been dropped, the provider behaves as if it is empty.
+/// With the `async` feature, the [reload token](crate::ConfigurationProvider::reload_token) of
+/// the provider also changes when the configuration is dropped so that cached values are not
+/// used; otherwise, cached values remain until the configuration is reloaded.
 pub struct ChainedConfigurationProvider {
-    configuration: Rc<dyn Configuration>,
+    configuration: Link,
+    prefix: Option<String>,
 }
 
 impl ChainedConfigurationProvider {
@@ -17,36 +68,136 @@ impl ChainedConfigurationProvider {
     /// # Arguments
     ///
     /// * `configuration` - The [`Configuration`](crate::Configuration) to chain
-    pub fn new(configuration: Rc<dyn Configuration>) -> Self {
-        Self { configuration }
+    pub fn new(configuration: Arc<dyn Configuration>) -> Self {
+        Self {
+            configuration: Link::Strong(configuration),
+            prefix: None,
+        }
+    }
+
+    /// Initializes a new chained configuration provider that weakly references the chained
+    /// configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `configuration` - The [`Configuration`](crate::Configuration) to chain
+    pub fn weak(configuration: Weak<dyn Configuration>) -> Self {
+        Self {
+            configuration: Link::Weak(configuration),
+            prefix: None,
+        }
+    }
+
+    /// Initializes a new chained configuration provider that places all of the keys of the
+    /// configuration under a section path.
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix` - The section path all keys are placed under
+    /// * `configuration` - The [`Configuration`](crate::Configuration) to chain
+    pub fn with_prefix<S: AsRef<str>>(prefix: S, configuration: Arc<dyn Configuration>) -> Self {
+        Self {
+            configuration: Link::Strong(configuration),
+            prefix: Some(prefix.as_ref().to_owned()),
+        }
+    }
+
+    /// Gets a value indicating whether the chained configuration is still available.
+    ///
+    /// # Remarks
+    ///
+    /// This is always `true` unless the configuration is weakly referenced and has been dropped.
+    pub fn is_alive(&self) -> bool {
+        self.configuration.resolve().is_some()
+    }
+
+    fn subkey<'a>(&self, key: &'a str) -> Option<&'a str> {
+        match &self.prefix {
+            Some(prefix) => strip_prefix(key, prefix),
+            _ => Some(key),
+        }
+    }
+
+    fn children(&self, parent_path: Option<&str>) -> Vec<String> {
+        let configuration = match self.configuration.resolve() {
+            Some(configuration) => configuration,
+            _ => return Vec::new(),
+        };
+        let children = match parent_path {
+            Some(path) => configuration.section(path).children(),
+            _ => configuration.children(),
+        };
+
+        children.iter().map(|c| c.key().to_owned()).collect()
     }
 }
 
 impl ConfigurationProvider for ChainedConfigurationProvider {
     fn get(&self, key: &str) -> Option<Value> {
-        self.configuration.get(key)
+        let subkey = self.subkey(key)?;
+        self.configuration.resolve()?.get(subkey)
     }
 
     fn reload_token(&self) -> Box<dyn ChangeToken> {
-        self.configuration.reload_token()
+        let token = match self.configuration.resolve() {
+            Some(configuration) => configuration.reload_token(),
+            _ => Box::new(NeverChangeToken::new()),
+        };
+
+        #[cfg(feature = "async")]
+        if let Link::Weak(configuration) = &self.configuration {
+            return Box::new(LivenessChangeToken {
+                configuration: configuration.clone(),
+                inner: token,
+            });
+        }
+
+        token
+    }
+
+    fn try_iter(&self) -> Option<ConfigurationPairs<'_>> {
+        let pairs = match self.configuration.resolve() {
+            Some(configuration) => configuration.iter_leaves(Some(ConfigurationPath::Relative)),
+            _ => Box::new(std::iter::empty()),
+        };
+
+        match &self.prefix {
+            Some(prefix) => Some(Box::new(pairs.map(move |(key, value)| {
+                (ConfigurationPath::combine(&[prefix, &key]), value)
+            }))),
+            _ => Some(pairs),
+        }
     }
 
     fn child_keys(&self, earlier_keys: &mut Vec<String>, parent_path: Option<&str>) {
-        if let Some(path) = parent_path {
-            earlier_keys.extend(
-                self.configuration
-                    .section(path)
-                    .children()
-                    .iter()
-                    .map(|c| c.key().to_owned()),
-            );
+        if let Some(prefix) = &self.prefix {
+            match parent_path {
+                Some(path) if path.eq_ignore_ascii_case(prefix) => {
+                    earlier_keys.extend(self.children(None))
+                }
+                Some(path) => {
+                    if let Some(subpath) = strip_prefix(path, prefix) {
+                        earlier_keys.extend(self.children(Some(subpath)));
+                    } else if let Some(rest) = strip_prefix(prefix, path) {
+                        if self.children(None).is_empty() {
+                            return;
+                        }
+
+                        earlier_keys.push(first_segment(rest).to_owned());
+                    } else {
+                        return;
+                    }
+                }
+                _ => {
+                    if self.children(None).is_empty() {
+                        return;
+                    }
+
+                    earlier_keys.push(first_segment(prefix).to_owned());
+                }
+            }
         } else {
-            earlier_keys.extend(
-                self.configuration
-                    .children()
-                    .iter()
-                    .map(|c| c.key().to_owned()),
-            );
+            earlier_keys.extend(self.children(parent_path));
         }
 
         earlier_keys.sort_by(|k1, k2| cmp_keys(k1, k2));
@@ -55,7 +206,8 @@ impl ConfigurationProvider for ChainedConfigurationProvider {
 
 /// Represents a chained [`ConfigurationSource`](crate::ConfigurationSource).
 pub struct ChainedConfigurationSource {
-    configuration: Rc<dyn Configuration>,
+    configuration: Link,
+    prefix: Option<String>,
 }
 
 impl ChainedConfigurationSource {
@@ -66,21 +218,65 @@ impl ChainedConfigurationSource {
     /// * `configuration` - The [`Configuration`](crate::Configuration) to chain
     pub fn new(configuration: Box<dyn Configuration>) -> Self {
         Self {
-            configuration: Rc::from(configuration),
+            configuration: Link::Strong(Arc::from(configuration)),
+            prefix: None,
         }
     }
 
-    /// Gets the associated [`Configuration`](crate::Configuration).
-    pub fn configuration(&self) -> &dyn Configuration {
-        self.configuration.borrow()
+    /// Initializes a new chained configuration source that weakly references the chained
+    /// configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `configuration` - The [`Configuration`](crate::Configuration) to chain
+    ///
+    /// # Remarks
+    ///
+    /// The chained configuration is resolved each time a value is requested so that it can be
+    /// dropped, or reloaded, independently of the configuration it is chained to. Once it has
+    /// been dropped, the provider built from this source behaves as if it is empty.
+    pub fn weak(configuration: &Arc<dyn Configuration>) -> Self {
+        Self {
+            configuration: Link::Weak(Arc::downgrade(configuration)),
+            prefix: None,
+        }
+    }
+
+    /// Initializes a new chained configuration source that places all of the keys of the
+    /// configuration under a section path.
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix` - The section path all keys are placed under, such as `Parent`
+    /// * `configuration` - The [`Configuration`](crate::Configuration) to chain
+    pub fn with_prefix<S: AsRef<str>>(prefix: S, configuration: Box<dyn Configuration>) -> Self {
+        Self {
+            configuration: Link::Strong(Arc::from(configuration)),
+            prefix: Some(prefix.as_ref().to_owned()),
+        }
+    }
+
+    /// Gets the associated [`Configuration`](crate::Configuration), if it is still available.
+    ///
+    /// # Remarks
+    ///
+    /// This is always `Some` unless the configuration is weakly referenced and has been dropped.
+    pub fn configuration(&self) -> Option<Arc<dyn Configuration>> {
+        self.configuration.resolve()
+    }
+
+    /// Gets the section path the configuration is placed under, if any.
+    pub fn prefix(&self) -> Option<&str> {
+        self.prefix.as_deref()
     }
 }
 
 impl ConfigurationSource for ChainedConfigurationSource {
     fn build(&self, _builder: &dyn ConfigurationBuilder) -> Box<dyn ConfigurationProvider> {
-        Box::new(ChainedConfigurationProvider::new(
-            self.configuration.clone(),
-        ))
+        Box::new(ChainedConfigurationProvider {
+            configuration: self.configuration.clone(),
+            prefix: self.prefix.clone(),
+        })
     }
 }
 
@@ -90,14 +286,21 @@ impl From<Box<dyn Configuration>> for ChainedConfigurationSource {
     }
 }
 
-impl From<Rc<dyn Configuration>> for ChainedConfigurationSource {
-    fn from(value: Rc<dyn Configuration>) -> Self {
+impl From<Arc<dyn Configuration>> for ChainedConfigurationSource {
+    fn from(value: Arc<dyn Configuration>) -> Self {
         Self {
-            configuration: value,
+            configuration: Link::Strong(value),
+            prefix: None,
         }
     }
 }
 
+impl From<Box<dyn ConfigurationSection>> for ChainedConfigurationSource {
+    fn from(value: Box<dyn ConfigurationSection>) -> Self {
+        Self::new(value.as_config())
+    }
+}
+
 pub mod ext {
 
     use super::*;
@@ -110,6 +313,45 @@ pub mod ext {
         ///
         /// * `configuration` - The existing [`Configuration`](crate::Configuration) to add
         fn add_configuration(&mut self, configuration: Box<dyn Configuration>) -> &mut Self;
+
+        /// Adds the existing configuration under a section path.
+        ///
+        /// # Arguments
+        ///
+        /// * `prefix` - The section path the configuration is placed under, such as `Parent`
+        /// * `configuration` - The existing [`Configuration`](crate::Configuration) to add
+        fn add_configuration_at(
+            &mut self,
+            prefix: &str,
+            configuration: Box<dyn Configuration>,
+        ) -> &mut Self;
+
+        /// Adds an existing configuration section.
+        ///
+        /// # Arguments
+        ///
+        /// * `section` - The existing [`ConfigurationSection`](crate::ConfigurationSection) to add
+        ///
+        /// # Remarks
+        ///
+        /// The keys of the section are relative to the section. For example, chaining the
+        /// `Database` section makes the value of `Database:Host` available as `Host`.
+        fn add_configuration_section(
+            &mut self,
+            section: Box<dyn ConfigurationSection>,
+        ) -> &mut Self;
+
+        /// Adds a weak reference to the existing configuration.
+        ///
+        /// # Arguments
+        ///
+        /// * `configuration` - The existing [`Configuration`](crate::Configuration) to add
+        ///
+        /// # Remarks
+        ///
+        /// The existing configuration is not kept alive by the built configuration. Once it has
+        /// been dropped, it no longer provides any values.
+        fn add_configuration_weak(&mut self, configuration: &Arc<dyn Configuration>) -> &mut Self;
     }
 
     impl ChainedBuilderExtensions for dyn ConfigurationBuilder + '_ {
@@ -117,6 +359,31 @@ pub mod ext {
             self.add(Box::new(ChainedConfigurationSource::new(configuration)));
             self
         }
+
+        fn add_configuration_at(
+            &mut self,
+            prefix: &str,
+            configuration: Box<dyn Configuration>,
+        ) -> &mut Self {
+            self.add(Box::new(ChainedConfigurationSource::with_prefix(
+                prefix,
+                configuration,
+            )));
+            self
+        }
+
+        fn add_configuration_section(
+            &mut self,
+            section: Box<dyn ConfigurationSection>,
+        ) -> &mut Self {
+            self.add(Box::new(ChainedConfigurationSource::from(section)));
+            self
+        }
+
+        fn add_configuration_weak(&mut self, configuration: &Arc<dyn Configuration>) -> &mut Self {
+            self.add(Box::new(ChainedConfigurationSource::weak(configuration)));
+            self
+        }
     }
 
     impl<T: ConfigurationBuilder> ChainedBuilderExtensions for T {
@@ -124,5 +391,30 @@ pub mod ext {
             self.add(Box::new(ChainedConfigurationSource::new(configuration)));
             self
         }
+
+        fn add_configuration_at(
+            &mut self,
+            prefix: &str,
+            configuration: Box<dyn Configuration>,
+        ) -> &mut Self {
+            self.add(Box::new(ChainedConfigurationSource::with_prefix(
+                prefix,
+                configuration,
+            )));
+            self
+        }
+
+        fn add_configuration_section(
+            &mut self,
+            section: Box<dyn ConfigurationSection>,
+        ) -> &mut Self {
+            self.add(Box::new(ChainedConfigurationSource::from(section)));
+            self
+        }
+
+        fn add_configuration_weak(&mut self, configuration: &Arc<dyn Configuration>) -> &mut Self {
+            self.add(Box::new(ChainedConfigurationSource::weak(configuration)));
+            self
+        }
     }
 }
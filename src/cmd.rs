@@ -1,5 +1,6 @@
 use crate::{
-    util::*, ConfigurationBuilder, ConfigurationProvider, ConfigurationSource, LoadResult, Value,
+    util::*, ConfigurationBuilder, ConfigurationPairs, ConfigurationPath, ConfigurationProvider,
+    ConfigurationSource, LoadError, LoadResult, Value, ValueRef,
 };
 use std::borrow::Cow;
 use std::collections::HashMap;
@@ -7,9 +8,11 @@ use std::collections::HashMap;
 /// Represents a [`ConfigurationProvider`](crate::ConfigurationProvider) that
 /// provides command line configuration values.
 pub struct CommandLineConfigurationProvider {
-    data: HashMap<String, (String, Value)>,
+    data: ConfigurationData,
     args: Vec<String>,
     switch_mappings: HashMap<String, String>,
+    positional_args: Option<String>,
+    strict: bool,
 }
 
 impl CommandLineConfigurationProvider {
@@ -23,26 +26,76 @@ impl CommandLineConfigurationProvider {
     /// # Remarks
     ///
     /// Only switch mapping keys that start with `--` or `-` are acceptable. Command
-    /// line arguments may start with `--`, `-`, or `/`
+    /// line arguments may start with `--`, `-`, or `/`. The arguments after the `--` separator
+    /// are never interpreted as switches.
     pub fn new(args: Vec<String>, switch_mappings: HashMap<String, String>) -> Self {
         Self {
             data: Default::default(),
             args,
             switch_mappings,
+            positional_args: None,
+            strict: false,
         }
     }
+
+    /// Captures the positional arguments under the specified section.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the section the positional arguments are placed in, such as `args`
+    ///
+    /// # Remarks
+    ///
+    /// A positional argument is an argument that is not a switch, the value of a switch, or a
+    /// key/value pair, as well as every argument after the `--` separator. Each argument is
+    /// placed at its zero-based position, such as `args:0`. Positional arguments are ignored by
+    /// default.
+    pub fn with_positional_args<S: Into<String>>(mut self, key: S) -> Self {
+        self.positional_args = Some(key.into());
+        self
+    }
+
+    /// Rejects the arguments that do not match a switch mapping.
+    ///
+    /// # Remarks
+    ///
+    /// In strict mode, loading fails with an error listing every unexpected argument instead
+    /// of silently ignoring or inferring them. Positional arguments are only expected when they
+    /// are captured.
+    pub fn strict(mut self) -> Self {
+        self.strict = true;
+        self
+    }
 }
 
 impl ConfigurationProvider for CommandLineConfigurationProvider {
     fn get(&self, key: &str) -> Option<Value> {
-        self.data.get(&key.to_uppercase()).map(|t| t.1.clone())
+        self.data.get(key)
+    }
+
+    fn get_str(&self, key: &str) -> Option<ValueRef<'_>> {
+        self.data.get_str(key).map(ValueRef::Borrowed)
     }
 
     fn load(&mut self) -> LoadResult {
         let mut data = HashMap::new();
+        let mut positional = Vec::new();
+        let mut unexpected = Vec::new();
+        let capture = self.positional_args.is_some();
         let mut args = self.args.iter();
 
         while let Some(arg) = args.next() {
+            // all arguments after the separator are positional
+            if arg == "--" {
+                if capture {
+                    positional.extend(args.by_ref().cloned());
+                } else if self.strict {
+                    unexpected.extend(args.by_ref().cloned());
+                }
+
+                break;
+            }
+
             let mut current = Cow::Borrowed(arg);
             let start: usize = if arg.starts_with("--") {
                 2
@@ -58,7 +111,7 @@ impl ConfigurationProvider for CommandLineConfigurationProvider {
                 0
             };
 
-            let mut key: String;
+            let key: String;
             let value: String;
 
             if let Some(separator) = current.find('=') {
@@ -69,29 +122,44 @@ impl ConfigurationProvider for CommandLineConfigurationProvider {
                     .collect();
 
                 key = if let Some(mapping) = self.switch_mappings.get(&segment) {
+                    // mappings are used as is so they can target nested paths
                     mapping.clone()
+                } else if self.strict {
+                    unexpected.push(arg.clone());
+                    continue;
                 } else if start == 1 {
                     continue;
                 } else {
-                    current
-                        .chars()
-                        .skip(start)
-                        .take(separator - start)
-                        .collect()
+                    to_pascal_case_parts(
+                        current
+                            .chars()
+                            .skip(start)
+                            .take(separator - start)
+                            .collect::<String>(),
+                        '-',
+                    )
                 };
 
                 value = current.chars().skip(separator + 1).collect();
             } else {
                 if start == 0 {
+                    if capture {
+                        positional.push(arg.clone());
+                    } else if self.strict {
+                        unexpected.push(arg.clone());
+                    }
+
                     continue;
                 }
 
                 key = if let Some(mapping) = self.switch_mappings.get(&current.to_uppercase()) {
                     mapping.clone()
-                } else if start == 0 {
+                } else if self.strict {
+                    unexpected.push(arg.clone());
+                    args.next();
                     continue;
                 } else {
-                    current.chars().skip(start).collect()
+                    to_pascal_case_parts(current.chars().skip(start).collect::<String>(), '-')
                 };
 
                 if let Some(next) = args.next() {
@@ -101,17 +169,37 @@ impl ConfigurationProvider for CommandLineConfigurationProvider {
                 }
             }
 
-            key = to_pascal_case_parts(key, '-');
             data.insert(key.to_uppercase(), (key, value.into()));
         }
 
+        if !unexpected.is_empty() {
+            return Err(LoadError::UnexpectedArguments {
+                message: format!(
+                    "Unexpected command line arguments: {}",
+                    unexpected.join(", ")
+                ),
+                args: unexpected,
+            });
+        }
+
+        if let Some(section) = &self.positional_args {
+            for (index, value) in positional.into_iter().enumerate() {
+                let key = ConfigurationPath::combine(&[section, &index.to_string()]);
+                data.insert(key.to_uppercase(), (key, value.into()));
+            }
+        }
+
         data.shrink_to_fit();
-        self.data = data;
+        self.data = data.into();
         Ok(())
     }
 
+    fn try_iter(&self) -> Option<ConfigurationPairs<'_>> {
+        Some(self.data.pairs())
+    }
+
     fn child_keys(&self, earlier_keys: &mut Vec<String>, parent_path: Option<&str>) {
-        accumulate_child_keys(&self.data, earlier_keys, parent_path)
+        self.data.child_keys(earlier_keys, parent_path)
     }
 }
 
@@ -124,6 +212,14 @@ pub struct CommandLineConfigurationSource {
 
     /// Gets or sets the command line arguments.
     pub args: Vec<String>,
+
+    /// Gets or sets the key of the section the positional arguments are placed in, such as
+    /// `args`. The default value is `None`, which ignores positional arguments.
+    pub positional_args: Option<String>,
+
+    /// Gets or sets a value indicating whether arguments that do not match a switch mapping
+    /// are rejected. The default value is `false`.
+    pub strict: bool,
 }
 
 impl CommandLineConfigurationSource {
@@ -137,7 +233,8 @@ impl CommandLineConfigurationSource {
     /// # Remarks
     ///
     /// Only switch mapping keys that start with `--` or `-` are acceptable. Command
-    /// line arguments may start with `--`, `-`, or `/`.
+    /// line arguments may start with `--`, `-`, or `/`. The arguments after the `--`
+    /// separator are never interpreted as switches.
     pub fn new<I, S1, S2>(args: I, switch_mappings: &[(S2, S2)]) -> Self
     where
         I: Iterator<Item = S1>,
@@ -151,8 +248,26 @@ impl CommandLineConfigurationSource {
                 .filter(|m| m.0.as_ref().starts_with("--") || m.0.as_ref().starts_with('-'))
                 .map(|(k, v)| (k.as_ref().to_uppercase(), v.as_ref().to_owned()))
                 .collect(),
+            positional_args: None,
+            strict: false,
         }
     }
+
+    /// Captures the positional arguments under the specified section.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the section the positional arguments are placed in, such as `args`
+    pub fn with_positional_args<S: Into<String>>(mut self, key: S) -> Self {
+        self.positional_args = Some(key.into());
+        self
+    }
+
+    /// Rejects the arguments that do not match a switch mapping.
+    pub fn strict(mut self) -> Self {
+        self.strict = true;
+        self
+    }
 }
 
 impl<I, S> From<I> for CommandLineConfigurationSource
@@ -168,10 +283,18 @@ where
 
 impl ConfigurationSource for CommandLineConfigurationSource {
     fn build(&self, _builder: &dyn ConfigurationBuilder) -> Box<dyn ConfigurationProvider> {
-        Box::new(CommandLineConfigurationProvider::new(
-            self.args.clone(),
-            self.switch_mappings.clone(),
-        ))
+        let mut provider =
+            CommandLineConfigurationProvider::new(self.args.clone(), self.switch_mappings.clone());
+
+        if let Some(key) = &self.positional_args {
+            provider = provider.with_positional_args(key.clone());
+        }
+
+        if self.strict {
+            provider = provider.strict();
+        }
+
+        Box::new(provider)
     }
 }
 
@@ -182,6 +305,10 @@ pub mod ext {
     /// Defines extension methods for [`ConfigurationBuilder`](crate::ConfigurationBuilder).
     pub trait CommandLineConfigurationBuilderExtensions {
         /// Adds the command line configuration source.
+        ///
+        /// # Remarks
+        ///
+        /// The name of the program, which is the first command line argument, is skipped.
         fn add_command_line(&mut self) -> &mut Self;
 
         /// Adds the command line configuration source.
@@ -189,41 +316,73 @@ pub mod ext {
         /// # Arguments
         ///
         /// * `switch_mappings` - The mapping of switches to configuration values
+        ///
+        /// # Remarks
+        ///
+        /// The name of the program, which is the first command line argument, is skipped.
         fn add_command_line_map<S: AsRef<str>>(&mut self, switch_mappings: &[(S, S)]) -> &mut Self;
+
+        /// Adds the command line configuration source with the positional arguments.
+        ///
+        /// # Arguments
+        ///
+        /// * `key` - The key of the section the positional arguments are placed in, such as `args`
+        ///
+        /// # Remarks
+        ///
+        /// The name of the program, which is the first command line argument, is not a
+        /// positional argument.
+        fn add_command_line_with_positional_args(&mut self, key: &str) -> &mut Self;
     }
 
     impl CommandLineConfigurationBuilderExtensions for dyn ConfigurationBuilder + '_ {
         fn add_command_line(&mut self) -> &mut Self {
             self.add(Box::new(CommandLineConfigurationSource::from(
-                std::env::args(),
+                std::env::args().skip(1),
             )));
             self
         }
 
         fn add_command_line_map<S: AsRef<str>>(&mut self, switch_mappings: &[(S, S)]) -> &mut Self {
             self.add(Box::new(CommandLineConfigurationSource::new(
-                std::env::args(),
+                std::env::args().skip(1),
                 switch_mappings,
             )));
             self
         }
+
+        fn add_command_line_with_positional_args(&mut self, key: &str) -> &mut Self {
+            self.add(Box::new(
+                CommandLineConfigurationSource::from(std::env::args().skip(1))
+                    .with_positional_args(key),
+            ));
+            self
+        }
     }
 
     impl<T: ConfigurationBuilder> CommandLineConfigurationBuilderExtensions for T {
         fn add_command_line(&mut self) -> &mut Self {
             self.add(Box::new(CommandLineConfigurationSource::from(
-                std::env::args(),
+                std::env::args().skip(1),
             )));
             self
         }
 
         fn add_command_line_map<S: AsRef<str>>(&mut self, switch_mappings: &[(S, S)]) -> &mut Self {
             self.add(Box::new(CommandLineConfigurationSource::new(
-                std::env::args(),
+                std::env::args().skip(1),
                 switch_mappings,
             )));
             self
         }
+
+        fn add_command_line_with_positional_args(&mut self, key: &str) -> &mut Self {
+            self.add(Box::new(
+                CommandLineConfigurationSource::from(std::env::args().skip(1))
+                    .with_positional_args(key),
+            ));
+            self
+        }
     }
 }
 
@@ -301,6 +460,124 @@ mod tests {
         assert_eq!(provider.get("Key5").unwrap().as_str(), "Value5");
     }
 
+    #[test]
+    fn load_should_not_parse_switches_after_separator() {
+        // arrange
+        let args = ["--Key1", "Value1", "--", "--Key2", "Value2"].iter();
+        let source = CommandLineConfigurationSource::from(args);
+        let mut provider = source.build(&TestConfigurationBuilder);
+
+        // act
+        provider.load().unwrap();
+
+        // assert
+        assert_eq!(provider.get("Key1").unwrap().as_str(), "Value1");
+        assert!(provider.get("Key2").is_none());
+    }
+
+    #[test]
+    fn load_should_capture_positional_arguments() {
+        // arrange
+        let args = [
+            "Bogus1",
+            "--Key1",
+            "Value1",
+            "Bogus2",
+            "--",
+            "--Key2=Value2",
+            "Bogus3",
+        ]
+        .iter();
+        let source = CommandLineConfigurationSource::from(args).with_positional_args("args");
+        let mut provider = source.build(&TestConfigurationBuilder);
+        let mut child_keys = Vec::new();
+
+        // act
+        provider.load().unwrap();
+        provider.child_keys(&mut child_keys, Some("args"));
+
+        // assert
+        assert_eq!(provider.get("Key1").unwrap().as_str(), "Value1");
+        assert!(provider.get("Key2").is_none());
+        assert_eq!(provider.get("args:0").unwrap().as_str(), "Bogus1");
+        assert_eq!(provider.get("args:1").unwrap().as_str(), "Bogus2");
+        assert_eq!(provider.get("args:2").unwrap().as_str(), "--Key2=Value2");
+        assert_eq!(provider.get("args:3").unwrap().as_str(), "Bogus3");
+        assert_eq!(child_keys.len(), 4);
+    }
+
+    #[test]
+    fn load_should_map_switches_to_nested_keys() {
+        // arrange
+        let args = ["--db-host", "localhost", "--db-port=5432"].iter();
+        let switch_mappings = [
+            ("--db-host", "Database:Host"),
+            ("--db-port", "Database:Port"),
+        ];
+        let source = CommandLineConfigurationSource::new(args, &switch_mappings);
+        let mut provider = source.build(&TestConfigurationBuilder);
+        let mut child_keys = Vec::new();
+
+        // act
+        provider.load().unwrap();
+        provider.child_keys(&mut child_keys, Some("Database"));
+
+        // assert
+        assert_eq!(provider.get("Database:Host").unwrap().as_str(), "localhost");
+        assert_eq!(provider.get("Database:Port").unwrap().as_str(), "5432");
+        assert_eq!(child_keys.len(), 2);
+    }
+
+    #[test]
+    fn load_should_reject_unexpected_arguments_in_strict_mode() {
+        // arrange
+        let args = [
+            "--db-host",
+            "localhost",
+            "--Bogus1",
+            "Value1",
+            "Bogus2",
+            "-b=Value3",
+        ]
+        .iter();
+        let switch_mappings = [("--db-host", "Database:Host")];
+        let source = CommandLineConfigurationSource::new(args, &switch_mappings).strict();
+        let mut provider = source.build(&TestConfigurationBuilder);
+
+        // act
+        let result = provider.load();
+
+        // assert
+        match result.unwrap_err() {
+            LoadError::UnexpectedArguments { message, args } => {
+                assert_eq!(
+                    message,
+                    "Unexpected command line arguments: --Bogus1, Bogus2, -b=Value3"
+                );
+                assert_eq!(args, ["--Bogus1", "Bogus2", "-b=Value3"]);
+            }
+            _ => panic!("expected unexpected arguments"),
+        }
+    }
+
+    #[test]
+    fn load_should_accept_captured_positional_arguments_in_strict_mode() {
+        // arrange
+        let args = ["--db-host", "localhost", "file.txt"].iter();
+        let switch_mappings = [("--db-host", "Database:Host")];
+        let source = CommandLineConfigurationSource::new(args, &switch_mappings)
+            .with_positional_args("args")
+            .strict();
+        let mut provider = source.build(&TestConfigurationBuilder);
+
+        // act
+        provider.load().unwrap();
+
+        // assert
+        assert_eq!(provider.get("Database:Host").unwrap().as_str(), "localhost");
+        assert_eq!(provider.get("args:0").unwrap().as_str(), "file.txt");
+    }
+
     #[test]
     fn load_should_process_key_value_pairs_without_mappings() {
         // arrange
@@ -0,0 +1,162 @@
+use crate::{
+    prioritize, Configuration, ConfigurationBuilder, ConfigurationProvider, ConfigurationSource,
+    DefaultConfigurationRoot, Value,
+};
+
+type Predicate = Box<dyn Fn(&dyn Configuration) -> bool>;
+
+// provides no values for a source whose condition is not met
+struct EmptyProvider;
+
+impl ConfigurationProvider for EmptyProvider {
+    fn name(&self) -> &str {
+        "Empty"
+    }
+
+    fn get(&self, _key: &str) -> Option<Value> {
+        None
+    }
+
+    fn child_keys(&self, _earlier_keys: &mut Vec<String>, _parent_path: Option<&str>) {}
+}
+
+/// Represents a [`ConfigurationSource`](crate::ConfigurationSource) that is only used when a
+/// condition is met.
+///
+/// # Remarks
+///
+/// The condition is evaluated when the source is built using the configuration composed from the
+/// sources that precede it, which enables a configuration value, such as a feature flag, to
+/// determine whether the source is used. When the condition is not met, the source provides no
+/// values. The condition is evaluated against an empty configuration if the source is not added
+/// directly to the [`ConfigurationBuilder`](crate::ConfigurationBuilder); for example, when it is
+/// wrapped by another source.
+pub struct ConditionalConfigurationSource {
+    predicate: Predicate,
+    inner: Box<dyn ConfigurationSource>,
+}
+
+impl ConditionalConfigurationSource {
+    /// Initializes a new conditional configuration source.
+    ///
+    /// # Arguments
+    ///
+    /// * `predicate` - The function that determines whether the source is used from the
+    ///   configuration composed from the preceding sources
+    /// * `inner` - The inner [`ConfigurationSource`](crate::ConfigurationSource) to use
+    pub fn new<F>(predicate: F, inner: Box<dyn ConfigurationSource>) -> Self
+    where
+        F: Fn(&dyn Configuration) -> bool + 'static,
+    {
+        Self {
+            predicate: Box::new(predicate),
+            inner,
+        }
+    }
+
+    fn is_enabled(&self, builder: &dyn ConfigurationBuilder) -> bool {
+        let sources = prioritize(builder.default_sources().iter().chain(builder.sources()));
+        let me = self as *const Self as *const ();
+        let index = sources
+            .iter()
+            .position(|source| std::ptr::eq(*source as *const _ as *const (), me))
+            .unwrap_or_default();
+        let providers = sources
+            .into_iter()
+            .take(index)
+            .map(|source| source.build(builder))
+            .collect();
+
+        // a preceding source that fails to load is reported when the configuration is built
+        DefaultConfigurationRoot::new(providers)
+            .map(|root| (self.predicate)(&root))
+            .unwrap_or(false)
+    }
+}
+
+impl ConfigurationSource for ConditionalConfigurationSource {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn build(&self, builder: &dyn ConfigurationBuilder) -> Box<dyn ConfigurationProvider> {
+        if self.is_enabled(builder) {
+            self.inner.build(builder)
+        } else {
+            Box::new(EmptyProvider)
+        }
+    }
+
+    fn priority(&self) -> i32 {
+        self.inner.priority()
+    }
+}
+
+pub mod ext {
+
+    use super::*;
+
+    /// Defines extension methods to conditionally add a
+    /// [`ConfigurationSource`](crate::ConfigurationSource) to a
+    /// [`ConfigurationBuilder`](crate::ConfigurationBuilder).
+    pub trait ConditionalConfigurationBuilderExtensions {
+        /// Adds the configuration source if the specified condition is met.
+        ///
+        /// # Arguments
+        ///
+        /// * `condition` - Indicates whether the source is added
+        /// * `source` - The [`ConfigurationSource`](crate::ConfigurationSource) to add
+        fn add_if(&mut self, condition: bool, source: Box<dyn ConfigurationSource>) -> &mut Self;
+
+        /// Adds the configuration source, which is only used if the specified predicate is met.
+        ///
+        /// # Arguments
+        ///
+        /// * `predicate` - The function that determines whether the source is used from the
+        ///   configuration composed from the sources added before it
+        /// * `source` - The [`ConfigurationSource`](crate::ConfigurationSource) to add
+        fn add_when<F>(&mut self, predicate: F, source: Box<dyn ConfigurationSource>) -> &mut Self
+        where
+            F: Fn(&dyn Configuration) -> bool + 'static;
+    }
+
+    impl ConditionalConfigurationBuilderExtensions for dyn ConfigurationBuilder + '_ {
+        fn add_if(&mut self, condition: bool, source: Box<dyn ConfigurationSource>) -> &mut Self {
+            if condition {
+                self.add(source);
+            }
+
+            self
+        }
+
+        fn add_when<F>(&mut self, predicate: F, source: Box<dyn ConfigurationSource>) -> &mut Self
+        where
+            F: Fn(&dyn Configuration) -> bool + 'static,
+        {
+            self.add(Box::new(ConditionalConfigurationSource::new(
+                predicate, source,
+            )));
+            self
+        }
+    }
+
+    impl<T: ConfigurationBuilder> ConditionalConfigurationBuilderExtensions for T {
+        fn add_if(&mut self, condition: bool, source: Box<dyn ConfigurationSource>) -> &mut Self {
+            if condition {
+                self.add(source);
+            }
+
+            self
+        }
+
+        fn add_when<F>(&mut self, predicate: F, source: Box<dyn ConfigurationSource>) -> &mut Self
+        where
+            F: Fn(&dyn Configuration) -> bool + 'static,
+        {
+            self.add(Box::new(ConditionalConfigurationSource::new(
+                predicate, source,
+            )));
+            self
+        }
+    }
+}
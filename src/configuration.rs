@@ -1,4 +1,7 @@
-use crate::{ConfigurationPath, ConfigurationSection, Value};
+use crate::{
+    ConfigValue, ConfigurationPath, ConfigurationSection, SparseArrayPolicy, Value,
+    ValueConversion, ValueRef,
+};
 use cfg_if::cfg_if;
 use tokens::ChangeToken;
 
@@ -13,6 +16,33 @@ cfg_if! {
             /// * `key` - The configuration key
             fn get(&self, key: &str) -> Option<Value>;
 
+            /// Gets a reference to the configuration value.
+            ///
+            /// # Arguments
+            ///
+            /// * `key` - The configuration key
+            ///
+            /// # Remarks
+            ///
+            /// The default implementation defers to [`get`](Configuration::get).
+            fn get_str(&self, key: &str) -> Option<ValueRef<'_>> {
+                self.get(key).map(ValueRef::Shared)
+            }
+
+            /// Gets the typed configuration value.
+            ///
+            /// # Arguments
+            ///
+            /// * `key` - The configuration key
+            ///
+            /// # Remarks
+            ///
+            /// The default implementation defers to [`get`](Configuration::get) and always
+            /// produces [`ConfigValue::String`](crate::ConfigValue::String).
+            fn get_typed(&self, key: &str) -> Option<ConfigValue> {
+                self.get(key).map(ConfigValue::String)
+            }
+
             /// Gets a [`ConfigurationSection`](crate::ConfigurationSection) with the specified key.
             fn section(&self, key: &str) -> Box<dyn ConfigurationSection>;
 
@@ -22,6 +52,39 @@ cfg_if! {
             /// Returns a [`ChangeToken`](tokens::ChangeToken) that can be used to observe when this configuration is reloaded.
             fn reload_token(&self) -> Box<dyn ChangeToken>;
 
+            /// Gets the [`ValueConversion`](crate::ValueConversion) applied to values before they are parsed.
+            ///
+            /// # Remarks
+            ///
+            /// The default implementation returns [`ValueConversion::Strict`](crate::ValueConversion::Strict).
+            fn value_conversion(&self) -> ValueConversion {
+                ValueConversion::Strict
+            }
+
+            /// Gets the [`SparseArrayPolicy`](crate::SparseArrayPolicy) used to bind arrays whose indices have gaps.
+            ///
+            /// # Remarks
+            ///
+            /// The default implementation returns [`SparseArrayPolicy::Compact`](crate::SparseArrayPolicy::Compact).
+            fn sparse_array_policy(&self) -> SparseArrayPolicy {
+                SparseArrayPolicy::Compact
+            }
+
+            /// Gets a value indicating whether the value of the specified key is sensitive, such as
+            /// a password, and must be redacted in diagnostic output.
+            ///
+            /// # Arguments
+            ///
+            /// * `key` - The key to evaluate
+            ///
+            /// # Remarks
+            ///
+            /// The default implementation does not consider any key sensitive.
+            fn is_sensitive(&self, key: &str) -> bool {
+                let _ = key;
+                false
+            }
+
             /// Attempts to convert the [`Configuration`] as a [`ConfigurationSection`](crate::ConfigurationSection).
             fn as_section(&self) -> Option<&dyn ConfigurationSection> {
                 None
@@ -44,6 +107,33 @@ cfg_if! {
             /// * `key` - The configuration key
             fn get(&self, key: &str) -> Option<Value>;
 
+            /// Gets a reference to the configuration value.
+            ///
+            /// # Arguments
+            ///
+            /// * `key` - The configuration key
+            ///
+            /// # Remarks
+            ///
+            /// The default implementation defers to [`get`](Configuration::get).
+            fn get_str(&self, key: &str) -> Option<ValueRef<'_>> {
+                self.get(key).map(ValueRef::Shared)
+            }
+
+            /// Gets the typed configuration value.
+            ///
+            /// # Arguments
+            ///
+            /// * `key` - The configuration key
+            ///
+            /// # Remarks
+            ///
+            /// The default implementation defers to [`get`](Configuration::get) and always
+            /// produces [`ConfigValue::String`](crate::ConfigValue::String).
+            fn get_typed(&self, key: &str) -> Option<ConfigValue> {
+                self.get(key).map(ConfigValue::String)
+            }
+
             /// Gets a [`ConfigurationSection`](crate::ConfigurationSection) with the specified key.
             fn section(&self, key: &str) -> Box<dyn ConfigurationSection>;
 
@@ -53,6 +143,39 @@ cfg_if! {
             /// Returns a [`ChangeToken`](tokens::ChangeToken) that can be used to observe when this configuration is reloaded.
             fn reload_token(&self) -> Box<dyn ChangeToken>;
 
+            /// Gets the [`ValueConversion`](crate::ValueConversion) applied to values before they are parsed.
+            ///
+            /// # Remarks
+            ///
+            /// The default implementation returns [`ValueConversion::Strict`](crate::ValueConversion::Strict).
+            fn value_conversion(&self) -> ValueConversion {
+                ValueConversion::Strict
+            }
+
+            /// Gets the [`SparseArrayPolicy`](crate::SparseArrayPolicy) used to bind arrays whose indices have gaps.
+            ///
+            /// # Remarks
+            ///
+            /// The default implementation returns [`SparseArrayPolicy::Compact`](crate::SparseArrayPolicy::Compact).
+            fn sparse_array_policy(&self) -> SparseArrayPolicy {
+                SparseArrayPolicy::Compact
+            }
+
+            /// Gets a value indicating whether the value of the specified key is sensitive, such as
+            /// a password, and must be redacted in diagnostic output.
+            ///
+            /// # Arguments
+            ///
+            /// * `key` - The key to evaluate
+            ///
+            /// # Remarks
+            ///
+            /// The default implementation does not consider any key sensitive.
+            fn is_sensitive(&self, key: &str) -> bool {
+                let _ = key;
+                false
+            }
+
             /// Attempts to convert the [`Configuration`] as a [`ConfigurationSection`](crate::ConfigurationSection).
             fn as_section(&self) -> Option<&dyn ConfigurationSection> {
                 None
@@ -73,6 +196,7 @@ pub struct ConfigurationIterator {
     stack: Vec<Box<dyn ConfigurationSection>>,
     first: Option<(String, Value)>,
     prefix_length: usize,
+    leaves_only: bool,
 }
 
 impl ConfigurationIterator {
@@ -102,8 +226,19 @@ impl ConfigurationIterator {
             stack,
             first,
             prefix_length,
+            leaves_only: false,
         }
     }
+
+    /// Configures the iterator to only yield leaf values, skipping the sections that have children.
+    pub fn leaves_only(mut self) -> Self {
+        if !self.stack.is_empty() {
+            self.first = None;
+        }
+
+        self.leaves_only = true;
+        self
+    }
 }
 
 impl Iterator for ConfigurationIterator {
@@ -115,7 +250,14 @@ impl Iterator for ConfigurationIterator {
         }
 
         while let Some(config) = self.stack.pop() {
-            self.stack.extend(config.children().into_iter());
+            let children = config.children();
+            let leaf = children.is_empty();
+
+            self.stack.extend(children.into_iter());
+
+            if self.leaves_only && !leaf {
+                continue;
+            }
 
             if let Some(section) = config.as_section() {
                 let key = section.path()[self.prefix_length..].to_owned();
@@ -127,3 +269,94 @@ impl Iterator for ConfigurationIterator {
         None
     }
 }
+
+pub mod ext {
+
+    use super::*;
+    use std::str::FromStr;
+
+    /// Defines extension methods to iterate the leaf values of a [`Configuration`].
+    pub trait ConfigurationIteratorExtensions {
+        /// Gets an iterator of the key/value pairs of the sections that do not have children.
+        ///
+        /// # Arguments
+        ///
+        /// * `path` - The type of [`ConfigurationPath`] used when iterating
+        fn iter_leaves(
+            &self,
+            path: Option<ConfigurationPath>,
+        ) -> Box<dyn Iterator<Item = (String, Value)>>;
+
+        /// Gets an iterator of the parsed key/value pairs of the sections that do not have
+        /// children.
+        ///
+        /// # Arguments
+        ///
+        /// * `path` - The type of [`ConfigurationPath`] used when iterating
+        ///
+        /// # Remarks
+        ///
+        /// A value that cannot be parsed is parsed again after applying the
+        /// [`ValueConversion`](crate::ValueConversion) of the configuration. Values that still
+        /// cannot be parsed are skipped.
+        fn iter_as<T: FromStr + 'static>(
+            &self,
+            path: Option<ConfigurationPath>,
+        ) -> Box<dyn Iterator<Item = (String, T)>>;
+    }
+
+    fn leaves(
+        configuration: &dyn Configuration,
+        path: Option<ConfigurationPath>,
+    ) -> ConfigurationIterator {
+        ConfigurationIterator::new(configuration, path.unwrap_or(ConfigurationPath::Absolute))
+            .leaves_only()
+    }
+
+    fn parsed<T: FromStr + 'static>(
+        configuration: &dyn Configuration,
+        path: Option<ConfigurationPath>,
+    ) -> Box<dyn Iterator<Item = (String, T)>> {
+        let conversion = configuration.value_conversion();
+
+        Box::new(leaves(configuration, path).filter_map(move |(key, value)| {
+            let parsed = T::from_str(&value)
+                .ok()
+                .or_else(|| T::from_str(conversion.convert(&value)?).ok())?;
+
+            Some((key, parsed))
+        }))
+    }
+
+    impl ConfigurationIteratorExtensions for dyn Configuration + '_ {
+        fn iter_leaves(
+            &self,
+            path: Option<ConfigurationPath>,
+        ) -> Box<dyn Iterator<Item = (String, Value)>> {
+            Box::new(leaves(self, path))
+        }
+
+        fn iter_as<T: FromStr + 'static>(
+            &self,
+            path: Option<ConfigurationPath>,
+        ) -> Box<dyn Iterator<Item = (String, T)>> {
+            parsed(self, path)
+        }
+    }
+
+    impl<C: Configuration> ConfigurationIteratorExtensions for C {
+        fn iter_leaves(
+            &self,
+            path: Option<ConfigurationPath>,
+        ) -> Box<dyn Iterator<Item = (String, Value)>> {
+            Box::new(leaves(self, path))
+        }
+
+        fn iter_as<T: FromStr + 'static>(
+            &self,
+            path: Option<ConfigurationPath>,
+        ) -> Box<dyn Iterator<Item = (String, T)>> {
+            parsed(self, path)
+        }
+    }
+}
@@ -0,0 +1,198 @@
+use crate::{Configuration, ConfigurationSection, Value};
+
+const SECTION: &str = "ConnectionStrings";
+
+/// Represents the key/value pairs of a connection string, such as
+/// `Server=localhost;Database=app;User Id=admin`.
+///
+/// # Remarks
+///
+/// Pairs are separated by `;` and keys are separated from values by `=`. A value can be
+/// enclosed in single or double quotes so that it can contain `;`. Keys are case-insensitive.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ConnectionString {
+    pairs: Vec<(String, String)>,
+}
+
+impl ConnectionString {
+    /// Parses the specified connection string.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The connection string to parse
+    ///
+    /// # Remarks
+    ///
+    /// Empty pairs are ignored. A key without a value has an empty value. When a key is
+    /// repeated, the last value wins.
+    pub fn parse(text: &str) -> Self {
+        let mut pairs: Vec<(String, String)> = Vec::new();
+
+        for pair in split(text) {
+            let (key, value) = match pair.find('=') {
+                Some(index) => (pair[..index].trim(), unquote(pair[(index + 1)..].trim())),
+                _ => (pair.trim(), ""),
+            };
+
+            if key.is_empty() {
+                continue;
+            }
+
+            if let Some(existing) = pairs.iter_mut().find(|p| p.0.eq_ignore_ascii_case(key)) {
+                existing.1 = value.to_owned();
+            } else {
+                pairs.push((key.to_owned(), value.to_owned()));
+            }
+        }
+
+        Self { pairs }
+    }
+
+    /// Gets the value for the specified key, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The case-insensitive key of the value to retrieve
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.pairs
+            .iter()
+            .find(|pair| pair.0.eq_ignore_ascii_case(key))
+            .map(|pair| pair.1.as_str())
+    }
+
+    /// Gets the number of key/value pairs.
+    pub fn len(&self) -> usize {
+        self.pairs.len()
+    }
+
+    /// Gets a value indicating whether there are no key/value pairs.
+    pub fn is_empty(&self) -> bool {
+        self.pairs.is_empty()
+    }
+
+    /// Returns an iterator over the key/value pairs in the order they were defined.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.pairs
+            .iter()
+            .map(|pair| (pair.0.as_str(), pair.1.as_str()))
+    }
+}
+
+// splits on ';' outside of quotes
+fn split(text: &str) -> Vec<&str> {
+    let mut pairs = Vec::new();
+    let mut quote = None;
+    let mut start = 0;
+
+    for (index, ch) in text.char_indices() {
+        match ch {
+            '"' | '\'' if quote == Some(ch) => quote = None,
+            '"' | '\'' if quote.is_none() => quote = Some(ch),
+            ';' if quote.is_none() => {
+                pairs.push(&text[start..index]);
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+
+    pairs.push(&text[start..]);
+    pairs.retain(|pair| !pair.trim().is_empty());
+    pairs
+}
+
+fn unquote(value: &str) -> &str {
+    for quote in ['"', '\''] {
+        if value.len() > 1 && value.starts_with(quote) && value.ends_with(quote) {
+            return &value[1..(value.len() - 1)];
+        }
+    }
+
+    value
+}
+
+/// Represents the conventional `ConnectionStrings` section of a
+/// [`Configuration`](crate::Configuration).
+pub struct ConnectionStrings {
+    section: Box<dyn ConfigurationSection>,
+}
+
+impl ConnectionStrings {
+    /// Initializes new connection strings from the specified configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `configuration` - The [`Configuration`](crate::Configuration) containing the
+    ///   `ConnectionStrings` section
+    pub fn new(configuration: &dyn Configuration) -> Self {
+        Self {
+            section: configuration.section(SECTION),
+        }
+    }
+
+    /// Gets the connection string with the specified name, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The case-insensitive name of the connection string
+    pub fn get(&self, name: &str) -> Option<Value> {
+        self.section.get(name)
+    }
+
+    /// Gets and parses the connection string with the specified name, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The case-insensitive name of the connection string
+    pub fn parse(&self, name: &str) -> Option<ConnectionString> {
+        self.get(name).map(|value| ConnectionString::parse(&value))
+    }
+
+    /// Gets the names of all connection strings.
+    pub fn names(&self) -> Vec<String> {
+        self.section
+            .children()
+            .iter()
+            .map(|child| child.key().to_owned())
+            .collect()
+    }
+}
+
+pub mod ext {
+
+    use super::*;
+
+    /// Defines connection string extension methods for [`Configuration`](crate::Configuration).
+    pub trait ConnectionStringExtensions {
+        /// Gets the connection string with the specified name from the `ConnectionStrings`
+        /// section, if any.
+        ///
+        /// # Arguments
+        ///
+        /// * `name` - The case-insensitive name of the connection string
+        fn connection_string(&self, name: &str) -> Option<Value>;
+
+        /// Gets the [`ConnectionStrings`] of the configuration.
+        fn connection_strings(&self) -> ConnectionStrings;
+    }
+
+    impl ConnectionStringExtensions for dyn Configuration + '_ {
+        fn connection_string(&self, name: &str) -> Option<Value> {
+            self.connection_strings().get(name)
+        }
+
+        fn connection_strings(&self) -> ConnectionStrings {
+            ConnectionStrings::new(self)
+        }
+    }
+
+    impl<T: Configuration> ConnectionStringExtensions for T {
+        fn connection_string(&self, name: &str) -> Option<Value> {
+            self.connection_strings().get(name)
+        }
+
+        fn connection_strings(&self) -> ConnectionStrings {
+            ConnectionStrings::new(self)
+        }
+    }
+}
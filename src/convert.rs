@@ -0,0 +1,221 @@
+use std::convert::TryFrom;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+/// Defines the possible conversions applied to configuration values before they are parsed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ValueConversion {
+    /// Indicates values are parsed as-is; for example, only `true` and `false` are booleans.
+    Strict,
+
+    /// Indicates human-friendly values are also accepted; for example, `yes`, `no`, `on`,
+    /// `off`, `1`, and `0` are booleans.
+    Lenient,
+}
+
+impl ValueConversion {
+    /// Converts the specified value into its canonical form, if required.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value to convert
+    ///
+    /// # Remarks
+    ///
+    /// `None` is returned if the value does not have an alternate, canonical form.
+    pub fn convert(&self, value: &str) -> Option<&'static str> {
+        if *self == Self::Strict {
+            return None;
+        }
+
+        match value.trim().to_lowercase().as_str() {
+            "true" | "yes" | "y" | "on" | "1" => Some("true"),
+            "false" | "no" | "n" | "off" | "0" => Some("false"),
+            _ => None,
+        }
+    }
+}
+
+impl Default for ValueConversion {
+    fn default() -> Self {
+        Self::Strict
+    }
+}
+
+/// Represents the error that occurs when a [`ByteSize`] cannot be parsed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseByteSizeError(String);
+
+impl Display for ParseByteSizeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "'{}' is not a valid byte size", self.0)
+    }
+}
+
+impl std::error::Error for ParseByteSizeError {}
+
+/// Represents a number of bytes that can be expressed with a unit, such as `10MB` or `4KiB`.
+///
+/// # Remarks
+///
+/// Decimal units (`KB`, `MB`, `GB`, `TB`, `PB`) are multiples of 1000 and binary units
+/// (`KiB`, `MiB`, `GiB`, `TiB`, `PiB`) are multiples of 1024. Units are case-insensitive and a
+/// number without a unit is in bytes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ByteSize(pub u64);
+
+impl ByteSize {
+    /// Gets the number of bytes.
+    pub fn as_u64(&self) -> u64 {
+        self.0
+    }
+}
+
+impl From<u64> for ByteSize {
+    fn from(value: u64) -> Self {
+        Self(value)
+    }
+}
+
+impl From<ByteSize> for u64 {
+    fn from(value: ByteSize) -> Self {
+        value.0
+    }
+}
+
+impl Display for ByteSize {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}B", self.0)
+    }
+}
+
+impl FromStr for ByteSize {
+    type Err = ParseByteSizeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || ParseByteSizeError(s.to_owned());
+        let text = s.trim();
+        let index = text
+            .find(|c: char| !c.is_ascii_digit() && c != '.')
+            .unwrap_or(text.len());
+        let (number, unit) = text.split_at(index);
+        let unit: u128 = match unit.trim_start().to_lowercase().as_str() {
+            "" | "b" => 1,
+            "kb" => 1_000,
+            "mb" => 1_000_000,
+            "gb" => 1_000_000_000,
+            "tb" => 1_000_000_000_000,
+            "pb" => 1_000_000_000_000_000,
+            "kib" => 1 << 10,
+            "mib" => 1 << 20,
+            "gib" => 1 << 30,
+            "tib" => 1 << 40,
+            "pib" => 1 << 50,
+            _ => return Err(error()),
+        };
+        let (whole, fraction) = match number.find('.') {
+            Some(index) => (&number[..index], &number[(index + 1)..]),
+            _ => (number, ""),
+        };
+
+        if whole.is_empty() && fraction.is_empty() {
+            return Err(error());
+        }
+
+        let mut bytes = if whole.is_empty() {
+            0
+        } else {
+            whole.parse::<u128>().map_err(|_| error())? * unit
+        };
+
+        if !fraction.is_empty() {
+            let digits = fraction.len().min(18);
+            let fraction = fraction[..digits].parse::<u128>().map_err(|_| error())?;
+            bytes += fraction * unit / 10u128.pow(digits as u32);
+        }
+
+        u64::try_from(bytes).map(Self).map_err(|_| error())
+    }
+}
+
+#[cfg(feature = "binder")]
+impl<'de> serde::Deserialize<'de> for ByteSize {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ByteSizeVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for ByteSizeVisitor {
+            type Value = ByteSize;
+
+            fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+                formatter.write_str("a number of bytes or a size such as 10MB")
+            }
+
+            fn visit_u64<E: serde::de::Error>(self, v: u64) -> Result<Self::Value, E> {
+                Ok(ByteSize(v))
+            }
+
+            fn visit_i64<E: serde::de::Error>(self, v: i64) -> Result<Self::Value, E> {
+                u64::try_from(v)
+                    .map(ByteSize)
+                    .map_err(|_| E::custom(format_args!("{} is not a valid byte size", v)))
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                v.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_any(ByteSizeVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("512", 512 ; "bytes without unit")]
+    #[test_case("512B", 512 ; "bytes")]
+    #[test_case("10MB", 10_000_000 ; "megabytes")]
+    #[test_case("4KiB", 4096 ; "kibibytes")]
+    #[test_case("1.5 GiB", 1_610_612_736 ; "fractional gibibytes")]
+    #[test_case("2gb", 2_000_000_000 ; "lowercase unit")]
+    fn byte_size_should_parse_expected_value(text: &str, expected: u64) {
+        // arrange
+
+        // act
+        let size = text.parse::<ByteSize>();
+
+        // assert
+        assert_eq!(size, Ok(ByteSize(expected)));
+    }
+
+    #[test_case("" ; "empty")]
+    #[test_case("10XB" ; "unknown unit")]
+    #[test_case("MB" ; "missing number")]
+    fn byte_size_should_fail_for_invalid_value(text: &str) {
+        // arrange
+
+        // act
+        let size = text.parse::<ByteSize>();
+
+        // assert
+        assert!(size.is_err());
+    }
+
+    #[test_case("yes", Some("true") ; "yes")]
+    #[test_case("OFF", Some("false") ; "off")]
+    #[test_case("1", Some("true") ; "one")]
+    #[test_case("maybe", None ; "unknown")]
+    fn lenient_conversion_should_return_canonical_boolean(value: &str, expected: Option<&str>) {
+        // arrange
+        let conversion = ValueConversion::Lenient;
+
+        // act
+        let converted = conversion.convert(value);
+
+        // assert
+        assert_eq!(converted, expected);
+    }
+}
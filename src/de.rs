@@ -1,9 +1,12 @@
-use crate::{Configuration, ConfigurationSection};
+use crate::{
+    time::{parse_duration, parse_timestamp},
+    ConfigValue, Configuration, ConfigurationPath, ConfigurationSection, SparseArrayPolicy,
+};
 use serde::{
     de::{
         self,
         value::{MapDeserializer, SeqDeserializer},
-        IntoDeserializer, Visitor,
+        DeserializeSeed, IntoDeserializer, SeqAccess, Visitor,
     },
     Deserialize,
 };
@@ -61,6 +64,14 @@ macro_rules! forward_parsed_values {
             fn $method<V>(self, visitor: V) -> Result<V::Value, Self::Error>
                 where V: de::Visitor<'de>
             {
+                // typed values are visited directly to avoid a parse round-trip
+                match self.0.typed_value() {
+                    ConfigValue::Bool(value) => return visitor.visit_bool(value),
+                    ConfigValue::Int(value) => return visitor.visit_i64(value),
+                    ConfigValue::Float(value) => return visitor.visit_f64(value),
+                    _ => {}
+                }
+
                 match self.0.value().parse::<$ty>() {
                     Ok(val) => val.into_deserializer().$method(visitor),
                     Err(e) => Err(de::Error::custom(format_args!("{} while parsing value '{}' provided by {}", e, self.0.value(), self.0.key())))
@@ -70,11 +81,40 @@ macro_rules! forward_parsed_values {
     }
 }
 
+// integer keys are parsed, which binds an indexed section to a map such as BTreeMap<usize, T>
+macro_rules! forward_parsed_keys {
+    ($($ty:ident => $method:ident => $visit:ident,)*) => {
+        $(
+            fn $method<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+                where V: de::Visitor<'de>
+            {
+                match self.0.parse::<$ty>() {
+                    Ok(value) => visitor.$visit(value),
+                    _ => self.deserialize_any(visitor),
+                }
+            }
+        )*
+    }
+}
+
 // configuration is a key/value pair mapping of String: String or String: Vec<String>; however,
 // we need a surrogate type to implement forward the deserialization on to underlying primitives
 struct Key(String);
 struct Val(Box<dyn ConfigurationSection>);
 
+impl Key {
+    // map keys are unescaped and keep their original casing so that they match the keys in the
+    // configuration file; however, struct fields are matched without regard to casing
+    fn of(section: &dyn ConfigurationSection, fields: &[&str]) -> Self {
+        let key = ConfigurationPath::unescape(section.key());
+
+        match fields.iter().find(|field| field.eq_ignore_ascii_case(&key)) {
+            Some(field) => Self((*field).to_owned()),
+            _ => Self(key.into_owned()),
+        }
+    }
+}
+
 impl<'de> IntoDeserializer<'de, Error> for Key {
     type Deserializer = Self;
 
@@ -104,11 +144,169 @@ impl<'de> de::Deserializer<'de> for Key {
         visitor.visit_newtype_struct(self)
     }
 
+    forward_parsed_keys! {
+        u8 => deserialize_u8 => visit_u8,
+        u16 => deserialize_u16 => visit_u16,
+        u32 => deserialize_u32 => visit_u32,
+        u64 => deserialize_u64 => visit_u64,
+        i8 => deserialize_i8 => visit_i8,
+        i16 => deserialize_i16 => visit_i16,
+        i32 => deserialize_i32 => visit_i32,
+        i64 => deserialize_i64 => visit_i64,
+    }
+
     serde::forward_to_deserialize_any! {
         char str string unit seq option
         bytes byte_buf map unit_struct tuple_struct
         identifier tuple ignored_any enum
-        struct bool u8 u16 u32 u64 i8 i16 i32 i64 f32 f64
+        struct bool f32 f64
+    }
+}
+
+// the elements of an array where a missing element is None
+struct Elements(IntoIter<Option<Val>>);
+
+impl<'de> SeqAccess<'de> for Elements {
+    type Error = Error;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        match self.0.next() {
+            Some(Some(value)) => seed.deserialize(value).map(Some),
+            Some(None) => seed.deserialize(Gap).map(Some),
+            _ => Ok(None),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.0.len())
+    }
+}
+
+// deserializes a sequence, tuple, or fixed-size array from the children keyed by their ordinal
+fn elements<'de, V>(
+    children: Vec<Box<dyn ConfigurationSection>>,
+    policy: SparseArrayPolicy,
+    visitor: V,
+) -> Result<V::Value, Error>
+where
+    V: Visitor<'de>,
+{
+    let mut values: Vec<_> = children
+        .into_iter()
+        .filter(|c| c.key().parse::<usize>().is_ok())
+        .map(Val)
+        .collect();
+
+    // guarantee stable ordering by zero-based ordinal index; for example,
+    // Key:0
+    // Key:1
+    // Key:n
+    values.sort_by(|s1, s2| {
+        s1.0.key()
+            .parse::<usize>()
+            .unwrap()
+            .cmp(&s2.0.key().parse::<usize>().unwrap())
+    });
+
+    if policy == SparseArrayPolicy::Compact {
+        return de::Deserializer::deserialize_seq(
+            SeqDeserializer::new(values.into_iter()),
+            visitor,
+        );
+    }
+
+    let mut elements = Vec::with_capacity(values.len());
+
+    for value in values {
+        let index = value.0.key().parse::<usize>().unwrap();
+
+        if index != elements.len() {
+            if policy == SparseArrayPolicy::Error {
+                return Err(missing_index(elements.len(), value.0.as_ref()));
+            }
+
+            elements.resize_with(index, || None);
+        }
+
+        elements.push(Some(value));
+    }
+
+    visitor.visit_seq(Elements(elements.into_iter()))
+}
+
+macro_rules! forward_empty_values {
+    ($($method:ident => $visit:ident($($value:expr),*),)*) => {
+        $(
+            fn $method<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+                where V: de::Visitor<'de>
+            {
+                visitor.$visit($($value),*)
+            }
+        )*
+    }
+}
+
+// a missing array element, which is deserialized from an empty value
+struct Gap;
+
+impl<'de> de::Deserializer<'de> for Gap {
+    type Error = Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        visitor.visit_unit()
+    }
+
+    forward_empty_values! {
+        deserialize_bool => visit_bool(false),
+        deserialize_u8 => visit_u8(0),
+        deserialize_u16 => visit_u16(0),
+        deserialize_u32 => visit_u32(0),
+        deserialize_u64 => visit_u64(0),
+        deserialize_i8 => visit_i8(0),
+        deserialize_i16 => visit_i16(0),
+        deserialize_i32 => visit_i32(0),
+        deserialize_i64 => visit_i64(0),
+        deserialize_f32 => visit_f32(0.0),
+        deserialize_f64 => visit_f64(0.0),
+        deserialize_str => visit_str(""),
+        deserialize_string => visit_str(""),
+        deserialize_option => visit_none(),
+        deserialize_seq => visit_seq(Elements(Vec::new().into_iter())),
+        deserialize_map => visit_map(MapDeserializer::new(Vec::<(Key, Val)>::new().into_iter())),
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: serde::de::Visitor<'de>,
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_struct<V>(
+        self,
+        _name: &'static str,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_map(visitor)
+    }
+
+    serde::forward_to_deserialize_any! {
+        char unit bytes byte_buf unit_struct tuple_struct
+        identifier tuple ignored_any enum
     }
 }
 
@@ -124,6 +322,31 @@ impl<'de> de::Deserializer<'de> for Val {
     type Error = Error;
 
     fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        match self.0.typed_value() {
+            ConfigValue::Bool(value) => visitor.visit_bool(value),
+            ConfigValue::Int(value) => visitor.visit_i64(value),
+            ConfigValue::Float(value) => visitor.visit_f64(value),
+            ConfigValue::Null => visitor.visit_unit(),
+            _ => {
+                let children = self.0.children();
+
+                // a section with children is buffered as a whole, which is required by
+                // #[serde(flatten)] and untagged enums; otherwise, nested values are lost
+                if children.is_empty() {
+                    self.deserialize_string(visitor)
+                } else if children.iter().all(|c| c.key().parse::<usize>().is_ok()) {
+                    self.deserialize_seq(visitor)
+                } else {
+                    self.deserialize_map(visitor)
+                }
+            }
+        }
+    }
+
+    fn deserialize_string<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: de::Visitor<'de>,
     {
@@ -135,30 +358,37 @@ impl<'de> de::Deserializer<'de> for Val {
             .deserialize_any(visitor)
     }
 
+    fn deserialize_str<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        self.deserialize_string(visitor)
+    }
+
     fn deserialize_seq<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: de::Visitor<'de>,
     {
-        let mut values: Vec<_> = self
-            .0
-            .children()
-            .into_iter()
-            .take_while(|c| c.key().parse::<usize>().is_ok())
-            .map(|s| Val(s))
-            .collect();
+        elements(self.0.children(), self.0.sparse_array_policy(), visitor)
+    }
 
-        // guarantee stable ordering by zero-based ordinal index; for example,
-        // Key:0
-        // Key:1
-        // Key:n
-        values.sort_by(|s1, s2| {
-            s1.0.key()
-                .parse::<usize>()
-                .unwrap()
-                .cmp(&s2.0.key().parse::<usize>().unwrap())
-        });
+    fn deserialize_tuple<V>(self, _len: usize, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        self.deserialize_seq(visitor)
+    }
 
-        SeqDeserializer::new(values.into_iter()).deserialize_seq(visitor)
+    fn deserialize_tuple_struct<V>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        self.deserialize_seq(visitor)
     }
 
     fn deserialize_map<V>(self, visitor: V) -> Result<V::Value, Self::Error>
@@ -169,7 +399,7 @@ impl<'de> de::Deserializer<'de> for Val {
             .0
             .children()
             .into_iter()
-            .map(|section| (section.key().to_owned(), Val(section)));
+            .map(|section| (Key::of(section.as_ref(), &[]), Val(section)));
 
         MapDeserializer::new(values).deserialize_map(visitor)
     }
@@ -178,11 +408,36 @@ impl<'de> de::Deserializer<'de> for Val {
     where
         V: de::Visitor<'de>,
     {
-        visitor.visit_some(self)
+        if self.0.is_null() {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_bool<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        if let ConfigValue::Bool(value) = self.0.typed_value() {
+            return visitor.visit_bool(value);
+        }
+
+        let value = self.0.value();
+        let converted = self.0.value_conversion().convert(&value);
+
+        match converted.unwrap_or(&value).parse::<bool>() {
+            Ok(val) => visitor.visit_bool(val),
+            Err(e) => Err(de::Error::custom(format_args!(
+                "{} while parsing value '{}' provided by {}",
+                e,
+                value,
+                self.0.key()
+            ))),
+        }
     }
 
     forward_parsed_values! {
-        bool => deserialize_bool,
         u8 => deserialize_u8,
         u16 => deserialize_u16,
         u32 => deserialize_u32,
@@ -208,16 +463,40 @@ impl<'de> de::Deserializer<'de> for Val {
 
     fn deserialize_struct<V>(
         self,
-        _name: &'static str,
-        _fields: &'static [&'static str],
+        name: &'static str,
+        fields: &'static [&'static str],
         visitor: V,
     ) -> Result<V::Value, Self::Error>
     where
         V: Visitor<'de>,
     {
+        // std::time::{Duration, SystemTime} are serialized as structs, but are more
+        // naturally configured as text such as "30s" or "2024-01-02T03:04:05Z"
+        if let ("Duration", ["secs", "nanos"])
+        | ("SystemTime", ["secs_since_epoch", "nanos_since_epoch"]) = (name, fields)
+        {
+            if self.0.children().is_empty() {
+                let value = self.0.value();
+                let duration = if name == "Duration" {
+                    parse_duration(&value)
+                } else {
+                    parse_timestamp(&value)
+                }
+                .map_err(|e| {
+                    de::Error::custom(format_args!("{} provided by {}", e, self.0.key()))
+                })?;
+                let parts = vec![
+                    (fields[0], duration.as_secs()),
+                    (fields[1], duration.subsec_nanos() as u64),
+                ];
+
+                return MapDeserializer::new(parts.into_iter()).deserialize_map(visitor);
+            }
+        }
+
         let config = self.0.deref();
         let deserializer = Deserializer::new(config);
-        de::Deserializer::deserialize_any(deserializer, visitor)
+        de::Deserializer::deserialize_struct(deserializer, name, fields, visitor)
     }
 
     fn deserialize_enum<V>(
@@ -229,41 +508,127 @@ impl<'de> de::Deserializer<'de> for Val {
     where
         V: de::Visitor<'de>,
     {
-        visitor.visit_enum(self.0.value().deref().clone().into_deserializer())
+        let mut children = self.0.children();
+
+        // a unit variant is a value whereas any other variant is a section keyed by the
+        // variant name; for example, Store:File:Path
+        if children.is_empty() {
+            visitor.visit_enum(self.0.value().deref().clone().into_deserializer())
+        } else if children.len() == 1 {
+            visitor.visit_enum(Variant(children.remove(0)))
+        } else {
+            Err(de::Error::custom(format_args!(
+                "expected a single variant for '{}', but found {}",
+                self.0.key(),
+                children.len()
+            )))
+        }
+    }
+
+    // an ignored section is never read, so an invalid value cannot fail binding
+    fn deserialize_ignored_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_unit()
     }
 
     serde::forward_to_deserialize_any! {
-        char str string unit
-        bytes byte_buf unit_struct tuple_struct
-        identifier tuple ignored_any
+        char unit
+        bytes byte_buf unit_struct
+        identifier
     }
 }
 
-struct ConfigValues(IntoIter<Box<dyn ConfigurationSection>>);
+struct Variant(Box<dyn ConfigurationSection>);
+
+impl<'de> de::EnumAccess<'de> for Variant {
+    type Error = Error;
+    type Variant = Val;
+
+    fn variant_seed<V>(self, seed: V) -> Result<(V::Value, Self::Variant), Self::Error>
+    where
+        V: de::DeserializeSeed<'de>,
+    {
+        let key = Key(self.0.key().to_owned());
+        Ok((seed.deserialize(key)?, Val(self.0)))
+    }
+}
+
+impl<'de> de::VariantAccess<'de> for Val {
+    type Error = Error;
+
+    fn unit_variant(self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T>(self, seed: T) -> Result<T::Value, Self::Error>
+    where
+        T: de::DeserializeSeed<'de>,
+    {
+        seed.deserialize(self)
+    }
+
+    fn tuple_variant<V>(self, _len: usize, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        de::Deserializer::deserialize_seq(self, visitor)
+    }
+
+    fn struct_variant<V>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        let deserializer = Deserializer::new(self.0.deref());
+        de::Deserializer::deserialize_struct(deserializer, "", fields, visitor)
+    }
+}
+
+struct ConfigValues {
+    sections: IntoIter<Box<dyn ConfigurationSection>>,
+    fields: &'static [&'static str],
+}
 
 impl Iterator for ConfigValues {
     type Item = (Key, Val);
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.0
+        self.sections
             .next()
-            .map(|section| (Key(section.key().to_owned()), Val(section)))
+            .map(|section| (Key::of(section.as_ref(), self.fields), Val(section)))
     }
 }
 
-struct Deserializer<'de> {
-    inner: MapDeserializer<'de, ConfigValues, Error>,
+struct Deserializer {
+    children: Vec<Box<dyn ConfigurationSection>>,
+    policy: SparseArrayPolicy,
 }
 
-impl<'de> Deserializer<'de> {
+impl Deserializer {
     fn new(config: &dyn Configuration) -> Self {
-        Deserializer {
-            inner: MapDeserializer::new(ConfigValues(config.children().into_iter())),
+        Self {
+            children: config.children(),
+            policy: config.sparse_array_policy(),
         }
     }
+
+    fn values<'de>(
+        self,
+        fields: &'static [&'static str],
+    ) -> MapDeserializer<'de, ConfigValues, Error> {
+        MapDeserializer::new(ConfigValues {
+            sections: self.children.into_iter(),
+            fields,
+        })
+    }
 }
 
-impl<'de> de::Deserializer<'de> for Deserializer<'de> {
+impl<'de> de::Deserializer<'de> for Deserializer {
     type Error = Error;
 
     fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
@@ -277,14 +642,51 @@ impl<'de> de::Deserializer<'de> for Deserializer<'de> {
     where
         V: de::Visitor<'de>,
     {
-        visitor.visit_map(self.inner)
+        visitor.visit_map(self.values(&[]))
+    }
+
+    fn deserialize_struct<V>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        visitor.visit_map(self.values(fields))
+    }
+
+    fn deserialize_seq<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        elements(self.children, self.policy, visitor)
+    }
+
+    fn deserialize_tuple<V>(self, _len: usize, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        self.deserialize_seq(visitor)
     }
 
     serde::forward_to_deserialize_any! {
-        bool u8 u16 u32 u64 i8 i16 i32 i64 f32 f64 char str string unit seq
-        bytes byte_buf unit_struct tuple_struct
-        identifier tuple ignored_any option newtype_struct enum
-        struct
+        bool u8 u16 u32 u64 i8 i16 i32 i64 f32 f64 char str string unit
+        bytes byte_buf unit_struct
+        identifier ignored_any option newtype_struct enum
     }
 }
 
@@ -293,6 +695,13 @@ impl<'de> de::Deserializer<'de> for Deserializer<'de> {
 /// # Arguments
 ///
 /// * `configuration` - The [`Configuration`](crate::Configuration) to deserialize
+///
+/// # Remarks
+///
+/// Fields using `#[serde(flatten)]` as well as untagged, internally tagged, and adjacently
+/// tagged enums are supported; however, their values are buffered without their target
+/// type. Text values within these structures can only be bound to strings unless the
+/// source preserves their type, such as JSON.
 pub fn from_config<'a, T>(configuration: &'a dyn Configuration) -> Result<T, Error>
 where
     T: Deserialize<'a>,
@@ -314,3 +723,20 @@ where
         data,
     )?)
 }
+
+// creates the error for a missing array index, which is reported before the next element
+pub(crate) fn missing_index(index: usize, next: &dyn ConfigurationSection) -> Error {
+    Error::Custom(format!(
+        "the array index {} is missing before '{}'",
+        index,
+        next.path()
+    ))
+}
+
+// deserializes a section as a whole, including a section that only has a value
+pub(crate) fn from_section<T>(section: Box<dyn ConfigurationSection>) -> Result<T, Error>
+where
+    T: de::DeserializeOwned,
+{
+    T::deserialize(Val(section))
+}
@@ -0,0 +1,209 @@
+use crate::{
+    ConfigValue, ConfigurationBuilder, ConfigurationPairs, ConfigurationProvider,
+    ConfigurationSource, DefaultConfigurationBuilder, LoadError, LoadResult, Value,
+};
+use std::sync::Arc;
+use tokens::ChangeToken;
+
+/// Defines the behavior of an object that decrypts configuration values.
+///
+/// # Remarks
+///
+/// A decryptor for a specific algorithm, such as AES-GCM, is not provided because it requires a
+/// cryptography library. Implement this trait, or provide a function, that uses the library and
+/// key management of the application.
+pub trait ValueDecryptor: Send + Sync {
+    /// Gets a value indicating whether the specified value is encrypted.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The configuration value to evaluate
+    ///
+    /// # Remarks
+    ///
+    /// The default implementation considers any value starting with `enc:` to be encrypted.
+    fn is_encrypted(&self, value: &str) -> bool {
+        value.starts_with("enc:")
+    }
+
+    /// Decrypts the specified value.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The encrypted configuration value
+    ///
+    /// # Remarks
+    ///
+    /// A value that cannot be decrypted causes the provider to fail to load. The value is never
+    /// replaced by a value for the same key from a preceding provider.
+    fn decrypt(&self, value: &str) -> Option<String>;
+}
+
+impl<F: Fn(&str) -> Option<String> + Send + Sync> ValueDecryptor for F {
+    fn decrypt(&self, value: &str) -> Option<String> {
+        (self)(value)
+    }
+}
+
+/// Represents a [`ConfigurationProvider`](crate::ConfigurationProvider) that decrypts
+/// the encrypted values of another provider.
+pub struct DecryptedConfigurationProvider {
+    inner: Box<dyn ConfigurationProvider>,
+    decryptor: Arc<dyn ValueDecryptor>,
+}
+
+impl DecryptedConfigurationProvider {
+    /// Initializes a new decrypted configuration provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `inner` - The inner [`ConfigurationProvider`](crate::ConfigurationProvider) to decrypt
+    /// * `decryptor` - The [`ValueDecryptor`] used to decrypt values
+    pub fn new(inner: Box<dyn ConfigurationProvider>, decryptor: Arc<dyn ValueDecryptor>) -> Self {
+        Self { inner, decryptor }
+    }
+
+    fn decrypt(&self, value: Value) -> Option<Value> {
+        if self.decryptor.is_encrypted(&value) {
+            self.decryptor.decrypt(&value).map(Value::new)
+        } else {
+            Some(value)
+        }
+    }
+
+    fn undecryptable(&self, value: &str) -> bool {
+        self.decryptor.is_encrypted(value) && self.decryptor.decrypt(value).is_none()
+    }
+}
+
+impl ConfigurationProvider for DecryptedConfigurationProvider {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn get(&self, key: &str) -> Option<Value> {
+        self.inner.get(key).and_then(|value| self.decrypt(value))
+    }
+
+    fn get_typed(&self, key: &str) -> Option<ConfigValue> {
+        match self.inner.get_typed(key)? {
+            ConfigValue::String(value) => self.decrypt(value).map(ConfigValue::String),
+            other => Some(other),
+        }
+    }
+
+    fn is_removed(&self, key: &str) -> bool {
+        // a value that cannot be decrypted masks the values of preceding providers so that
+        // it is never silently replaced by a value that is not a secret
+        self.inner.is_removed(key)
+            || self
+                .inner
+                .get(key)
+                .map(|value| self.undecryptable(&value))
+                .unwrap_or_default()
+    }
+
+    fn reload_token(&self) -> Box<dyn ChangeToken> {
+        self.inner.reload_token()
+    }
+
+    fn load(&mut self) -> LoadResult {
+        self.inner.load()?;
+
+        if let Some(pairs) = self.inner.try_iter() {
+            let mut keys: Vec<_> = pairs
+                .filter(|(_, value)| self.undecryptable(value))
+                .map(|(key, _)| key)
+                .collect();
+
+            if !keys.is_empty() {
+                keys.sort();
+
+                return Err(LoadError::Generic(format!(
+                    "The configuration values for the following keys could not be decrypted: {}",
+                    keys.join(", ")
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn close(&mut self) {
+        self.inner.close()
+    }
+
+    fn save(&self) -> LoadResult {
+        self.inner.save()
+    }
+
+    fn try_iter(&self) -> Option<ConfigurationPairs<'_>> {
+        let pairs = self.inner.try_iter()?;
+        Some(Box::new(pairs.filter_map(move |(key, value)| {
+            self.decrypt(value).map(|value| (key, value))
+        })))
+    }
+
+    fn child_keys(&self, earlier_keys: &mut Vec<String>, parent_path: Option<&str>) {
+        self.inner.child_keys(earlier_keys, parent_path)
+    }
+}
+
+/// Represents a [`ConfigurationSource`](crate::ConfigurationSource) that decrypts the
+/// encrypted values of another source.
+pub struct DecryptedConfigurationSource {
+    inner: Box<dyn ConfigurationSource>,
+    decryptor: Arc<dyn ValueDecryptor>,
+}
+
+impl DecryptedConfigurationSource {
+    /// Initializes a new decrypted configuration source.
+    ///
+    /// # Arguments
+    ///
+    /// * `inner` - The inner [`ConfigurationSource`](crate::ConfigurationSource) to decrypt
+    /// * `decryptor` - The [`ValueDecryptor`] used to decrypt values
+    pub fn new(inner: Box<dyn ConfigurationSource>, decryptor: Arc<dyn ValueDecryptor>) -> Self {
+        Self { inner, decryptor }
+    }
+}
+
+impl ConfigurationSource for DecryptedConfigurationSource {
+    fn build(&self, builder: &dyn ConfigurationBuilder) -> Box<dyn ConfigurationProvider> {
+        Box::new(DecryptedConfigurationProvider::new(
+            self.inner.build(builder),
+            self.decryptor.clone(),
+        ))
+    }
+
+    fn priority(&self) -> i32 {
+        self.inner.priority()
+    }
+}
+
+impl DefaultConfigurationBuilder {
+    /// Decrypts the encrypted values of all of the configuration sources added so far.
+    ///
+    /// # Arguments
+    ///
+    /// * `decryptor` - The [`ValueDecryptor`] used to decrypt values
+    ///
+    /// # Remarks
+    ///
+    /// Values are decrypted lazily when they are retrieved. Sources added after this
+    /// call are not decrypted unless this method is called again.
+    pub fn add_decryption(&mut self, decryptor: Box<dyn ValueDecryptor>) -> &mut Self {
+        let decryptor: Arc<dyn ValueDecryptor> = decryptor.into();
+
+        self.sources = self
+            .sources
+            .drain(..)
+            .map(|source| {
+                Box::new(DecryptedConfigurationSource::new(source, decryptor.clone()))
+                    as Box<dyn ConfigurationSource>
+            })
+            .collect();
+
+        self
+    }
+}
@@ -1,50 +1,235 @@
-use crate::{util::fmt_debug_view, *};
+use crate::{
+    builder::{BASE_PATH, BOOTSTRAP}, file::RELOAD_ERROR_CALLBACK, host::HOST_ENVIRONMENT, util::fmt_debug_view,
+    *,
+};
 use cfg_if::cfg_if;
 use std::any::Any;
 use std::borrow::Borrow;
 use std::collections::{HashMap, HashSet};
 use std::fmt::{Debug, Formatter, Result as FormatResult};
-use std::ops::Deref;
-use tokens::{ChangeToken, CompositeChangeToken, SharedChangeToken};
+use std::marker::PhantomData;
+use std::ops::{Deref, DerefMut};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{PoisonError, TryLockError};
+use std::time::SystemTime;
+use tokens::{Callback, ChangeToken, CompositeChangeToken, Registration, SharedChangeToken, State};
 
 cfg_if! {
     if #[cfg(feature = "async")] {
-        use std::sync::Arc;
-
         type Pc<T> = std::sync::Arc<T>;
         type Mut<T> = std::sync::RwLock<T>;
-        type Ref<'a, T> = Arc<std::sync::RwLockReadGuard<'a, T>>;
+
+        fn read<T>(cell: &Mut<T>) -> std::sync::RwLockReadGuard<'_, T> {
+            cell.read().unwrap()
+        }
+
+        fn write<T>(cell: &Mut<T>) -> std::sync::RwLockWriteGuard<'_, T> {
+            cell.write().unwrap()
+        }
     } else {
         use std::cell::Ref;
 
         type Pc<T> = std::rc::Rc<T>;
         type Mut<T> = std::cell::RefCell<T>;
+
+        fn read<T>(cell: &Mut<T>) -> Ref<'_, T> {
+            cell.borrow()
+        }
+
+        fn write<T>(cell: &Mut<T>) -> std::cell::RefMut<'_, T> {
+            cell.borrow_mut()
+        }
+    }
+}
+
+// the source of the epoch of each cache
+static EPOCH: AtomicUsize = AtomicUsize::new(0);
+
+// memoizes values, child keys, and the provider slot of each key until the reload token of
+// any provider changes
+#[derive(Default)]
+struct Cache {
+    epoch: usize,
+    tokens: Vec<Box<dyn ChangeToken>>,
+    values: HashMap<String, Option<Value>>,
+    children: HashMap<Option<String>, Vec<String>>,
+    aliased: HashMap<String, String>,
+    slots: HashMap<String, Option<usize>>,
+}
+
+impl Cache {
+    fn new(root: &dyn ConfigurationRoot) -> Self {
+        Self {
+            epoch: EPOCH.fetch_add(1, Ordering::Relaxed) + 1,
+            tokens: layers(root).iter().map(|p| p.reload_token()).collect(),
+            ..Default::default()
+        }
+    }
+
+    fn is_valid(&self) -> bool {
+        !self.tokens.iter().any(|t| t.changed())
+    }
+}
+
+// the providers of the root followed by its overlay, if any
+fn layers(root: &dyn ConfigurationRoot) -> Vec<Box<dyn ConfigurationProvider + '_>> {
+    let overlay = root
+        .overlay()
+        .map(|overlay| Box::new(overlay) as Box<dyn ConfigurationProvider>);
+
+    root.providers().chain(overlay).collect()
+}
+
+// replaces the cache if it is no longer valid and returns the epoch of the current cache
+fn validate(cache: &Mut<Cache>, root: &dyn ConfigurationRoot) -> usize {
+    if !read(cache).is_valid() {
+        *write(cache) = Cache::new(root);
+    }
+
+    read(cache).epoch
+}
+
+// updates the cache unless it has been replaced, such as by a reload, since the epoch was read
+// so that a value computed from the previous providers is never cached
+fn update<F: FnOnce(&mut Cache)>(cache: &Mut<Cache>, epoch: usize, action: F) {
+    let mut cache = write(cache);
+
+    if cache.epoch == epoch {
+        action(&mut cache);
+    }
+}
+
+fn child_keys(
+    cache: &Mut<Cache>,
+    root: &dyn ConfigurationRoot,
+    policy: ArrayMergePolicy,
+    aliases: &[(String, String)],
+    parent_path: Option<&str>,
+) -> Vec<String> {
+    let epoch = validate(cache, root);
+    let key = parent_path.map(|p| p.to_uppercase());
+
+    if let Some(keys) = read(cache).children.get(&key) {
+        return keys.clone();
+    }
+
+    let mut keys = collect_child_keys(root, policy, parent_path);
+
+    for (old, new) in aliases {
+        let aliased = match parent_path.and_then(|parent| strip_path(parent, new)) {
+            Some(rest) => collect_child_keys(root, policy, Some(&format!("{}{}", old, rest))),
+            _ => ConfigurationPath::segments(match parent_path {
+                Some(parent) => strip_path(new, parent).unwrap_or_default(),
+                _ => new,
+            })
+            .find(|segment| !segment.is_empty())
+            .filter(|_| {
+                root.providers().any(|p| p.get(old).is_some())
+                    || !collect_child_keys(root, policy, Some(old)).is_empty()
+            })
+            .map(|segment| vec![segment.to_owned()])
+            .unwrap_or_default(),
+        };
+
+        for key in aliased {
+            if !keys.iter().any(|k| k.eq_ignore_ascii_case(&key)) {
+                keys.push(key);
+            }
+        }
+    }
+
+    update(cache, epoch, |cache| {
+        cache.children.insert(key, keys.clone());
+    });
+    keys
+}
+
+fn collect_child_keys(
+    root: &dyn ConfigurationRoot,
+    policy: ArrayMergePolicy,
+    parent_path: Option<&str>,
+) -> Vec<String> {
+    let layers = layers(root);
+    let keys = if policy == ArrayMergePolicy::Merge {
+        layers
+            .iter()
+            .fold(Vec::new(), |mut earlier_keys, provider| {
+                provider.child_keys(&mut earlier_keys, parent_path);
+                earlier_keys
+            })
+    } else {
+        policy.child_keys(&layers, parent_path)
+    };
+
+    // keys that only differ by casing are the same key, which keeps the casing of the provider
+    // with the highest precedence
+    let mut visited = HashSet::new();
+    let mut keys: Vec<_> = keys
+        .into_iter()
+        .rev()
+        .filter(|key| visited.insert(key.to_uppercase()))
+        .collect();
+
+    keys.reverse();
+    keys
+}
+
+// gets the remainder of the path after the prefix, which is empty or begins with a delimiter
+fn strip_path<'a>(path: &'a str, prefix: &str) -> Option<&'a str> {
+    match path.get(..prefix.len()) {
+        Some(start) if start.eq_ignore_ascii_case(prefix) => {}
+        _ => return None,
+    }
+
+    let rest = &path[prefix.len()..];
+
+    if rest.is_empty() || rest.starts_with(ConfigurationPath::key_delimiter()) {
+        Some(rest)
+    } else {
+        None
     }
 }
 
-struct ProviderItem<'a> {
+type Providers = Pc<Mut<Vec<Box<dyn ConfigurationProvider>>>>;
+
+// each item only borrows the providers while it is being used so that it can be shared
+struct ProviderItem {
     index: usize,
     name: String,
-    items: Ref<'a, Vec<Box<dyn ConfigurationProvider + 'a>>>,
+    items: Providers,
 }
 
-impl<'a> ProviderItem<'a> {
-    fn new(
-        items: Ref<'a, Vec<Box<dyn ConfigurationProvider + 'a>>>,
-        index: usize,
-        name: String,
-    ) -> Self {
+impl ProviderItem {
+    fn new(items: Providers, index: usize, name: String) -> Self {
         Self { index, name, items }
     }
 }
 
-impl ConfigurationProvider for ProviderItem<'_> {
+impl ConfigurationProvider for ProviderItem {
     fn get(&self, key: &str) -> Option<Value> {
-        self.items[self.index].get(key)
+        read(&self.items)[self.index].get(key)
+    }
+
+    fn get_typed(&self, key: &str) -> Option<ConfigValue> {
+        read(&self.items)[self.index].get_typed(key)
+    }
+
+    fn is_removed(&self, key: &str) -> bool {
+        read(&self.items)[self.index].is_removed(key)
+    }
+
+    fn save(&self) -> LoadResult {
+        read(&self.items)[self.index].save()
+    }
+
+    fn try_iter(&self) -> Option<ConfigurationPairs<'_>> {
+        let pairs: Vec<_> = read(&self.items)[self.index].try_iter()?.collect();
+        Some(Box::new(pairs.into_iter()))
     }
 
     fn child_keys(&self, earlier_keys: &mut Vec<String>, parent_path: Option<&str>) {
-        self.items[self.index].child_keys(earlier_keys, parent_path)
+        read(&self.items)[self.index].child_keys(earlier_keys, parent_path)
     }
 
     fn name(&self) -> &str {
@@ -52,50 +237,43 @@ impl ConfigurationProvider for ProviderItem<'_> {
     }
 
     fn reload_token(&self) -> Box<dyn ChangeToken> {
-        self.items[self.index].reload_token()
+        read(&self.items)[self.index].reload_token()
     }
 }
 
 struct ProviderIter<'a> {
     head: usize,
     tail: usize,
-    items: Ref<'a, Vec<Box<dyn ConfigurationProvider>>>,
+    items: Providers,
+    _lifetime: PhantomData<&'a ()>,
 }
 
-impl<'a> ProviderIter<'a> {
-    fn new(items: Ref<'a, Vec<Box<dyn ConfigurationProvider>>>) -> Self {
+impl ProviderIter<'_> {
+    fn new(items: Providers) -> Self {
+        let tail = read(&items).len();
+
         Self {
             head: 0,
-            tail: items.len(),
+            tail,
             items,
+            _lifetime: PhantomData,
         }
     }
+
+    fn item(&self, index: usize) -> ProviderItem {
+        let name = read(&self.items)[index].name().to_owned();
+        ProviderItem::new(self.items.clone(), index, name)
+    }
 }
 
 impl<'a> Iterator for ProviderIter<'a> {
     type Item = Box<dyn ConfigurationProvider + 'a>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.head < self.items.len() {
-            let i = self.head;
-            let name = self.items[i].name().to_owned();
+        if self.head < self.tail {
+            let item = self.item(self.head);
             self.head += 1;
-
-            cfg_if! {
-                if #[cfg(feature = "async")] {
-                    Some(Box::new(ProviderItem::new(
-                        self.items.clone(),
-                        i,
-                        name,
-                    )))
-                } else {
-                    Some(Box::new(ProviderItem::new(
-                        Ref::clone(&self.items),
-                        i,
-                        name,
-                    )))
-                }
-            }
+            Some(Box::new(item))
         } else {
             None
         }
@@ -104,31 +282,15 @@ impl<'a> Iterator for ProviderIter<'a> {
 
 impl ExactSizeIterator for ProviderIter<'_> {
     fn len(&self) -> usize {
-        self.items.len()
+        self.tail - self.head
     }
 }
 
 impl DoubleEndedIterator for ProviderIter<'_> {
     fn next_back(&mut self) -> Option<Self::Item> {
-        if self.tail > 0 {
+        if self.tail > self.head {
             self.tail -= 1;
-            let name = self.items[self.tail].name().to_owned();
-
-            cfg_if! {
-                if #[cfg(feature = "async")] {
-                    Some(Box::new(ProviderItem::new(
-                        self.items.clone(),
-                        self.tail,
-                        name,
-                    )))
-                } else {
-                    Some(Box::new(ProviderItem::new(
-                        Ref::clone(&self.items),
-                        self.tail,
-                        name,
-                    )))
-                }
-            }
+            Some(Box::new(self.item(self.tail)))
         } else {
             None
         }
@@ -137,11 +299,239 @@ impl DoubleEndedIterator for ProviderIter<'_> {
 
 impl<'a> ConfigurationProviderIterator<'a> for ProviderIter<'a> {}
 
+// defers loading a provider until one of its values is first requested
+struct LazyState {
+    provider: Mut<Box<dyn ConfigurationProvider>>,
+    result: Mut<Option<LoadResult>>,
+}
+
+impl LazyState {
+    fn ensure_loaded(&self) -> LoadResult {
+        if let Some(result) = &*read(&self.result) {
+            return result.clone();
+        }
+
+        let mut result = write(&self.result);
+
+        // another caller may have loaded the provider while waiting for the lock
+        if result.is_none() {
+            *result = Some(trace::load(&mut **write(&self.provider)));
+        }
+
+        result.clone().unwrap()
+    }
+}
+
+struct LazyProvider {
+    name: String,
+    state: Pc<LazyState>,
+}
+
+impl LazyProvider {
+    fn new(provider: Box<dyn ConfigurationProvider>) -> Self {
+        Self {
+            name: provider.name().to_owned(),
+            state: Pc::new(LazyState {
+                provider: Mut::new(provider),
+                result: Default::default(),
+            }),
+        }
+    }
+
+    fn provider(&self) -> impl Deref<Target = Box<dyn ConfigurationProvider>> + '_ {
+        // a provider that fails to load is treated as empty; the error is reported by
+        // DefaultConfigurationRoot::ensure_loaded
+        let _ = self.state.ensure_loaded();
+        read(&self.state.provider)
+    }
+}
+
+impl ConfigurationProvider for LazyProvider {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn get(&self, key: &str) -> Option<Value> {
+        self.provider().get(key)
+    }
+
+    fn get_typed(&self, key: &str) -> Option<ConfigValue> {
+        self.provider().get_typed(key)
+    }
+
+    fn is_removed(&self, key: &str) -> bool {
+        self.provider().is_removed(key)
+    }
+
+    fn reload_token(&self) -> Box<dyn ChangeToken> {
+        read(&self.state.provider).reload_token()
+    }
+
+    fn load(&mut self) -> LoadResult {
+        let result = write(&self.state.provider).load();
+        *write(&self.state.result) = Some(result.clone());
+        result
+    }
+
+    fn close(&mut self) {
+        write(&self.state.provider).close()
+    }
+
+    fn save(&self) -> LoadResult {
+        self.provider().save()
+    }
+
+    fn try_iter(&self) -> Option<ConfigurationPairs<'_>> {
+        let pairs: Vec<_> = self.provider().try_iter()?.collect();
+        Some(Box::new(pairs.into_iter()))
+    }
+
+    fn child_keys(&self, earlier_keys: &mut Vec<String>, parent_path: Option<&str>) {
+        self.provider().child_keys(earlier_keys, parent_path)
+    }
+}
+
+// serves the values of a provider while the provider is reloaded so that readers are not
+// blocked by the reload
+struct SnapshotProvider {
+    name: String,
+    data: ConfigurationData,
+}
+
+impl SnapshotProvider {
+    fn of(provider: &dyn ConfigurationProvider) -> Option<Self> {
+        let mut data = HashMap::new();
+        let mut types = HashMap::new();
+
+        for (key, value) in provider.try_iter()? {
+            let normalized = key.to_uppercase();
+
+            match provider.get_typed(&key) {
+                None | Some(ConfigValue::String(_)) => {}
+                Some(typed) => {
+                    types.insert(normalized.clone(), typed);
+                }
+            }
+
+            data.insert(normalized, (key, value));
+        }
+
+        Some(Self {
+            name: provider.name().to_owned(),
+            data: ConfigurationData::new(data).with_types(types),
+        })
+    }
+}
+
+impl ConfigurationProvider for SnapshotProvider {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn get(&self, key: &str) -> Option<Value> {
+        self.data.get(key)
+    }
+
+    fn get_str(&self, key: &str) -> Option<ValueRef<'_>> {
+        self.data.get_str(key).map(ValueRef::Borrowed)
+    }
+
+    fn get_typed(&self, key: &str) -> Option<ConfigValue> {
+        self.data.get_typed(key)
+    }
+
+    fn try_iter(&self) -> Option<ConfigurationPairs<'_>> {
+        Some(self.data.pairs())
+    }
+
+    fn child_keys(&self, earlier_keys: &mut Vec<String>, parent_path: Option<&str>) {
+        self.data.child_keys(earlier_keys, parent_path)
+    }
+}
+
+// the gate is held for the duration of a reload
+type Gate = std::sync::Arc<std::sync::Mutex<()>>;
+
+fn is_closed(gate: &Gate) -> bool {
+    matches!(gate.try_lock(), Err(TryLockError::WouldBlock))
+}
+
+// ignores the changes signalled by a provider while it is reloaded; the reload signals the
+// reload token of the configuration once every provider has been reloaded
+struct GatedChangeToken {
+    token: Box<dyn ChangeToken>,
+    gate: Gate,
+}
+
+impl ChangeToken for GatedChangeToken {
+    fn changed(&self) -> bool {
+        !is_closed(&self.gate) && self.token.changed()
+    }
+
+    fn must_poll(&self) -> bool {
+        self.token.must_poll()
+    }
+
+    fn register(&self, callback: Callback, state: State) -> Registration {
+        let gate = self.gate.clone();
+
+        self.token.register(
+            Box::new(move |state| {
+                if !is_closed(&gate) {
+                    (callback)(state)
+                }
+            }),
+            state,
+        )
+    }
+}
+
+fn compose<I>(gate: &Gate, tokens: I) -> CompositeChangeToken
+where
+    I: IntoIterator<Item = Box<dyn ChangeToken>>,
+{
+    CompositeChangeToken::new(tokens.into_iter().map(|token| {
+        Box::new(GatedChangeToken {
+            token,
+            gate: gate.clone(),
+        }) as Box<dyn ChangeToken>
+    }))
+}
+
+/// Represents the type alias for a function that transforms a configuration value.
+///
+/// # Remarks
+///
+/// The function is invoked with the key and value, and returns the new value or `None`
+/// if the value is unchanged.
+pub type ValueTransform = std::sync::Arc<dyn Fn(&str, &str) -> Option<String> + Send + Sync>;
+
+/// Represents the type alias for a function that is invoked when a deprecated key is read.
+///
+/// # Remarks
+///
+/// The function is invoked with the deprecated key and the key that replaces it.
+pub type DeprecatedKeyCallback = std::sync::Arc<dyn Fn(&str, &str) + Send + Sync>;
+
 /// Represents the root of a configuration.
 #[derive(Clone)]
 pub struct DefaultConfigurationRoot {
-    token: SharedChangeToken<CompositeChangeToken>,
+    token: Pc<Mut<SharedChangeToken<CompositeChangeToken>>>,
     providers: Pc<Mut<Vec<Box<dyn ConfigurationProvider>>>>,
+    cache: Pc<Mut<Cache>>,
+    policy: ArrayMergePolicy,
+    conversion: ValueConversion,
+    sparse: SparseArrayPolicy,
+    transforms: Vec<ValueTransform>,
+    lazy: Pc<Mut<Vec<Pc<LazyState>>>>,
+    accessed: Option<Pc<Mut<HashSet<String>>>>,
+    aliases: std::sync::Arc<Vec<(String, String)>>,
+    deprecated: Pc<Mut<HashSet<String>>>,
+    on_deprecated_key: Option<DeprecatedKeyCallback>,
+    overlay: ConfigurationOverlay,
+    access_log: Option<Pc<Mut<Vec<AccessRecord>>>>,
+    sensitive: std::sync::Arc<Vec<String>>,
+    gate: Gate,
 }
 
 impl DefaultConfigurationRoot {
@@ -155,7 +545,7 @@ impl DefaultConfigurationRoot {
         let mut tokens = Vec::with_capacity(providers.len());
 
         for provider in providers.iter_mut() {
-            let result = provider.load();
+            let result = trace::load(provider.as_mut());
 
             if let Err(error) = result {
                 errors.push((provider.name().to_owned(), error));
@@ -165,105 +555,632 @@ impl DefaultConfigurationRoot {
         }
 
         if errors.is_empty() {
-            Ok(Self {
-                token: SharedChangeToken::new(CompositeChangeToken::new(tokens.into_iter())),
-                providers: Pc::new(providers.into()),
-            })
+            Ok(Self::create(providers, tokens, Vec::new()))
         } else {
             Err(ReloadError::Provider(errors))
         }
     }
-}
 
-impl ConfigurationRoot for DefaultConfigurationRoot {
-    fn reload(&mut self) -> ReloadResult {
-        let borrowed = (Pc::strong_count(&self.providers) - 1) + Pc::weak_count(&self.providers);
+    /// Initializes a new root configuration whose providers are loaded on first access.
+    ///
+    /// # Arguments
+    ///
+    /// * `providers` - The [`ConfigurationProvider`](crate::ConfigurationProvider) list used in the configuration
+    ///
+    /// # Remarks
+    ///
+    /// A provider is loaded the first time the configuration requests one of its values or keys.
+    /// Loading a provider that supports change tracking may signal the
+    /// [`reload_token`](crate::Configuration::reload_token) of the configuration. Use
+    /// [`ensure_loaded`](crate::ConfigurationRoot::ensure_loaded) to load every provider and
+    /// report any errors.
+    pub fn new_lazy(providers: Vec<Box<dyn ConfigurationProvider>>) -> Self {
+        let providers: Vec<_> = providers.into_iter().map(LazyProvider::new).collect();
+        let tokens = providers.iter().map(|p| p.reload_token()).collect();
+        let lazy = providers.iter().map(|p| p.state.clone()).collect();
+        let providers = providers
+            .into_iter()
+            .map(|p| Box::new(p) as Box<dyn ConfigurationProvider>)
+            .collect();
+
+        Self::create(providers, tokens, lazy)
+    }
+
+    fn create(
+        providers: Vec<Box<dyn ConfigurationProvider>>,
+        mut tokens: Vec<Box<dyn ChangeToken>>,
+        lazy: Vec<Pc<LazyState>>,
+    ) -> Self {
+        let overlay = ConfigurationOverlay::new();
+        let gate = Gate::default();
+
+        tokens.push(overlay.reload_token());
+
+        let root = Self {
+            token: Pc::new(Mut::new(SharedChangeToken::new(compose(&gate, tokens)))),
+            providers: Pc::new(providers.into()),
+            cache: Default::default(),
+            policy: Default::default(),
+            conversion: Default::default(),
+            sparse: Default::default(),
+            transforms: Default::default(),
+            lazy: Pc::new(lazy.into()),
+            accessed: None,
+            aliases: Default::default(),
+            deprecated: Default::default(),
+            on_deprecated_key: None,
+            overlay,
+            access_log: None,
+            sensitive: Default::default(),
+            gate,
+        };
+
+        *write(&root.cache) = Cache::new(&root);
+        root
+    }
+
+    /// Sets the policy used to combine indexed sections across providers.
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - The [`ArrayMergePolicy`](crate::ArrayMergePolicy) to apply
+    pub fn with_array_merge_policy(mut self, policy: ArrayMergePolicy) -> Self {
+        self.policy = policy;
+        *write(&self.cache) = Cache::new(&self);
+        self
+    }
+
+    /// Sets the conversion applied to configuration values before they are parsed.
+    ///
+    /// # Arguments
+    ///
+    /// * `conversion` - The [`ValueConversion`](crate::ValueConversion) to apply
+    pub fn with_value_conversion(mut self, conversion: ValueConversion) -> Self {
+        self.conversion = conversion;
+        self
+    }
+
+    /// Sets the policy used to bind arrays whose indices have gaps.
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - The [`SparseArrayPolicy`](crate::SparseArrayPolicy) to apply
+    pub fn with_sparse_array_policy(mut self, policy: SparseArrayPolicy) -> Self {
+        self.sparse = policy;
+        self
+    }
+
+    /// Adds a transform applied to every configuration value.
+    ///
+    /// # Arguments
+    ///
+    /// * `transform` - The [`ValueTransform`] to apply
+    ///
+    /// # Remarks
+    ///
+    /// Transforms are applied in the order they are added.
+    pub fn with_value_transform(mut self, transform: ValueTransform) -> Self {
+        self.transforms.push(transform);
+        *write(&self.cache) = Cache::new(&self);
+        self
+    }
+
+    /// Adds an alias that maps a deprecated key to the key that replaces it.
+    ///
+    /// # Arguments
+    ///
+    /// * `old` - The deprecated key or section path
+    /// * `new` - The key or section path that replaces it
+    ///
+    /// # Remarks
+    ///
+    /// A value configured with the new key always takes precedence. Otherwise, reading the
+    /// new key, or any key in its section, reads the corresponding deprecated key.
+    pub fn with_key_alias(mut self, old: &str, new: &str) -> Self {
+        std::sync::Arc::make_mut(&mut self.aliases).push((old.to_owned(), new.to_owned()));
+        *write(&self.cache) = Cache::new(&self);
+        self
+    }
+
+    /// Sets the function invoked when a deprecated key is read through an alias.
+    ///
+    /// # Arguments
+    ///
+    /// * `callback` - The [`DeprecatedKeyCallback`] to invoke
+    ///
+    /// # Remarks
+    ///
+    /// The function is invoked once for each deprecated key that is read.
+    pub fn with_deprecated_key_callback(mut self, callback: DeprecatedKeyCallback) -> Self {
+        self.on_deprecated_key = Some(callback);
+        self
+    }
+
+    /// Marks the keys that match a pattern as sensitive.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - The case-insensitive pattern of the keys, where `*` matches any text,
+    ///   such as `*:Password`
+    ///
+    /// # Remarks
+    ///
+    /// The values of sensitive keys are redacted in the debug view of the configuration.
+    pub fn with_sensitive_key(mut self, pattern: &str) -> Self {
+        std::sync::Arc::make_mut(&mut self.sensitive).push(pattern.to_owned());
+        self
+    }
+
+    /// Records every configuration value that is read.
+    ///
+    /// # Remarks
+    ///
+    /// Each read is retained and is retrieved with
+    /// [`access_log`](crate::ConfigurationRoot::access_log).
+    pub fn with_access_log(mut self) -> Self {
+        self.access_log = Some(Default::default());
+        self
+    }
+
+    /// Tracks which configuration values are read.
+    ///
+    /// # Remarks
+    ///
+    /// The values that have never been read are retrieved with
+    /// [`unused_keys`](crate::ConfigurationRoot::unused_keys).
+    pub fn with_unused_key_tracking(mut self) -> Self {
+        self.accessed = Some(Default::default());
+        self
+    }
 
+    // marks a key as read when unused keys are tracked
+    fn mark_read(&self, key: &str) {
+        if let Some(accessed) = &self.accessed {
+            write(accessed).insert(key.to_uppercase());
+        }
+    }
+
+    // records the read of a key whose value, if any, was resolved from the source key
+    fn record_access(&self, key: &str, source_key: Option<&str>) {
+        if let Some(log) = &self.access_log {
+            let provider = source_key.and_then(|source_key| {
+                for provider in layers(self).iter().rev() {
+                    if provider.get(source_key).is_some() {
+                        return Some(provider.name().to_owned());
+                    } else if provider.is_removed(source_key) {
+                        break;
+                    }
+                }
+
+                None
+            });
+
+            write(log).push(AccessRecord {
+                key: key.to_owned(),
+                provider,
+                time: SystemTime::now(),
+            });
+        }
+    }
+
+    fn aliased_keys<'a>(&'a self, key: &'a str) -> impl Iterator<Item = String> + 'a {
+        self.aliases.iter().filter_map(move |(old, new)| {
+            strip_path(key, new).map(|rest| format!("{}{}", old, rest))
+        })
+    }
+
+    fn deprecated_key_read(&self, old: &str, new: &str) {
+        self.mark_read(old);
+
+        if write(&self.deprecated).insert(old.to_uppercase()) {
+            if let Some(callback) = &self.on_deprecated_key {
+                (callback)(old, new);
+            }
+        }
+    }
+
+    fn transform(&self, key: &str, value: Option<Value>) -> Option<Value> {
+        let mut value = value?;
+
+        for transform in &self.transforms {
+            if let Some(new_value) = (transform)(key, &value) {
+                value = new_value.into();
+            }
+        }
+
+        Some(value)
+    }
+
+    fn resolve(&self, key: &str) -> Option<Value> {
+        let epoch = validate(&self.cache, self);
+        let normalized_key = key.to_uppercase();
+
+        if let Some(value) = read(&self.cache).values.get(&normalized_key) {
+            return value.clone();
+        }
+
+        let mut value = self.lookup(key, |p, k| p.get(k));
+
+        if value.is_none() {
+            for old in self.aliased_keys(key) {
+                value = self.lookup(&old, |p, k| p.get(k));
+
+                if value.is_some() {
+                    update(&self.cache, epoch, |cache| {
+                        cache.aliased.insert(normalized_key.clone(), old);
+                    });
+                    break;
+                }
+            }
+        }
+
+        let value = self.transform(key, value);
+
+        update(&self.cache, epoch, |cache| {
+            cache.values.insert(normalized_key, value.clone());
+        });
+        value
+    }
+
+    fn lookup<T, F>(&self, key: &str, get: F) -> Option<T>
+    where
+        F: Fn(&dyn ConfigurationProvider, &str) -> Option<T>,
+    {
+        if self.policy != ArrayMergePolicy::Merge {
+            return self.policy.find(&layers(self), key, get);
+        }
+
+        let epoch = validate(&self.cache, self);
+        let normalized_key = key.to_uppercase();
+        let slot = read(&self.cache).slots.get(&normalized_key).copied();
+
+        if let Some(slot) = slot {
+            let providers = read(&self.providers);
+
+            return match slot.map(|slot| providers.get(slot)) {
+                Some(Some(provider)) => get(provider.as_ref(), key),
+                Some(_) => get(&self.overlay, key),
+                _ => None,
+            };
+        }
+
+        let layers = layers(self);
+        let mut found = None;
+
+        // the first provider, in reverse order, with the key has the value unless a later
+        // provider removes it
+        for (slot, layer) in layers.iter().enumerate().rev() {
+            if let Some(value) = get(layer.as_ref(), key) {
+                found = Some((slot, value));
+                break;
+            } else if layer.is_removed(key) {
+                break;
+            }
+        }
+
+        let slot = found.as_ref().map(|(slot, _)| *slot);
+
+        update(&self.cache, epoch, |cache| {
+            cache.slots.insert(normalized_key, slot);
+        });
+        found.map(|(_, value)| value)
+    }
+
+    fn warm(&self, parent_path: Option<&str>) {
+        for key in child_keys(&self.cache, self, self.policy, &self.aliases, parent_path) {
+            let path = match parent_path {
+                Some(parent) => ConfigurationPath::combine(&[parent, &key]),
+                _ => key,
+            };
+
+            self.resolve(&path);
+            self.warm(Some(&path));
+        }
+    }
+
+    fn collect_unused(
+        &self,
+        accessed: &HashSet<String>,
+        parent_path: Option<&str>,
+        keys: &mut Vec<String>,
+    ) {
+        for key in child_keys(&self.cache, self, self.policy, &self.aliases, parent_path) {
+            let path = match parent_path {
+                Some(parent) => ConfigurationPath::combine(&[parent, &key]),
+                _ => key,
+            };
+            let normalized_key = path.to_uppercase();
+
+            if !accessed.contains(&normalized_key)
+                && self.resolve(&path).is_some()
+                && !read(&self.cache).aliased.contains_key(&normalized_key)
+            {
+                keys.push(path.clone());
+            }
+
+            self.collect_unused(accessed, Some(&path), keys);
+        }
+    }
+}
+
+impl DefaultConfigurationRoot {
+    // readers only hold the providers for the duration of a single call, so waiting for
+    // exclusive access never fails unless the current thread is itself a reader
+    fn providers_mut(
+        &self,
+    ) -> Result<impl DerefMut<Target = Vec<Box<dyn ConfigurationProvider>>> + '_, ReloadError> {
         cfg_if! {
             if #[cfg(feature = "async")] {
-                let result = self.providers.try_write();
+                Ok(write(&self.providers))
             } else {
-                let result = self.providers.try_borrow_mut();
+                self.providers.try_borrow_mut().map_err(|_| ReloadError::Borrowed(None))
             }
         }
+    }
+
+    // replaces each provider whose values can be enumerated with a snapshot of its values and
+    // returns the replaced providers, which are loaded while readers use the snapshots
+    fn stage(&self) -> Result<Vec<Option<Box<dyn ConfigurationProvider>>>, ReloadError> {
+        let mut providers = self.providers_mut()?;
+        let mut staged = Vec::with_capacity(providers.len());
 
-        if let Ok(mut providers) = result {
-            let mut errors = Vec::new();
-            let mut tokens = Vec::with_capacity(providers.len());
+        for provider in providers.iter_mut() {
+            staged.push(
+                SnapshotProvider::of(provider.as_ref())
+                    .map(|snapshot| std::mem::replace(provider, Box::new(snapshot))),
+            );
+        }
 
-            for provider in providers.iter_mut() {
-                let result = provider.load();
+        Ok(staged)
+    }
 
-                if let Err(error) = result {
-                    errors.push((provider.name().to_owned(), error));
+    // replaces the reload token with a token composed from the provider tokens, resets the
+    // cache, and signals the previous token
+    fn swap(&self, mut tokens: Vec<Box<dyn ChangeToken>>) {
+        tokens.push(self.overlay.reload_token());
+
+        let token = SharedChangeToken::new(compose(&self.gate, tokens));
+        let old_token = std::mem::replace(&mut *write(&self.token), token);
+
+        *write(&self.cache) = Cache::new(self);
+        old_token.notify();
+    }
+}
+
+impl ConfigurationRoot for DefaultConfigurationRoot {
+    fn reload(&self) -> ReloadResult {
+        let gate = self.gate.lock().unwrap_or_else(PoisonError::into_inner);
+        let mut staged = self.stage()?;
+        let mut errors = Vec::new();
+
+        // a staged provider is loaded without holding the providers so that readers, which
+        // use its snapshot, are not blocked; any other provider is loaded in place
+        for (index, provider) in staged.iter_mut().enumerate() {
+            let (name, result) = match provider {
+                Some(provider) => (provider.name().to_owned(), trace::load(provider.as_mut())),
+                None => {
+                    let mut providers = write(&self.providers);
+                    let provider = providers[index].as_mut();
+                    (provider.name().to_owned(), trace::load(provider))
                 }
+            };
 
-                tokens.push(provider.reload_token());
+            if let Err(error) = result {
+                errors.push((name, error));
             }
+        }
 
-            let new_token = SharedChangeToken::new(CompositeChangeToken::new(tokens.into_iter()));
-            let old_token = std::mem::replace(&mut self.token, new_token);
-
-            old_token.notify();
+        let mut providers = write(&self.providers);
 
-            if errors.is_empty() {
-                Ok(())
-            } else {
-                Err(ReloadError::Provider(errors))
+        for (current, provider) in providers.iter_mut().zip(staged) {
+            if let Some(provider) = provider {
+                *current = provider;
             }
+        }
+
+        let tokens = providers.iter().map(|p| p.reload_token()).collect();
+
+        trace::reloaded(providers.len(), &errors);
+        drop(providers);
+        drop(gate);
+        self.swap(tokens);
+
+        if errors.is_empty() {
+            Ok(())
         } else {
-            Err(ReloadError::Borrowed(Some(borrowed)))
+            Err(ReloadError::Provider(errors))
+        }
+    }
+
+    fn replace_sources(&self, builder: &dyn ConfigurationBuilder) -> ReloadResult {
+        let mut replacements: Vec<_> =
+            prioritize(builder.default_sources().iter().chain(builder.sources()))
+                .into_iter()
+                .map(|source| source.build(builder))
+                .collect();
+        let mut errors = Vec::new();
+        let mut tokens = Vec::with_capacity(replacements.len() + 1);
+
+        // the replacements are staged so that the current providers remain available to
+        // readers until every replacement loads successfully
+        for provider in replacements.iter_mut() {
+            if let Err(error) = trace::load(provider.as_mut()) {
+                errors.push((provider.name().to_owned(), error));
+            }
+
+            tokens.push(provider.reload_token());
+        }
+
+        trace::reloaded(replacements.len(), &errors);
+
+        if !errors.is_empty() {
+            return Err(ReloadError::Provider(errors));
         }
+
+        let gate = self.gate.lock().unwrap_or_else(PoisonError::into_inner);
+
+        *self.providers_mut()? = replacements;
+        drop(gate);
+        write(&self.lazy).clear();
+        self.swap(tokens);
+        Ok(())
     }
 
     fn providers(&self) -> Box<dyn ConfigurationProviderIterator + '_> {
-        cfg_if! {
-            if #[cfg(feature = "async")] {
-                Box::new(ProviderIter::new(self.providers.deref().read().unwrap().into()))
-            } else {
-                Box::new(ProviderIter::new(self.providers.deref().borrow()))
+        Box::new(ProviderIter::new(self.providers.clone()))
+    }
+
+    fn as_config(&self) -> Box<dyn Configuration> {
+        Box::new(self.clone())
+    }
+
+    #[cfg(feature = "async")]
+    fn into_shared(self: Box<Self>) -> std::sync::Arc<dyn Configuration + Send + Sync> {
+        std::sync::Arc::new(*self)
+    }
+
+    fn access_log(&self) -> Vec<AccessRecord> {
+        self.access_log
+            .as_ref()
+            .map(|log| read(log).clone())
+            .unwrap_or_default()
+    }
+
+    fn overlay(&self) -> Option<ConfigurationOverlay> {
+        Some(self.overlay.clone())
+    }
+
+    fn prewarm(&self) {
+        self.warm(None);
+    }
+
+    fn close(&self) {
+        for provider in write(&self.providers).iter_mut() {
+            provider.close();
+        }
+    }
+
+    fn unused_keys(&self) -> Vec<String> {
+        let mut keys = Vec::new();
+
+        if let Some(accessed) = &self.accessed {
+            // the keys read while collecting are not marked, so a copy is not necessary
+            self.collect_unused(&read(accessed), None, &mut keys);
+            keys.sort();
+        }
+
+        keys
+    }
+
+    fn ensure_loaded(&self) -> ReloadResult {
+        let mut errors = Vec::new();
+
+        for (state, provider) in read(&self.lazy).iter().zip(self.providers()) {
+            if let Err(error) = state.ensure_loaded() {
+                errors.push((provider.name().to_owned(), error));
             }
         }
-    }
 
-    fn as_config(&self) -> Box<dyn Configuration> {
-        Box::new(self.clone())
-    }
-}
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(ReloadError::Provider(errors))
+        }
+    }
+}
+
+impl Configuration for DefaultConfigurationRoot {
+    fn get(&self, key: &str) -> Option<Value> {
+        let normalized_key = key.to_uppercase();
+        let value = self.resolve(key);
+        let old = read(&self.cache).aliased.get(&normalized_key).cloned();
+
+        self.mark_read(key);
+
+        if value.is_some() {
+            self.record_access(key, Some(old.as_deref().unwrap_or(key)));
+        } else {
+            self.record_access(key, None);
+        }
+
+        if let Some(old) = old {
+            self.deprecated_key_read(&old, key);
+        }
+
+        value
+    }
+
+    fn get_typed(&self, key: &str) -> Option<ConfigValue> {
+        // a transformed value is no longer the typed value from the provider
+        if !self.transforms.is_empty() {
+            return self.get(key).map(ConfigValue::String);
+        }
+
+        self.mark_read(key);
+
+        let value = self.lookup(key, |p, k| p.get_typed(k));
+
+        if value.is_some() {
+            self.record_access(key, Some(key));
+            return value;
+        }
+
+        for old in self.aliased_keys(key) {
+            let value = self.lookup(&old, |p, k| p.get_typed(k));
 
-impl Configuration for DefaultConfigurationRoot {
-    fn get(&self, key: &str) -> Option<Value> {
-        for provider in self.providers().rev() {
-            if let Some(value) = provider.get(key) {
-                return Some(value);
+            if value.is_some() {
+                self.record_access(key, Some(&old));
+                self.deprecated_key_read(&old, key);
+                return value;
             }
         }
 
+        self.record_access(key, None);
         None
     }
 
     fn section(&self, key: &str) -> Box<dyn ConfigurationSection> {
-        Box::new(DefaultConfigurationSection::new(
-            Box::new(self.clone()),
-            key,
-        ))
+        let mut section = DefaultConfigurationSection::new(Box::new(self.clone()), key);
+        section.cache = Some(self.cache.clone());
+        section.policy = self.policy;
+        section.aliases = self.aliases.clone();
+        Box::new(section)
     }
 
     fn children(&self) -> Vec<Box<dyn ConfigurationSection>> {
-        self.providers()
-            .fold(Vec::new(), |mut earlier_keys, provider| {
-                provider.child_keys(&mut earlier_keys, None);
-                earlier_keys
-            })
-            .into_iter()
-            .collect::<HashSet<_>>()
+        child_keys(&self.cache, self, self.policy, &self.aliases, None)
             .iter()
             .map(|key| self.section(key))
             .collect()
     }
 
     fn reload_token(&self) -> Box<dyn ChangeToken> {
-        Box::new(self.token.clone())
+        // once a provider has changed, the token is only replaced by a reload, so a new
+        // token is composed from the current provider tokens to signal subsequent changes
+        let token = read(&self.token).clone();
+
+        if token.changed() {
+            Box::new(compose(
+                &self.gate,
+                layers(self).iter().map(|p| p.reload_token()),
+            ))
+        } else {
+            Box::new(token)
+        }
+    }
+
+    fn value_conversion(&self) -> ValueConversion {
+        self.conversion
+    }
+
+    fn sparse_array_policy(&self) -> SparseArrayPolicy {
+        self.sparse
+    }
+
+    fn is_sensitive(&self, key: &str) -> bool {
+        self.sensitive
+            .iter()
+            .any(|pattern| crate::redact::is_match(pattern, key))
     }
 
     fn iter(&self, path: Option<ConfigurationPath>) -> Box<dyn Iterator<Item = (String, Value)>> {
@@ -300,18 +1217,14 @@ impl Deref for DefaultConfigurationRoot {
     }
 }
 
-cfg_if! {
-    if #[cfg(feature = "async")] {
-        unsafe impl Send for DefaultConfigurationRoot {}
-        unsafe impl Sync for DefaultConfigurationRoot {}
-    }
-}
-
 /// Represent a configuration section.
 #[derive(Clone)]
 pub struct DefaultConfigurationSection {
     root: Pc<dyn ConfigurationRoot>,
     path: String,
+    cache: Option<Pc<Mut<Cache>>>,
+    policy: ArrayMergePolicy,
+    aliases: std::sync::Arc<Vec<(String, String)>>,
 }
 
 impl DefaultConfigurationSection {
@@ -325,6 +1238,9 @@ impl DefaultConfigurationSection {
         Self {
             root: root.into(),
             path: path.to_owned(),
+            cache: None,
+            policy: Default::default(),
+            aliases: Default::default(),
         }
     }
 
@@ -339,28 +1255,46 @@ impl Configuration for DefaultConfigurationSection {
         self.root.get(&self.subkey(key))
     }
 
+    fn get_typed(&self, key: &str) -> Option<ConfigValue> {
+        self.root.get_typed(&self.subkey(key))
+    }
+
     fn section(&self, key: &str) -> Box<dyn ConfigurationSection> {
         self.root.section(&self.subkey(key))
     }
 
     fn children(&self) -> Vec<Box<dyn ConfigurationSection>> {
-        self.root
-            .providers()
-            .fold(Vec::new(), |mut earlier_keys, provider| {
-                provider.child_keys(&mut earlier_keys, Some(&self.path));
-                earlier_keys
-            })
-            .into_iter()
-            .collect::<HashSet<_>>()
-            .iter()
-            .map(|key| self.section(key))
-            .collect()
+        let keys = if let Some(cache) = &self.cache {
+            child_keys(
+                cache,
+                self.root.as_ref(),
+                self.policy,
+                &self.aliases,
+                Some(&self.path),
+            )
+        } else {
+            collect_child_keys(self.root.as_ref(), self.policy, Some(&self.path))
+        };
+
+        keys.iter().map(|key| self.section(key)).collect()
     }
 
     fn reload_token(&self) -> Box<dyn ChangeToken> {
         self.root.reload_token()
     }
 
+    fn value_conversion(&self) -> ValueConversion {
+        self.root.value_conversion()
+    }
+
+    fn sparse_array_policy(&self) -> SparseArrayPolicy {
+        self.root.sparse_array_policy()
+    }
+
+    fn is_sensitive(&self, key: &str) -> bool {
+        self.root.is_sensitive(&self.subkey(key))
+    }
+
     fn as_section(&self) -> Option<&dyn ConfigurationSection> {
         Some(self)
     }
@@ -386,9 +1320,19 @@ impl ConfigurationSection for DefaultConfigurationSection {
         self.root.get(&self.path).unwrap_or_default()
     }
 
+    fn typed_value(&self) -> ConfigValue {
+        self.root
+            .get_typed(&self.path)
+            .unwrap_or_else(|| ConfigValue::String(Default::default()))
+    }
+
     fn as_config(&self) -> Box<dyn Configuration> {
         Box::new(self.clone())
     }
+
+    fn overlay(&self) -> Option<ConfigurationOverlay> {
+        self.root.overlay()
+    }
 }
 
 impl<'a> AsRef<dyn Configuration + 'a> for DefaultConfigurationSection {
@@ -411,14 +1355,87 @@ impl Deref for DefaultConfigurationSection {
     }
 }
 
+// provides the default values registered with a builder
+struct DefaultValuesSource {
+    values: Vec<(String, String)>,
+}
+
+struct DefaultValuesProvider {
+    data: ConfigurationData,
+}
+
+impl ConfigurationProvider for DefaultValuesProvider {
+    fn get(&self, key: &str) -> Option<Value> {
+        self.data.get(key)
+    }
+
+    fn get_str(&self, key: &str) -> Option<ValueRef<'_>> {
+        self.data.get_str(key).map(ValueRef::Borrowed)
+    }
+
+    fn try_iter(&self) -> Option<ConfigurationPairs<'_>> {
+        Some(self.data.pairs())
+    }
+
+    fn child_keys(&self, earlier_keys: &mut Vec<String>, parent_path: Option<&str>) {
+        self.data.child_keys(earlier_keys, parent_path)
+    }
+}
+
+impl ConfigurationSource for DefaultValuesSource {
+    fn build(&self, _builder: &dyn ConfigurationBuilder) -> Box<dyn ConfigurationProvider> {
+        let data: HashMap<_, _> = self
+            .values
+            .iter()
+            .map(|(key, value)| (key.to_uppercase(), (key.clone(), value.clone().into())))
+            .collect();
+
+        Box::new(DefaultValuesProvider { data: data.into() })
+    }
+}
+
 /// Represents a configuration builder.
 #[derive(Default)]
 pub struct DefaultConfigurationBuilder {
     /// Gets the associated configuration sources.
     pub sources: Vec<Box<dyn ConfigurationSource>>,
 
+    /// Gets the configuration sources that provide default values.
+    ///
+    /// # Remarks
+    ///
+    /// Default sources are always built before, and are overridden by, all other sources.
+    pub default_sources: Vec<Box<dyn ConfigurationSource>>,
+
     /// Gets the properties that can be passed to configuration sources.
     pub properties: HashMap<String, Box<dyn Any>>,
+
+    /// Gets the policy used to combine indexed sections across sources.
+    pub array_merge_policy: ArrayMergePolicy,
+
+    /// Gets the conversion applied to configuration values before they are parsed.
+    pub value_conversion: ValueConversion,
+
+    /// Gets the policy used to bind arrays whose indices have gaps.
+    pub sparse_array_policy: SparseArrayPolicy,
+
+    /// Gets the transforms applied to every configuration value.
+    pub value_transforms: Vec<ValueTransform>,
+
+    /// Gets the aliases that map deprecated keys to the keys that replace them.
+    pub key_aliases: Vec<(String, String)>,
+
+    /// Gets the function invoked when a deprecated key is read.
+    pub deprecated_key_callback: Option<DeprecatedKeyCallback>,
+
+    /// Gets a value indicating whether every configuration value that is read is recorded.
+    pub access_log: bool,
+
+    /// Gets a value indicating whether the configuration values that are never read are tracked.
+    pub unused_keys: bool,
+
+    /// Gets the patterns of the keys whose values are sensitive.
+    pub sensitive_keys: Vec<String>,
 }
 
 impl DefaultConfigurationBuilder {
@@ -426,6 +1443,389 @@ impl DefaultConfigurationBuilder {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Sets the policy used to combine indexed sections across sources.
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - The [`ArrayMergePolicy`](crate::ArrayMergePolicy) to apply
+    pub fn merge_arrays(&mut self, policy: ArrayMergePolicy) -> &mut Self {
+        self.array_merge_policy = policy;
+        self
+    }
+
+    /// Sets the conversion applied to configuration values before they are parsed.
+    ///
+    /// # Arguments
+    ///
+    /// * `conversion` - The [`ValueConversion`](crate::ValueConversion) to apply
+    ///
+    /// # Remarks
+    ///
+    /// The conversion is used by [`ConfigurationBinder`](crate::ConfigurationBinder) when
+    /// getting and binding values.
+    pub fn convert_values(&mut self, conversion: ValueConversion) -> &mut Self {
+        self.value_conversion = conversion;
+        self
+    }
+
+    /// Sets the policy used to bind arrays whose indices have gaps.
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - The [`SparseArrayPolicy`](crate::SparseArrayPolicy) to apply
+    ///
+    /// # Remarks
+    ///
+    /// Gaps typically occur when the arrays of multiple sources are merged. The policy is used by
+    /// [`ConfigurationBinder`](crate::ConfigurationBinder) when binding arrays.
+    pub fn sparse_arrays(&mut self, policy: SparseArrayPolicy) -> &mut Self {
+        self.sparse_array_policy = policy;
+        self
+    }
+
+    /// Inserts a configuration source at the specified position.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The zero-based position of the source
+    /// * `source` - The [`ConfigurationSource`](crate::ConfigurationSource) to insert
+    ///
+    /// # Remarks
+    ///
+    /// Sources added later override the values of sources added earlier. This method panics if
+    /// the index is greater than the number of sources.
+    pub fn insert(&mut self, index: usize, source: Box<dyn ConfigurationSource>) -> &mut Self {
+        self.sources.insert(index, source);
+        self
+    }
+
+    /// Removes the configuration sources that match the specified predicate.
+    ///
+    /// # Arguments
+    ///
+    /// * `predicate` - The function used to determine whether a source is removed
+    pub fn remove_where<F>(&mut self, mut predicate: F) -> &mut Self
+    where
+        F: FnMut(&dyn ConfigurationSource) -> bool,
+    {
+        self.sources.retain(|source| !predicate(source.as_ref()));
+        self
+    }
+
+    /// Replaces the first configuration source of the specified type.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The [`ConfigurationSource`](crate::ConfigurationSource) that replaces the
+    ///   existing source
+    ///
+    /// # Remarks
+    ///
+    /// A source matches when its [type](crate::ConfigurationSource::source_type) is `T`,
+    /// regardless of its name. If no source matches, the source is added.
+    pub fn replace<T: ConfigurationSource + 'static>(
+        &mut self,
+        source: Box<dyn ConfigurationSource>,
+    ) -> &mut Self {
+        let source_type = std::any::TypeId::of::<T>();
+
+        match self
+            .sources
+            .iter()
+            .position(|s| s.source_type() == source_type)
+        {
+            Some(index) => self.sources[index] = source,
+            _ => self.sources.push(source),
+        }
+
+        self
+    }
+
+    /// Adds a configuration source that provides default values.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The [`ConfigurationSource`](crate::ConfigurationSource) to add
+    ///
+    /// # Remarks
+    ///
+    /// Default sources are always built before, and are overridden by, all other sources
+    /// regardless of the order in which they are added.
+    pub fn add_default(&mut self, source: Box<dyn ConfigurationSource>) -> &mut Self {
+        self.default_sources.push(source);
+        self
+    }
+
+    /// Sets the default value for a configuration key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the value to set
+    /// * `value` - The default value
+    pub fn set_default(&mut self, key: &str, value: &str) -> &mut Self {
+        self.set_defaults(&[(key, value)])
+    }
+
+    /// Sets the default values for a set of configuration keys.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - The key/value pairs of the default values
+    pub fn set_defaults<S: AsRef<str>>(&mut self, values: &[(S, S)]) -> &mut Self {
+        let values = values
+            .iter()
+            .map(|(key, value)| (key.as_ref().to_owned(), value.as_ref().to_owned()))
+            .collect();
+
+        self.add_default(Box::new(DefaultValuesSource { values }))
+    }
+
+    /// Adds a transform applied to every configuration value, such as trimming whitespace or
+    /// expanding variables.
+    ///
+    /// # Arguments
+    ///
+    /// * `transform` - The function invoked with the key and value, which returns the new
+    ///   value or `None` if the value is unchanged
+    ///
+    /// # Remarks
+    ///
+    /// Transforms are applied in the order they are added.
+    pub fn add_value_transform<F>(&mut self, transform: F) -> &mut Self
+    where
+        F: Fn(&str, &str) -> Option<String> + Send + Sync + 'static,
+    {
+        self.value_transforms.push(std::sync::Arc::new(transform));
+        self
+    }
+
+    /// Declares that a key has been renamed so that the deprecated key continues to work.
+    ///
+    /// # Arguments
+    ///
+    /// * `old` - The deprecated key or section path, such as `Old:Path`
+    /// * `new` - The key or section path that replaces it, such as `New:Path`
+    ///
+    /// # Remarks
+    ///
+    /// A value configured with the new key always takes precedence. Otherwise, reading the
+    /// new key, or any key in its section, reads the corresponding deprecated key.
+    pub fn alias(&mut self, old: &str, new: &str) -> &mut Self {
+        self.key_aliases.push((old.to_owned(), new.to_owned()));
+        self
+    }
+
+    /// Sets the function invoked when a deprecated key is read through an alias.
+    ///
+    /// # Arguments
+    ///
+    /// * `callback` - The function invoked with the deprecated key and the key that replaces it
+    ///
+    /// # Remarks
+    ///
+    /// The function is invoked once for each deprecated key that is read, which is typically
+    /// used to log a warning.
+    pub fn on_deprecated_key<F>(&mut self, callback: F) -> &mut Self
+    where
+        F: Fn(&str, &str) + Send + Sync + 'static,
+    {
+        self.deprecated_key_callback = Some(std::sync::Arc::new(callback));
+        self
+    }
+
+    /// Marks the keys that match the specified patterns as sensitive.
+    ///
+    /// # Arguments
+    ///
+    /// * `patterns` - The case-insensitive patterns of the keys, where `*` matches any text,
+    ///   such as `*:Password` or `*Secret*`
+    ///
+    /// # Remarks
+    ///
+    /// The values of sensitive keys are displayed as [`REDACTED`](crate::REDACTED) in the
+    /// debug view of a configuration and in a [`diff`](crate::diff).
+    pub fn mark_sensitive<S: AsRef<str>>(&mut self, patterns: &[S]) -> &mut Self {
+        self.sensitive_keys
+            .extend(patterns.iter().map(|pattern| pattern.as_ref().to_owned()));
+        self
+    }
+
+    /// Records every configuration value that is read.
+    ///
+    /// # Remarks
+    ///
+    /// Each read is recorded with its key, the name of the provider that resolved the value,
+    /// and the time it was read, which are retrieved with
+    /// [`access_log`](crate::ConfigurationRoot::access_log). Every read is retained, so
+    /// recording is typically only enabled while diagnosing an application.
+    pub fn record_access(&mut self) -> &mut Self {
+        self.access_log = true;
+        self
+    }
+
+    /// Tracks the configuration values that are never read.
+    ///
+    /// # Remarks
+    ///
+    /// The keys of the values that have never been read are retrieved with
+    /// [`unused_keys`](crate::ConfigurationRoot::unused_keys). Tracking is opt-in because every
+    /// read must be recorded.
+    pub fn track_unused_keys(&mut self) -> &mut Self {
+        self.unused_keys = true;
+        self
+    }
+
+    /// Builds [`ConfigurationRoot`](crate::ConfigurationRoot) with the keys and values from the
+    /// registered [`ConfigurationSource`](crate::ConfigurationSource) set, deferring the loading
+    /// of each provider until one of its values is first requested.
+    ///
+    /// # Remarks
+    ///
+    /// Errors are not reported until [`ensure_loaded`](crate::ConfigurationRoot::ensure_loaded)
+    /// or [`reload`](crate::ConfigurationRoot::reload) is called. A provider that fails to load
+    /// on first access does not provide any values.
+    pub fn build_lazy(&self) -> Box<dyn ConfigurationRoot> {
+        let root = DefaultConfigurationRoot::new_lazy(self.build_providers());
+        Box::new(self.configure(root))
+    }
+
+    fn build_providers(&self) -> Vec<Box<dyn ConfigurationProvider>> {
+        prioritize(self.default_sources.iter().chain(self.sources.iter()))
+            .into_iter()
+            .map(|s| s.build(self))
+            .collect()
+    }
+
+    fn configure(&self, root: DefaultConfigurationRoot) -> DefaultConfigurationRoot {
+        let mut root = self.value_transforms.iter().cloned().fold(
+            root.with_array_merge_policy(self.array_merge_policy)
+                .with_value_conversion(self.value_conversion)
+                .with_sparse_array_policy(self.sparse_array_policy),
+            |root, transform| root.with_value_transform(transform),
+        );
+
+        for (old, new) in &self.key_aliases {
+            root = root.with_key_alias(old, new);
+        }
+
+        if let Some(callback) = &self.deprecated_key_callback {
+            root = root.with_deprecated_key_callback(callback.clone());
+        }
+
+        if self.access_log {
+            root = root.with_access_log();
+        }
+
+        if self.unused_keys {
+            root = root.with_unused_key_tracking();
+        }
+
+        for pattern in &self.sensitive_keys {
+            root = root.with_sensitive_key(pattern);
+        }
+
+        root
+    }
+
+    /// Sets the function invoked when a configuration provider fails to reload in the background.
+    ///
+    /// # Arguments
+    ///
+    /// * `callback` - The function invoked with the provider name and error
+    ///
+    /// # Remarks
+    ///
+    /// The function applies to every source that does not specify its own function; for
+    /// example, with [`FileSourceBuilder::on_reload_error`](crate::FileSourceBuilder::on_reload_error).
+    pub fn on_reload_error<F>(&mut self, callback: F) -> &mut Self
+    where
+        F: Fn(&str, LoadError) + Send + Sync + 'static,
+    {
+        let callback: ReloadErrorCallback = std::sync::Arc::new(callback);
+        self.set_property(RELOAD_ERROR_CALLBACK, callback)
+    }
+
+    /// Sets the environment the application is hosted in.
+    ///
+    /// # Arguments
+    ///
+    /// * `environment` - The [`HostEnvironment`](crate::HostEnvironment) of the application
+    ///
+    /// # Remarks
+    ///
+    /// The environment is added to the [`properties`](crate::ConfigurationBuilder::properties)
+    /// so that configuration sources can react to it. Use [`HostEnvironment::of`] to get it.
+    pub fn use_environment(&mut self, environment: HostEnvironment) -> &mut Self {
+        self.set_property(HOST_ENVIRONMENT, environment)
+    }
+
+    /// Sets the path that relative file paths are resolved against.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The base path of relative files
+    ///
+    /// # Remarks
+    ///
+    /// The path applies to every file source, including sources that are added before it is set,
+    /// because it is read when the sources are built. Absolute file paths are not affected.
+    pub fn set_base_path<P: Into<PathBuf>>(&mut self, path: P) -> &mut Self {
+        self.set_property(BASE_PATH, path.into())
+    }
+
+    /// Sets the bootstrap configuration that is available to each configuration source.
+    ///
+    /// # Arguments
+    ///
+    /// * `configuration` - The [`ConfigurationRoot`](crate::ConfigurationRoot) built before this
+    ///   configuration, typically from environment variables and the command line
+    ///
+    /// # Remarks
+    ///
+    /// The bootstrap configuration is added to the
+    /// [`properties`](crate::ConfigurationBuilder::properties) so that configuration sources can
+    /// read settings that apply to the sources themselves, such as the address of a secret store,
+    /// when they are built. Use [`bootstrap`](trait.ConfigurationBuilder.html#method.bootstrap)
+    /// to get it. The bootstrap configuration is not part of the built configuration.
+    pub fn with_bootstrap<C>(&mut self, configuration: C) -> &mut Self
+    where
+        C: Into<std::sync::Arc<dyn ConfigurationRoot>>,
+    {
+        self.set_property(BOOTSTRAP, configuration.into())
+    }
+
+    /// Sets a property shared with each configuration source.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the property
+    /// * `value` - The value of the property
+    ///
+    /// # Remarks
+    ///
+    /// An existing property with the same key is replaced. Configuration sources read the
+    /// property when they are built; for example, with
+    /// [`property`](trait.ConfigurationBuilder.html#method.property).
+    pub fn set_property<T: Any>(&mut self, key: &str, value: T) -> &mut Self {
+        self.properties.insert(key.to_owned(), Box::new(value));
+        self
+    }
+
+    /// Gets a typed property shared with each configuration source, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the property to retrieve
+    ///
+    /// # Remarks
+    ///
+    /// `None` is returned when the property does not exist or is not of the requested type.
+    pub fn property<T: Any>(&self, key: &str) -> Option<&T> {
+        self.properties
+            .get(key)
+            .and_then(|value| value.downcast_ref::<T>())
+    }
 }
 
 impl ConfigurationBuilder for DefaultConfigurationBuilder {
@@ -437,13 +1837,16 @@ impl ConfigurationBuilder for DefaultConfigurationBuilder {
         &self.sources
     }
 
+    fn default_sources(&self) -> &[Box<dyn ConfigurationSource>] {
+        &self.default_sources
+    }
+
     fn add(&mut self, source: Box<dyn ConfigurationSource>) {
         self.sources.push(source)
     }
 
     fn build(&self) -> Result<Box<dyn ConfigurationRoot>, ReloadError> {
-        Ok(Box::new(DefaultConfigurationRoot::new(
-            self.sources.iter().map(|s| s.build(self)).collect(),
-        )?))
+        let root = DefaultConfigurationRoot::new(self.build_providers())?;
+        Ok(Box::new(self.configure(root)))
     }
 }
@@ -0,0 +1,130 @@
+use crate::{Configuration, ConfigurationPath, REDACTED};
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter, Result as FormatResult};
+
+/// Represents a difference between two configurations.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DiffEntry {
+    /// Indicates a key that was added.
+    Added {
+        /// Gets the key that was added.
+        key: String,
+
+        /// Gets the value of the key.
+        value: String,
+    },
+
+    /// Indicates a key that was removed.
+    Removed {
+        /// Gets the key that was removed.
+        key: String,
+
+        /// Gets the value of the key before it was removed.
+        value: String,
+    },
+
+    /// Indicates a key whose value changed.
+    Changed {
+        /// Gets the key whose value changed.
+        key: String,
+
+        /// Gets the old value of the key.
+        old: String,
+
+        /// Gets the new value of the key.
+        new: String,
+    },
+}
+
+impl DiffEntry {
+    /// Gets the key the difference applies to.
+    pub fn key(&self) -> &str {
+        match self {
+            Self::Added { key, .. } => key,
+            Self::Removed { key, .. } => key,
+            Self::Changed { key, .. } => key,
+        }
+    }
+}
+
+impl Display for DiffEntry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FormatResult {
+        match self {
+            Self::Added { key, value } => write!(f, "+ {} = {}", key, value),
+            Self::Removed { key, value } => write!(f, "- {} = {}", key, value),
+            Self::Changed { key, old, new } => write!(f, "~ {} = {} -> {}", key, old, new),
+        }
+    }
+}
+
+// the values of a configuration keyed by the normalized key
+fn values(configuration: &dyn Configuration) -> BTreeMap<String, (String, String)> {
+    configuration
+        .iter(Some(ConfigurationPath::Relative))
+        .filter_map(|(key, _)| {
+            configuration
+                .get(&key)
+                .map(|value| (key.to_uppercase(), (key, value.to_string())))
+        })
+        .collect()
+}
+
+fn redact(value: String, sensitive: bool) -> String {
+    if sensitive {
+        REDACTED.to_owned()
+    } else {
+        value
+    }
+}
+
+/// Compares two configurations.
+///
+/// # Arguments
+///
+/// * `old` - The original [`Configuration`](crate::Configuration), such as a snapshot
+///   before a reload
+/// * `new` - The [`Configuration`](crate::Configuration) to compare with
+///
+/// # Remarks
+///
+/// Keys are compared case-insensitively. The differences are ordered by key. The values of
+/// sensitive keys are reported as [`REDACTED`](crate::REDACTED).
+pub fn diff(old: &dyn Configuration, new: &dyn Configuration) -> Vec<DiffEntry> {
+    let sensitive = |key: &str| old.is_sensitive(key) || new.is_sensitive(key);
+    let mut old_values = values(old);
+    let mut entries = Vec::new();
+
+    for (normalized_key, (key, value)) in values(new) {
+        let redacted = sensitive(&key);
+
+        match old_values.remove(&normalized_key) {
+            Some((_, old)) if old != value => entries.push((
+                normalized_key,
+                DiffEntry::Changed {
+                    old: redact(old, redacted),
+                    new: redact(value, redacted),
+                    key,
+                },
+            )),
+            Some(_) => {}
+            _ => entries.push((
+                normalized_key,
+                DiffEntry::Added {
+                    value: redact(value, redacted),
+                    key,
+                },
+            )),
+        }
+    }
+
+    entries.extend(
+        old_values
+            .into_iter()
+            .map(|(normalized_key, (key, value))| {
+                let value = redact(value, sensitive(&key));
+                (normalized_key, DiffEntry::Removed { key, value })
+            }),
+    );
+    entries.sort_by(|e1, e2| e1.0.cmp(&e2.0));
+    entries.into_iter().map(|(_, entry)| entry).collect()
+}
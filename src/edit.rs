@@ -0,0 +1,189 @@
+use crate::ini::{denormalize, normalize, sections, COMMENTS};
+use crate::{util::ConfigurationData, ConfigurationPath, ParseError};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::Arc;
+
+// replaces the value of a key/value line, retaining its spacing and any trailing comment
+fn replace_value(line: &str, value: &str) -> String {
+    let code_end = line.find(&COMMENTS[..]).unwrap_or(line.len());
+    let code = &line[..code_end];
+    let comment = &line[code_end..];
+
+    let separator = match code.find('=').or_else(|| code.find(':')) {
+        Some(separator) => separator,
+        _ => {
+            let spacing = if comment.is_empty() { "" } else { " " };
+            return format!("{}={}{}{}", code.trim_end(), value, spacing, comment);
+        }
+    };
+
+    let rest = &code[(separator + 1)..];
+    let start = separator + 1 + (rest.len() - rest.trim_start().len());
+    let end = start + line[start..code_end].trim_end().len();
+
+    if &line[start..end] == value {
+        return line.to_owned();
+    }
+
+    let spacing = if end == code_end && !comment.is_empty() && !value.is_empty() {
+        " "
+    } else {
+        ""
+    };
+
+    format!("{}{}{}{}", &line[..start], value, spacing, &line[end..])
+}
+
+/// Edits the content of an `*.ini` file so that it matches the configuration data.
+///
+/// # Remarks
+///
+/// Comments, blank lines, and the order and spacing of the existing entries are retained. The
+/// values of existing keys are replaced in place, keys that no longer exist are removed, and new
+/// keys are appended to the end of their section. New sections are appended to the end of the
+/// content.
+pub(crate) fn ini(
+    content: &[u8],
+    data: &ConfigurationData,
+    delimiter: Option<&str>,
+) -> Result<Vec<u8>, ParseError> {
+    // every value must be representable, including the values that are replaced in place
+    sections(data.pairs())?;
+
+    let text = std::str::from_utf8(content).map_err(|e| ParseError {
+        message: e.to_string(),
+        source: Some(Arc::new(e)),
+        ..Default::default()
+    })?;
+    let newline = if text.contains("\r\n") { "\r\n" } else { "\n" };
+    let mut lines: Vec<_> = text.lines().map(str::to_owned).collect();
+    let mut entries = Vec::<(usize, String)>::new();
+    let mut counts = HashMap::<String, usize>::new();
+    let mut ends = HashMap::<String, usize>::new();
+    let mut root_before_header = false;
+    let mut section = String::new();
+
+    for (number, line) in lines.iter().enumerate() {
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with(&COMMENTS[..]) {
+            continue;
+        }
+
+        let line = match line.find(&COMMENTS[..]) {
+            Some(end) => line[..end].trim_end(),
+            _ => line,
+        };
+
+        if let Some(header) = line.strip_prefix('[') {
+            if let Some(end) = header.rfind(']') {
+                if !ends.contains_key("") {
+                    ends.insert(String::new(), number);
+                    root_before_header = true;
+                }
+
+                section = normalize(&header[..end], delimiter);
+                ends.entry(section.to_uppercase()).or_insert(number + 1);
+            }
+
+            continue;
+        }
+
+        let key = match line.find('=').or_else(|| line.find(':')) {
+            Some(position) => &line[..position],
+            _ => line,
+        };
+        let key = normalize(key, delimiter);
+        let key = if section.is_empty() {
+            key
+        } else {
+            ConfigurationPath::combine(&[&section, &key])
+        };
+
+        *counts.entry(key.to_uppercase()).or_default() += 1;
+        ends.insert(section.to_uppercase(), number + 1);
+        entries.push((number, key));
+    }
+
+    ends.entry(String::new()).or_insert(lines.len());
+
+    let mut indexes = HashMap::<String, usize>::new();
+    let mut written = HashSet::new();
+    let mut removed = HashSet::new();
+
+    for (number, key) in entries {
+        let normalized = key.to_uppercase();
+
+        // repeated keys are the elements of an array
+        let key = if counts[&normalized] > 1 {
+            let index = indexes.entry(normalized).or_default();
+            let key = ConfigurationPath::combine(&[&key, &index.to_string()]);
+            *index += 1;
+            key
+        } else {
+            key
+        };
+
+        if let Some(value) = data.get(&key) {
+            lines[number] = replace_value(&lines[number], &value);
+            written.insert(key.to_uppercase());
+        } else {
+            removed.insert(number);
+        }
+    }
+
+    let mut insertions = BTreeMap::<usize, Vec<String>>::new();
+    let mut appended = Vec::new();
+    let added = data
+        .pairs()
+        .filter(|(key, _)| !written.contains(&key.to_uppercase()));
+
+    for (section, pairs) in sections(added)? {
+        let pairs = pairs
+            .into_iter()
+            .map(|(name, value)| format!("{}={}", denormalize(&name, delimiter), value));
+
+        if let Some(&end) = ends.get(&section.to_uppercase()) {
+            let insertion = insertions.entry(end).or_default();
+
+            insertion.extend(pairs);
+
+            if section.is_empty() && root_before_header {
+                insertion.push(String::new());
+            }
+        } else {
+            if !appended.is_empty() || lines.last().map_or(false, |l| !l.trim().is_empty()) {
+                appended.push(String::new());
+            }
+
+            appended.push(format!("[{}]", denormalize(&section, delimiter)));
+            appended.extend(pairs);
+        }
+    }
+
+    let mut output = Vec::with_capacity(lines.len() + appended.len());
+
+    for (number, line) in lines.into_iter().enumerate() {
+        if let Some(insertion) = insertions.remove(&number) {
+            output.extend(insertion);
+        }
+
+        if !removed.contains(&number) {
+            output.push(line);
+        }
+    }
+
+    for (_, insertion) in insertions {
+        output.extend(insertion);
+    }
+
+    output.extend(appended);
+
+    let mut content = output.join(newline);
+
+    if !content.is_empty() {
+        content.push_str(newline);
+    }
+
+    Ok(content.into_bytes())
+}
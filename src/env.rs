@@ -1,15 +1,125 @@
 use crate::{
-    util::accumulate_child_keys, ConfigurationBuilder, ConfigurationProvider, ConfigurationSource,
-    LoadResult, Value,
+    util::{to_pascal_case_parts, ConfigurationData},
+    ConfigurationBuilder, ConfigurationPairs, ConfigurationPath, ConfigurationProvider,
+    ConfigurationSource, LoadResult, Value, ValueRef,
 };
+use std::collections::hash_map::DefaultHasher;
 use std::collections::HashMap;
 use std::env::vars;
+use std::hash::{Hash, Hasher};
+use std::sync::mpsc::{channel, RecvTimeoutError, Sender};
+use std::sync::Arc;
+use std::thread::spawn;
+use std::time::Duration;
+use tokens::{
+    Callback, ChangeToken, NeverChangeToken, Registration, SharedChangeToken, SingleChangeToken,
+    State,
+};
+
+// the environment variables that start with any of the uppercase prefixes, sorted by name
+fn filter(prefixes: &[String]) -> Vec<(String, String)> {
+    let mut variables: Vec<_> = vars()
+        .filter(|(key, _)| {
+            let key = key.to_uppercase();
+            prefixes.iter().any(|prefix| key.starts_with(prefix))
+        })
+        .collect();
+
+    variables.sort();
+    variables
+}
+
+fn fingerprint(variables: &[(String, String)]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    variables.hash(&mut hasher);
+    hasher.finish()
+}
+
+// polls the environment variables that start with the prefix until they differ from the
+// variables that were loaded. the polling thread is not joined so that the token can be
+// dropped from a callback it raised; disconnecting the channel stops it at the next poll
+struct PollingEnvironmentChangeToken {
+    _stop: Sender<()>,
+    inner: Arc<SingleChangeToken>,
+}
+
+impl PollingEnvironmentChangeToken {
+    fn new(prefixes: Vec<String>, interval: Duration, original: u64) -> Self {
+        let inner = Arc::new(SingleChangeToken::default());
+        let handler = inner.clone();
+        let (sender, receiver) = channel::<()>();
+
+        spawn(move || {
+            while let Err(RecvTimeoutError::Timeout) = receiver.recv_timeout(interval) {
+                if fingerprint(&filter(&prefixes)) != original {
+                    handler.notify();
+                    break;
+                }
+            }
+        });
+
+        Self {
+            _stop: sender,
+            inner,
+        }
+    }
+}
+
+impl ChangeToken for PollingEnvironmentChangeToken {
+    fn changed(&self) -> bool {
+        self.inner.changed()
+    }
+
+    fn register(&self, callback: Callback, state: State) -> Registration {
+        self.inner.register(callback, state)
+    }
+}
+
+/// Defines the casing applied to the keys of environment variables.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeyCasing {
+    /// Indicates the keys retain the casing of the environment variables.
+    Preserve,
+
+    /// Indicates the keys are lowercase; for example, `connection_string`.
+    Lowercase,
+
+    /// Indicates the keys are PascalCase and underscores are removed; for example,
+    /// `ConnectionString`.
+    PascalCase,
+}
+
+impl Default for KeyCasing {
+    fn default() -> Self {
+        Self::Preserve
+    }
+}
+
+impl KeyCasing {
+    fn apply(&self, key: String) -> String {
+        match self {
+            Self::Preserve => key,
+            Self::Lowercase => key.to_lowercase(),
+            Self::PascalCase => key
+                .split(ConfigurationPath::key_delimiter())
+                .map(|segment| to_pascal_case_parts(segment.to_lowercase(), '_'))
+                .collect::<Vec<_>>()
+                .join(ConfigurationPath::key_delimiter()),
+        }
+    }
+}
 
 /// Represents a [`ConfigurationProvider`](crate::ConfigurationProvider) for environment variables.
 #[derive(Default)]
 pub struct EnvironmentVariablesConfigurationProvider {
-    prefix: String,
-    data: HashMap<String, (String, Value)>,
+    prefixes: Vec<String>,
+    casing: KeyCasing,
+    variables: HashMap<String, String>,
+    lists: Vec<String>,
+    list_separator: Option<char>,
+    poll_interval: Option<Duration>,
+    token: Option<SharedChangeToken<PollingEnvironmentChangeToken>>,
+    data: ConfigurationData,
 }
 
 impl EnvironmentVariablesConfigurationProvider {
@@ -20,36 +130,181 @@ impl EnvironmentVariablesConfigurationProvider {
     /// * `prefix` - A prefix used to filter the environment variables
     pub fn new(prefix: String) -> Self {
         Self {
-            prefix,
-            data: HashMap::with_capacity(0),
+            prefixes: vec![prefix],
+            casing: KeyCasing::Preserve,
+            variables: HashMap::new(),
+            lists: Vec::new(),
+            list_separator: None,
+            poll_interval: None,
+            token: None,
+            data: Default::default(),
         }
     }
+
+    /// Adds an alternate prefix used to filter the environment variables.
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix` - The alternate prefix
+    ///
+    /// # Remarks
+    ///
+    /// The longest matching prefix is removed from the environment variable names. For example,
+    /// both `MYAPP_` and `MY_APP_` can be accepted for the same application.
+    pub fn with_prefix<S: Into<String>>(mut self, prefix: S) -> Self {
+        self.prefixes.push(prefix.into());
+        self
+    }
+
+    /// Sets the casing applied to the configuration keys.
+    ///
+    /// # Arguments
+    ///
+    /// * `casing` - The [casing](KeyCasing) applied to the keys after the prefix is removed
+    pub fn with_casing(mut self, casing: KeyCasing) -> Self {
+        self.casing = casing;
+        self
+    }
+
+    /// Gets the name of the environment variable the specified key was loaded from, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The case-insensitive configuration key
+    pub fn variable(&self, key: &str) -> Option<&str> {
+        self.variables.get(&key.to_uppercase()).map(String::as_str)
+    }
+
+    /// Splits the values of the matching keys into indexed children.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - The key or key pattern of the values to split
+    ///
+    /// # Remarks
+    ///
+    /// The pattern is matched case-insensitively against the configuration key after the prefix is
+    /// removed. A pattern that ends with `*` matches every key that starts with the rest of the
+    /// pattern. For example, `SERVERS=a,b` becomes `Servers:0=a` and `Servers:1=b`.
+    pub fn with_list<S: Into<String>>(mut self, pattern: S) -> Self {
+        self.lists.push(pattern.into());
+        self
+    }
+
+    /// Sets the separator used to split list values.
+    ///
+    /// # Arguments
+    ///
+    /// * `separator` - The list value separator. The default value is `,`.
+    pub fn with_list_separator(mut self, separator: char) -> Self {
+        self.list_separator = Some(separator);
+        self
+    }
+
+    /// Polls the environment variables for changes at the specified interval.
+    ///
+    /// # Arguments
+    ///
+    /// * `interval` - The interval at which the environment variables are polled
+    ///
+    /// # Remarks
+    ///
+    /// The environment variables are enumerated again at each interval and the
+    /// [reload token](crate::ConfigurationProvider::reload_token) is triggered when they
+    /// differ from the loaded variables. The new values are available after the provider
+    /// is reloaded; for example, with [`ConfigurationRoot::reload`](crate::ConfigurationRoot::reload).
+    pub fn with_poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = Some(interval);
+        self
+    }
+
+    fn is_list(&self, key: &str) -> bool {
+        self.lists.iter().any(|pattern| {
+            if let Some(start) = pattern.strip_suffix('*') {
+                key.len() >= start.len()
+                    && key.is_char_boundary(start.len())
+                    && key[..start.len()].eq_ignore_ascii_case(start)
+            } else {
+                key.eq_ignore_ascii_case(pattern)
+            }
+        })
+    }
 }
 
 impl ConfigurationProvider for EnvironmentVariablesConfigurationProvider {
     fn get(&self, key: &str) -> Option<Value> {
-        self.data.get(&key.to_uppercase()).map(|t| t.1.clone())
+        self.data.get(key)
+    }
+
+    fn get_str(&self, key: &str) -> Option<ValueRef<'_>> {
+        self.data.get_str(key).map(ValueRef::Borrowed)
+    }
+
+    fn reload_token(&self) -> Box<dyn ChangeToken> {
+        if let Some(token) = &self.token {
+            Box::new(token.clone())
+        } else {
+            Box::new(NeverChangeToken::new())
+        }
     }
 
     fn load(&mut self) -> LoadResult {
         let mut data = HashMap::new();
-        let prefix = self.prefix.to_uppercase();
-        let prefix_len = self.prefix.len();
+        let mut names = HashMap::new();
+        let prefixes: Vec<_> = self.prefixes.iter().map(|p| p.to_uppercase()).collect();
+        let variables = filter(&prefixes);
 
-        for (key, value) in vars() {
-            if key.to_uppercase().starts_with(&prefix) {
-                let new_key = key[prefix_len..].to_string();
-                data.insert(new_key.to_uppercase().replace("__", ":"), (new_key, value.into()));
+        if let Some(interval) = self.poll_interval {
+            self.token = Some(SharedChangeToken::new(PollingEnvironmentChangeToken::new(
+                prefixes.clone(),
+                interval,
+                fingerprint(&variables),
+            )));
+        }
+
+        for (key, value) in variables {
+            let upper = key.to_uppercase();
+            let prefix_len = prefixes
+                .iter()
+                .filter(|prefix| upper.starts_with(prefix.as_str()))
+                .map(String::len)
+                .max()
+                .unwrap_or_default();
+            let new_key = self
+                .casing
+                .apply(key[prefix_len..].replace("__", ConfigurationPath::key_delimiter()));
+
+            if self.is_list(&new_key) {
+                let separator = self.list_separator.unwrap_or(',');
+
+                for (index, item) in value.split(separator).enumerate() {
+                    let item_key = ConfigurationPath::combine(&[&new_key, &index.to_string()]);
+                    names.insert(item_key.to_uppercase(), key.clone());
+                    data.insert(
+                        item_key.to_uppercase(),
+                        (item_key, item.trim().to_owned().into()),
+                    );
+                }
+            } else {
+                names.insert(new_key.to_uppercase(), key);
+                data.insert(new_key.to_uppercase(), (new_key, value.into()));
             }
         }
 
+        names.shrink_to_fit();
+        self.variables = names;
+
         data.shrink_to_fit();
-        self.data = data;
+        self.data = data.into();
         Ok(())
     }
 
+    fn try_iter(&self) -> Option<ConfigurationPairs<'_>> {
+        Some(self.data.pairs())
+    }
+
     fn child_keys(&self, earlier_keys: &mut Vec<String>, parent_path: Option<&str>) {
-        accumulate_child_keys(&self.data, earlier_keys, parent_path)
+        self.data.child_keys(earlier_keys, parent_path)
     }
 }
 
@@ -58,6 +313,22 @@ impl ConfigurationProvider for EnvironmentVariablesConfigurationProvider {
 pub struct EnvironmentVariablesConfigurationSource {
     /// A prefix used to filter environment variables.
     pub prefix: String,
+
+    /// Gets or sets the alternate prefixes used to filter environment variables.
+    pub prefixes: Vec<String>,
+
+    /// Gets or sets the casing applied to the configuration keys. The default value is
+    /// [`KeyCasing::Preserve`].
+    pub casing: KeyCasing,
+
+    /// Gets or sets the key patterns of the values that are split into indexed children.
+    pub lists: Vec<String>,
+
+    /// Gets or sets the separator used to split list values. The default value is `,`.
+    pub list_separator: Option<char>,
+
+    /// Gets or sets the interval used to poll the environment variables for changes, if any.
+    pub poll_interval: Option<Duration>,
 }
 
 impl EnvironmentVariablesConfigurationSource {
@@ -69,15 +340,83 @@ impl EnvironmentVariablesConfigurationSource {
     pub fn new(prefix: &str) -> Self {
         Self {
             prefix: prefix.to_owned(),
+            ..Default::default()
         }
     }
+
+    /// Adds an alternate prefix used to filter environment variables.
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix` - The alternate prefix
+    pub fn with_prefix<S: Into<String>>(mut self, prefix: S) -> Self {
+        self.prefixes.push(prefix.into());
+        self
+    }
+
+    /// Sets the casing applied to the configuration keys.
+    ///
+    /// # Arguments
+    ///
+    /// * `casing` - The [casing](KeyCasing) applied to the keys
+    pub fn with_casing(mut self, casing: KeyCasing) -> Self {
+        self.casing = casing;
+        self
+    }
+
+    /// Splits the values of the matching keys into indexed children.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - The key or key pattern of the values to split
+    pub fn with_list<S: Into<String>>(mut self, pattern: S) -> Self {
+        self.lists.push(pattern.into());
+        self
+    }
+
+    /// Sets the separator used to split list values.
+    ///
+    /// # Arguments
+    ///
+    /// * `separator` - The list value separator
+    pub fn with_list_separator(mut self, separator: char) -> Self {
+        self.list_separator = Some(separator);
+        self
+    }
+
+    /// Polls the environment variables for changes at the specified interval.
+    ///
+    /// # Arguments
+    ///
+    /// * `interval` - The interval at which the environment variables are polled
+    pub fn with_poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = Some(interval);
+        self
+    }
 }
 
 impl ConfigurationSource for EnvironmentVariablesConfigurationSource {
     fn build(&self, _builder: &dyn ConfigurationBuilder) -> Box<dyn ConfigurationProvider> {
-        Box::new(EnvironmentVariablesConfigurationProvider::new(
-            self.prefix.clone(),
-        ))
+        let mut provider = EnvironmentVariablesConfigurationProvider::new(self.prefix.clone())
+            .with_casing(self.casing);
+
+        for prefix in &self.prefixes {
+            provider = provider.with_prefix(prefix.clone());
+        }
+
+        for pattern in &self.lists {
+            provider = provider.with_list(pattern.clone());
+        }
+
+        if let Some(separator) = self.list_separator {
+            provider = provider.with_list_separator(separator);
+        }
+
+        if let Some(interval) = self.poll_interval {
+            provider = provider.with_poll_interval(interval);
+        }
+
+        Box::new(provider)
     }
 }
 
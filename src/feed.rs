@@ -0,0 +1,242 @@
+use crate::{
+    util::ConfigurationData, ConfigurationBuilder, ConfigurationPairs, ConfigurationProvider,
+    ConfigurationSource, LoadError, LoadResult, Value,
+};
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock, Weak};
+use tokens::{ChangeToken, SharedChangeToken, SingleChangeToken};
+
+/// Represents an update pushed by a dynamic key/value feed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ConfigurationUpdate {
+    /// Indicates that all of the key/value pairs are replaced.
+    Snapshot(Vec<(String, String)>),
+
+    /// Indicates that a key/value pair is added or changed.
+    Set(String, String),
+
+    /// Indicates that a key is removed.
+    Remove(String),
+}
+
+/// Defines the behavior of a dynamic key/value feed, such as a feature flag service.
+///
+/// # Remarks
+///
+/// A feed adapts a third-party SDK, such as LaunchDarkly, Unleash, or Flagsmith, so that its
+/// values can be used as configuration without this crate depending on the SDK.
+pub trait DynamicKeyValueFeed: Send + Sync {
+    /// Gets a snapshot of all of the key/value pairs provided by the feed.
+    fn snapshot(&self) -> Result<Vec<(String, String)>, LoadError>;
+
+    /// Subscribes to the updates of the feed.
+    ///
+    /// # Arguments
+    ///
+    /// * `sink` - The [`ConfigurationUpdateSink`] each update is sent to
+    ///
+    /// # Remarks
+    ///
+    /// This method is called once, after the first successful snapshot. It should return
+    /// immediately and send each update to the sink, typically from the change callback of
+    /// the SDK, until [`send`](ConfigurationUpdateSink::send) returns `false`.
+    fn subscribe(&self, sink: ConfigurationUpdateSink);
+}
+
+#[derive(Default)]
+struct State {
+    data: RwLock<ConfigurationData>,
+    token: RwLock<SharedChangeToken<SingleChangeToken>>,
+}
+
+impl State {
+    fn replace(&self, pairs: Vec<(String, String)>) {
+        let data: HashMap<_, _> = pairs
+            .into_iter()
+            .map(|(key, value)| (key.to_uppercase(), (key, value.into())))
+            .collect();
+
+        *self.data.write().unwrap() = data.into();
+    }
+
+    fn apply(&self, update: ConfigurationUpdate) {
+        match update {
+            ConfigurationUpdate::Snapshot(pairs) => self.replace(pairs),
+            ConfigurationUpdate::Set(key, value) => {
+                let mut data = self.data.write().unwrap();
+                let mut map = data.as_map().clone();
+
+                map.insert(key.to_uppercase(), (key, value.into()));
+                *data = map.into();
+            }
+            ConfigurationUpdate::Remove(key) => {
+                let mut data = self.data.write().unwrap();
+                let mut map = data.as_map().clone();
+
+                map.remove(&key.to_uppercase());
+                *data = map.into();
+            }
+        }
+
+        let previous = std::mem::take(&mut *self.token.write().unwrap());
+        previous.notify();
+    }
+}
+
+/// Represents the handle a feed sends its updates to.
+#[derive(Clone)]
+pub struct ConfigurationUpdateSink {
+    state: Weak<State>,
+}
+
+impl ConfigurationUpdateSink {
+    /// Applies an update and signals the reload token of the provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `update` - The [`ConfigurationUpdate`] to apply
+    ///
+    /// # Remarks
+    ///
+    /// `false` is returned if the provider has been dropped, which indicates the feed should stop
+    /// sending updates.
+    pub fn send(&self, update: ConfigurationUpdate) -> bool {
+        match self.state.upgrade() {
+            Some(state) => {
+                state.apply(update);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    // indicates whether the provider is still alive without applying an update
+    #[cfg(feature = "web")]
+    pub(crate) fn is_alive(&self) -> bool {
+        self.state.strong_count() > 0
+    }
+}
+
+/// Represents a [`ConfigurationProvider`](crate::ConfigurationProvider) for a
+/// [`DynamicKeyValueFeed`].
+pub struct FeedConfigurationProvider {
+    feed: Arc<dyn DynamicKeyValueFeed>,
+    state: Arc<State>,
+    subscribed: bool,
+}
+
+impl FeedConfigurationProvider {
+    /// Initializes a new feed configuration provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `feed` - The [`DynamicKeyValueFeed`] that provides the key/value pairs
+    pub fn new(feed: Arc<dyn DynamicKeyValueFeed>) -> Self {
+        Self {
+            feed,
+            state: Default::default(),
+            subscribed: false,
+        }
+    }
+}
+
+impl ConfigurationProvider for FeedConfigurationProvider {
+    fn get(&self, key: &str) -> Option<Value> {
+        self.state.data.read().unwrap().get(key)
+    }
+
+    fn reload_token(&self) -> Box<dyn ChangeToken> {
+        Box::new(self.state.token.read().unwrap().clone())
+    }
+
+    fn load(&mut self) -> LoadResult {
+        self.state.replace(self.feed.snapshot()?);
+
+        if !self.subscribed {
+            self.subscribed = true;
+            self.feed.subscribe(ConfigurationUpdateSink {
+                state: Arc::downgrade(&self.state),
+            });
+        }
+
+        Ok(())
+    }
+
+    fn close(&mut self) {
+        // the feed holds a weak reference to the state, so replacing the state causes
+        // subsequent updates to be rejected while the current values are retained
+        let data = self.state.data.read().unwrap().clone();
+
+        self.state = Arc::new(State {
+            data: RwLock::new(data),
+            token: Default::default(),
+        });
+    }
+
+    fn try_iter(&self) -> Option<ConfigurationPairs<'_>> {
+        let pairs: Vec<_> = self.state.data.read().unwrap().pairs().collect();
+        Some(Box::new(pairs.into_iter()))
+    }
+
+    fn child_keys(&self, earlier_keys: &mut Vec<String>, parent_path: Option<&str>) {
+        self.state
+            .data
+            .read()
+            .unwrap()
+            .child_keys(earlier_keys, parent_path)
+    }
+}
+
+/// Represents a [`ConfigurationSource`](crate::ConfigurationSource) for a
+/// [`DynamicKeyValueFeed`].
+pub struct FeedConfigurationSource {
+    feed: Arc<dyn DynamicKeyValueFeed>,
+}
+
+impl FeedConfigurationSource {
+    /// Initializes a new feed configuration source.
+    ///
+    /// # Arguments
+    ///
+    /// * `feed` - The [`DynamicKeyValueFeed`] that provides the key/value pairs
+    pub fn new<F: DynamicKeyValueFeed + 'static>(feed: F) -> Self {
+        Self {
+            feed: Arc::new(feed),
+        }
+    }
+}
+
+impl ConfigurationSource for FeedConfigurationSource {
+    fn build(&self, _builder: &dyn ConfigurationBuilder) -> Box<dyn ConfigurationProvider> {
+        Box::new(FeedConfigurationProvider::new(self.feed.clone()))
+    }
+}
+
+pub mod ext {
+
+    use super::*;
+
+    /// Defines extension methods for [`ConfigurationBuilder`](crate::ConfigurationBuilder).
+    pub trait FeedConfigurationBuilderExtensions {
+        /// Adds a dynamic key/value feed as a configuration source.
+        ///
+        /// # Arguments
+        ///
+        /// * `feed` - The [`DynamicKeyValueFeed`] that provides the key/value pairs
+        fn add_feed<F: DynamicKeyValueFeed + 'static>(&mut self, feed: F) -> &mut Self;
+    }
+
+    impl FeedConfigurationBuilderExtensions for dyn ConfigurationBuilder + '_ {
+        fn add_feed<F: DynamicKeyValueFeed + 'static>(&mut self, feed: F) -> &mut Self {
+            self.add(Box::new(FeedConfigurationSource::new(feed)));
+            self
+        }
+    }
+
+    impl<T: ConfigurationBuilder> FeedConfigurationBuilderExtensions for T {
+        fn add_feed<F: DynamicKeyValueFeed + 'static>(&mut self, feed: F) -> &mut Self {
+            self.add(Box::new(FeedConfigurationSource::new(feed)));
+            self
+        }
+    }
+}
@@ -1,5 +1,24 @@
+use crate::LoadError;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
 use std::path::{Path, PathBuf};
-use std::time::Duration;
+use std::sync::mpsc::{channel, RecvTimeoutError, Sender};
+use std::sync::Arc;
+use std::thread::spawn;
+use std::time::{Duration, SystemTime};
+use tokens::{Callback, ChangeToken, Registration, SingleChangeToken, State};
+
+/// Represents the type alias for a function that is invoked when a configuration provider
+/// fails to reload in the background.
+///
+/// # Remarks
+///
+/// The function is invoked with the name of the provider and the error that occurred.
+pub type ReloadErrorCallback = Arc<dyn Fn(&str, LoadError) + Send + Sync>;
+
+// the key of the ConfigurationBuilder property that holds the default ReloadErrorCallback
+#[cfg(any(feature = "std", feature = "file", all(feature = "refresh", feature = "async")))]
+pub(crate) const RELOAD_ERROR_CALLBACK: &str = "ReloadErrorCallback";
 
 /// Represents a file configuration source.
 #[derive(Clone)]
@@ -7,6 +26,15 @@ pub struct FileSource {
     /// Gets or sets the source file path.
     pub path: PathBuf,
 
+    /// Gets or sets the ordered paths of the files used when the source file does not exist.
+    /// The default value is empty.
+    ///
+    /// # Remarks
+    ///
+    /// The first existing file is chosen when the provider is built. Only the chosen file is
+    /// loaded and, if applicable, watched for changes.
+    pub fallbacks: Vec<PathBuf>,
+
     /// Gets or sets a value indicating whether the file is optional.
     /// The default value is false.
     pub optional: bool,
@@ -22,6 +50,24 @@ pub struct FileSource {
     ///
     /// This helps avoid triggering reload before a file is completely written.
     pub reload_delay: Duration,
+
+    /// Gets or sets the interval used to poll the file for changes, if any.
+    /// The default value is `None`.
+    ///
+    /// # Remarks
+    ///
+    /// When specified, changes are detected by periodically comparing the modification
+    /// time and content hash of the file instead of file system events. This is useful
+    /// for network file systems and bind mounts, which often do not raise events.
+    pub poll_interval: Option<Duration>,
+
+    /// Gets or sets the function invoked when the file fails to reload, if any.
+    /// The default value is `None`.
+    ///
+    /// # Remarks
+    ///
+    /// The previously loaded configuration is retained when the file fails to reload.
+    pub on_reload_error: Option<ReloadErrorCallback>,
 }
 
 impl FileSource {
@@ -41,9 +87,12 @@ impl FileSource {
     ) -> Self {
         Self {
             path,
+            fallbacks: Vec::new(),
             optional,
             reload_on_change,
             reload_delay: reload_delay.unwrap_or(Duration::from_millis(250)),
+            poll_interval: None,
+            on_reload_error: None,
         }
     }
 
@@ -55,6 +104,94 @@ impl FileSource {
     pub fn optional<P: AsRef<Path>>(path: P) -> Self {
         Self::new(path.as_ref().to_path_buf(), true, false, None)
     }
+
+    /// Gets the path of the first existing file among the source file and its fallbacks.
+    ///
+    /// # Remarks
+    ///
+    /// The source file path is returned when none of the files exist.
+    pub fn resolve(&self) -> &Path {
+        std::iter::once(&self.path)
+            .chain(&self.fallbacks)
+            .find(|path| path.is_file())
+            .unwrap_or(&self.path)
+    }
+
+    #[cfg(feature = "file")]
+    pub(crate) fn change_token(&self) -> Box<dyn ChangeToken> {
+        if let Some(interval) = self.poll_interval {
+            Box::new(PollingFileChangeToken::new(&self.path, interval))
+        } else {
+            Box::new(WatchedFileChangeToken(Some(tokens::FileChangeToken::new(
+                &self.path,
+            ))))
+        }
+    }
+
+    // the file source configured for a provider, which uses the reload error callback of
+    // the builder unless the file source specifies its own and resolves relative paths
+    // against the base path of the builder
+    #[cfg(feature = "file")]
+    pub(crate) fn configure(&self, builder: &dyn crate::ConfigurationBuilder) -> Self {
+        let mut file = self.clone();
+
+        if file.on_reload_error.is_none() {
+            file.on_reload_error = builder
+                .property::<ReloadErrorCallback>(RELOAD_ERROR_CALLBACK)
+                .cloned();
+        }
+
+        if let Some(base) = builder.base_path() {
+            file.path = base.join(&file.path);
+
+            for fallback in &mut file.fallbacks {
+                *fallback = base.join(&fallback);
+            }
+        }
+
+        file
+    }
+
+    #[cfg(feature = "file")]
+    pub(crate) fn read(&self) -> Result<Vec<u8>, LoadError> {
+        std::fs::read(&self.path).map_err(|e| {
+            self.invalid(crate::ParseError {
+                message: format!(
+                    "The configuration file '{}' could not be read. {}",
+                    self.path.display(),
+                    e
+                ),
+                source: Some(Arc::new(e)),
+                ..Default::default()
+            })
+        })
+    }
+
+    #[cfg(feature = "file")]
+    pub(crate) fn error(&self, message: String) -> LoadError {
+        self.invalid(message.into())
+    }
+
+    #[cfg(feature = "file")]
+    pub(crate) fn invalid(&self, error: crate::ParseError) -> LoadError {
+        LoadError::File {
+            message: error.message,
+            path: self.path.clone(),
+            line: error.line,
+            column: error.column,
+            key: error.key,
+            source: error.source,
+        }
+    }
+
+    #[cfg(feature = "file")]
+    pub(crate) fn reload_failed(&self, provider: &str, error: LoadError) {
+        crate::trace::reload_failed(provider, &error);
+
+        if let Some(callback) = &self.on_reload_error {
+            callback(provider, error)
+        }
+    }
 }
 
 impl From<PathBuf> for FileSource {
@@ -96,9 +233,12 @@ impl From<&String> for FileSource {
 /// Represents a builder for a file source.
 pub struct FileSourceBuilder {
     path: PathBuf,
+    fallbacks: Vec<PathBuf>,
     optional: bool,
     reload_on_change: bool,
     reload_delay: Option<Duration>,
+    poll_interval: Option<Duration>,
+    on_reload_error: Option<ReloadErrorCallback>,
 }
 
 impl FileSourceBuilder {
@@ -110,12 +250,43 @@ impl FileSourceBuilder {
     pub fn new(path: PathBuf) -> Self {
         Self {
             path,
+            fallbacks: Vec::new(),
             optional: false,
             reload_on_change: false,
             reload_delay: None,
+            poll_interval: None,
+            on_reload_error: None,
         }
     }
 
+    /// Initializes a new file source builder for the first existing file of the specified paths.
+    ///
+    /// # Arguments
+    ///
+    /// * `paths` - The ordered paths of the candidate files
+    ///
+    /// # Remarks
+    ///
+    /// Candidates are typically ordered from the most to the least specific location, such as
+    /// `/etc/app/config.json`, `~/.config/app.json`, and `./config.json`.
+    pub fn first_existing<P: AsRef<Path>>(paths: &[P]) -> Self {
+        let mut paths = paths.iter().map(|path| path.as_ref().to_path_buf());
+        let mut builder = Self::new(paths.next().unwrap_or_default());
+
+        builder.fallbacks.extend(paths);
+        builder
+    }
+
+    /// Adds a file to use when the preceding files do not exist.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the fallback file
+    pub fn or_else<P: AsRef<Path>>(mut self, path: P) -> Self {
+        self.fallbacks.push(path.as_ref().to_path_buf());
+        self
+    }
+
     /// Indicates the file source is optional.
     pub fn optional(mut self) -> Self {
         self.optional = true;
@@ -134,14 +305,39 @@ impl FileSourceBuilder {
         self
     }
 
+    /// Indicates the file source can be reloaded and polls the file for changes at the
+    /// specified interval instead of watching for file system events.
+    pub fn poll_interval(mut self, interval: Duration) -> Self {
+        self.reload_on_change = true;
+        self.poll_interval = Some(interval);
+        self
+    }
+
+    /// Sets the function invoked when the file source fails to reload.
+    ///
+    /// # Arguments
+    ///
+    /// * `callback` - The function invoked with the provider name and error
+    pub fn on_reload_error<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&str, LoadError) + Send + Sync + 'static,
+    {
+        self.on_reload_error = Some(Arc::new(callback));
+        self
+    }
+
     /// Creates and returns a new [`FileSource`].
     pub fn build(&self) -> FileSource {
-        FileSource::new(
+        let mut file = FileSource::new(
             self.path.clone(),
             self.optional,
             self.reload_on_change,
             self.reload_delay,
-        )
+        );
+        file.fallbacks = self.fallbacks.clone();
+        file.poll_interval = self.poll_interval;
+        file.on_reload_error = self.on_reload_error.clone();
+        file
     }
 }
 
@@ -157,6 +353,136 @@ impl From<&FileSourceBuilder> for FileSource {
     }
 }
 
+// a file is considered changed when its modification time or content changes. the modification
+// time alone is not sufficient because it may have a coarse resolution or be preserved by a copy
+fn fingerprint(path: &Path) -> Option<(Option<SystemTime>, u64)> {
+    let modified = path.metadata().ok()?.modified().ok();
+    Some((modified, hash(&std::fs::read(path).ok()?)))
+}
+
+fn hash(content: &[u8]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    content.hash(&mut hasher);
+    hasher.finish()
+}
+
+// editors often raise several events for a single save (ex: truncate, write, rename). change
+// notifications are coalesced so that only the last notification within the reload delay is
+// honored and a reload only occurs when the content of the file has actually changed
+#[cfg(feature = "file")]
+#[derive(Default)]
+pub(crate) struct Debounce {
+    generation: std::sync::atomic::AtomicUsize,
+    hash: std::sync::Mutex<Option<u64>>,
+}
+
+#[cfg(feature = "file")]
+impl Debounce {
+    pub(crate) fn settle(&self, delay: Duration) -> bool {
+        use std::sync::atomic::Ordering::SeqCst;
+
+        let generation = self.generation.fetch_add(1, SeqCst) + 1;
+        std::thread::sleep(delay);
+        self.generation.load(SeqCst) == generation
+    }
+
+    pub(crate) fn changed(&self, content: &[u8]) -> bool {
+        let hash = Some(hash(content));
+        let mut current = self.hash.lock().unwrap();
+
+        if *current == hash {
+            false
+        } else {
+            *current = hash;
+            true
+        }
+    }
+
+    // forgets the content of a file that no longer exists so that the file is loaded again when
+    // it is recreated, even with the same content
+    pub(crate) fn reset(&self) {
+        *self.hash.lock().unwrap() = None;
+    }
+}
+
+/// Represents a [`ChangeToken`](tokens::ChangeToken) that polls a file for changes.
+///
+/// # Remarks
+///
+/// Registered notifications always occur on another thread. The polling thread is not joined so
+/// that the token can be dropped from a callback it raised; dropping the token stops polling at
+/// the next interval.
+pub struct PollingFileChangeToken {
+    _stop: Sender<()>,
+    inner: Arc<SingleChangeToken>,
+}
+
+impl PollingFileChangeToken {
+    /// Initializes a new polling file change token.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The [path](std::path::Path) of the file to poll for changes
+    /// * `interval` - The interval at which the file is polled
+    pub fn new<P: AsRef<Path>>(path: P, interval: Duration) -> Self {
+        let path = path.as_ref().to_path_buf();
+        let inner = Arc::new(SingleChangeToken::default());
+        let handler = inner.clone();
+        let (sender, receiver) = channel();
+        let original = fingerprint(&path);
+
+        spawn(move || {
+            while let Err(RecvTimeoutError::Timeout) = receiver.recv_timeout(interval) {
+                if fingerprint(&path) != original {
+                    handler.notify();
+                    break;
+                }
+            }
+        });
+
+        Self {
+            _stop: sender,
+            inner,
+        }
+    }
+}
+
+impl ChangeToken for PollingFileChangeToken {
+    fn changed(&self) -> bool {
+        self.inner.changed()
+    }
+
+    fn register(&self, callback: Callback, state: State) -> Registration {
+        self.inner.register(callback, state)
+    }
+}
+
+// a file change token joins its watcher thread when it is dropped, but the token is replaced,
+// and dropped, by the callback it raised on that same thread; the token is dropped on another
+// thread instead, which completes once the callback returns
+#[cfg(feature = "file")]
+struct WatchedFileChangeToken(Option<tokens::FileChangeToken>);
+
+#[cfg(feature = "file")]
+impl ChangeToken for WatchedFileChangeToken {
+    fn changed(&self) -> bool {
+        self.0.as_ref().map(|token| token.changed()).unwrap_or_default()
+    }
+
+    fn register(&self, callback: Callback, state: State) -> Registration {
+        self.0.as_ref().unwrap().register(callback, state)
+    }
+}
+
+#[cfg(feature = "file")]
+impl Drop for WatchedFileChangeToken {
+    fn drop(&mut self) {
+        if let Some(token) = self.0.take() {
+            spawn(move || drop(token));
+        }
+    }
+}
+
 pub mod ext {
 
     use super::*;
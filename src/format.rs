@@ -0,0 +1,110 @@
+use crate::{util::ConfigurationData, ParseError};
+
+/// Defines the possible configuration text formats.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    /// Indicates that the format is detected from the content.
+    Auto,
+
+    /// Indicates the JSON format.
+    #[cfg(feature = "json")]
+    Json,
+
+    /// Indicates the INI format.
+    #[cfg(feature = "ini")]
+    Ini,
+
+    /// Indicates the XML format.
+    #[cfg(feature = "xml")]
+    Xml,
+}
+
+impl Format {
+    /// Detects the format of the specified content.
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - The content to detect the format of
+    ///
+    /// # Remarks
+    ///
+    /// Content starting with `{` is JSON, content starting with `<` is XML, and any
+    /// other content is INI. `None` is returned if the detected format is not enabled.
+    pub fn detect(content: &[u8]) -> Option<Self> {
+        let first = content.iter().find(|b| !b.is_ascii_whitespace());
+
+        match first {
+            #[cfg(feature = "json")]
+            Some(b'{') => Some(Self::Json),
+            #[cfg(feature = "xml")]
+            Some(b'<') => Some(Self::Xml),
+            #[cfg(feature = "ini")]
+            Some(b) if *b != b'{' && *b != b'<' => Some(Self::Ini),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn parse(&self, content: &[u8]) -> Result<ConfigurationData, ParseError> {
+        let format = if *self == Self::Auto {
+            Self::detect(content).ok_or_else(|| {
+                ParseError::from("The configuration format could not be determined.".to_owned())
+            })?
+        } else {
+            *self
+        };
+
+        match format {
+            #[cfg(feature = "json")]
+            Self::Json => crate::json::parse(content),
+            #[cfg(feature = "ini")]
+            Self::Ini => crate::ini::parse(content, None),
+            #[cfg(feature = "xml")]
+            Self::Xml => crate::xml::parse(content, &Default::default()),
+            Self::Auto => unreachable!(),
+        }
+    }
+}
+
+impl Default for Format {
+    fn default() -> Self {
+        Self::Auto
+    }
+}
+
+pub mod ext {
+
+    use super::*;
+    use crate::{ConfigurationBuilder, StreamConfigurationSource};
+    use std::io::Cursor;
+
+    /// Defines extension methods for [`ConfigurationBuilder`](crate::ConfigurationBuilder).
+    pub trait TextConfigurationBuilderExtensions {
+        /// Adds configuration text as a configuration source.
+        ///
+        /// # Arguments
+        ///
+        /// * `text` - The configuration text
+        /// * `format` - The [`Format`] of the text
+        fn add_config_text(&mut self, text: &str, format: Format) -> &mut Self;
+    }
+
+    impl TextConfigurationBuilderExtensions for dyn ConfigurationBuilder + '_ {
+        fn add_config_text(&mut self, text: &str, format: Format) -> &mut Self {
+            self.add(Box::new(StreamConfigurationSource::new(
+                Cursor::new(text.to_owned()),
+                move |content| format.parse(content).map_err(|error| error.message),
+            )));
+            self
+        }
+    }
+
+    impl<T: ConfigurationBuilder> TextConfigurationBuilderExtensions for T {
+        fn add_config_text(&mut self, text: &str, format: Format) -> &mut Self {
+            self.add(Box::new(StreamConfigurationSource::new(
+                Cursor::new(text.to_owned()),
+                move |content| format.parse(content).map_err(|error| error.message),
+            )));
+            self
+        }
+    }
+}
@@ -0,0 +1,284 @@
+use crate::{
+    Configuration, ConfigurationIterator, ConfigurationPath, ConfigurationSection, Value, ValueRef,
+    REDACTED,
+};
+use std::borrow::Borrow;
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Debug, Formatter, Result as FormatResult};
+use std::ops::Deref;
+use std::sync::Arc;
+use tokens::{ChangeToken, NeverChangeToken};
+
+// keys are normalized to uppercase; the root does not have a parent path
+#[derive(Default)]
+struct FrozenData {
+    values: HashMap<String, (String, String)>,
+    children: HashMap<Option<String>, Vec<String>>,
+    sensitive: HashSet<String>,
+}
+
+impl FrozenData {
+    fn collect(&mut self, root: &dyn Configuration, sections: Vec<Box<dyn ConfigurationSection>>) {
+        for section in sections {
+            let path = section.path();
+            let parent = if path.contains(ConfigurationPath::key_delimiter()) {
+                Some(ConfigurationPath::parent_path(path).to_uppercase())
+            } else {
+                None
+            };
+
+            self.children
+                .entry(parent)
+                .or_default()
+                .push(section.key().to_owned());
+
+            if let Some(value) = root.get(path) {
+                self.values
+                    .insert(path.to_uppercase(), (path.to_owned(), value.to_string()));
+            }
+
+            if root.is_sensitive(path) {
+                self.sensitive.insert(path.to_uppercase());
+            }
+
+            self.collect(root, section.children());
+        }
+    }
+
+    fn get(&self, key: &str) -> Option<&str> {
+        self.values
+            .get(&key.to_uppercase())
+            .map(|(_, value)| value.as_str())
+    }
+
+    fn is_sensitive(&self, key: &str) -> bool {
+        self.sensitive.contains(&key.to_uppercase())
+    }
+
+    fn children(&self, path: Option<&str>) -> &[String] {
+        self.children
+            .get(&path.map(|p| p.to_uppercase()))
+            .map(|keys| keys.as_slice())
+            .unwrap_or_default()
+    }
+}
+
+/// Represents an immutable snapshot of a [`Configuration`](crate::Configuration).
+///
+/// # Remarks
+///
+/// A snapshot contains the merged key/value pairs of a configuration at the time it was
+/// created. It does not track changes, never reloads, and does not require any locking
+/// to read a value. A snapshot is cheap to clone and can be shared across threads.
+#[derive(Clone)]
+pub struct FrozenConfiguration {
+    data: Arc<FrozenData>,
+}
+
+impl FrozenConfiguration {
+    /// Initializes a new snapshot of the specified configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `configuration` - The [`Configuration`](crate::Configuration) to take a snapshot of
+    pub fn new(configuration: &dyn Configuration) -> Self {
+        let mut data = FrozenData::default();
+        data.collect(configuration, configuration.children());
+        Self {
+            data: Arc::new(data),
+        }
+    }
+
+    /// Gets a reference to the configuration value with the specified key, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The case-insensitive key of the value to retrieve
+    pub fn value(&self, key: &str) -> Option<&str> {
+        self.data.get(key)
+    }
+
+    /// Gets the number of key/value pairs in the snapshot.
+    pub fn len(&self) -> usize {
+        self.data.values.len()
+    }
+
+    /// Gets a value indicating whether the snapshot does not contain any key/value pairs.
+    pub fn is_empty(&self) -> bool {
+        self.data.values.is_empty()
+    }
+}
+
+impl Configuration for FrozenConfiguration {
+    fn get(&self, key: &str) -> Option<Value> {
+        self.data.get(key).map(|value| Value::new(value.to_owned()))
+    }
+
+    fn get_str(&self, key: &str) -> Option<ValueRef<'_>> {
+        self.data.get(key).map(ValueRef::Borrowed)
+    }
+
+    fn section(&self, key: &str) -> Box<dyn ConfigurationSection> {
+        Box::new(FrozenSection::new(self.data.clone(), key.to_owned()))
+    }
+
+    fn children(&self) -> Vec<Box<dyn ConfigurationSection>> {
+        self.data
+            .children(None)
+            .iter()
+            .map(|key| self.section(key))
+            .collect()
+    }
+
+    fn reload_token(&self) -> Box<dyn ChangeToken> {
+        Box::new(NeverChangeToken::new())
+    }
+
+    fn is_sensitive(&self, key: &str) -> bool {
+        self.data.is_sensitive(key)
+    }
+
+    fn iter(&self, path: Option<ConfigurationPath>) -> Box<dyn Iterator<Item = (String, Value)>> {
+        Box::new(ConfigurationIterator::new(
+            self,
+            path.unwrap_or(ConfigurationPath::Absolute),
+        ))
+    }
+}
+
+impl Debug for FrozenConfiguration {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FormatResult {
+        let mut values: Vec<_> = self.data.values.values().collect();
+        values.sort();
+        f.debug_map()
+            .entries(values.into_iter().map(|(key, value)| {
+                if self.data.is_sensitive(key) {
+                    (key, REDACTED)
+                } else {
+                    (key, value.as_str())
+                }
+            }))
+            .finish()
+    }
+}
+
+impl<'a> AsRef<dyn Configuration + 'a> for FrozenConfiguration {
+    fn as_ref(&self) -> &(dyn Configuration + 'a) {
+        self
+    }
+}
+
+impl<'a> Borrow<dyn Configuration + 'a> for FrozenConfiguration {
+    fn borrow(&self) -> &(dyn Configuration + 'a) {
+        self
+    }
+}
+
+impl Deref for FrozenConfiguration {
+    type Target = dyn Configuration;
+
+    fn deref(&self) -> &Self::Target {
+        self
+    }
+}
+
+#[derive(Clone)]
+struct FrozenSection {
+    data: Arc<FrozenData>,
+    path: String,
+}
+
+impl FrozenSection {
+    fn new(data: Arc<FrozenData>, path: String) -> Self {
+        Self { data, path }
+    }
+
+    #[inline]
+    fn subkey(&self, key: &str) -> String {
+        ConfigurationPath::combine(&[&self.path, key])
+    }
+}
+
+impl Configuration for FrozenSection {
+    fn get(&self, key: &str) -> Option<Value> {
+        self.data
+            .get(&self.subkey(key))
+            .map(|value| Value::new(value.to_owned()))
+    }
+
+    fn get_str(&self, key: &str) -> Option<ValueRef<'_>> {
+        self.data.get(&self.subkey(key)).map(ValueRef::Borrowed)
+    }
+
+    fn section(&self, key: &str) -> Box<dyn ConfigurationSection> {
+        Box::new(FrozenSection::new(self.data.clone(), self.subkey(key)))
+    }
+
+    fn children(&self) -> Vec<Box<dyn ConfigurationSection>> {
+        self.data
+            .children(Some(&self.path))
+            .iter()
+            .map(|key| self.section(key))
+            .collect()
+    }
+
+    fn reload_token(&self) -> Box<dyn ChangeToken> {
+        Box::new(NeverChangeToken::new())
+    }
+
+    fn is_sensitive(&self, key: &str) -> bool {
+        self.data.is_sensitive(&self.subkey(key))
+    }
+
+    fn as_section(&self) -> Option<&dyn ConfigurationSection> {
+        Some(self)
+    }
+
+    fn iter(&self, path: Option<ConfigurationPath>) -> Box<dyn Iterator<Item = (String, Value)>> {
+        Box::new(ConfigurationIterator::new(
+            self,
+            path.unwrap_or(ConfigurationPath::Absolute),
+        ))
+    }
+}
+
+impl ConfigurationSection for FrozenSection {
+    fn key(&self) -> &str {
+        ConfigurationPath::section_key(&self.path)
+    }
+
+    fn path(&self) -> &str {
+        &self.path
+    }
+
+    fn value(&self) -> Value {
+        self.data
+            .get(&self.path)
+            .map(|value| Value::new(value.to_owned()))
+            .unwrap_or_default()
+    }
+
+    fn as_config(&self) -> Box<dyn Configuration> {
+        Box::new(self.clone())
+    }
+}
+
+impl<'a> AsRef<dyn Configuration + 'a> for FrozenSection {
+    fn as_ref(&self) -> &(dyn Configuration + 'a) {
+        self
+    }
+}
+
+impl<'a> Borrow<dyn Configuration + 'a> for FrozenSection {
+    fn borrow(&self) -> &(dyn Configuration + 'a) {
+        self
+    }
+}
+
+impl Deref for FrozenSection {
+    type Target = dyn Configuration;
+
+    fn deref(&self) -> &Self::Target {
+        self
+    }
+}
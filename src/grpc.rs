@@ -0,0 +1,139 @@
+use crate::{
+    ConfigurationBuilder, ConfigurationPairs, ConfigurationProvider, ConfigurationSource,
+    ConfigurationUpdateSink, DynamicKeyValueFeed, FeedConfigurationProvider, LoadError, LoadResult,
+    Value,
+};
+use std::sync::Arc;
+use tokens::ChangeToken;
+
+/// Defines the behavior of a client for a gRPC configuration service.
+///
+/// # Remarks
+///
+/// The client is typically implemented over the client generated from the proto of the
+/// service, which keeps this crate independent of any particular gRPC runtime or proto.
+pub trait ConfigurationServiceClient: Send + Sync {
+    /// Gets a snapshot of all of the key/value pairs provided by the service.
+    fn snapshot(&self) -> Result<Vec<(String, String)>, LoadError>;
+
+    /// Subscribes to the updates pushed by the service.
+    ///
+    /// # Arguments
+    ///
+    /// * `sink` - The [`ConfigurationUpdateSink`] each update is sent to
+    ///
+    /// # Remarks
+    ///
+    /// This method is called once, after the first successful snapshot. It should return
+    /// immediately and forward the messages of a server-streaming call to the sink, such as
+    /// from a background task, until [`send`](ConfigurationUpdateSink::send) returns `false`.
+    fn subscribe(&self, sink: ConfigurationUpdateSink);
+}
+
+// adapts a client to the feed that backs the provider
+struct ClientFeed(Arc<dyn ConfigurationServiceClient>);
+
+impl DynamicKeyValueFeed for ClientFeed {
+    fn snapshot(&self) -> Result<Vec<(String, String)>, LoadError> {
+        self.0.snapshot()
+    }
+
+    fn subscribe(&self, sink: ConfigurationUpdateSink) {
+        self.0.subscribe(sink)
+    }
+}
+
+/// Represents a [`ConfigurationProvider`](crate::ConfigurationProvider) for a gRPC
+/// configuration service.
+pub struct GrpcConfigurationProvider(FeedConfigurationProvider);
+
+impl GrpcConfigurationProvider {
+    /// Initializes a new gRPC configuration provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The [`ConfigurationServiceClient`] used to communicate with the service
+    pub fn new(client: Arc<dyn ConfigurationServiceClient>) -> Self {
+        Self(FeedConfigurationProvider::new(Arc::new(ClientFeed(client))))
+    }
+}
+
+impl ConfigurationProvider for GrpcConfigurationProvider {
+    fn get(&self, key: &str) -> Option<Value> {
+        self.0.get(key)
+    }
+
+    fn reload_token(&self) -> Box<dyn ChangeToken> {
+        self.0.reload_token()
+    }
+
+    fn load(&mut self) -> LoadResult {
+        self.0.load()
+    }
+
+    fn close(&mut self) {
+        self.0.close()
+    }
+
+    fn try_iter(&self) -> Option<ConfigurationPairs<'_>> {
+        self.0.try_iter()
+    }
+
+    fn child_keys(&self, earlier_keys: &mut Vec<String>, parent_path: Option<&str>) {
+        self.0.child_keys(earlier_keys, parent_path)
+    }
+}
+
+/// Represents a [`ConfigurationSource`](crate::ConfigurationSource) for a gRPC configuration
+/// service.
+pub struct GrpcConfigurationSource {
+    client: Arc<dyn ConfigurationServiceClient>,
+}
+
+impl GrpcConfigurationSource {
+    /// Initializes a new gRPC configuration source.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The [`ConfigurationServiceClient`] used to communicate with the service
+    pub fn new<C: ConfigurationServiceClient + 'static>(client: C) -> Self {
+        Self {
+            client: Arc::new(client),
+        }
+    }
+}
+
+impl ConfigurationSource for GrpcConfigurationSource {
+    fn build(&self, _builder: &dyn ConfigurationBuilder) -> Box<dyn ConfigurationProvider> {
+        Box::new(GrpcConfigurationProvider::new(self.client.clone()))
+    }
+}
+
+pub mod ext {
+
+    use super::*;
+
+    /// Defines extension methods for [`ConfigurationBuilder`](crate::ConfigurationBuilder).
+    pub trait GrpcConfigurationBuilderExtensions {
+        /// Adds a gRPC configuration service as a configuration source.
+        ///
+        /// # Arguments
+        ///
+        /// * `client` - The [`ConfigurationServiceClient`] used to communicate with the service
+        fn add_grpc<C: ConfigurationServiceClient + 'static>(&mut self, client: C) -> &mut Self;
+    }
+
+    impl GrpcConfigurationBuilderExtensions for dyn ConfigurationBuilder + '_ {
+        fn add_grpc<C: ConfigurationServiceClient + 'static>(&mut self, client: C) -> &mut Self {
+            self.add(Box::new(GrpcConfigurationSource::new(client)));
+            self
+        }
+    }
+
+    impl<T: ConfigurationBuilder> GrpcConfigurationBuilderExtensions for T {
+        fn add_grpc<C: ConfigurationServiceClient + 'static>(&mut self, client: C) -> &mut Self {
+            self.add(Box::new(GrpcConfigurationSource::new(client)));
+            self
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use crate::ConfigurationBuilder;
+use std::env::{current_dir, var};
+use std::path::{Path, PathBuf};
+
+// the key of the ConfigurationBuilder property that holds the HostEnvironment
+pub(crate) const HOST_ENVIRONMENT: &str = "HostEnvironment";
+
+/// Represents the environment an application is hosted in.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HostEnvironment {
+    name: String,
+    content_root: PathBuf,
+}
+
+impl HostEnvironment {
+    /// Gets the name of the development environment.
+    pub const DEVELOPMENT: &'static str = "Development";
+
+    /// Gets the name of the staging environment.
+    pub const STAGING: &'static str = "Staging";
+
+    /// Gets the name of the production environment.
+    pub const PRODUCTION: &'static str = "Production";
+
+    /// Initializes a new host environment.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the environment, such as `Development`
+    /// * `content_root` - The path of the directory that contains the content files of the application
+    pub fn new<S: AsRef<str>, P: AsRef<Path>>(name: S, content_root: P) -> Self {
+        Self {
+            name: name.as_ref().to_owned(),
+            content_root: content_root.as_ref().to_path_buf(),
+        }
+    }
+
+    /// Initializes a new host environment from the environment variables of the current process.
+    ///
+    /// # Remarks
+    ///
+    /// The name is read from the `APP_ENVIRONMENT` environment variable, then the `RUST_ENV`
+    /// environment variable, and is `Production` if neither is set. The content root is the
+    /// current directory.
+    pub fn from_env() -> Self {
+        let name = var("APP_ENVIRONMENT")
+            .or_else(|_| var("RUST_ENV"))
+            .ok()
+            .filter(|name| !name.is_empty())
+            .unwrap_or_else(|| Self::PRODUCTION.to_owned());
+
+        Self::new(name, current_dir().unwrap_or_default())
+    }
+
+    /// Gets the host environment registered with a configuration builder, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `builder` - The [`ConfigurationBuilder`](crate::ConfigurationBuilder) to get the environment from
+    pub fn of(builder: &dyn ConfigurationBuilder) -> Option<&Self> {
+        builder.property::<Self>(HOST_ENVIRONMENT)
+    }
+
+    /// Gets the name of the environment.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets the path of the directory that contains the content files of the application.
+    pub fn content_root(&self) -> &Path {
+        &self.content_root
+    }
+
+    /// Gets a value indicating whether the environment has the specified name.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The case-insensitive name to compare
+    pub fn is(&self, name: &str) -> bool {
+        self.name.eq_ignore_ascii_case(name)
+    }
+
+    /// Gets a value indicating whether the environment is the development environment.
+    pub fn is_development(&self) -> bool {
+        self.is(Self::DEVELOPMENT)
+    }
+
+    /// Gets a value indicating whether the environment is the staging environment.
+    pub fn is_staging(&self) -> bool {
+        self.is(Self::STAGING)
+    }
+
+    /// Gets a value indicating whether the environment is the production environment.
+    pub fn is_production(&self) -> bool {
+        self.is(Self::PRODUCTION)
+    }
+
+    /// Gets the paths of a file and its environment-specific variant.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The path of the file, relative to the content root and without an extension
+    /// * `extension` - The extension of the file, such as `json`
+    ///
+    /// # Remarks
+    ///
+    /// For example, a `base` of `appsettings` and an `extension` of `json` in the
+    /// `Development` environment resolves `appsettings.json` and `appsettings.Development.json`.
+    pub fn files(&self, base: &str, extension: &str) -> (PathBuf, PathBuf) {
+        (
+            self.content_root.join(format!("{}.{}", base, extension)),
+            self.content_root
+                .join(format!("{}.{}.{}", base, self.name, extension)),
+        )
+    }
+}
@@ -1,102 +1,239 @@
-use crate::FileSource;
 use crate::{
-    util::accumulate_child_keys, ConfigurationBuilder, ConfigurationPath, ConfigurationProvider,
-    ConfigurationSource, LoadError, LoadResult, Value
+    util::ConfigurationData, ConfigurationBuilder, ConfigurationPairs, ConfigurationPath,
+    ConfigurationProvider, ConfigurationSource, LoadResult, Value,
 };
-use configparser::ini::Ini;
+use crate::{ConfigurationFileParser, FileConfigurationProvider, FileSource, ParseError};
+use std::any::type_name;
+use std::cmp::Ordering;
 use std::collections::HashMap;
-use std::sync::{Arc, RwLock};
-use tokens::{ChangeToken, FileChangeToken, SharedChangeToken, SingleChangeToken, Subscription};
+use std::path::Path;
+use std::sync::Arc;
+use tokens::ChangeToken;
 
-struct InnerProvider {
-    file: FileSource,
-    data: RwLock<HashMap<String, (String, Value)>>,
-    token: RwLock<SharedChangeToken<SingleChangeToken>>,
+pub(crate) const COMMENTS: [char; 2] = [';', '#'];
+
+pub(crate) fn normalize(key: &str, delimiter: Option<&str>) -> String {
+    let key = key.trim();
+
+    match delimiter {
+        Some(delimiter) if !delimiter.is_empty() => {
+            key.replace(delimiter, ConfigurationPath::key_delimiter())
+        }
+        _ => key.to_owned(),
+    }
 }
 
-impl InnerProvider {
-    fn new(file: FileSource) -> Self {
-        Self {
-            file,
-            data: RwLock::new(HashMap::with_capacity(0)),
-            token: Default::default(),
+fn to_data(entries: Vec<(String, Vec<String>)>) -> HashMap<String, (String, Value)> {
+    let capacity = entries.iter().map(|e| e.1.len()).sum();
+    let mut map = HashMap::with_capacity(capacity);
+
+    for (key, mut values) in entries {
+        if values.len() == 1 {
+            let value = values.remove(0);
+            map.insert(key.to_uppercase(), (key, value.into()));
+        } else {
+            for (index, value) in values.into_iter().enumerate() {
+                let key = ConfigurationPath::combine(&[&key, &index.to_string()]);
+                map.insert(key.to_uppercase(), (key, value.into()));
+            }
         }
     }
 
-    fn get(&self, key: &str) -> Option<Value> {
-        self.data
-            .read()
-            .unwrap()
-            .get(&key.to_uppercase())
-            .map(|t| t.1.clone())
+    map
+}
+
+// orders keys by segment where numeric segments, such as array indexes, are ordered by value
+fn cmp_segments(key: &str, other: &str) -> Ordering {
+    let delimiter = ConfigurationPath::key_delimiter();
+    let mut segments = key.split(delimiter);
+    let mut others = other.split(delimiter);
+
+    loop {
+        let result = match (segments.next(), others.next()) {
+            (Some(x), Some(y)) => match (x.parse::<usize>(), y.parse::<usize>()) {
+                (Ok(x), Ok(y)) => x.cmp(&y),
+                _ => x.cmp(y),
+            },
+            (x, y) => return x.is_some().cmp(&y.is_some()),
+        };
+
+        if result != Ordering::Equal {
+            return result;
+        }
     }
+}
 
-    fn reload_token(&self) -> Box<dyn ChangeToken> {
-        Box::new(self.token.read().unwrap().clone())
-    }
-
-    fn load(&self, reload: bool) -> LoadResult {
-        if !self.file.path.is_file() {
-            if self.file.optional || reload {
-                let mut data = self.data.write().unwrap();
-                if !data.is_empty() {
-                    *data = HashMap::with_capacity(0);
-                }
-
-                return Ok(());
-            } else {
-                return Err(LoadError::File {
-                    message: format!(
-                        "The configuration file '{}' was not found and is not optional.",
-                        self.file.path.display()
-                    ),
-                    path: self.file.path.clone(),
-                });
-            }
+pub(crate) fn denormalize(key: &str, delimiter: Option<&str>) -> String {
+    match delimiter {
+        Some(delimiter) if !delimiter.is_empty() => {
+            key.replace(ConfigurationPath::key_delimiter(), delimiter)
         }
+        _ => key.to_owned(),
+    }
+}
+
+// a section name and the names and values of its keys
+pub(crate) type Section = (String, Vec<(String, Value)>);
 
-        let mut ini = Ini::new_cs();
-        let data = if let Ok(sections) = ini.load(&self.file.path) {
-            let capacity = sections.iter().map(|p| p.1.len()).sum();
-            let mut map = HashMap::with_capacity(capacity);
+// groups the key/value pairs by section, which is the parent path of each key. the sections
+// and the keys within each section are ordered. the name of each key is relative to its section
+pub(crate) fn sections<I>(pairs: I) -> Result<Vec<Section>, ParseError>
+where
+    I: Iterator<Item = (String, Value)>,
+{
+    let mut pairs: Vec<_> = pairs.collect();
+    let mut sections = Vec::<Section>::new();
 
-            for (section, pairs) in sections {
-                for (key, value) in pairs {
-                    let mut new_key = section.to_owned();
-                    let new_value = value.unwrap_or_default();
+    pairs.sort_by(|(key, _), (other, _)| {
+        let parent = ConfigurationPath::parent_path(key);
+        let other_parent = ConfigurationPath::parent_path(other);
+        cmp_segments(parent, other_parent).then_with(|| cmp_segments(key, other))
+    });
 
-                    new_key.push_str(ConfigurationPath::key_delimiter());
-                    new_key.push_str(&key);
-                    map.insert(new_key.to_uppercase(), (new_key, new_value.into()));
-                }
+    for (key, value) in pairs {
+        let section = ConfigurationPath::parent_path(&key);
+        let name = ConfigurationPath::section_key(&key);
+
+        if name.contains('=')
+            || name.trim() != name
+            || value.contains(&COMMENTS[..])
+            || value.contains('\n')
+            || value.trim() != value.as_str()
+        {
+            return Err(ParseError {
+                message: format!("The key '{}' cannot be written as INI.", key),
+                key: Some(key),
+                ..Default::default()
+            });
+        }
+
+        match sections.last_mut() {
+            Some((last, entries)) if last == section => entries.push((name.to_owned(), value)),
+            _ => sections.push((section.to_owned(), vec![(name.to_owned(), value)])),
+        }
+    }
+
+    Ok(sections)
+}
+
+fn format(data: &ConfigurationData, delimiter: Option<&str>) -> Result<Vec<u8>, ParseError> {
+    let mut content = String::new();
+
+    for (section, entries) in sections(data.pairs())? {
+        if !section.is_empty() {
+            if !content.is_empty() {
+                content.push('\n');
             }
 
-            map
+            content.push_str(&format!("[{}]\n", denormalize(&section, delimiter)));
+        }
+
+        for (name, value) in entries {
+            content.push_str(&format!("{}={}\n", denormalize(&name, delimiter), value));
+        }
+    }
+
+    Ok(content.into_bytes())
+}
+
+pub(crate) fn parse(
+    content: &[u8],
+    delimiter: Option<&str>,
+) -> Result<ConfigurationData, ParseError> {
+    let text = std::str::from_utf8(content).map_err(|e| ParseError {
+        message: e.to_string(),
+        source: Some(Arc::new(e)),
+        ..Default::default()
+    })?;
+    let mut entries = Vec::<(String, Vec<String>)>::new();
+    let mut index = HashMap::<String, usize>::new();
+    let mut section = String::new();
+
+    for (number, line) in (1..).zip(text.lines()) {
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with(&COMMENTS[..]) {
+            continue;
+        }
+
+        let line = match line.find(&COMMENTS[..]) {
+            Some(end) => line[..end].trim_end(),
+            _ => line,
+        };
+
+        if let Some(header) = line.strip_prefix('[') {
+            let end = header.rfind(']').ok_or_else(|| ParseError {
+                message: format!(
+                    "line {}: Found opening bracket for section name but no closing bracket",
+                    number
+                ),
+                line: Some(number),
+                ..Default::default()
+            })?;
+
+            section = normalize(&header[..end], delimiter);
+            continue;
+        }
+
+        // a key can contain ':', so it only separates the value when '=' is absent
+        let (key, value) = match line.find('=').or_else(|| line.find(':')) {
+            Some(position) => (&line[..position], line[(position + 1)..].trim()),
+            _ => (line, ""),
+        };
+        let key = normalize(key, delimiter);
+
+        if key.is_empty() {
+            return Err(ParseError {
+                message: format!("line {}: Key cannot be empty", number),
+                line: Some(number),
+                column: Some(1),
+                ..Default::default()
+            });
+        }
+
+        let key = if section.is_empty() {
+            key
         } else {
-            HashMap::with_capacity(0)
+            ConfigurationPath::combine(&[&section, &key])
         };
+        let value = value.to_owned();
 
-        *self.data.write().unwrap() = data;
+        // repeated keys become an array
+        match index.get(&key.to_uppercase()) {
+            Some(&i) => entries[i].1.push(value),
+            _ => {
+                index.insert(key.to_uppercase(), entries.len());
+                entries.push((key, vec![value]));
+            }
+        }
+    }
 
-        let previous = std::mem::replace(
-            &mut *self.token.write().unwrap(),
-            SharedChangeToken::default(),
-        );
+    Ok(to_data(entries).into())
+}
 
-        previous.notify();
-        Ok(())
+#[derive(Clone)]
+struct IniFileParser {
+    delimiter: Option<String>,
+}
+
+impl ConfigurationFileParser for IniFileParser {
+    fn parse(&self, _path: &Path, content: &[u8]) -> Result<ConfigurationData, ParseError> {
+        parse(content, self.delimiter.as_deref())
     }
 
-    fn child_keys(&self, earlier_keys: &mut Vec<String>, parent_path: Option<&str>) {
-        let data = self.data.read().unwrap();
-        accumulate_child_keys(&data, earlier_keys, parent_path)
+    fn format(&self, data: &ConfigurationData) -> Result<Vec<u8>, ParseError> {
+        format(data, self.delimiter.as_deref())
+    }
+
+    #[cfg(feature = "edit")]
+    fn edit(&self, content: &[u8], data: &ConfigurationData) -> Result<Vec<u8>, ParseError> {
+        crate::edit::ini(content, data, self.delimiter.as_deref())
     }
 }
 
 /// Represents a [`ConfigurationProvider`](crate::ConfigurationProvider) for `*.ini` files.
 pub struct IniConfigurationProvider {
-    inner: Arc<InnerProvider>,
-    _subscription: Option<Box<dyn Subscription>>,
+    inner: FileConfigurationProvider<IniFileParser>,
 }
 
 impl IniConfigurationProvider {
@@ -106,27 +243,48 @@ impl IniConfigurationProvider {
     ///
     /// * `file` - The `*.ini` [`FileSource`](crate::FileSource) information
     pub fn new(file: FileSource) -> Self {
-        let path = file.path.clone();
-        let inner = Arc::new(InnerProvider::new(file));
-        let subscription: Option<Box<dyn Subscription>> = if inner.file.reload_on_change {
-            Some(Box::new(tokens::on_change(
-                move || FileChangeToken::new(path.clone()),
-                |state| {
-                    let provider = state.unwrap();
-                    std::thread::sleep(provider.file.reload_delay);
-                    provider.load(true).ok();
-                },
-                Some(inner.clone()),
-            )))
-        } else {
-            None
-        };
+        Self::with_delimiter(file, None)
+    }
 
+    /// Initializes a new `*.ini` file configuration provider with an alternate key delimiter.
+    ///
+    /// # Arguments
+    ///
+    /// * `file` - The `*.ini` [`FileSource`](crate::FileSource) information
+    /// * `delimiter` - The optional delimiter in section names and keys, such as `.`, that is
+    ///   converted to the `:` key delimiter
+    pub fn with_delimiter(file: FileSource, delimiter: Option<String>) -> Self {
         Self {
-            inner,
-            _subscription: subscription,
+            inner: FileConfigurationProvider::named(
+                type_name::<Self>(),
+                file,
+                IniFileParser { delimiter },
+            ),
         }
     }
+
+    /// Sets a configuration value in the loaded data.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the value to set
+    /// * `value` - The value to set
+    ///
+    /// # Remarks
+    ///
+    /// The file is unchanged until the provider is [saved](ConfigurationProvider::save).
+    pub fn set(&self, key: &str, value: &str) {
+        self.inner.set(key, value)
+    }
+
+    /// Removes a configuration value, including all of its descendants, from the loaded data.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the value to remove
+    pub fn remove(&self, key: &str) {
+        self.inner.remove(key)
+    }
 }
 
 impl ConfigurationProvider for IniConfigurationProvider {
@@ -139,7 +297,19 @@ impl ConfigurationProvider for IniConfigurationProvider {
     }
 
     fn load(&mut self) -> LoadResult {
-        self.inner.load(false)
+        self.inner.load()
+    }
+
+    fn close(&mut self) {
+        self.inner.close()
+    }
+
+    fn save(&self) -> LoadResult {
+        self.inner.save()
+    }
+
+    fn try_iter(&self) -> Option<ConfigurationPairs<'_>> {
+        self.inner.try_iter()
     }
 
     fn child_keys(&self, earlier_keys: &mut Vec<String>, parent_path: Option<&str>) {
@@ -150,6 +320,7 @@ impl ConfigurationProvider for IniConfigurationProvider {
 /// Represents a [`ConfigurationSource`](crate::ConfigurationSource) for `*.ini` files.
 pub struct IniConfigurationSource {
     file: FileSource,
+    delimiter: Option<String>,
 }
 
 impl IniConfigurationSource {
@@ -159,19 +330,41 @@ impl IniConfigurationSource {
     ///
     /// * `file` - The `*.ini` [`FileSource`](crate::FileSource) information
     pub fn new(file: FileSource) -> Self {
-        Self { file }
+        Self {
+            file,
+            delimiter: None,
+        }
+    }
+
+    /// Initializes a new `*.ini` file configuration source with an alternate key delimiter.
+    ///
+    /// # Arguments
+    ///
+    /// * `file` - The `*.ini` [`FileSource`](crate::FileSource) information
+    /// * `delimiter` - The delimiter in section names and keys, such as `.`, that is
+    ///   converted to the `:` key delimiter
+    pub fn with_delimiter(file: FileSource, delimiter: &str) -> Self {
+        Self {
+            file,
+            delimiter: Some(delimiter.to_owned()),
+        }
     }
 }
 
 impl ConfigurationSource for IniConfigurationSource {
-    fn build(&self, _builder: &dyn ConfigurationBuilder) -> Box<dyn ConfigurationProvider> {
-        Box::new(IniConfigurationProvider::new(self.file.clone()))
+    fn build(&self, builder: &dyn ConfigurationBuilder) -> Box<dyn ConfigurationProvider> {
+        Box::new(IniConfigurationProvider::with_delimiter(
+            self.file.configure(builder),
+            self.delimiter.clone(),
+        ))
     }
 }
 
 pub mod ext {
 
     use super::*;
+    use crate::{HostEnvironment, StreamConfigurationSource};
+    use std::io::{Cursor, Read};
 
     /// Defines extension methods for [`ConfigurationBuilder`](crate::ConfigurationBuilder).
     pub trait IniConfigurationExtensions {
@@ -181,6 +374,47 @@ pub mod ext {
         ///
         /// * `file` - The `*.ini` [`FileSource`](crate::FileSource) information
         fn add_ini_file<T: Into<FileSource>>(&mut self, file: T) -> &mut Self;
+
+        /// Adds an `*.ini` file and its variant for the host environment as configuration sources.
+        ///
+        /// # Arguments
+        ///
+        /// * `base` - The path of the file, relative to the content root and without an extension,
+        ///   such as `appsettings`
+        /// * `environment` - The [`HostEnvironment`](crate::HostEnvironment) used to resolve the files
+        ///
+        /// # Remarks
+        ///
+        /// The environment-specific file, such as `appsettings.Development.ini`, is optional and
+        /// overrides the values of the base file.
+        fn add_ini_file_for_env(&mut self, base: &str, environment: &HostEnvironment) -> &mut Self;
+
+        /// Adds an `*.ini` file with an alternate key delimiter as a configuration source.
+        ///
+        /// # Arguments
+        ///
+        /// * `file` - The `*.ini` [`FileSource`](crate::FileSource) information
+        /// * `delimiter` - The delimiter in section names and keys, such as `.`, that is
+        ///   converted to the `:` key delimiter
+        fn add_ini_file_with_delimiter<T: Into<FileSource>>(
+            &mut self,
+            file: T,
+            delimiter: &str,
+        ) -> &mut Self;
+
+        /// Adds INI read from a stream as a configuration source.
+        ///
+        /// # Arguments
+        ///
+        /// * `stream` - The [`Read`](std::io::Read) stream containing INI
+        fn add_ini_stream<R: Read + 'static>(&mut self, stream: R) -> &mut Self;
+
+        /// Adds embedded INI content as a configuration source.
+        ///
+        /// # Arguments
+        ///
+        /// * `content` - The INI content, which is typically from [`include_str`]
+        fn add_embedded_ini(&mut self, content: &str) -> &mut Self;
     }
 
     impl IniConfigurationExtensions for dyn ConfigurationBuilder + '_ {
@@ -188,6 +422,36 @@ pub mod ext {
             self.add(Box::new(IniConfigurationSource::new(file.into())));
             self
         }
+
+        fn add_ini_file_for_env(&mut self, base: &str, environment: &HostEnvironment) -> &mut Self {
+            let (file, variant) = environment.files(base, "ini");
+            self.add_ini_file(file)
+                .add_ini_file(FileSource::optional(variant))
+        }
+
+        fn add_ini_file_with_delimiter<F: Into<FileSource>>(
+            &mut self,
+            file: F,
+            delimiter: &str,
+        ) -> &mut Self {
+            self.add(Box::new(IniConfigurationSource::with_delimiter(
+                file.into(),
+                delimiter,
+            )));
+            self
+        }
+
+        fn add_ini_stream<R: Read + 'static>(&mut self, stream: R) -> &mut Self {
+            self.add(Box::new(StreamConfigurationSource::new(
+                stream,
+                |content| parse(content, None).map_err(|error| error.message),
+            )));
+            self
+        }
+
+        fn add_embedded_ini(&mut self, content: &str) -> &mut Self {
+            self.add_ini_stream(Cursor::new(content.to_owned()))
+        }
     }
 
     impl<T: ConfigurationBuilder> IniConfigurationExtensions for T {
@@ -195,5 +459,35 @@ pub mod ext {
             self.add(Box::new(IniConfigurationSource::new(file.into())));
             self
         }
+
+        fn add_ini_file_for_env(&mut self, base: &str, environment: &HostEnvironment) -> &mut Self {
+            let (file, variant) = environment.files(base, "ini");
+            self.add_ini_file(file)
+                .add_ini_file(FileSource::optional(variant))
+        }
+
+        fn add_ini_file_with_delimiter<F: Into<FileSource>>(
+            &mut self,
+            file: F,
+            delimiter: &str,
+        ) -> &mut Self {
+            self.add(Box::new(IniConfigurationSource::with_delimiter(
+                file.into(),
+                delimiter,
+            )));
+            self
+        }
+
+        fn add_ini_stream<R: Read + 'static>(&mut self, stream: R) -> &mut Self {
+            self.add(Box::new(StreamConfigurationSource::new(
+                stream,
+                |content| parse(content, None).map_err(|error| error.message),
+            )));
+            self
+        }
+
+        fn add_embedded_ini(&mut self, content: &str) -> &mut Self {
+            self.add_ini_stream(Cursor::new(content.to_owned()))
+        }
     }
 }
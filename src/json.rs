@@ -1,174 +1,299 @@
+use crate::{util::*, ConfigValue, ParseError};
+use serde_json::{Map, Value as JsonValue};
+use std::sync::Arc;
+
+#[cfg(feature = "file")]
 use crate::{
-    util::*, ConfigurationBuilder, ConfigurationPath, ConfigurationProvider, ConfigurationSource,
-    FileSource, LoadError, LoadResult, Value,
+    ConfigurationBuilder, ConfigurationFileParser, ConfigurationPairs, ConfigurationProvider,
+    ConfigurationSource, FileConfigurationProvider, FileSource, LoadResult, Value,
 };
-use serde_json::{map::Map, Value as JsonValue};
-use std::collections::HashMap;
-use std::fs;
-use std::sync::{Arc, RwLock};
-use tokens::{ChangeToken, FileChangeToken, SharedChangeToken, SingleChangeToken, Subscription};
-
-#[derive(Default)]
-struct JsonVisitor {
-    data: HashMap<String, (String, Value)>,
-    paths: Vec<String>,
+#[cfg(feature = "file")]
+use std::any::type_name;
+#[cfg(feature = "file")]
+use std::path::{Path, PathBuf};
+#[cfg(feature = "file")]
+use tokens::ChangeToken;
+
+struct JsonDocument<'a>(&'a JsonValue);
+
+impl IntoConfigurationTree for JsonDocument<'_> {
+    fn into_tree(self) -> ConfigurationNode {
+        match self.0 {
+            JsonValue::Object(members) => ConfigurationNode::Object(
+                members
+                    .iter()
+                    .map(|(name, value)| (name.clone(), JsonDocument(value).into_tree()))
+                    .collect(),
+            ),
+            JsonValue::Array(elements) => ConfigurationNode::Array(
+                elements
+                    .iter()
+                    .map(|element| JsonDocument(element).into_tree())
+                    .collect(),
+            ),
+            JsonValue::Bool(value) => {
+                ConfigurationNode::Typed(value.to_string(), ConfigValue::Bool(*value))
+            }
+            JsonValue::Null => ConfigurationNode::Typed(String::new(), ConfigValue::Null),
+            JsonValue::Number(value) => {
+                if let Some(number) = value.as_i64() {
+                    ConfigurationNode::Typed(value.to_string(), ConfigValue::Int(number))
+                } else if value.is_f64() {
+                    let number = value.as_f64().unwrap();
+                    ConfigurationNode::Typed(value.to_string(), ConfigValue::Float(number))
+                } else {
+                    ConfigurationNode::Text(value.to_string())
+                }
+            }
+            JsonValue::String(value) => ConfigurationNode::Text(value.clone()),
+        }
+    }
+}
+
+fn visit(json: &JsonValue) -> ConfigurationData {
+    FlattenVisitor::new()
+        .pascal_case(true)
+        .visit(JsonDocument(json))
 }
 
-impl JsonVisitor {
-    fn visit(mut self, root: &Map<String, JsonValue>) -> HashMap<String, (String, Value)> {
-        self.visit_element(root);
-        self.data.shrink_to_fit();
-        self.data
+fn kind(json: &JsonValue) -> &'static str {
+    match json {
+        JsonValue::Array(_) => "array",
+        JsonValue::Bool(_) => "Boolean",
+        JsonValue::Null => "null",
+        JsonValue::Number(_) => "number",
+        JsonValue::String(_) => "string",
+        JsonValue::Object(_) => "object",
     }
+}
 
-    fn visit_element(&mut self, element: &Map<String, JsonValue>) {
-        if element.is_empty() {
-            if let Some(key) = self.paths.last() {
-                self.data
-                    .insert(key.to_uppercase(), (to_pascal_case(key), String::new().into()));
-            }
-        } else {
-            for (name, value) in element {
-                self.enter_context(to_pascal_case(name));
-                self.visit_value(value);
-                self.exit_context();
-            }
-        }
+fn read(content: &[u8]) -> Result<Map<String, JsonValue>, ParseError> {
+    let json: JsonValue = serde_json::from_slice(content).map_err(|e| ParseError {
+        message: e.to_string(),
+        line: Some(e.line()),
+        column: Some(e.column()),
+        source: Some(Arc::new(e)),
+        ..Default::default()
+    })?;
+
+    match json {
+        JsonValue::Object(members) => Ok(members),
+        json => Err(format!(
+            "Top-level JSON element must be an object. Instead, '{}' was found.",
+            kind(&json)
+        )
+        .into()),
     }
+}
 
-    fn visit_value(&mut self, value: &JsonValue) {
-        match value {
-            JsonValue::Object(ref element) => self.visit_element(element),
-            JsonValue::Array(array) => {
-                for (index, element) in array.iter().enumerate() {
-                    self.enter_context(index.to_string());
-                    self.visit_value(element);
-                    self.exit_context();
-                }
+pub(crate) fn parse(content: &[u8]) -> Result<ConfigurationData, ParseError> {
+    read(content).map(|members| visit(&JsonValue::Object(members)))
+}
+
+#[cfg(feature = "file")]
+// merges the source into the target, where the members of the source take precedence
+fn merge(target: &mut Map<String, JsonValue>, source: Map<String, JsonValue>) {
+    for (name, value) in source {
+        match (target.get_mut(&name), value) {
+            (Some(JsonValue::Object(current)), JsonValue::Object(value)) => merge(current, value),
+            (_, value) => {
+                target.insert(name, value);
             }
-            JsonValue::Bool(value) => self.add_value(value),
-            JsonValue::Null => self.add_value(String::new()),
-            JsonValue::Number(value) => self.add_value(value),
-            JsonValue::String(value) => self.add_value(value),
         }
     }
+}
 
-    fn add_value<T: ToString>(&mut self, value: T) {
-        let key = self.paths.last().unwrap().to_string();
-        self.data
-            .insert(key.to_uppercase(), (key, value.to_string().into()));
+#[cfg(feature = "file")]
+fn unreadable(path: &Path, error: std::io::Error) -> ParseError {
+    ParseError {
+        message: format!(
+            "The included configuration file '{}' could not be read. {}",
+            path.display(),
+            error
+        ),
+        source: Some(Arc::new(error)),
+        ..Default::default()
     }
+}
 
-    fn enter_context(&mut self, context: String) {
-        if self.paths.is_empty() {
-            self.paths.push(context);
-            return;
-        }
+#[cfg(feature = "file")]
+fn include(path: &Path, includes: &mut Vec<PathBuf>) -> Result<Map<String, JsonValue>, ParseError> {
+    let path = path.canonicalize().map_err(|e| unreadable(path, e))?;
 
-        let path = ConfigurationPath::combine(&[&self.paths[self.paths.len() - 1], &context]);
-        self.paths.push(path);
+    if includes.contains(&path) {
+        return Err(format!(
+            "The configuration file '{}' is included recursively.",
+            path.display()
+        )
+        .into());
     }
 
-    fn exit_context(&mut self) {
-        self.paths.pop();
-    }
-}
+    let content = std::fs::read(&path).map_err(|e| unreadable(&path, e))?;
 
-struct InnerProvider {
-    file: FileSource,
-    data: RwLock<HashMap<String, (String, Value)>>,
-    token: RwLock<SharedChangeToken<SingleChangeToken>>,
+    // the line and column refer to the included file rather than the file being loaded
+    let mut members = read(&content).map_err(|error| ParseError {
+        message: format!(
+            "The included configuration file '{}' is invalid. {}",
+            path.display(),
+            error.message
+        ),
+        source: error.source,
+        ..Default::default()
+    })?;
+
+    includes.push(path);
+    expand_members(&mut members, includes)?;
+    includes.pop();
+
+    Ok(members)
 }
 
-impl InnerProvider {
-    fn new(file: FileSource) -> Self {
-        Self {
-            file,
-            data: RwLock::new(HashMap::with_capacity(0)),
-            token: Default::default(),
-        }
+#[cfg(feature = "file")]
+fn expand(json: &mut JsonValue, includes: &mut Vec<PathBuf>) -> Result<(), ParseError> {
+    match json {
+        JsonValue::Object(members) => expand_members(members, includes),
+        JsonValue::Array(elements) => elements
+            .iter_mut()
+            .try_for_each(|element| expand(element, includes)),
+        _ => Ok(()),
     }
+}
 
-    fn load(&self, reload: bool) -> LoadResult {
-        if !self.file.path.is_file() {
-            if self.file.optional || reload {
-                let mut data = self.data.write().unwrap();
-                if !data.is_empty() {
-                    *data = HashMap::with_capacity(0);
-                }
+#[cfg(feature = "file")]
+// replaces the $include directive, if any, with the members of the included files. the file at
+// the top of the stack is the file being expanded, which is used to resolve relative paths
+fn expand_members(
+    members: &mut Map<String, JsonValue>,
+    includes: &mut Vec<PathBuf>,
+) -> Result<(), ParseError> {
+    let directive = members.remove(INCLUDE);
+
+    for value in members.values_mut() {
+        expand(value, includes)?;
+    }
 
-                return Ok(());
-            } else {
-                return Err(LoadError::File {
-                    message: format!(
-                        "The configuration file '{}' was not found and is not optional.",
-                        self.file.path.display()
-                    ),
-                    path: self.file.path.clone(),
-                });
-            }
-        }
+    let paths = match directive {
+        Some(JsonValue::String(path)) => vec![JsonValue::String(path)],
+        Some(JsonValue::Array(paths)) => paths,
+        Some(_) => Vec::with_capacity(0),
+        _ => return Ok(()),
+    };
+
+    if paths.is_empty() || paths.iter().any(|path| !path.is_string()) {
+        return Err(format!(
+            "The '{}' directive must be a path or an array of paths.",
+            INCLUDE
+        )
+        .into());
+    }
 
-        // REF: https://docs.serde.rs/serde_json/de/fn.from_reader.html
-        let content = fs::read(&self.file.path).unwrap();
-        let json: JsonValue = serde_json::from_slice(&content).unwrap();
-
-        if let Some(root) = json.as_object() {
-            let visitor = JsonVisitor::default();
-            let data = visitor.visit(root);
-            *self.data.write().unwrap() = data;
-        } else if reload {
-            *self.data.write().unwrap() = HashMap::with_capacity(0);
-        } else {
-            return Err(LoadError::File {
-                message: format!(
-                    "Top-level JSON element must be an object. Instead, '{}' was found.",
-                    match json {
-                        JsonValue::Array(_) => "array",
-                        JsonValue::Bool(_) => "Boolean",
-                        JsonValue::Null => "null",
-                        JsonValue::Number(_) => "number",
-                        JsonValue::String(_) => "string",
-                        _ => unreachable!(),
-                    }
-                ),
-                path: self.file.path.clone(),
-            });
-        }
+    let mut spliced = Map::new();
 
-        let previous = std::mem::replace(
-            &mut *self.token.write().unwrap(),
-            SharedChangeToken::default(),
-        );
+    for path in paths {
+        let dir = includes
+            .last()
+            .and_then(|file| file.parent())
+            .unwrap_or_else(|| Path::new(""));
+        let path = dir.join(path.as_str().unwrap_or_default());
 
-        previous.notify();
-        Ok(())
+        merge(&mut spliced, include(&path, includes)?);
     }
 
-    fn get(&self, key: &str) -> Option<Value> {
-        self.data
-            .read()
-            .unwrap()
-            .get(&key.to_uppercase())
-            .map(|t| t.1.clone())
+    merge(&mut spliced, std::mem::take(members));
+    *members = spliced;
+    Ok(())
+}
+
+#[cfg(feature = "file")]
+const INCLUDE: &str = "$include";
+
+#[cfg(feature = "file")]
+#[derive(Clone)]
+struct JsonFileParser {
+    includes: bool,
+}
+
+#[cfg(feature = "file")]
+impl ConfigurationFileParser for JsonFileParser {
+    fn parse(&self, path: &Path, content: &[u8]) -> Result<ConfigurationData, ParseError> {
+        let result = read(content).and_then(|mut members| {
+            if self.includes {
+                let file = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+                expand_members(&mut members, &mut vec![file])?;
+            }
+
+            Ok(visit(&JsonValue::Object(members)))
+        });
+
+        result.map_err(|mut error| {
+            error.message = format!(
+                "The configuration file '{}' is invalid. {}",
+                path.display(),
+                error.message
+            );
+            error
+        })
     }
 
-    fn reload_token(&self) -> Box<dyn ChangeToken> {
-        Box::new(self.token.read().unwrap().clone())
+    fn format(&self, data: &ConfigurationData) -> Result<Vec<u8>, ParseError> {
+        // the included members cannot be separated from the members of the including file
+        if self.includes {
+            return Err(format!(
+                "A configuration file that supports the '{}' directive cannot be written.",
+                INCLUDE
+            )
+            .into());
+        }
+
+        let tree = data.to_tree().map_err(|key| ParseError {
+            message: format!(
+                "The key '{}' has a value and child keys, which cannot be written as JSON.",
+                key
+            ),
+            key: Some(key),
+            ..Default::default()
+        })?;
+        let mut content = serde_json::to_vec_pretty(&to_json(tree)).map_err(|e| ParseError {
+            message: e.to_string(),
+            source: Some(Arc::new(e)),
+            ..Default::default()
+        })?;
+
+        content.push(b'\n');
+        Ok(content)
     }
+}
 
-    fn child_keys(&self, earlier_keys: &mut Vec<String>, parent_path: Option<&str>) {
-        let data = self.data.read().unwrap();
-        accumulate_child_keys(&data, earlier_keys, parent_path)
+#[cfg(feature = "file")]
+fn to_json(node: ConfigurationNode) -> JsonValue {
+    match node {
+        ConfigurationNode::Text(text) => JsonValue::String(text),
+        ConfigurationNode::Typed(text, value) => match value {
+            ConfigValue::Bool(value) => JsonValue::Bool(value),
+            ConfigValue::Int(value) => JsonValue::from(value),
+            ConfigValue::Float(value) => JsonValue::from(value),
+            ConfigValue::Null => JsonValue::Null,
+            ConfigValue::String(_) => JsonValue::String(text),
+        },
+        ConfigurationNode::Object(members) => JsonValue::Object(
+            members
+                .into_iter()
+                .map(|(name, member)| (name, to_json(member)))
+                .collect(),
+        ),
+        ConfigurationNode::Array(elements) => {
+            JsonValue::Array(elements.into_iter().map(to_json).collect())
+        }
     }
 }
 
 /// Represents a [`ConfigurationProvider`](crate::ConfigurationProvider) for `*.json` files.
+#[cfg(feature = "file")]
 pub struct JsonConfigurationProvider {
-    inner: Arc<InnerProvider>,
-    _subscription: Option<Box<dyn Subscription>>,
+    inner: FileConfigurationProvider<JsonFileParser>,
 }
 
+#[cfg(feature = "file")]
 impl JsonConfigurationProvider {
     /// Initializes a new `*.json` file configuration provider.
     ///
@@ -176,40 +301,84 @@ impl JsonConfigurationProvider {
     ///
     /// * `file` - The `*.json` [`FileSource`](crate::FileSource) information
     pub fn new(file: FileSource) -> Self {
-        let path = file.path.clone();
-        let inner = Arc::new(InnerProvider::new(file));
-        let subscription: Option<Box<dyn Subscription>> = if inner.file.reload_on_change {
-            Some(Box::new(tokens::on_change(
-                move || FileChangeToken::new(path.clone()),
-                |state| {
-                    let provider = state.unwrap();
-                    std::thread::sleep(provider.file.reload_delay);
-                    provider.load(true).ok();
-                },
-                Some(inner.clone())
-            )))
-        } else {
-            None
-        };
+        Self::with_includes(file, false)
+    }
 
+    /// Initializes a new `*.json` file configuration provider that supports include directives.
+    ///
+    /// # Arguments
+    ///
+    /// * `file` - The `*.json` [`FileSource`](crate::FileSource) information
+    /// * `includes` - Indicates whether `$include` directives are expanded
+    ///
+    /// # Remarks
+    ///
+    /// An `$include` directive is a path or an array of paths, relative to the including file,
+    /// whose members are spliced into the object that contains the directive. The members of
+    /// the object take precedence over the included members and later files take precedence
+    /// over earlier files. Only the including file is watched for changes.
+    pub fn with_includes(file: FileSource, includes: bool) -> Self {
         Self {
-            inner,
-            _subscription: subscription,
+            inner: FileConfigurationProvider::named(
+                type_name::<Self>(),
+                file,
+                JsonFileParser { includes },
+            ),
         }
     }
+
+    /// Sets a configuration value in the loaded data.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the value to set
+    /// * `value` - The value to set
+    ///
+    /// # Remarks
+    ///
+    /// The file is unchanged until the provider is [saved](ConfigurationProvider::save).
+    pub fn set(&self, key: &str, value: &str) {
+        self.inner.set(key, value)
+    }
+
+    /// Removes a configuration value, including all of its descendants, from the loaded data.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the value to remove
+    pub fn remove(&self, key: &str) {
+        self.inner.remove(key)
+    }
 }
 
+#[cfg(feature = "file")]
 impl ConfigurationProvider for JsonConfigurationProvider {
     fn get(&self, key: &str) -> Option<Value> {
         self.inner.get(key)
     }
 
+    fn get_typed(&self, key: &str) -> Option<ConfigValue> {
+        self.inner.get_typed(key)
+    }
+
     fn reload_token(&self) -> Box<dyn ChangeToken> {
         self.inner.reload_token()
     }
 
     fn load(&mut self) -> LoadResult {
-        self.inner.load(false)
+        self.inner.load()
+    }
+
+    fn close(&mut self) {
+        self.inner.close()
+    }
+
+    fn save(&self) -> LoadResult {
+        self.inner.save()
+    }
+
+    fn try_iter(&self) -> Option<ConfigurationPairs<'_>> {
+        self.inner.try_iter()
     }
 
     fn child_keys(&self, earlier_keys: &mut Vec<String>, parent_path: Option<&str>) {
@@ -218,10 +387,13 @@ impl ConfigurationProvider for JsonConfigurationProvider {
 }
 
 /// Represents a [`ConfigurationSource`](crate::ConfigurationSource) for `*.json` files.
+#[cfg(feature = "file")]
 pub struct JsonConfigurationSource {
     file: FileSource,
+    includes: bool,
 }
 
+#[cfg(feature = "file")]
 impl JsonConfigurationSource {
     /// Initializes a new `*.json` file configuration source.
     ///
@@ -229,19 +401,43 @@ impl JsonConfigurationSource {
     ///
     /// * `file` - The `*.json` [`FileSource`](crate::FileSource) information
     pub fn new(file: FileSource) -> Self {
-        Self { file }
+        Self {
+            file,
+            includes: false,
+        }
+    }
+
+    /// Initializes a new `*.json` file configuration source that expands `$include` directives.
+    ///
+    /// # Arguments
+    ///
+    /// * `file` - The `*.json` [`FileSource`](crate::FileSource) information
+    pub fn with_includes(file: FileSource) -> Self {
+        Self {
+            file,
+            includes: true,
+        }
     }
 }
 
+#[cfg(feature = "file")]
 impl ConfigurationSource for JsonConfigurationSource {
-    fn build(&self, _builder: &dyn ConfigurationBuilder) -> Box<dyn ConfigurationProvider> {
-        Box::new(JsonConfigurationProvider::new(self.file.clone()))
+    fn build(&self, builder: &dyn ConfigurationBuilder) -> Box<dyn ConfigurationProvider> {
+        Box::new(JsonConfigurationProvider::with_includes(
+            self.file.configure(builder),
+            self.includes,
+        ))
     }
 }
 
 pub mod ext {
 
     use super::*;
+    use crate::{ConfigurationBuilder, StreamConfigurationSource};
+    use std::io::{Cursor, Read};
+
+    #[cfg(feature = "file")]
+    use crate::HostEnvironment;
 
     /// Defines extension methods for [`ConfigurationBuilder`](crate::ConfigurationBuilder).
     pub trait JsonConfigurationExtensions {
@@ -250,20 +446,132 @@ pub mod ext {
         /// # Arguments
         ///
         /// * `file` - The `*.json` [`FileSource`](crate::FileSource) information
+        #[cfg(feature = "file")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "file")))]
         fn add_json_file<T: Into<FileSource>>(&mut self, file: T) -> &mut Self;
+
+        /// Adds a `*.json` file that can include other `*.json` files as a configuration source.
+        ///
+        /// # Arguments
+        ///
+        /// * `file` - The `*.json` [`FileSource`](crate::FileSource) information
+        ///
+        /// # Remarks
+        ///
+        /// An `$include` directive, such as `"$include": "logging.json"` or
+        /// `"$include": ["a.json", "b.json"]`, splices the members of the included files into the
+        /// object that contains the directive. Paths are relative to the including file.
+        #[cfg(feature = "file")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "file")))]
+        fn add_json_file_with_includes<T: Into<FileSource>>(&mut self, file: T) -> &mut Self;
+
+        /// Adds a `*.json` file and its variant for the host environment as configuration sources.
+        ///
+        /// # Arguments
+        ///
+        /// * `base` - The path of the file, relative to the content root and without an extension,
+        ///   such as `appsettings`
+        /// * `environment` - The [`HostEnvironment`](crate::HostEnvironment) used to resolve the files
+        ///
+        /// # Remarks
+        ///
+        /// The environment-specific file, such as `appsettings.Development.json`, is optional and
+        /// overrides the values of the base file.
+        #[cfg(feature = "file")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "file")))]
+        fn add_json_file_for_env(&mut self, base: &str, environment: &HostEnvironment)
+            -> &mut Self;
+
+        /// Adds JSON read from a stream as a configuration source.
+        ///
+        /// # Arguments
+        ///
+        /// * `stream` - The [`Read`](std::io::Read) stream containing JSON
+        fn add_json_stream<R: Read + 'static>(&mut self, stream: R) -> &mut Self;
+
+        /// Adds embedded JSON content as a configuration source.
+        ///
+        /// # Arguments
+        ///
+        /// * `content` - The JSON content, which is typically from [`include_str`]
+        fn add_embedded_json(&mut self, content: &str) -> &mut Self;
     }
 
     impl JsonConfigurationExtensions for dyn ConfigurationBuilder + '_ {
+        #[cfg(feature = "file")]
         fn add_json_file<T: Into<FileSource>>(&mut self, file: T) -> &mut Self {
             self.add(Box::new(JsonConfigurationSource::new(file.into())));
             self
         }
+
+        #[cfg(feature = "file")]
+        fn add_json_file_with_includes<T: Into<FileSource>>(&mut self, file: T) -> &mut Self {
+            self.add(Box::new(JsonConfigurationSource::with_includes(
+                file.into(),
+            )));
+            self
+        }
+
+        #[cfg(feature = "file")]
+        fn add_json_file_for_env(
+            &mut self,
+            base: &str,
+            environment: &HostEnvironment,
+        ) -> &mut Self {
+            let (file, variant) = environment.files(base, "json");
+            self.add_json_file(file)
+                .add_json_file(FileSource::optional(variant))
+        }
+
+        fn add_json_stream<R: Read + 'static>(&mut self, stream: R) -> &mut Self {
+            self.add(Box::new(StreamConfigurationSource::new(
+                stream,
+                |content| parse(content).map_err(|error| error.message),
+            )));
+            self
+        }
+
+        fn add_embedded_json(&mut self, content: &str) -> &mut Self {
+            self.add_json_stream(Cursor::new(content.to_owned()))
+        }
     }
 
     impl<T: ConfigurationBuilder> JsonConfigurationExtensions for T {
+        #[cfg(feature = "file")]
         fn add_json_file<F: Into<FileSource>>(&mut self, file: F) -> &mut Self {
             self.add(Box::new(JsonConfigurationSource::new(file.into())));
             self
         }
+
+        #[cfg(feature = "file")]
+        fn add_json_file_with_includes<F: Into<FileSource>>(&mut self, file: F) -> &mut Self {
+            self.add(Box::new(JsonConfigurationSource::with_includes(
+                file.into(),
+            )));
+            self
+        }
+
+        #[cfg(feature = "file")]
+        fn add_json_file_for_env(
+            &mut self,
+            base: &str,
+            environment: &HostEnvironment,
+        ) -> &mut Self {
+            let (file, variant) = environment.files(base, "json");
+            self.add_json_file(file)
+                .add_json_file(FileSource::optional(variant))
+        }
+
+        fn add_json_stream<R: Read + 'static>(&mut self, stream: R) -> &mut Self {
+            self.add(Box::new(StreamConfigurationSource::new(
+                stream,
+                |content| parse(content).map_err(|error| error.message),
+            )));
+            self
+        }
+
+        fn add_embedded_json(&mut self, content: &str) -> &mut Self {
+            self.add_json_stream(Cursor::new(content.to_owned()))
+        }
     }
 }
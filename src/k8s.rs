@@ -0,0 +1,478 @@
+use crate::{
+    ConfigurationBuilder, ConfigurationPairs, ConfigurationPath, ConfigurationProvider,
+    ConfigurationSource, ConfigurationUpdate, ConfigurationUpdateSink, DynamicKeyValueFeed,
+    FeedConfigurationProvider, LoadError, LoadResult, Value,
+};
+use std::fmt::{Display, Formatter, Result as FormatResult};
+use std::fs;
+use std::sync::{Arc, Mutex};
+use tokens::ChangeToken;
+
+type Pairs = Vec<(String, String)>;
+
+const NAMESPACE_PATH: &str = "/var/run/secrets/kubernetes.io/serviceaccount/namespace";
+
+/// Represents the kind of a Kubernetes object that holds configuration data.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KubernetesObjectKind {
+    /// Indicates a ConfigMap, whose data values are text.
+    ConfigMap,
+
+    /// Indicates a Secret, whose data values are Base64 encoded.
+    Secret,
+}
+
+impl Display for KubernetesObjectKind {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> FormatResult {
+        match self {
+            Self::ConfigMap => formatter.write_str("ConfigMap"),
+            Self::Secret => formatter.write_str("Secret"),
+        }
+    }
+}
+
+/// Represents a named Kubernetes object whose data is used as configuration.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KubernetesObject {
+    /// Gets or sets the kind of object.
+    pub kind: KubernetesObjectKind,
+
+    /// Gets or sets the name of the object.
+    pub name: String,
+}
+
+impl KubernetesObject {
+    /// Initializes a new ConfigMap object.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the ConfigMap
+    pub fn config_map<S: AsRef<str>>(name: S) -> Self {
+        Self {
+            kind: KubernetesObjectKind::ConfigMap,
+            name: name.as_ref().to_owned(),
+        }
+    }
+
+    /// Initializes a new Secret object.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the Secret
+    pub fn secret<S: AsRef<str>>(name: S) -> Self {
+        Self {
+            kind: KubernetesObjectKind::Secret,
+            name: name.as_ref().to_owned(),
+        }
+    }
+}
+
+/// Represents an event observed while watching a Kubernetes object.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum KubernetesWatchEvent {
+    /// Indicates that the object was added or modified and now has the specified data.
+    Applied(Vec<(String, String)>),
+
+    /// Indicates that the object was deleted.
+    Deleted,
+}
+
+/// Defines the behavior of a client for the Kubernetes API server.
+///
+/// # Remarks
+///
+/// The client is typically implemented over a Kubernetes client, such as `kube`, that is
+/// configured with the in-cluster service account, which keeps this crate independent of any
+/// particular HTTP client or TLS implementation. The data of an object is returned as it is
+/// received from the API server, so the values of a Secret remain Base64 encoded.
+pub trait KubernetesClient: Send + Sync {
+    /// Gets the data of an object.
+    ///
+    /// # Arguments
+    ///
+    /// * `namespace` - The namespace of the object
+    /// * `object` - The [`KubernetesObject`] to get
+    ///
+    /// # Remarks
+    ///
+    /// `None` is returned if the object does not exist.
+    fn get(
+        &self,
+        namespace: &str,
+        object: &KubernetesObject,
+    ) -> Result<Option<Vec<(String, String)>>, LoadError>;
+
+    /// Watches an object for changes.
+    ///
+    /// # Arguments
+    ///
+    /// * `namespace` - The namespace of the object
+    /// * `object` - The [`KubernetesObject`] to watch
+    /// * `watcher` - The [`KubernetesWatcher`] each event is sent to
+    ///
+    /// # Remarks
+    ///
+    /// This method is called once for each object, after the first successful load. It should
+    /// return immediately and forward the events of a watch stream to the watcher, such as from
+    /// a background task, until [`send`](KubernetesWatcher::send) returns `false`.
+    fn watch(&self, namespace: &str, object: &KubernetesObject, watcher: KubernetesWatcher);
+}
+
+// decodes standard Base64, which is how the API server encodes the values of a Secret
+fn decode_base64(text: &str) -> Option<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(text.len() * 3 / 4);
+    let mut buffer = 0u32;
+    let mut bits = 0;
+
+    for ch in text.trim_end_matches('=').bytes() {
+        let value = match ch {
+            b'A'..=b'Z' => ch - b'A',
+            b'a'..=b'z' => ch - b'a' + 26,
+            b'0'..=b'9' => ch - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            _ => return None,
+        };
+
+        buffer = (buffer << 6) | u32::from(value);
+        bits += 6;
+
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+
+    // a single trailing character cannot encode a whole byte
+    if bits == 6 {
+        None
+    } else {
+        Some(bytes)
+    }
+}
+
+// flattens the data of an object into key/value pairs where a double underscore in a data key
+// is a key delimiter; for example, Logging__LogLevel becomes Logging:LogLevel
+fn flatten(object: &KubernetesObject, data: Pairs) -> Result<Pairs, LoadError> {
+    let delimiter = ConfigurationPath::key_delimiter();
+    let mut pairs = Vec::with_capacity(data.len());
+
+    for (key, value) in data {
+        let value = match object.kind {
+            KubernetesObjectKind::ConfigMap => value,
+            KubernetesObjectKind::Secret => decode_base64(&value)
+                .and_then(|bytes| String::from_utf8(bytes).ok())
+                .ok_or_else(|| {
+                    LoadError::Generic(format!(
+                        "The value of '{}' in the Secret '{}' is not Base64 encoded text.",
+                        key, object.name
+                    ))
+                })?,
+        };
+
+        pairs.push((key.replace("__", delimiter), value));
+    }
+
+    Ok(pairs)
+}
+
+// combines the data of each object where the data of later objects overrides earlier objects
+fn combine(data: &[Pairs]) -> Pairs {
+    data.iter().flatten().cloned().collect()
+}
+
+/// Represents the handle the events of a watched Kubernetes object are sent to.
+#[derive(Clone)]
+pub struct KubernetesWatcher {
+    index: usize,
+    object: KubernetesObject,
+    data: Arc<Mutex<Vec<Pairs>>>,
+    sink: ConfigurationUpdateSink,
+}
+
+impl KubernetesWatcher {
+    /// Applies an event and signals the reload token of the provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `event` - The [`KubernetesWatchEvent`] to apply
+    ///
+    /// # Remarks
+    ///
+    /// `false` is returned if the provider has been dropped, which indicates the watch should be
+    /// stopped. An applied event whose data cannot be decoded is ignored and the current values
+    /// are retained.
+    pub fn send(&self, event: KubernetesWatchEvent) -> bool {
+        let pairs = match event {
+            KubernetesWatchEvent::Applied(data) => match flatten(&self.object, data) {
+                Ok(pairs) => pairs,
+                _ => return true,
+            },
+            KubernetesWatchEvent::Deleted => Vec::new(),
+        };
+        let snapshot = {
+            let mut data = self.data.lock().unwrap();
+            data[self.index] = pairs;
+            combine(&data)
+        };
+
+        self.sink.send(ConfigurationUpdate::Snapshot(snapshot))
+    }
+}
+
+// adapts the objects read from a client to the feed that backs the provider
+struct ObjectFeed {
+    client: Arc<dyn KubernetesClient>,
+    namespace: String,
+    objects: Vec<KubernetesObject>,
+    optional: bool,
+    data: Arc<Mutex<Vec<Pairs>>>,
+}
+
+impl DynamicKeyValueFeed for ObjectFeed {
+    fn snapshot(&self) -> Result<Pairs, LoadError> {
+        let mut data = Vec::with_capacity(self.objects.len());
+
+        for object in &self.objects {
+            match self.client.get(&self.namespace, object)? {
+                Some(pairs) => data.push(flatten(object, pairs)?),
+                None if self.optional => data.push(Vec::new()),
+                _ => {
+                    return Err(LoadError::Generic(format!(
+                        "The {} '{}' was not found in the namespace '{}' and is not optional.",
+                        object.kind, object.name, self.namespace
+                    )))
+                }
+            }
+        }
+
+        let snapshot = combine(&data);
+        *self.data.lock().unwrap() = data;
+        Ok(snapshot)
+    }
+
+    fn subscribe(&self, sink: ConfigurationUpdateSink) {
+        for (index, object) in self.objects.iter().enumerate() {
+            let watcher = KubernetesWatcher {
+                index,
+                object: object.clone(),
+                data: self.data.clone(),
+                sink: sink.clone(),
+            };
+
+            self.client.watch(&self.namespace, object, watcher);
+        }
+    }
+}
+
+/// Represents a [`ConfigurationProvider`](crate::ConfigurationProvider) for the ConfigMaps and
+/// Secrets read from the Kubernetes API server.
+pub struct KubernetesConfigurationProvider(FeedConfigurationProvider);
+
+impl KubernetesConfigurationProvider {
+    /// Initializes a new Kubernetes configuration provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The [`KubernetesClient`] used to communicate with the API server
+    /// * `namespace` - The namespace of the objects
+    /// * `objects` - The [`KubernetesObject`] sequence to read, in order of precedence
+    /// * `optional` - Indicates whether the objects are optional
+    pub fn new(
+        client: Arc<dyn KubernetesClient>,
+        namespace: &str,
+        objects: &[KubernetesObject],
+        optional: bool,
+    ) -> Self {
+        let feed = ObjectFeed {
+            client,
+            namespace: namespace.to_owned(),
+            objects: objects.to_vec(),
+            optional,
+            data: Default::default(),
+        };
+
+        Self(FeedConfigurationProvider::new(Arc::new(feed)))
+    }
+}
+
+impl ConfigurationProvider for KubernetesConfigurationProvider {
+    fn get(&self, key: &str) -> Option<Value> {
+        self.0.get(key)
+    }
+
+    fn reload_token(&self) -> Box<dyn ChangeToken> {
+        self.0.reload_token()
+    }
+
+    fn load(&mut self) -> LoadResult {
+        self.0.load()
+    }
+
+    fn close(&mut self) {
+        self.0.close()
+    }
+
+    fn try_iter(&self) -> Option<ConfigurationPairs<'_>> {
+        self.0.try_iter()
+    }
+
+    fn child_keys(&self, earlier_keys: &mut Vec<String>, parent_path: Option<&str>) {
+        self.0.child_keys(earlier_keys, parent_path)
+    }
+}
+
+/// Represents a [`ConfigurationSource`](crate::ConfigurationSource) for the ConfigMaps and
+/// Secrets read from the Kubernetes API server.
+///
+/// # Remarks
+///
+/// Each object is read into the root of the configuration; a double underscore in a data key
+/// is a key delimiter, so `Logging__LogLevel` becomes `Logging:LogLevel`. When the same key is
+/// defined by more than one object, the object specified last wins.
+pub struct KubernetesConfigurationSource {
+    client: Arc<dyn KubernetesClient>,
+    namespace: String,
+    objects: Vec<KubernetesObject>,
+    optional: bool,
+}
+
+impl KubernetesConfigurationSource {
+    /// Initializes a new Kubernetes configuration source.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The [`KubernetesClient`] used to communicate with the API server
+    /// * `namespace` - The namespace of the objects
+    /// * `objects` - The [`KubernetesObject`] sequence to read, in order of precedence
+    pub fn new<C: KubernetesClient + 'static>(
+        client: C,
+        namespace: &str,
+        objects: &[KubernetesObject],
+    ) -> Self {
+        Self {
+            client: Arc::new(client),
+            namespace: namespace.to_owned(),
+            objects: objects.to_vec(),
+            optional: false,
+        }
+    }
+
+    /// Sets a value indicating whether the objects are optional.
+    ///
+    /// # Arguments
+    ///
+    /// * `optional` - `true` if a missing object is treated as having no data; otherwise, `false`
+    pub fn optional(mut self, optional: bool) -> Self {
+        self.optional = optional;
+        self
+    }
+
+    /// Gets the namespace of the current pod from its service account, if any.
+    ///
+    /// # Remarks
+    ///
+    /// The namespace is only available when running inside of a Kubernetes cluster.
+    pub fn in_cluster_namespace() -> Option<String> {
+        fs::read_to_string(NAMESPACE_PATH)
+            .ok()
+            .map(|namespace| namespace.trim().to_owned())
+            .filter(|namespace| !namespace.is_empty())
+    }
+}
+
+impl ConfigurationSource for KubernetesConfigurationSource {
+    fn build(&self, _builder: &dyn ConfigurationBuilder) -> Box<dyn ConfigurationProvider> {
+        Box::new(KubernetesConfigurationProvider::new(
+            self.client.clone(),
+            &self.namespace,
+            &self.objects,
+            self.optional,
+        ))
+    }
+}
+
+pub mod ext {
+
+    use super::*;
+
+    /// Defines extension methods for [`ConfigurationBuilder`](crate::ConfigurationBuilder).
+    pub trait KubernetesConfigurationBuilderExtensions {
+        /// Adds ConfigMaps and Secrets read from the Kubernetes API server as a configuration
+        /// source.
+        ///
+        /// # Arguments
+        ///
+        /// * `client` - The [`KubernetesClient`] used to communicate with the API server
+        /// * `namespace` - The namespace of the objects
+        /// * `objects` - The [`KubernetesObject`] sequence to read, in order of precedence
+        fn add_kubernetes<C: KubernetesClient + 'static>(
+            &mut self,
+            client: C,
+            namespace: &str,
+            objects: &[KubernetesObject],
+        ) -> &mut Self;
+    }
+
+    impl KubernetesConfigurationBuilderExtensions for dyn ConfigurationBuilder + '_ {
+        fn add_kubernetes<C: KubernetesClient + 'static>(
+            &mut self,
+            client: C,
+            namespace: &str,
+            objects: &[KubernetesObject],
+        ) -> &mut Self {
+            self.add(Box::new(KubernetesConfigurationSource::new(
+                client, namespace, objects,
+            )));
+            self
+        }
+    }
+
+    impl<T: ConfigurationBuilder> KubernetesConfigurationBuilderExtensions for T {
+        fn add_kubernetes<C: KubernetesClient + 'static>(
+            &mut self,
+            client: C,
+            namespace: &str,
+            objects: &[KubernetesObject],
+        ) -> &mut Self {
+            self.add(Box::new(KubernetesConfigurationSource::new(
+                client, namespace, objects,
+            )));
+            self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn decode_base64_should_decode_padded_and_unpadded_text() {
+        // arrange
+        let padded = "c2VjcmV0";
+        let unpadded = "cGFzcw";
+
+        // act
+        let secret = decode_base64(padded).unwrap();
+        let pass = decode_base64(unpadded).unwrap();
+
+        // assert
+        assert_eq!(secret, b"secret");
+        assert_eq!(pass, b"pass");
+    }
+
+    #[test]
+    fn decode_base64_should_reject_invalid_text() {
+        // arrange
+        let invalid = ["c2Vj!", "c2VjcmV0c"];
+
+        // act
+        let results: Vec<_> = invalid.iter().map(|text| decode_base64(text)).collect();
+
+        // assert
+        assert!(results.iter().all(Option::is_none));
+    }
+}
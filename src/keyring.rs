@@ -0,0 +1,221 @@
+use crate::{
+    util::ConfigurationData, ConfigurationBuilder, ConfigurationPairs, ConfigurationProvider,
+    ConfigurationSource, LoadError, LoadResult, Value, ValueRef,
+};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Defines the behavior of a credential store.
+pub trait CredentialStore: Send + Sync {
+    /// Gets the secret stored for the specified service and account.
+    ///
+    /// # Arguments
+    ///
+    /// * `service` - The name of the service the secret belongs to
+    /// * `account` - The account, or configuration key, of the secret
+    ///
+    /// # Remarks
+    ///
+    /// `None` indicates there is no secret for the account. An error indicates the credential
+    /// store could not be read.
+    fn get(&self, service: &str, account: &str) -> Result<Option<String>, LoadError>;
+}
+
+/// Represents the [`CredentialStore`] of the current platform.
+///
+/// # Remarks
+///
+/// Secrets are read using the command-line tool of the platform, which must be installed and
+/// available on the `PATH`:
+///
+/// * macOS - the Keychain is read using the `security` tool
+/// * Other Unix platforms - the Secret Service is read using the `secret-tool` tool, which is
+///   typically provided by the `libsecret-tools` package
+///
+/// Other platforms, including Windows, are not supported and fail to load; use a custom
+/// [`CredentialStore`] instead.
+#[derive(Default)]
+pub struct PlatformCredentialStore;
+
+impl PlatformCredentialStore {
+    #[cfg(target_os = "macos")]
+    fn command(service: &str, account: &str) -> Result<std::process::Command, LoadError> {
+        let mut command = std::process::Command::new("security");
+        command.args(["find-generic-password", "-s", service, "-a", account, "-w"]);
+        Ok(command)
+    }
+
+    #[cfg(all(unix, not(target_os = "macos")))]
+    fn command(service: &str, account: &str) -> Result<std::process::Command, LoadError> {
+        let mut command = std::process::Command::new("secret-tool");
+        command.args(["lookup", "service", service, "account", account]);
+        Ok(command)
+    }
+
+    #[cfg(not(unix))]
+    fn command(_service: &str, _account: &str) -> Result<std::process::Command, LoadError> {
+        Err(LoadError::Generic(
+            "The platform credential store is not supported on this platform. \
+             Use KeyringConfigurationSource::with_store to provide a credential store."
+                .into(),
+        ))
+    }
+}
+
+impl CredentialStore for PlatformCredentialStore {
+    fn get(&self, service: &str, account: &str) -> Result<Option<String>, LoadError> {
+        let mut command = Self::command(service, account)?;
+        let output = command.output().map_err(|error| {
+            LoadError::Generic(format!(
+                "The platform credential store could not be read because '{}' could not be run. {}",
+                command.get_program().to_string_lossy(),
+                error
+            ))
+        })?;
+
+        // the tools exit with an error when the secret does not exist
+        if output.status.success() {
+            let secret = String::from_utf8_lossy(&output.stdout);
+            Ok(Some(secret.trim_end_matches(&['\r', '\n'][..]).to_owned()))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+/// Represents a [`ConfigurationProvider`](crate::ConfigurationProvider) for secrets in a
+/// credential store.
+pub struct KeyringConfigurationProvider {
+    service: String,
+    keys: Vec<String>,
+    store: Arc<dyn CredentialStore>,
+    data: ConfigurationData,
+}
+
+impl KeyringConfigurationProvider {
+    /// Initializes a new keyring configuration provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `service` - The name of the service the secrets belong to
+    /// * `keys` - The configuration keys resolved from the credential store
+    /// * `store` - The [`CredentialStore`] secrets are resolved from
+    pub fn new(service: String, keys: Vec<String>, store: Arc<dyn CredentialStore>) -> Self {
+        Self {
+            service,
+            keys,
+            store,
+            data: Default::default(),
+        }
+    }
+}
+
+impl ConfigurationProvider for KeyringConfigurationProvider {
+    fn get(&self, key: &str) -> Option<Value> {
+        self.data.get(key)
+    }
+
+    fn get_str(&self, key: &str) -> Option<ValueRef<'_>> {
+        self.data.get_str(key).map(ValueRef::Borrowed)
+    }
+
+    fn load(&mut self) -> LoadResult {
+        let mut data = HashMap::with_capacity(self.keys.len());
+
+        for key in &self.keys {
+            if let Some(secret) = self.store.get(&self.service, key)? {
+                data.insert(key.to_uppercase(), (key.clone(), secret.into()));
+            }
+        }
+
+        self.data = data.into();
+        Ok(())
+    }
+
+    fn try_iter(&self) -> Option<ConfigurationPairs<'_>> {
+        Some(self.data.pairs())
+    }
+
+    fn child_keys(&self, earlier_keys: &mut Vec<String>, parent_path: Option<&str>) {
+        self.data.child_keys(earlier_keys, parent_path)
+    }
+}
+
+/// Represents a [`ConfigurationSource`](crate::ConfigurationSource) for secrets in a
+/// credential store.
+pub struct KeyringConfigurationSource {
+    service: String,
+    keys: Vec<String>,
+    store: Arc<dyn CredentialStore>,
+}
+
+impl KeyringConfigurationSource {
+    /// Initializes a new keyring configuration source.
+    ///
+    /// # Arguments
+    ///
+    /// * `service` - The name of the service the secrets belong to
+    /// * `keys` - The configuration keys resolved from the credential store
+    ///
+    /// # Remarks
+    ///
+    /// Secrets are resolved from the [`PlatformCredentialStore`] by default. Keys that do
+    /// not have a secret are ignored.
+    pub fn new<S: AsRef<str>>(service: &str, keys: &[S]) -> Self {
+        Self {
+            service: service.to_owned(),
+            keys: keys.iter().map(|k| k.as_ref().to_owned()).collect(),
+            store: Arc::new(PlatformCredentialStore),
+        }
+    }
+
+    /// Sets the credential store secrets are resolved from.
+    ///
+    /// # Arguments
+    ///
+    /// * `store` - The [`CredentialStore`] secrets are resolved from
+    pub fn with_store(mut self, store: Box<dyn CredentialStore>) -> Self {
+        self.store = store.into();
+        self
+    }
+}
+
+impl ConfigurationSource for KeyringConfigurationSource {
+    fn build(&self, _builder: &dyn ConfigurationBuilder) -> Box<dyn ConfigurationProvider> {
+        Box::new(KeyringConfigurationProvider::new(
+            self.service.clone(),
+            self.keys.clone(),
+            self.store.clone(),
+        ))
+    }
+}
+
+pub mod ext {
+
+    use super::*;
+
+    /// Defines extension methods for [`ConfigurationBuilder`](crate::ConfigurationBuilder).
+    pub trait KeyringConfigurationBuilderExtensions {
+        /// Adds secrets from the platform credential store as a configuration source.
+        ///
+        /// # Arguments
+        ///
+        /// * `service` - The name of the service the secrets belong to
+        /// * `keys` - The configuration keys resolved from the credential store
+        fn add_keyring<S: AsRef<str>>(&mut self, service: &str, keys: &[S]) -> &mut Self;
+    }
+
+    impl KeyringConfigurationBuilderExtensions for dyn ConfigurationBuilder + '_ {
+        fn add_keyring<S: AsRef<str>>(&mut self, service: &str, keys: &[S]) -> &mut Self {
+            self.add(Box::new(KeyringConfigurationSource::new(service, keys)));
+            self
+        }
+    }
+
+    impl<T: ConfigurationBuilder> KeyringConfigurationBuilderExtensions for T {
+        fn add_keyring<S: AsRef<str>>(&mut self, service: &str, keys: &[S]) -> &mut Self {
+            self.add(Box::new(KeyringConfigurationSource::new(service, keys)));
+            self
+        }
+    }
+}
@@ -11,22 +11,53 @@ pub type Value = std::sync::Arc<String>;
 
 mod builder;
 mod configuration;
+mod connection;
+mod convert;
+mod diff;
+mod frozen;
+mod host;
+mod localized;
+mod named;
+mod overlay;
 mod path;
+mod priority;
 mod provider;
+mod query;
+mod redact;
+mod required;
 mod root;
 mod section;
 mod source;
+mod trace;
+mod value;
 
 /// Contains configuration utility functions.
 #[cfg(feature = "util")]
 pub mod util;
 
+/// Contains configuration file path utilities.
+#[cfg(feature = "paths")]
+#[cfg_attr(docsrs, doc(cfg(feature = "paths")))]
+pub mod paths;
+
 #[cfg(feature = "chained")]
 mod chained;
 
+#[cfg(feature = "std")]
+mod conditional;
+
 #[cfg(feature = "std")]
 mod default;
 
+#[cfg(feature = "std")]
+mod merge;
+
+#[cfg(feature = "std")]
+mod scoped;
+
+#[cfg(feature = "std")]
+mod tenant;
+
 #[cfg(feature = "mem")]
 mod memory;
 
@@ -36,6 +67,9 @@ mod env;
 #[cfg(feature = "ini")]
 mod ini;
 
+#[cfg(feature = "edit")]
+mod edit;
+
 #[cfg(feature = "json")]
 mod json;
 
@@ -45,21 +79,89 @@ mod cmd;
 #[cfg(feature = "xml")]
 mod xml;
 
+#[cfg(feature = "prefix")]
+mod prefix;
+
+#[cfg(feature = "mapped")]
+mod mapped;
+
+#[cfg(feature = "decrypt")]
+mod decrypt;
+
+#[cfg(feature = "keyring")]
+mod keyring;
+
+#[cfg(feature = "stream")]
+mod stream;
+
+#[cfg(any(feature = "json", feature = "ini", feature = "xml"))]
+mod format;
+
+#[cfg(feature = "struct")]
+mod structure;
+
+#[cfg(feature = "refresh")]
+mod refresh;
+
+#[cfg(feature = "file")]
+mod parser;
+
+#[cfg(feature = "schema")]
+mod schema;
+
+#[cfg(feature = "derive")]
+mod options;
+
+#[cfg(feature = "test-util")]
+mod testing;
+
+#[cfg(feature = "grpc")]
+mod grpc;
+
+#[cfg(feature = "feed")]
+mod feed;
+
+#[cfg(feature = "k8s")]
+mod k8s;
+
+#[cfg(feature = "web")]
+mod web;
+
+#[cfg(feature = "profiles")]
+mod profiles;
+
 #[cfg(feature = "binder")]
 mod binder;
 
 #[cfg(feature = "binder")]
 mod de;
 
+#[cfg(feature = "binder")]
+mod watch;
+
+#[cfg(feature = "binder")]
+mod time;
+
 mod file;
 pub use builder::*;
 pub use configuration::*;
+pub use connection::{ConnectionString, ConnectionStrings};
+pub use convert::{ByteSize, ParseByteSizeError, ValueConversion};
+pub use diff::{diff, DiffEntry};
 pub use file::*;
+pub use frozen::FrozenConfiguration;
+pub use host::HostEnvironment;
+pub use named::{NamedConfigurationProvider, NamedConfigurationSource};
+pub use overlay::{ConfigurationOverlay, ConfigurationTransaction};
 pub use path::*;
+pub use priority::PrioritizedConfigurationSource;
 pub use provider::*;
+pub use redact::REDACTED;
+pub use required::ConfigError;
 pub use root::*;
 pub use section::ConfigurationSection;
 pub use source::*;
+pub use value::{ConfigValue, ValueRef};
 
 #[cfg(feature = "util")]
 #[cfg_attr(docsrs, doc(cfg(feature = "util")))]
@@ -69,24 +171,44 @@ pub use util::*;
 #[cfg_attr(docsrs, doc(cfg(feature = "chained")))]
 pub use chained::{ChainedConfigurationProvider, ChainedConfigurationSource};
 
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use conditional::ConditionalConfigurationSource;
+
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 pub use default::*;
 
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use merge::ArrayMergePolicy;
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use scoped::ScopedConfiguration;
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use tenant::TenantConfiguration;
+
 #[cfg(feature = "mem")]
 #[cfg_attr(docsrs, doc(cfg(feature = "mem")))]
-pub use memory::{MemoryConfigurationProvider, MemoryConfigurationSource};
+pub use memory::{
+    MemoryConfigurationHandle, MemoryConfigurationProvider, MemoryConfigurationSource,
+};
 
 #[cfg(feature = "env")]
 #[cfg_attr(docsrs, doc(cfg(feature = "env")))]
-pub use env::{EnvironmentVariablesConfigurationProvider, EnvironmentVariablesConfigurationSource};
+pub use env::{
+    EnvironmentVariablesConfigurationProvider, EnvironmentVariablesConfigurationSource, KeyCasing,
+};
 
 #[cfg(feature = "ini")]
 #[cfg_attr(docsrs, doc(cfg(feature = "ini")))]
 pub use ini::{IniConfigurationProvider, IniConfigurationSource};
 
-#[cfg(feature = "json")]
-#[cfg_attr(docsrs, doc(cfg(feature = "json")))]
+#[cfg(all(feature = "json", feature = "file"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "json", feature = "file"))))]
 pub use json::{JsonConfigurationProvider, JsonConfigurationSource};
 
 #[cfg(feature = "cmd")]
@@ -95,7 +217,109 @@ pub use cmd::{CommandLineConfigurationProvider, CommandLineConfigurationSource};
 
 #[cfg(feature = "xml")]
 #[cfg_attr(docsrs, doc(cfg(feature = "xml")))]
-pub use xml::{XmlConfigurationProvider, XmlConfigurationSource};
+pub use xml::{
+    XmlConfigurationProvider, XmlConfigurationSource, XmlDuplicateKeys, XmlNamespaces, XmlOptions,
+};
+
+#[cfg(feature = "prefix")]
+#[cfg_attr(docsrs, doc(cfg(feature = "prefix")))]
+pub use prefix::{PrefixedConfigurationProvider, PrefixedConfigurationSource};
+
+#[cfg(feature = "mapped")]
+#[cfg_attr(docsrs, doc(cfg(feature = "mapped")))]
+pub use mapped::{MappedConfigurationProvider, MappedConfigurationSource};
+
+#[cfg(feature = "decrypt")]
+#[cfg_attr(docsrs, doc(cfg(feature = "decrypt")))]
+pub use decrypt::{DecryptedConfigurationProvider, DecryptedConfigurationSource, ValueDecryptor};
+
+#[cfg(feature = "keyring")]
+#[cfg_attr(docsrs, doc(cfg(feature = "keyring")))]
+pub use keyring::{
+    CredentialStore, KeyringConfigurationProvider, KeyringConfigurationSource,
+    PlatformCredentialStore,
+};
+
+#[cfg(feature = "stream")]
+#[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+pub use stream::{StreamConfigurationProvider, StreamConfigurationSource};
+
+#[cfg(any(feature = "json", feature = "ini", feature = "xml"))]
+#[cfg_attr(
+    docsrs,
+    doc(cfg(any(feature = "json", feature = "ini", feature = "xml")))
+)]
+pub use format::Format;
+
+#[cfg(feature = "struct")]
+#[cfg_attr(docsrs, doc(cfg(feature = "struct")))]
+pub use structure::{
+    StructConfigurationHandle, StructConfigurationProvider, StructConfigurationSource,
+};
+
+#[cfg(feature = "refresh")]
+#[cfg_attr(docsrs, doc(cfg(feature = "refresh")))]
+pub use refresh::{
+    RefreshHandle, RefreshableConfigurationProvider, RefreshableConfigurationSource,
+};
+
+#[cfg(feature = "file")]
+#[cfg_attr(docsrs, doc(cfg(feature = "file")))]
+pub use parser::{ConfigurationFileParser, FileConfigurationProvider, FileConfigurationSource};
+
+#[cfg(feature = "schema")]
+#[cfg_attr(docsrs, doc(cfg(feature = "schema")))]
+pub use schema::{ConfigurationSchema, SchemaProperty, SchemaReport, SchemaType, TypeMismatch};
+
+#[cfg(feature = "derive")]
+#[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
+pub use options::{Config, ConfigKey};
+
+#[cfg(feature = "derive")]
+#[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
+pub use more_config_derive::{config_keys, key, Config};
+
+#[cfg(feature = "derive")]
+#[doc(hidden)]
+pub use options::__private;
+
+#[cfg(feature = "binder")]
+#[cfg_attr(docsrs, doc(cfg(feature = "binder")))]
+pub use watch::Watched;
+
+#[cfg(feature = "test-util")]
+#[cfg_attr(docsrs, doc(cfg(feature = "test-util")))]
+pub use testing::{FakeConfigurationProvider, TempConfigFile};
+
+#[cfg(feature = "grpc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc")))]
+pub use grpc::{ConfigurationServiceClient, GrpcConfigurationProvider, GrpcConfigurationSource};
+
+#[cfg(feature = "feed")]
+#[cfg_attr(docsrs, doc(cfg(feature = "feed")))]
+pub use feed::{
+    ConfigurationUpdate, ConfigurationUpdateSink, DynamicKeyValueFeed, FeedConfigurationProvider,
+    FeedConfigurationSource,
+};
+
+#[cfg(feature = "k8s")]
+#[cfg_attr(docsrs, doc(cfg(feature = "k8s")))]
+pub use k8s::{
+    KubernetesClient, KubernetesConfigurationProvider, KubernetesConfigurationSource,
+    KubernetesObject, KubernetesObjectKind, KubernetesWatchEvent, KubernetesWatcher,
+};
+
+#[cfg(feature = "web")]
+#[cfg_attr(docsrs, doc(cfg(feature = "web")))]
+pub use web::{WebClient, WebConfigurationProvider, WebConfigurationSource, WebPoller};
+
+#[cfg(feature = "paths")]
+#[cfg_attr(docsrs, doc(cfg(feature = "paths")))]
+pub use paths::AppDirs;
+
+#[cfg(feature = "profiles")]
+#[cfg_attr(docsrs, doc(cfg(feature = "profiles")))]
+pub use profiles::{ProfiledConfigurationProvider, ProfiledConfigurationSource, PROFILES_SECTION};
 
 /// Contains configuration extension methods.
 pub mod ext {
@@ -130,6 +354,69 @@ pub mod ext {
     #[cfg_attr(docsrs, doc(cfg(feature = "xml")))]
     pub use super::xml::ext::*;
 
+    #[cfg(feature = "prefix")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "prefix")))]
+    pub use prefix::ext::*;
+
+    #[cfg(feature = "keyring")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "keyring")))]
+    pub use keyring::ext::*;
+
+    #[cfg(feature = "stream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+    pub use stream::ext::*;
+
+    #[cfg(any(feature = "json", feature = "ini", feature = "xml"))]
+    #[cfg_attr(
+        docsrs,
+        doc(cfg(any(feature = "json", feature = "ini", feature = "xml")))
+    )]
+    pub use format::ext::*;
+
+    #[cfg(feature = "struct")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "struct")))]
+    pub use structure::ext::*;
+
+    #[cfg(feature = "refresh")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "refresh")))]
+    pub use refresh::ext::*;
+
+    #[cfg(feature = "file")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "file")))]
+    pub use parser::ext::*;
+
+    #[cfg(feature = "schema")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "schema")))]
+    pub use schema::ext::*;
+
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub use conditional::ext::*;
+
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub use scoped::ext::*;
+
+    #[cfg(feature = "grpc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "grpc")))]
+    pub use grpc::ext::*;
+
+    #[cfg(feature = "feed")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "feed")))]
+    pub use feed::ext::*;
+
+    #[cfg(feature = "k8s")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "k8s")))]
+    pub use k8s::ext::*;
+
+    #[cfg(feature = "web")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "web")))]
+    pub use web::ext::*;
+
+    #[cfg(feature = "profiles")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "profiles")))]
+    pub use profiles::ext::*;
+
     #[cfg(feature = "binder")]
     #[cfg_attr(docsrs, doc(cfg(feature = "binder")))]
     pub use binder::*;
@@ -138,6 +425,18 @@ pub mod ext {
     #[cfg_attr(docsrs, doc(cfg(feature = "binder")))]
     pub use de::*;
 
+    #[cfg(feature = "binder")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "binder")))]
+    pub use watch::ext::*;
+
+    pub use root::ext::*;
     pub use section::ext::*;
+    pub use configuration::ext::*;
+    pub use named::ext::*;
+    pub use priority::ext::*;
+    pub use query::ext::*;
     pub use file::ext::*;
+    pub use connection::ext::*;
+    pub use localized::ext::*;
+    pub use required::ext::*;
 }
@@ -0,0 +1,60 @@
+use crate::{Configuration, ConfigurationPath, Value};
+
+// gets the specific to neutral candidates of a culture, such as fr-CA followed by fr
+fn candidates(culture: &str) -> Vec<&str> {
+    let mut candidates = Vec::new();
+    let mut culture = culture.trim();
+
+    while !culture.is_empty() {
+        candidates.push(culture);
+
+        culture = match culture.rfind(['-', '_']) {
+            Some(index) => &culture[..index],
+            _ => "",
+        };
+    }
+
+    candidates
+}
+
+fn localized(configuration: &dyn Configuration, key: &str, culture: &str) -> Option<Value> {
+    candidates(culture)
+        .into_iter()
+        .find_map(|culture| configuration.get(&ConfigurationPath::combine(&[key, culture])))
+        .or_else(|| configuration.get(key))
+}
+
+pub mod ext {
+
+    use super::*;
+
+    /// Defines extension methods to get localized values from a
+    /// [`Configuration`](crate::Configuration).
+    pub trait LocalizedConfigurationExtensions {
+        /// Gets the configuration value for the specified culture.
+        ///
+        /// # Arguments
+        ///
+        /// * `key` - The configuration key
+        /// * `culture` - The name of the culture, such as `fr-CA`
+        ///
+        /// # Remarks
+        ///
+        /// The culture is resolved from the most specific to the most neutral culture before
+        /// falling back to the key itself. For example, the `fr-CA` culture resolves `Key:fr-CA`,
+        /// then `Key:fr`, and then `Key`.
+        fn get_localized(&self, key: &str, culture: &str) -> Option<Value>;
+    }
+
+    impl LocalizedConfigurationExtensions for dyn Configuration + '_ {
+        fn get_localized(&self, key: &str, culture: &str) -> Option<Value> {
+            localized(self, key, culture)
+        }
+    }
+
+    impl<T: Configuration> LocalizedConfigurationExtensions for T {
+        fn get_localized(&self, key: &str, culture: &str) -> Option<Value> {
+            localized(self, key, culture)
+        }
+    }
+}
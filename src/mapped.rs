@@ -0,0 +1,256 @@
+use crate::{
+    util::*, ConfigValue, ConfigurationBuilder, ConfigurationPairs, ConfigurationPath,
+    ConfigurationProvider, ConfigurationSource, LoadResult, Value,
+};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::RwLock;
+use tokens::ChangeToken;
+
+type KeyMap = Arc<dyn Fn(&str) -> Option<String> + Send + Sync>;
+type ValueMap = Arc<dyn Fn(&str, &str) -> String + Send + Sync>;
+
+struct Snapshot {
+    token: Box<dyn ChangeToken>,
+    data: ConfigurationData,
+    // maps each transformed key, normalized to uppercase, back to the key of the inner provider
+    keys: HashMap<String, String>,
+}
+
+/// Represents a [`ConfigurationProvider`](crate::ConfigurationProvider) that transforms
+/// the keys and values of another provider.
+pub struct MappedConfigurationProvider {
+    inner: Box<dyn ConfigurationProvider>,
+    key_map: Option<KeyMap>,
+    value_map: Option<ValueMap>,
+    snapshot: RwLock<Snapshot>,
+}
+
+impl MappedConfigurationProvider {
+    fn new(
+        inner: Box<dyn ConfigurationProvider>,
+        key_map: Option<KeyMap>,
+        value_map: Option<ValueMap>,
+    ) -> Self {
+        let token = inner.reload_token();
+
+        Self {
+            inner,
+            key_map,
+            value_map,
+            snapshot: RwLock::new(Snapshot {
+                token,
+                data: Default::default(),
+                keys: Default::default(),
+            }),
+        }
+    }
+
+    fn map(&self, token: Box<dyn ChangeToken>) -> Snapshot {
+        let keys = all_keys(self.inner.as_ref());
+        let mut data = HashMap::with_capacity(keys.len());
+        let mut types = HashMap::new();
+        let mut originals = HashMap::with_capacity(keys.len());
+
+        for key in keys {
+            let new_key = if let Some(map) = &self.key_map {
+                if let Some(new_key) = (map)(&key) {
+                    new_key
+                } else {
+                    continue;
+                }
+            } else {
+                key.clone()
+            };
+            let value = if let Some(value) = self.inner.get(&key) {
+                value
+            } else {
+                continue;
+            };
+            let new_value: Value = if let Some(map) = &self.value_map {
+                (map)(&new_key, &value).into()
+            } else {
+                value.clone()
+            };
+            let normalized = new_key.to_uppercase();
+
+            // a typed value, such as a number or null, is retained unless its text is transformed
+            if new_value == value {
+                match self.inner.get_typed(&key) {
+                    Some(ConfigValue::String(_)) | None => {}
+                    Some(typed) => {
+                        types.insert(normalized.clone(), typed);
+                    }
+                }
+            }
+
+            originals.insert(normalized.clone(), key);
+            data.insert(normalized, (new_key, new_value));
+        }
+
+        Snapshot {
+            token,
+            data: ConfigurationData::from(data).with_types(types),
+            keys: originals,
+        }
+    }
+
+    // maps a transformed key back to the key of the inner provider, if possible; a key that is
+    // not known, such as a removed key, can only be mapped back when the key map retains it
+    fn original_key(&self, key: &str) -> Option<String> {
+        if let Some(original) = self.snapshot.read().unwrap().keys.get(&key.to_uppercase()) {
+            return Some(original.clone());
+        }
+
+        match &self.key_map {
+            Some(map) => (map)(key)
+                .filter(|new_key| new_key.eq_ignore_ascii_case(key))
+                .map(|_| key.to_owned()),
+            _ => Some(key.to_owned()),
+        }
+    }
+
+    fn refresh(&self) {
+        if self.snapshot.read().unwrap().token.changed() {
+            let snapshot = self.map(self.inner.reload_token());
+            *self.snapshot.write().unwrap() = snapshot;
+        }
+    }
+}
+
+impl ConfigurationProvider for MappedConfigurationProvider {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn get(&self, key: &str) -> Option<Value> {
+        self.refresh();
+        self.snapshot.read().unwrap().data.get(key)
+    }
+
+    fn get_typed(&self, key: &str) -> Option<ConfigValue> {
+        self.refresh();
+        self.snapshot.read().unwrap().data.get_typed(key)
+    }
+
+    fn is_removed(&self, key: &str) -> bool {
+        self.refresh();
+        self.original_key(key)
+            .map_or(false, |original| self.inner.is_removed(&original))
+    }
+
+    fn reload_token(&self) -> Box<dyn ChangeToken> {
+        self.inner.reload_token()
+    }
+
+    fn load(&mut self) -> LoadResult {
+        self.inner.load()?;
+
+        let snapshot = self.map(self.inner.reload_token());
+
+        *self.snapshot.write().unwrap() = snapshot;
+        Ok(())
+    }
+
+    fn close(&mut self) {
+        self.inner.close()
+    }
+
+    fn save(&self) -> LoadResult {
+        self.inner.save()
+    }
+
+    fn try_iter(&self) -> Option<ConfigurationPairs<'_>> {
+        self.refresh();
+        let pairs: Vec<_> = self.snapshot.read().unwrap().data.pairs().collect();
+        Some(Box::new(pairs.into_iter()))
+    }
+
+    fn child_keys(&self, earlier_keys: &mut Vec<String>, parent_path: Option<&str>) {
+        self.refresh();
+        earlier_keys.retain(|key| {
+            if let Some(path) = parent_path {
+                !self.is_removed(&ConfigurationPath::combine(&[path, key]))
+            } else {
+                !self.is_removed(key)
+            }
+        });
+        self.snapshot
+            .read()
+            .unwrap()
+            .data
+            .child_keys(earlier_keys, parent_path)
+    }
+}
+
+/// Represents a [`ConfigurationSource`](crate::ConfigurationSource) that transforms
+/// the keys and values of another source.
+pub struct MappedConfigurationSource {
+    inner: Box<dyn ConfigurationSource>,
+    key_map: Option<KeyMap>,
+    value_map: Option<ValueMap>,
+}
+
+impl MappedConfigurationSource {
+    /// Initializes a new mapped configuration source.
+    ///
+    /// # Arguments
+    ///
+    /// * `inner` - The inner [`ConfigurationSource`](crate::ConfigurationSource) to map
+    pub fn new(inner: Box<dyn ConfigurationSource>) -> Self {
+        Self {
+            inner,
+            key_map: None,
+            value_map: None,
+        }
+    }
+
+    /// Sets the function used to transform keys.
+    ///
+    /// # Arguments
+    ///
+    /// * `map` - The function that accepts the original key and returns the new key
+    ///
+    /// # Remarks
+    ///
+    /// A key, and its value, is removed when the function returns `None`.
+    pub fn map_keys<F>(mut self, map: F) -> Self
+    where
+        F: Fn(&str) -> Option<String> + Send + Sync + 'static,
+    {
+        self.key_map = Some(Arc::new(map));
+        self
+    }
+
+    /// Sets the function used to transform values.
+    ///
+    /// # Arguments
+    ///
+    /// * `map` - The function that accepts the transformed key and original value and
+    ///   returns the new value
+    ///
+    /// # Remarks
+    ///
+    /// A typed value, such as a number or null, remains typed unless its text is changed.
+    pub fn map_values<F>(mut self, map: F) -> Self
+    where
+        F: Fn(&str, &str) -> String + Send + Sync + 'static,
+    {
+        self.value_map = Some(Arc::new(map));
+        self
+    }
+}
+
+impl ConfigurationSource for MappedConfigurationSource {
+    fn build(&self, builder: &dyn ConfigurationBuilder) -> Box<dyn ConfigurationProvider> {
+        Box::new(MappedConfigurationProvider::new(
+            self.inner.build(builder),
+            self.key_map.clone(),
+            self.value_map.clone(),
+        ))
+    }
+
+    fn priority(&self) -> i32 {
+        self.inner.priority()
+    }
+}
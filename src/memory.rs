@@ -1,12 +1,84 @@
 use crate::{
-    util::accumulate_child_keys, ConfigurationBuilder, ConfigurationProvider, ConfigurationSource, Value,
+    util::ConfigurationData, ConfigurationBuilder, ConfigurationPairs, ConfigurationPath,
+    ConfigurationProvider, ConfigurationSource, Value, ValueRef,
 };
 use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use tokens::{ChangeToken, SharedChangeToken, SingleChangeToken};
+
+#[derive(Default)]
+struct State {
+    data: ConfigurationData,
+    token: SharedChangeToken<SingleChangeToken>,
+}
+
+/// Represents a handle used to change in-memory configuration data after it has been built.
+///
+/// # Remarks
+///
+/// Every change triggers the reload token of the associated
+/// [`MemoryConfigurationProvider`](crate::MemoryConfigurationProvider).
+#[derive(Clone, Default)]
+pub struct MemoryConfigurationHandle {
+    state: Arc<RwLock<State>>,
+}
+
+impl MemoryConfigurationHandle {
+    fn new(data: HashMap<String, (String, Value)>) -> Self {
+        Self {
+            state: Arc::new(RwLock::new(State {
+                data: data.into(),
+                token: Default::default(),
+            })),
+        }
+    }
+
+    /// Sets a configuration value.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the value to set
+    /// * `value` - The value to set
+    pub fn set(&self, key: &str, value: &str) {
+        self.change(|data| {
+            data.insert(
+                key.to_uppercase(),
+                (key.to_owned(), value.to_owned().into()),
+            );
+        })
+    }
+
+    /// Removes a configuration value.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the value to remove
+    pub fn remove(&self, key: &str) {
+        self.change(|data| {
+            data.remove(&key.to_uppercase());
+        })
+    }
+
+    fn change<F: FnOnce(&mut HashMap<String, (String, Value)>)>(&self, action: F) {
+        let mut state = self.state.write().unwrap();
+        let mut data = state.data.as_map().clone();
+
+        action(&mut data);
+        state.data = data.into();
+
+        let previous = std::mem::take(&mut state.token);
+
+        drop(state);
+        previous.notify();
+    }
+}
 
 /// Represents a [`ConfigurationProvider`](crate::ConfigurationProvider) that
 /// provides in-memory configuration values.
 pub struct MemoryConfigurationProvider {
-    data: HashMap<String, (String, Value)>,
+    data: ConfigurationData,
+    removals: Vec<String>,
+    handle: Option<MemoryConfigurationHandle>,
 }
 
 impl MemoryConfigurationProvider {
@@ -21,19 +93,104 @@ impl MemoryConfigurationProvider {
     /// The data key is normalized to uppercase. The value is a tuple where the
     /// first item is the originally-cased key and the second item is value.
     pub fn new(data: HashMap<String, (String, Value)>) -> Self {
-        Self { data }
+        Self {
+            data: data.into(),
+            removals: Vec::new(),
+            handle: None,
+        }
+    }
+
+    /// Initializes a new in-memory configuration provider whose data can be changed.
+    ///
+    /// # Arguments
+    ///
+    /// * `handle` - The [`MemoryConfigurationHandle`] used to change the data
+    pub fn from_handle(handle: MemoryConfigurationHandle) -> Self {
+        Self {
+            data: Default::default(),
+            removals: Vec::new(),
+            handle: Some(handle),
+        }
+    }
+
+    /// Sets the keys removed by the provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `removals` - The keys, including all of their descendants, to remove
+    ///
+    /// # Remarks
+    ///
+    /// A removed key masks the values set by any preceding provider. Values defined
+    /// by this provider are never removed.
+    pub fn with_removals(mut self, removals: Vec<String>) -> Self {
+        self.removals = removals;
+        self
     }
 }
 
 impl ConfigurationProvider for MemoryConfigurationProvider {
     fn get(&self, key: &str) -> Option<Value> {
-        self.data
-            .get(&key.to_uppercase())
-            .map(|t| t.1.clone())
+        if let Some(handle) = &self.handle {
+            handle.state.read().unwrap().data.get(key)
+        } else {
+            self.data.get(key)
+        }
+    }
+
+    fn get_str(&self, key: &str) -> Option<ValueRef<'_>> {
+        if self.handle.is_some() {
+            self.get(key).map(ValueRef::Shared)
+        } else {
+            self.data.get_str(key).map(ValueRef::Borrowed)
+        }
+    }
+
+    fn reload_token(&self) -> Box<dyn ChangeToken> {
+        if let Some(handle) = &self.handle {
+            Box::new(handle.state.read().unwrap().token.clone())
+        } else {
+            Box::new(tokens::NeverChangeToken::new())
+        }
+    }
+
+    fn is_removed(&self, key: &str) -> bool {
+        let delimiter = ConfigurationPath::key_delimiter();
+
+        self.removals.iter().any(|removal| {
+            key.len() >= removal.len()
+                && key.is_char_boundary(removal.len())
+                && key[..removal.len()].eq_ignore_ascii_case(removal)
+                && (key.len() == removal.len() || key[removal.len()..].starts_with(delimiter))
+        })
+    }
+
+    fn try_iter(&self) -> Option<ConfigurationPairs<'_>> {
+        if let Some(handle) = &self.handle {
+            let pairs: Vec<_> = handle.state.read().unwrap().data.pairs().collect();
+            Some(Box::new(pairs.into_iter()))
+        } else {
+            Some(self.data.pairs())
+        }
     }
 
     fn child_keys(&self, earlier_keys: &mut Vec<String>, parent_path: Option<&str>) {
-        accumulate_child_keys(&self.data, earlier_keys, parent_path)
+        if !self.removals.is_empty() {
+            earlier_keys.retain(|key| {
+                if let Some(path) = parent_path {
+                    !self.is_removed(&ConfigurationPath::combine(&[path, key]))
+                } else {
+                    !self.is_removed(key)
+                }
+            });
+        }
+
+        if let Some(handle) = &self.handle {
+            let state = handle.state.read().unwrap();
+            state.data.child_keys(earlier_keys, parent_path)
+        } else {
+            self.data.child_keys(earlier_keys, parent_path)
+        }
     }
 }
 
@@ -42,6 +199,11 @@ impl ConfigurationProvider for MemoryConfigurationProvider {
 pub struct MemoryConfigurationSource {
     /// Gets a list of key/value pairs representing the initial data.
     pub initial_data: Vec<(String, Value)>,
+
+    /// Gets a list of keys removed from preceding sources.
+    pub removals: Vec<String>,
+
+    handle: Option<MemoryConfigurationHandle>,
 }
 
 impl MemoryConfigurationSource {
@@ -56,18 +218,52 @@ impl MemoryConfigurationSource {
                 .iter()
                 .map(|t| (t.0.as_ref().to_owned(), t.1.as_ref().to_owned().into()))
                 .collect(),
+            removals: Vec::new(),
+            handle: None,
         }
     }
+
+    /// Gets a handle that can be used to change the data after it has been built.
+    ///
+    /// # Remarks
+    ///
+    /// The handle is initialized with the initial data the first time it is requested.
+    /// All providers built from this source share the same handle.
+    pub fn handle(&mut self) -> MemoryConfigurationHandle {
+        if self.handle.is_none() {
+            self.handle = Some(MemoryConfigurationHandle::new(self.data()));
+        }
+
+        self.handle.clone().unwrap()
+    }
+
+    fn data(&self) -> HashMap<String, (String, Value)> {
+        self.initial_data
+            .iter()
+            .map(|t| (t.0.to_uppercase(), (t.0.clone(), t.1.clone())))
+            .collect()
+    }
+
+    /// Sets the keys removed from preceding sources.
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - The keys, including all of their descendants, to remove
+    pub fn with_removals<S: AsRef<str>>(mut self, keys: &[S]) -> Self {
+        self.removals = keys.iter().map(|k| k.as_ref().to_owned()).collect();
+        self
+    }
 }
 
 impl ConfigurationSource for MemoryConfigurationSource {
     fn build(&self, _builder: &dyn ConfigurationBuilder) -> Box<dyn ConfigurationProvider> {
-        let data: HashMap<_, _> = self
-            .initial_data
-            .iter()
-            .map(|t| (t.0.to_uppercase(), (t.0.clone(), t.1.clone())))
-            .collect();
-        Box::new(MemoryConfigurationProvider::new(data))
+        let provider = if let Some(handle) = &self.handle {
+            MemoryConfigurationProvider::from_handle(handle.clone())
+        } else {
+            MemoryConfigurationProvider::new(self.data())
+        };
+
+        Box::new(provider.with_removals(self.removals.clone()))
     }
 }
 
@@ -0,0 +1,195 @@
+use crate::{ConfigurationPath, ConfigurationProvider};
+
+type Candidate = (usize, Option<String>);
+
+/// Defines the possible policies used to combine indexed sections across providers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ArrayMergePolicy {
+    /// Indicates that indexed sections are merged index by index. This is the default.
+    Merge,
+
+    /// Indicates that the indexed sections of a later provider replace all of the
+    /// indexed sections of earlier providers.
+    Replace,
+
+    /// Indicates that the indexed sections of a later provider are appended after
+    /// the indexed sections of earlier providers.
+    Append,
+}
+
+impl Default for ArrayMergePolicy {
+    fn default() -> Self {
+        Self::Merge
+    }
+}
+
+fn combine(path: &Option<String>, segment: &str) -> Option<String> {
+    Some(match path {
+        Some(path) => ConfigurationPath::combine(&[path, segment]),
+        _ => segment.to_owned(),
+    })
+}
+
+fn local_child_keys(provider: &dyn ConfigurationProvider, path: &Option<String>) -> Vec<String> {
+    let mut keys = Vec::new();
+    provider.child_keys(&mut keys, path.as_deref());
+    keys
+}
+
+fn array_len(keys: &[String]) -> Option<usize> {
+    if keys.is_empty() {
+        return None;
+    }
+
+    let mut len = 0;
+
+    for key in keys {
+        len = len.max(key.parse::<usize>().ok()? + 1);
+    }
+
+    Some(len)
+}
+
+// gets the length of each candidate that defines an array when all candidates with
+// child keys define an array; otherwise, the sections are objects and always merge
+fn arrays<'a>(
+    providers: &[Box<dyn ConfigurationProvider + 'a>],
+    candidates: &[Candidate],
+) -> Option<Vec<(Candidate, usize)>> {
+    let mut arrays = Vec::new();
+
+    for (index, path) in candidates {
+        let keys = local_child_keys(providers[*index].as_ref(), path);
+
+        if !keys.is_empty() {
+            arrays.push(((*index, path.clone()), array_len(&keys)?));
+        }
+    }
+
+    if arrays.is_empty() {
+        None
+    } else {
+        Some(arrays)
+    }
+}
+
+// finds the first value from the providers, which are in reverse order, stopping at
+// the first provider that removes the key
+pub(crate) fn find_value<'a, T>(
+    providers: impl Iterator<Item = (&'a dyn ConfigurationProvider, &'a str)>,
+    get: impl Fn(&dyn ConfigurationProvider, &str) -> Option<T>,
+) -> Option<T> {
+    for (provider, key) in providers {
+        if let Some(value) = get(provider, key) {
+            return Some(value);
+        } else if provider.is_removed(key) {
+            return None;
+        }
+    }
+
+    None
+}
+
+impl ArrayMergePolicy {
+    // resolves each provider, by index, and the provider key visible at the given path
+    fn resolve<'a>(
+        &self,
+        providers: &[Box<dyn ConfigurationProvider + 'a>],
+        path: Option<&str>,
+    ) -> Vec<Candidate> {
+        let mut candidates: Vec<_> = (0..providers.len()).map(|i| (i, None)).collect();
+
+        if let Some(path) = path {
+            for segment in ConfigurationPath::segments(path) {
+                candidates = self.next(providers, candidates, segment);
+
+                if candidates.is_empty() {
+                    break;
+                }
+            }
+        }
+
+        candidates
+    }
+
+    fn next<'a>(
+        &self,
+        providers: &[Box<dyn ConfigurationProvider + 'a>],
+        candidates: Vec<Candidate>,
+        segment: &str,
+    ) -> Vec<Candidate> {
+        if *self != Self::Merge {
+            if let Some(mut arrays) = arrays(providers, &candidates) {
+                if *self == Self::Replace {
+                    let ((index, path), _) = arrays.pop().unwrap();
+                    return vec![(index, combine(&path, segment))];
+                }
+
+                let mut position = match segment.parse::<usize>() {
+                    Ok(position) => position,
+                    _ => return Vec::new(),
+                };
+
+                for ((index, path), len) in arrays {
+                    if position < len {
+                        return vec![(index, combine(&path, &position.to_string()))];
+                    }
+
+                    position -= len;
+                }
+
+                return Vec::new();
+            }
+        }
+
+        candidates
+            .into_iter()
+            .map(|(index, path)| (index, combine(&path, segment)))
+            .collect()
+    }
+
+    pub(crate) fn find<'a, T>(
+        &self,
+        providers: &[Box<dyn ConfigurationProvider + 'a>],
+        key: &str,
+        get: impl Fn(&dyn ConfigurationProvider, &str) -> Option<T>,
+    ) -> Option<T> {
+        let candidates = self.resolve(providers, Some(key));
+
+        find_value(
+            candidates
+                .iter()
+                .rev()
+                .filter_map(|(index, path)| Some((providers[*index].as_ref(), path.as_deref()?))),
+            get,
+        )
+    }
+
+    pub(crate) fn child_keys<'a>(
+        &self,
+        providers: &[Box<dyn ConfigurationProvider + 'a>],
+        parent_path: Option<&str>,
+    ) -> Vec<String> {
+        let candidates = self.resolve(providers, parent_path);
+
+        if *self != Self::Merge {
+            if let Some(mut arrays) = arrays(providers, &candidates) {
+                if *self == Self::Replace {
+                    let ((index, path), _) = arrays.pop().unwrap();
+                    return local_child_keys(providers[index].as_ref(), &path);
+                }
+
+                let len = arrays.iter().map(|(_, len)| len).sum();
+                return (0..len).map(|i| i.to_string()).collect();
+            }
+        }
+
+        let mut keys = Vec::new();
+
+        for (index, path) in candidates {
+            providers[index].child_keys(&mut keys, path.as_deref());
+        }
+
+        keys
+    }
+}
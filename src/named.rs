@@ -0,0 +1,144 @@
+use crate::{
+    ConfigValue, ConfigurationBuilder, ConfigurationPairs, ConfigurationProvider,
+    ConfigurationSource, LoadResult, Value, ValueRef,
+};
+use std::any::TypeId;
+use tokens::ChangeToken;
+
+/// Represents a [`ConfigurationProvider`](crate::ConfigurationProvider) with a display name.
+pub struct NamedConfigurationProvider {
+    name: String,
+    inner: Box<dyn ConfigurationProvider>,
+}
+
+impl NamedConfigurationProvider {
+    /// Initializes a new named configuration provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The display name of the provider
+    /// * `inner` - The inner [`ConfigurationProvider`](crate::ConfigurationProvider) to name
+    pub fn new(name: String, inner: Box<dyn ConfigurationProvider>) -> Self {
+        Self { name, inner }
+    }
+}
+
+impl ConfigurationProvider for NamedConfigurationProvider {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn get(&self, key: &str) -> Option<Value> {
+        self.inner.get(key)
+    }
+
+    fn get_str(&self, key: &str) -> Option<ValueRef<'_>> {
+        self.inner.get_str(key)
+    }
+
+    fn get_typed(&self, key: &str) -> Option<ConfigValue> {
+        self.inner.get_typed(key)
+    }
+
+    fn is_removed(&self, key: &str) -> bool {
+        self.inner.is_removed(key)
+    }
+
+    fn reload_token(&self) -> Box<dyn ChangeToken> {
+        self.inner.reload_token()
+    }
+
+    fn load(&mut self) -> LoadResult {
+        self.inner.load()
+    }
+
+    fn close(&mut self) {
+        self.inner.close()
+    }
+
+    fn save(&self) -> LoadResult {
+        self.inner.save()
+    }
+
+    fn try_iter(&self) -> Option<ConfigurationPairs<'_>> {
+        self.inner.try_iter()
+    }
+
+    fn child_keys(&self, earlier_keys: &mut Vec<String>, parent_path: Option<&str>) {
+        self.inner.child_keys(earlier_keys, parent_path)
+    }
+}
+
+/// Represents a [`ConfigurationSource`](crate::ConfigurationSource) with a display name.
+///
+/// # Remarks
+///
+/// The display name is used as the [`name`](crate::ConfigurationProvider::name) of the
+/// provider, which appears in load errors and the debug view of a configuration.
+pub struct NamedConfigurationSource {
+    name: String,
+    inner: Box<dyn ConfigurationSource>,
+}
+
+impl NamedConfigurationSource {
+    /// Initializes a new named configuration source.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The display name of the source
+    /// * `inner` - The inner [`ConfigurationSource`](crate::ConfigurationSource) to name
+    pub fn new<S: AsRef<str>>(name: S, inner: Box<dyn ConfigurationSource>) -> Self {
+        Self {
+            name: name.as_ref().to_owned(),
+            inner,
+        }
+    }
+}
+
+impl ConfigurationSource for NamedConfigurationSource {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn build(&self, builder: &dyn ConfigurationBuilder) -> Box<dyn ConfigurationProvider> {
+        Box::new(NamedConfigurationProvider::new(
+            self.name.clone(),
+            self.inner.build(builder),
+        ))
+    }
+
+    fn priority(&self) -> i32 {
+        self.inner.priority()
+    }
+
+    fn source_type(&self) -> TypeId {
+        self.inner.source_type()
+    }
+}
+
+pub mod ext {
+
+    use super::*;
+
+    /// Defines extension methods for [`ConfigurationSource`](crate::ConfigurationSource).
+    pub trait NamedConfigurationSourceExtensions {
+        /// Gives the source a display name.
+        ///
+        /// # Arguments
+        ///
+        /// * `name` - The display name of the source and the provider it builds
+        fn named(self, name: &str) -> NamedConfigurationSource;
+    }
+
+    impl NamedConfigurationSourceExtensions for Box<dyn ConfigurationSource> {
+        fn named(self, name: &str) -> NamedConfigurationSource {
+            NamedConfigurationSource::new(name, self)
+        }
+    }
+
+    impl<T: ConfigurationSource + 'static> NamedConfigurationSourceExtensions for T {
+        fn named(self, name: &str) -> NamedConfigurationSource {
+            NamedConfigurationSource::new(name, Box::new(self))
+        }
+    }
+}
@@ -0,0 +1,171 @@
+use crate::{ConfigError, Configuration};
+
+/// Represents the metadata of a configuration key bound by a [`Config`] type.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConfigKey {
+    /// Gets the key relative to the bound configuration.
+    pub key: String,
+
+    /// Gets the default value of the key, if any.
+    pub default: Option<&'static str>,
+
+    /// Gets the name of the environment variable that provides the value, if any.
+    pub env: Option<&'static str>,
+
+    /// Gets a value indicating whether the key is required.
+    pub required: bool,
+}
+
+/// Defines the behavior of a type that can be bound from a
+/// [`Configuration`](crate::Configuration).
+///
+/// # Remarks
+///
+/// This trait is typically implemented with `#[derive(Config)]`. Each field is bound from the
+/// key that is the PascalCase form of its name unless the key is specified. The supported
+/// field attributes are:
+///
+/// * `#[config(key = "Name")]` - the key of the field
+/// * `#[config(default = "8080")]` - the text of the value used when the key is not configured
+/// * `#[config(env = "APP_PORT")]` - the environment variable that takes precedence over the key
+/// * `#[config(min = 1, max = 10)]` - the inclusive range of the value
+/// * `#[config(non_empty)]` - the value, such as a string or list, cannot be empty
+/// * `#[config(validate = "path::to::function")]` - a function that accepts a reference to the
+///   value and returns `Result<(), String>`
+/// * `#[config(nested)]` - the field is another [`Config`] type bound from a section
+///
+/// Fields of type [`Option`] are optional and fields of type [`Vec`] are bound from an indexed
+/// section. All other fields are parsed from text with [`FromStr`](std::str::FromStr) and are
+/// required unless they have a default value.
+pub trait Config: Sized {
+    /// Gets the metadata for the keys bound by the type.
+    fn keys() -> Vec<ConfigKey>;
+
+    /// Binds a new instance of the type from the specified configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `configuration` - The [`Configuration`](crate::Configuration) to bind
+    fn from_config(configuration: &dyn Configuration) -> Result<Self, ConfigError>;
+}
+
+// supports the code generated by #[derive(Config)]; it is not part of the public API
+#[doc(hidden)]
+pub mod __private {
+
+    use super::*;
+    use crate::{ext::*, required::full_path, ConfigurationPath};
+    use std::fmt::Display;
+    use std::str::FromStr;
+
+    fn parse<T>(configuration: &dyn Configuration, key: &str, text: &str) -> Result<T, ConfigError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        text.parse().or_else(|error: T::Err| {
+            match configuration.value_conversion().convert(text) {
+                Some(converted) => converted.parse(),
+                _ => Err(error),
+            }
+            .map_err(|error| invalid(configuration, key, text.to_owned(), error))
+        })
+    }
+
+    pub fn value<T>(
+        configuration: &dyn Configuration,
+        key: &str,
+        env: Option<&str>,
+        default: Option<&str>,
+    ) -> Result<Option<T>, ConfigError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let text = env
+            .and_then(|name| std::env::var(name).ok())
+            .or_else(|| configuration.get(key).map(|value| value.to_string()))
+            .or_else(|| default.map(ToOwned::to_owned));
+
+        match text {
+            Some(text) => parse(configuration, key, &text).map(Some),
+            _ => Ok(None),
+        }
+    }
+
+    pub fn values<T>(configuration: &dyn Configuration, key: &str) -> Result<Vec<T>, ConfigError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let mut children = configuration.section(key).children();
+
+        children.sort_by_key(|child| child.key().parse::<usize>().unwrap_or(usize::MAX));
+        children
+            .iter()
+            .map(|child| {
+                let key = ConfigurationPath::combine(&[key, child.key()]);
+                parse(configuration, &key, &child.value())
+            })
+            .collect()
+    }
+
+    pub fn section<T: Config>(
+        configuration: &dyn Configuration,
+        key: &str,
+    ) -> Result<T, ConfigError> {
+        let section = configuration.section(key);
+        T::from_config(&**section)
+    }
+
+    pub fn optional_section<T: Config>(
+        configuration: &dyn Configuration,
+        key: &str,
+    ) -> Result<Option<T>, ConfigError> {
+        let section = configuration.section(key);
+
+        if section.exists() {
+            T::from_config(&**section).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    pub fn required<T>(
+        configuration: &dyn Configuration,
+        key: &str,
+        value: Option<T>,
+    ) -> Result<T, ConfigError> {
+        value.ok_or_else(|| ConfigError::Missing {
+            path: full_path(configuration, key),
+            providers: Vec::new(),
+        })
+    }
+
+    pub fn raw(configuration: &dyn Configuration, key: &str) -> String {
+        configuration
+            .get(key)
+            .map(|value| value.to_string())
+            .unwrap_or_default()
+    }
+
+    pub fn invalid(
+        configuration: &dyn Configuration,
+        key: &str,
+        value: String,
+        message: impl Display,
+    ) -> ConfigError {
+        ConfigError::Invalid {
+            path: full_path(configuration, key),
+            value,
+            message: message.to_string(),
+        }
+    }
+
+    pub fn nested_keys(key: &str, keys: Vec<ConfigKey>) -> impl Iterator<Item = ConfigKey> + '_ {
+        keys.into_iter().map(move |mut nested| {
+            nested.key = ConfigurationPath::combine(&[key, &nested.key]);
+            nested
+        })
+    }
+}
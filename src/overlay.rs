@@ -0,0 +1,248 @@
+use crate::{ConfigurationPairs, ConfigurationPath, ConfigurationProvider, Value};
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use tokens::{ChangeToken, SharedChangeToken, SingleChangeToken};
+
+// values are keyed by the uppercase key and hold the originally-cased key
+#[derive(Default)]
+struct State {
+    values: HashMap<String, (String, Value)>,
+    removals: Vec<String>,
+    token: SharedChangeToken<SingleChangeToken>,
+}
+
+impl State {
+    fn set(&mut self, key: &str, value: &str) {
+        self.removals
+            .retain(|removal| !removal.eq_ignore_ascii_case(key));
+        self.values.insert(
+            key.to_uppercase(),
+            (key.to_owned(), value.to_owned().into()),
+        );
+    }
+
+    fn remove(&mut self, key: &str) {
+        self.values.retain(|_, (other, _)| !is_under(other, key));
+        self.removals.push(key.to_owned());
+    }
+
+    fn clear(&mut self) {
+        self.values.clear();
+        self.removals.clear();
+    }
+}
+
+/// Represents a set of changes that are applied to a [`ConfigurationOverlay`] at once.
+///
+/// # Remarks
+///
+/// A transaction is created with [`ConfigurationOverlay::transaction`]. Readers never observe
+/// the intermediate state of a transaction and the reload token is triggered, at most, once.
+pub struct ConfigurationTransaction<'a> {
+    state: &'a mut State,
+    changed: bool,
+}
+
+impl ConfigurationTransaction<'_> {
+    /// Sets a configuration value.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the value to set
+    /// * `value` - The value to set
+    pub fn set(&mut self, key: &str, value: &str) -> &mut Self {
+        self.state.set(key, value);
+        self.changed = true;
+        self
+    }
+
+    /// Removes a configuration value, including all of its descendants.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the value to remove
+    pub fn remove(&mut self, key: &str) -> &mut Self {
+        self.state.remove(key);
+        self.changed = true;
+        self
+    }
+
+    /// Clears all of the values set or removed with the overlay.
+    pub fn clear(&mut self) -> &mut Self {
+        self.state.clear();
+        self.changed = true;
+        self
+    }
+}
+
+// determines whether the key is the path or one of its descendants
+fn is_under(key: &str, path: &str) -> bool {
+    key.len() >= path.len()
+        && key.is_char_boundary(path.len())
+        && key[..path.len()].eq_ignore_ascii_case(path)
+        && (key.len() == path.len()
+            || key[path.len()..].starts_with(ConfigurationPath::key_delimiter()))
+}
+
+/// Represents a handle used to override configuration values at runtime.
+///
+/// # Remarks
+///
+/// The values set or removed with an overlay take precedence over all of the
+/// [`ConfigurationProvider`](crate::ConfigurationProvider) instances in a configuration. Every
+/// change triggers the reload token of the configuration, which is typically used for temporary
+/// overrides, such as from an administrative endpoint or a test, without rebuilding the
+/// configuration.
+#[derive(Clone, Default)]
+pub struct ConfigurationOverlay {
+    state: Arc<RwLock<State>>,
+}
+
+impl ConfigurationOverlay {
+    /// Initializes a new, empty configuration overlay.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets a configuration value.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the value to set
+    /// * `value` - The value to set
+    pub fn set(&self, key: &str, value: &str) {
+        self.change(|state| state.set(key, value))
+    }
+
+    /// Removes a configuration value, including all of its descendants.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the value to remove
+    ///
+    /// # Remarks
+    ///
+    /// A removed key masks the value configured by any provider.
+    pub fn remove(&self, key: &str) {
+        self.change(|state| state.remove(key))
+    }
+
+    /// Clears all of the values set or removed with the overlay.
+    pub fn clear(&self) {
+        self.change(State::clear)
+    }
+
+    /// Applies several changes at once.
+    ///
+    /// # Arguments
+    ///
+    /// * `action` - The function that makes the changes with a [`ConfigurationTransaction`]
+    ///
+    /// # Remarks
+    ///
+    /// The changes are applied atomically so that readers, such as bound options or watchers,
+    /// never observe an intermediate state. The reload token of the configuration is triggered
+    /// once when the transaction makes any change.
+    pub fn transaction<F: FnOnce(&mut ConfigurationTransaction<'_>)>(&self, action: F) {
+        let mut state = self.state.write().unwrap();
+        let mut transaction = ConfigurationTransaction {
+            state: &mut state,
+            changed: false,
+        };
+
+        action(&mut transaction);
+
+        if !transaction.changed {
+            return;
+        }
+
+        let previous = std::mem::take(&mut state.token);
+
+        drop(state);
+        previous.notify();
+    }
+
+    /// Gets a value indicating whether the overlay does not set or remove any values.
+    pub fn is_empty(&self) -> bool {
+        let state = self.state.read().unwrap();
+        state.values.is_empty() && state.removals.is_empty()
+    }
+
+    fn change<F: FnOnce(&mut State)>(&self, action: F) {
+        let mut state = self.state.write().unwrap();
+
+        action(&mut state);
+
+        let previous = std::mem::take(&mut state.token);
+
+        drop(state);
+        previous.notify();
+    }
+}
+
+impl ConfigurationProvider for ConfigurationOverlay {
+    fn name(&self) -> &str {
+        "Overlay"
+    }
+
+    fn get(&self, key: &str) -> Option<Value> {
+        self.state
+            .read()
+            .unwrap()
+            .values
+            .get(&key.to_uppercase())
+            .map(|(_, value)| value.clone())
+    }
+
+    fn is_removed(&self, key: &str) -> bool {
+        self.state
+            .read()
+            .unwrap()
+            .removals
+            .iter()
+            .any(|removal| is_under(key, removal))
+    }
+
+    fn reload_token(&self) -> Box<dyn ChangeToken> {
+        Box::new(self.state.read().unwrap().token.clone())
+    }
+
+    fn try_iter(&self) -> Option<ConfigurationPairs<'_>> {
+        let pairs: Vec<_> = self.state.read().unwrap().values.values().cloned().collect();
+        Some(Box::new(pairs.into_iter()))
+    }
+
+    fn child_keys(&self, earlier_keys: &mut Vec<String>, parent_path: Option<&str>) {
+        let state = self.state.read().unwrap();
+        let delimiter = ConfigurationPath::key_delimiter();
+
+        if !state.removals.is_empty() {
+            earlier_keys.retain(|key| {
+                let path = match parent_path {
+                    Some(path) => ConfigurationPath::combine(&[path, key]),
+                    _ => key.clone(),
+                };
+
+                !state
+                    .removals
+                    .iter()
+                    .any(|removal| is_under(&path, removal))
+            });
+        }
+
+        for (key, _) in state.values.values() {
+            let rest = match parent_path {
+                Some(path) if is_under(key, path) && key.len() > path.len() => {
+                    &key[(path.len() + delimiter.len())..]
+                }
+                Some(_) => continue,
+                _ => key.as_str(),
+            };
+            let segment = rest.split(delimiter).next().unwrap_or(rest);
+
+            if !earlier_keys.iter().any(|k| k.eq_ignore_ascii_case(segment)) {
+                earlier_keys.push(segment.to_owned());
+            }
+        }
+    }
+}
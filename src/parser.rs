@@ -0,0 +1,386 @@
+use crate::{
+    file::Debounce, trace, util::ConfigurationData, ConfigValue, ConfigurationBuilder,
+    ConfigurationPairs, ConfigurationProvider, ConfigurationSource, FileSource, LoadResult,
+    ParseError, Value,
+};
+use std::any::type_name;
+use std::path::Path;
+use std::sync::{Arc, RwLock};
+use tokens::{ChangeToken, SharedChangeToken, SingleChangeToken, Subscription};
+
+/// Defines the behavior of an object that parses the content of a configuration file.
+pub trait ConfigurationFileParser: Send + Sync {
+    /// Parses the content of a configuration file into configuration data.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the file being parsed
+    /// * `content` - The content of the file
+    fn parse(&self, path: &Path, content: &[u8]) -> Result<ConfigurationData, ParseError>;
+
+    /// Formats configuration data as the content of a configuration file.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The configuration data to format
+    ///
+    /// # Remarks
+    ///
+    /// The default implementation does not support formatting and always returns an error.
+    fn format(&self, data: &ConfigurationData) -> Result<Vec<u8>, ParseError> {
+        let _ = data;
+        Err("The configuration file format cannot be written.".into())
+    }
+
+    /// Edits the content of an existing configuration file so that it matches configuration data.
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - The current content of the file
+    /// * `data` - The configuration data to write
+    ///
+    /// # Remarks
+    ///
+    /// An implementation can retain the comments and formatting of the content. The default
+    /// implementation replaces the content with the [formatted](ConfigurationFileParser::format)
+    /// data.
+    fn edit(&self, content: &[u8], data: &ConfigurationData) -> Result<Vec<u8>, ParseError> {
+        let _ = content;
+        self.format(data)
+    }
+}
+
+impl<F> ConfigurationFileParser for F
+where
+    F: Fn(&Path, &[u8]) -> Result<ConfigurationData, ParseError> + Send + Sync,
+{
+    fn parse(&self, path: &Path, content: &[u8]) -> Result<ConfigurationData, ParseError> {
+        (self)(path, content)
+    }
+}
+
+struct InnerProvider<P> {
+    name: &'static str,
+    file: FileSource,
+    parser: P,
+    data: RwLock<ConfigurationData>,
+    token: RwLock<SharedChangeToken<SingleChangeToken>>,
+    debounce: Debounce,
+}
+
+impl<P: ConfigurationFileParser> InnerProvider<P> {
+    fn new(name: &'static str, file: FileSource, parser: P) -> Self {
+        Self {
+            name,
+            file,
+            parser,
+            data: Default::default(),
+            token: Default::default(),
+            debounce: Default::default(),
+        }
+    }
+
+    fn load(&self, reload: bool) -> LoadResult {
+        if !self.file.path.is_file() {
+            if self.file.optional || reload {
+                self.debounce.reset();
+
+                let mut data = self.data.write().unwrap();
+                if !data.is_empty() {
+                    *data = ConfigurationData::default();
+                    drop(data);
+
+                    let previous = std::mem::take(&mut *self.token.write().unwrap());
+
+                    previous.notify();
+                }
+
+                return Ok(());
+            } else {
+                return Err(self.file.error(format!(
+                    "The configuration file '{}' was not found and is not optional.",
+                    self.file.path.display()
+                )));
+            }
+        }
+
+        let content = self.file.read()?;
+
+        if !self.debounce.changed(&content) && reload {
+            return Ok(());
+        }
+
+        // parse into staging data so that the current data is retained if the file is
+        // invalid; for example, when it has only been partially written
+        let data = self
+            .parser
+            .parse(&self.file.path, &content)
+            .map_err(|error| self.file.invalid(error))?;
+
+        if reload && *self.data.read().unwrap() == data {
+            return Ok(());
+        }
+
+        *self.data.write().unwrap() = data;
+
+        let previous = std::mem::take(&mut *self.token.write().unwrap());
+
+        previous.notify();
+        Ok(())
+    }
+
+    fn save(&self) -> LoadResult {
+        let data = self.data.read().unwrap();
+        let content = if self.file.path.is_file() {
+            self.parser.edit(&self.file.read()?, &data)
+        } else {
+            self.parser.format(&data)
+        }
+        .map_err(|error| self.file.invalid(error))?;
+
+        drop(data);
+
+        std::fs::write(&self.file.path, &content).map_err(|e| {
+            self.file.error(format!(
+                "The configuration file '{}' could not be written. {}",
+                self.file.path.display(),
+                e
+            ))
+        })?;
+
+        // the saved content is already loaded so watching the file will not reload it
+        self.debounce.changed(&content);
+        Ok(())
+    }
+
+    fn change<F: FnOnce(&mut ConfigurationData)>(&self, action: F) {
+        action(&mut self.data.write().unwrap());
+
+        let previous = std::mem::take(&mut *self.token.write().unwrap());
+
+        previous.notify();
+    }
+
+    fn get(&self, key: &str) -> Option<Value> {
+        self.data.read().unwrap().get(key)
+    }
+
+    fn get_typed(&self, key: &str) -> Option<ConfigValue> {
+        self.data.read().unwrap().get_typed(key)
+    }
+
+    fn reload_token(&self) -> Box<dyn ChangeToken> {
+        Box::new(self.token.read().unwrap().clone())
+    }
+
+    fn child_keys(&self, earlier_keys: &mut Vec<String>, parent_path: Option<&str>) {
+        self.data
+            .read()
+            .unwrap()
+            .child_keys(earlier_keys, parent_path)
+    }
+}
+
+/// Represents a [`ConfigurationProvider`](crate::ConfigurationProvider) for a file whose
+/// content is parsed by a [`ConfigurationFileParser`].
+pub struct FileConfigurationProvider<P> {
+    inner: Arc<InnerProvider<P>>,
+    subscription: Option<Box<dyn Subscription>>,
+}
+
+impl<P: ConfigurationFileParser + 'static> FileConfigurationProvider<P> {
+    /// Initializes a new file configuration provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `file` - The [`FileSource`](crate::FileSource) information
+    /// * `parser` - The [`ConfigurationFileParser`] used to parse the file
+    pub fn new(file: FileSource, parser: P) -> Self {
+        Self::named(type_name::<Self>(), file, parser)
+    }
+
+    // the name is used to report reload errors on behalf of the provider wrapping this one
+    pub(crate) fn named(name: &'static str, mut file: FileSource, parser: P) -> Self {
+        // the first existing candidate is chosen once so that the same file is always reloaded
+        if !file.fallbacks.is_empty() {
+            file.path = file.resolve().to_path_buf();
+            file.fallbacks.clear();
+        }
+
+        let watch = file.clone();
+        let inner = Arc::new(InnerProvider::new(name, file, parser));
+        let subscription: Option<Box<dyn Subscription>> = if inner.file.reload_on_change {
+            Some(Box::new(tokens::on_change(
+                move || watch.change_token(),
+                |state| {
+                    let provider = state.unwrap();
+
+                    if provider.debounce.settle(provider.file.reload_delay) {
+                        trace::file_changed(provider.name, &provider.file.path);
+
+                        if let Err(error) = provider.load(true) {
+                            provider.file.reload_failed(provider.name, error);
+                        }
+                    }
+                },
+                Some(inner.clone()),
+            )))
+        } else {
+            None
+        };
+
+        Self {
+            inner,
+            subscription,
+        }
+    }
+}
+
+impl<P> FileConfigurationProvider<P> {
+    /// Gets the path of the file loaded by the provider.
+    ///
+    /// # Remarks
+    ///
+    /// When the [`FileSource`](crate::FileSource) has fallbacks, this is the path of the file
+    /// that was chosen, which is useful for diagnostics.
+    pub fn path(&self) -> &Path {
+        &self.inner.file.path
+    }
+}
+
+impl<P: ConfigurationFileParser> FileConfigurationProvider<P> {
+    /// Sets a configuration value in the loaded data.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the value to set
+    /// * `value` - The value to set
+    ///
+    /// # Remarks
+    ///
+    /// The file is unchanged until the provider is [saved](ConfigurationProvider::save).
+    pub fn set(&self, key: &str, value: &str) {
+        self.inner
+            .change(|data| data.set(key, value.to_owned().into()))
+    }
+
+    /// Removes a configuration value, including all of its descendants, from the loaded data.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the value to remove
+    ///
+    /// # Remarks
+    ///
+    /// The file is unchanged until the provider is [saved](ConfigurationProvider::save).
+    pub fn remove(&self, key: &str) {
+        self.inner.change(|data| data.remove(key))
+    }
+}
+
+impl<P: ConfigurationFileParser> ConfigurationProvider for FileConfigurationProvider<P> {
+    fn get(&self, key: &str) -> Option<Value> {
+        self.inner.get(key)
+    }
+
+    fn get_typed(&self, key: &str) -> Option<ConfigValue> {
+        self.inner.get_typed(key)
+    }
+
+    fn reload_token(&self) -> Box<dyn ChangeToken> {
+        self.inner.reload_token()
+    }
+
+    fn load(&mut self) -> LoadResult {
+        self.inner.load(false)
+    }
+
+    fn close(&mut self) {
+        // dropping the subscription stops watching the file
+        self.subscription = None;
+    }
+
+    fn save(&self) -> LoadResult {
+        self.inner.save()
+    }
+
+    fn try_iter(&self) -> Option<ConfigurationPairs<'_>> {
+        let pairs: Vec<_> = self.inner.data.read().unwrap().pairs().collect();
+        Some(Box::new(pairs.into_iter()))
+    }
+
+    fn child_keys(&self, earlier_keys: &mut Vec<String>, parent_path: Option<&str>) {
+        self.inner.child_keys(earlier_keys, parent_path)
+    }
+}
+
+/// Represents a [`ConfigurationSource`](crate::ConfigurationSource) for a file whose
+/// content is parsed by a [`ConfigurationFileParser`].
+pub struct FileConfigurationSource<P> {
+    file: FileSource,
+    parser: P,
+}
+
+impl<P: ConfigurationFileParser + Clone + 'static> FileConfigurationSource<P> {
+    /// Initializes a new file configuration source.
+    ///
+    /// # Arguments
+    ///
+    /// * `file` - The [`FileSource`](crate::FileSource) information
+    /// * `parser` - The [`ConfigurationFileParser`] used to parse the file
+    pub fn new(file: FileSource, parser: P) -> Self {
+        Self { file, parser }
+    }
+}
+
+impl<P: ConfigurationFileParser + Clone + 'static> ConfigurationSource
+    for FileConfigurationSource<P>
+{
+    fn build(&self, builder: &dyn ConfigurationBuilder) -> Box<dyn ConfigurationProvider> {
+        Box::new(FileConfigurationProvider::new(
+            self.file.configure(builder),
+            self.parser.clone(),
+        ))
+    }
+}
+
+pub mod ext {
+
+    use super::*;
+
+    /// Defines extension methods for [`ConfigurationBuilder`](crate::ConfigurationBuilder).
+    pub trait FileConfigurationExtensions {
+        /// Adds a file parsed by the specified parser as a configuration source.
+        ///
+        /// # Arguments
+        ///
+        /// * `file` - The [`FileSource`](crate::FileSource) information
+        /// * `parser` - The [`ConfigurationFileParser`] used to parse the file
+        fn add_file<T, P>(&mut self, file: T, parser: P) -> &mut Self
+        where
+            T: Into<FileSource>,
+            P: ConfigurationFileParser + Clone + 'static;
+    }
+
+    impl FileConfigurationExtensions for dyn ConfigurationBuilder + '_ {
+        fn add_file<T, P>(&mut self, file: T, parser: P) -> &mut Self
+        where
+            T: Into<FileSource>,
+            P: ConfigurationFileParser + Clone + 'static,
+        {
+            self.add(Box::new(FileConfigurationSource::new(file.into(), parser)));
+            self
+        }
+    }
+
+    impl<B: ConfigurationBuilder> FileConfigurationExtensions for B {
+        fn add_file<T, P>(&mut self, file: T, parser: P) -> &mut Self
+        where
+            T: Into<FileSource>,
+            P: ConfigurationFileParser + Clone + 'static,
+        {
+            self.add(Box::new(FileConfigurationSource::new(file.into(), parser)));
+            self
+        }
+    }
+}
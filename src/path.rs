@@ -1,5 +1,48 @@
+use std::borrow::Cow;
+
 const EMPTY: &str = "";
 const KEY_DELIMITER: &str = ":";
+const ESCAPE: char = '\\';
+const ESCAPED: [char; 2] = [ESCAPE, ':'];
+
+// gets the byte indices of each key delimiter in a path that is not escaped
+fn delimiters(path: &str) -> impl Iterator<Item = usize> + '_ {
+    let mut escaped = false;
+
+    path.char_indices().filter_map(move |(index, ch)| {
+        if escaped {
+            escaped = false;
+        } else if ch == ESCAPE {
+            escaped = true;
+        } else if KEY_DELIMITER.starts_with(ch) {
+            return Some(index);
+        }
+
+        None
+    })
+}
+
+/// Defines the possible policies used to bind arrays whose indices have gaps, such as `0`, `1`,
+/// and `3`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SparseArrayPolicy {
+    /// Indicates that the elements are bound in index order without the missing elements. This
+    /// is the default.
+    Compact,
+
+    /// Indicates that binding fails when an index is missing.
+    Error,
+
+    /// Indicates that each missing element is bound from an empty value; for example, `0`,
+    /// `false`, an empty string, `None`, or a structure whose fields have default values.
+    Fill,
+}
+
+impl Default for SparseArrayPolicy {
+    fn default() -> Self {
+        Self::Compact
+    }
+}
 
 /// Represents a configuration path.
 #[derive(Copy, Clone, Debug, PartialEq)]
@@ -26,13 +69,120 @@ impl ConfigurationPath {
         segments.join(KEY_DELIMITER)
     }
 
+    /// Escapes the key delimiter and escape character in the specified segment.
+    ///
+    /// # Arguments
+    ///
+    /// * `segment` - The segment to escape
+    ///
+    /// # Remarks
+    ///
+    /// A key delimiter in a segment is escaped as `\:` and a backslash is escaped as `\\`, which
+    /// enables a key, such as a URL, to contain the key delimiter.
+    pub fn escape(segment: &str) -> Cow<'_, str> {
+        if segment.contains(ESCAPED) {
+            let mut escaped = String::with_capacity(segment.len() + 2);
+
+            for ch in segment.chars() {
+                if ESCAPED.contains(&ch) {
+                    escaped.push(ESCAPE);
+                }
+
+                escaped.push(ch);
+            }
+
+            Cow::Owned(escaped)
+        } else {
+            Cow::Borrowed(segment)
+        }
+    }
+
+    /// Reverses the escaping of the key delimiter and escape character in the specified segment.
+    ///
+    /// # Arguments
+    ///
+    /// * `segment` - The segment to unescape
+    ///
+    /// # Remarks
+    ///
+    /// A backslash that does not precede a key delimiter or another backslash is preserved.
+    pub fn unescape(segment: &str) -> Cow<'_, str> {
+        if !segment.contains(ESCAPE) {
+            return Cow::Borrowed(segment);
+        }
+
+        let mut unescaped = String::with_capacity(segment.len());
+        let mut chars = segment.chars().peekable();
+
+        while let Some(ch) = chars.next() {
+            if ch == ESCAPE {
+                if let Some(next) = chars.peek().filter(|next| ESCAPED.contains(next)) {
+                    unescaped.push(*next);
+                    chars.next();
+                    continue;
+                }
+            }
+
+            unescaped.push(ch);
+        }
+
+        Cow::Owned(unescaped)
+    }
+
+    /// Splits the specified path into its segments, which remain escaped.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to split
+    ///
+    /// # Remarks
+    ///
+    /// An escaped key delimiter does not separate segments.
+    pub fn segments(path: &str) -> impl Iterator<Item = &str> + '_ {
+        let mut start = 0;
+        let mut ends = delimiters(path).chain(std::iter::once(path.len()));
+
+        std::iter::from_fn(move || {
+            let end = ends.next()?;
+            let segment = &path[start..end];
+
+            start = end + KEY_DELIMITER.len();
+            Some(segment)
+        })
+    }
+
+    /// Appends the specified segment, which is escaped, to a path.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to append the segment to
+    /// * `segment` - The unescaped segment to append
+    ///
+    /// # Remarks
+    ///
+    /// The segment is the entire path when `path` is empty.
+    pub fn join(path: &str, segment: &str) -> String {
+        let segment = Self::escape(segment);
+
+        if path.is_empty() {
+            segment.into_owned()
+        } else {
+            let mut joined = String::with_capacity(path.len() + segment.len() + 1);
+
+            joined.push_str(path);
+            joined.push_str(KEY_DELIMITER);
+            joined.push_str(&segment);
+            joined
+        }
+    }
+
     /// Extracts the last path segment from the path.
     ///
     /// # Arguments
     ///
     /// * `path` - The path to extract the key from
     pub fn section_key(path: &str) -> &str {
-        if let Some(index) = path.rfind(KEY_DELIMITER) {
+        if let Some(index) = delimiters(path).last() {
             &path[(index + 1)..]
         } else {
             path
@@ -45,7 +195,7 @@ impl ConfigurationPath {
     ///
     /// * `path` - The path to extract the parent path from
     pub fn parent_path(path: &str) -> &str {
-        if let Some(index) = path.rfind(KEY_DELIMITER) {
+        if let Some(index) = delimiters(path).last() {
             &path[..index]
         } else {
             EMPTY
@@ -72,6 +222,75 @@ mod tests {
         assert_eq!(&path, expected);
     }
 
+    #[test_case("Level", "Level" ; "without special characters")]
+    #[test_case("http://localhost", "http\\://localhost" ; "with delimiter")]
+    #[test_case(r"C:\Temp", r"C\:\\Temp" ; "with delimiter and backslash")]
+    fn escape_should_escape_delimiter_and_backslash(segment: &str, expected: &str) {
+        // arrange
+
+        // act
+        let escaped = ConfigurationPath::escape(segment);
+
+        // assert
+        assert_eq!(escaped, expected);
+    }
+
+    #[test_case("", "Level", "Level" ; "with empty path")]
+    #[test_case("Logging", "Level", "Logging:Level" ; "with path")]
+    #[test_case("Urls", "http://localhost", "Urls:http\\://localhost" ; "with delimiter")]
+    fn join_should_append_escaped_segment(path: &str, segment: &str, expected: &str) {
+        // arrange
+
+        // act
+        let joined = ConfigurationPath::join(path, segment);
+
+        // assert
+        assert_eq!(joined, expected);
+    }
+
+    #[test_case(r"Urls:http\://localhost", "Urls", r"http\://localhost" ; "with escaped delimiter")]
+    #[test_case(r"C\:\\:Temp", r"C\:\\", "Temp" ; "with escaped backslash before delimiter")]
+    fn parent_path_and_section_key_should_ignore_escaped_delimiter(
+        path: &str,
+        expected_parent: &str,
+        expected_key: &str,
+    ) {
+        // arrange
+
+        // act
+        let parent = ConfigurationPath::parent_path(path);
+        let key = ConfigurationPath::section_key(path);
+
+        // assert
+        assert_eq!(parent, expected_parent);
+        assert_eq!(key, expected_key);
+    }
+
+    #[test_case(r"http\://localhost", "http://localhost" ; "with escaped delimiter")]
+    #[test_case(r"C\:\\Temp", r"C:\Temp" ; "with escaped backslash")]
+    #[test_case(r"C:\Temp", r"C:\Temp" ; "with unescaped backslash")]
+    fn unescape_should_reverse_escape(segment: &str, expected: &str) {
+        // arrange
+
+        // act
+        let unescaped = ConfigurationPath::unescape(segment);
+
+        // assert
+        assert_eq!(unescaped, expected);
+    }
+
+    #[test]
+    fn segments_should_split_on_unescaped_delimiters() {
+        // arrange
+        let path = r"Urls:http\://localhost::Port";
+
+        // act
+        let segments: Vec<_> = ConfigurationPath::segments(path).collect();
+
+        // assert
+        assert_eq!(segments, vec!["Urls", r"http\://localhost", "", "Port"]);
+    }
+
     #[test_case("", "" ; "when empty")]
     #[test_case(":::", "" ; "when only delimiters")]
     #[test_case("a::b:::c", "c" ; "with empty segments in the middle")]
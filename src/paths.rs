@@ -0,0 +1,146 @@
+use crate::FileSourceBuilder;
+use std::path::{Path, PathBuf};
+
+/// Gets the home directory of the current user, if any.
+pub fn home_dir() -> Option<PathBuf> {
+    dirs::home_dir()
+}
+
+/// Expands a leading `~` in the specified path to the home directory of the current user.
+///
+/// # Arguments
+///
+/// * `path` - The path to expand
+///
+/// # Remarks
+///
+/// The path is returned unchanged when it does not start with `~` or the home directory cannot
+/// be determined.
+pub fn expand_home<P: AsRef<Path>>(path: P) -> PathBuf {
+    let path = path.as_ref();
+
+    if let Ok(rest) = path.strip_prefix("~") {
+        if let Some(home) = home_dir() {
+            return home.join(rest);
+        }
+    }
+
+    path.to_path_buf()
+}
+
+/// Represents the configuration directories of an application.
+///
+/// # Remarks
+///
+/// The directories follow the conventions of the current operating system.
+///
+/// | Platform | User                                      | System                             |
+/// | -------- | ----------------------------------------- | ---------------------------------- |
+/// | Linux    | `$XDG_CONFIG_HOME/app` or `~/.config/app` | `/etc/app`                         |
+/// | macOS    | `~/Library/Application Support/app`       | `/Library/Application Support/app` |
+/// | Windows  | `%APPDATA%\app`                           | `%PROGRAMDATA%\app`                |
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AppDirs {
+    name: String,
+}
+
+impl AppDirs {
+    /// Initializes new application directories.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the application, such as `myapp`
+    pub fn new<S: Into<String>>(name: S) -> Self {
+        Self { name: name.into() }
+    }
+
+    /// Gets the name of the application.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets the configuration directory of the application for the current user, if any.
+    pub fn config_dir(&self) -> Option<PathBuf> {
+        dirs::config_dir().map(|dir| dir.join(&self.name))
+    }
+
+    /// Gets the configuration directory of the application shared by all users, if any.
+    pub fn system_config_dir(&self) -> Option<PathBuf> {
+        system_config_dir().map(|dir| dir.join(&self.name))
+    }
+
+    /// Gets the path of a configuration file of the application for the current user.
+    ///
+    /// # Arguments
+    ///
+    /// * `file` - The name of the file, such as `settings.json`
+    ///
+    /// # Remarks
+    ///
+    /// The file is relative to the current directory when the configuration directory of the
+    /// current user cannot be determined.
+    pub fn config_file<P: AsRef<Path>>(&self, file: P) -> PathBuf {
+        match self.config_dir() {
+            Some(dir) => dir.join(file),
+            _ => file.as_ref().to_path_buf(),
+        }
+    }
+
+    /// Gets the paths of a configuration file of the application in the order they should be
+    /// layered.
+    ///
+    /// # Arguments
+    ///
+    /// * `file` - The name of the file, such as `settings.json`
+    ///
+    /// # Remarks
+    ///
+    /// The file shared by all users precedes the file for the current user so that, when each
+    /// file is added as an optional source, the values for the current user take precedence.
+    pub fn config_files<P: AsRef<Path>>(&self, file: P) -> Vec<PathBuf> {
+        let file = file.as_ref();
+
+        self.system_config_dir()
+            .into_iter()
+            .chain(self.config_dir())
+            .map(|dir| dir.join(file))
+            .collect()
+    }
+
+    /// Creates a [`FileSourceBuilder`](crate::FileSourceBuilder) for the first existing
+    /// configuration file of the application.
+    ///
+    /// # Arguments
+    ///
+    /// * `file` - The name of the file, such as `settings.json`
+    ///
+    /// # Remarks
+    ///
+    /// The file for the current user is preferred over the file shared by all users.
+    pub fn first_config_file<P: AsRef<Path>>(&self, file: P) -> FileSourceBuilder {
+        let mut paths = self.config_files(file);
+
+        paths.reverse();
+        FileSourceBuilder::first_existing(&paths)
+    }
+}
+
+#[cfg(windows)]
+fn system_config_dir() -> Option<PathBuf> {
+    std::env::var_os("PROGRAMDATA").map(PathBuf::from)
+}
+
+#[cfg(target_os = "macos")]
+fn system_config_dir() -> Option<PathBuf> {
+    Some(PathBuf::from("/Library/Application Support"))
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+fn system_config_dir() -> Option<PathBuf> {
+    Some(PathBuf::from("/etc"))
+}
+
+#[cfg(not(any(windows, unix)))]
+fn system_config_dir() -> Option<PathBuf> {
+    None
+}
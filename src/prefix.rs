@@ -0,0 +1,191 @@
+use crate::{
+    util::{cmp_keys, first_segment, strip_prefix},
+    ConfigValue, ConfigurationBuilder, ConfigurationPairs, ConfigurationPath,
+    ConfigurationProvider, ConfigurationSource, LoadResult, Value, ValueRef,
+};
+use tokens::ChangeToken;
+
+/// Represents a [`ConfigurationProvider`](crate::ConfigurationProvider) that places all
+/// of the keys of another provider under a section path.
+pub struct PrefixedConfigurationProvider {
+    prefix: String,
+    inner: Box<dyn ConfigurationProvider>,
+}
+
+impl PrefixedConfigurationProvider {
+    /// Initializes a new prefixed configuration provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix` - The section path all keys are placed under
+    /// * `inner` - The inner [`ConfigurationProvider`](crate::ConfigurationProvider) to prefix
+    pub fn new(prefix: String, inner: Box<dyn ConfigurationProvider>) -> Self {
+        Self { prefix, inner }
+    }
+
+    fn has_keys(&self) -> bool {
+        let mut keys = Vec::new();
+        self.inner.child_keys(&mut keys, None);
+        !keys.is_empty()
+    }
+}
+
+impl ConfigurationProvider for PrefixedConfigurationProvider {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn get(&self, key: &str) -> Option<Value> {
+        strip_prefix(key, &self.prefix).and_then(|subkey| self.inner.get(subkey))
+    }
+
+    fn get_str(&self, key: &str) -> Option<ValueRef<'_>> {
+        strip_prefix(key, &self.prefix).and_then(|subkey| self.inner.get_str(subkey))
+    }
+
+    fn get_typed(&self, key: &str) -> Option<ConfigValue> {
+        strip_prefix(key, &self.prefix).and_then(|subkey| self.inner.get_typed(subkey))
+    }
+
+    fn is_removed(&self, key: &str) -> bool {
+        strip_prefix(key, &self.prefix).map_or(false, |subkey| self.inner.is_removed(subkey))
+    }
+
+    fn reload_token(&self) -> Box<dyn ChangeToken> {
+        self.inner.reload_token()
+    }
+
+    fn load(&mut self) -> LoadResult {
+        self.inner.load()
+    }
+
+    fn close(&mut self) {
+        self.inner.close()
+    }
+
+    fn save(&self) -> LoadResult {
+        self.inner.save()
+    }
+
+    fn try_iter(&self) -> Option<ConfigurationPairs<'_>> {
+        let pairs = self.inner.try_iter()?;
+        Some(Box::new(pairs.map(move |(key, value)| {
+            (ConfigurationPath::combine(&[&self.prefix, &key]), value)
+        })))
+    }
+
+    fn child_keys(&self, earlier_keys: &mut Vec<String>, parent_path: Option<&str>) {
+        if let Some(path) = parent_path {
+            if path.eq_ignore_ascii_case(&self.prefix) {
+                self.inner.child_keys(earlier_keys, None);
+            } else if let Some(subpath) = strip_prefix(path, &self.prefix) {
+                self.inner.child_keys(earlier_keys, Some(subpath));
+            } else if let Some(rest) = strip_prefix(&self.prefix, path) {
+                if self.has_keys() {
+                    earlier_keys.push(first_segment(rest).to_owned());
+                }
+            } else {
+                return;
+            }
+        } else if self.has_keys() {
+            earlier_keys.push(first_segment(&self.prefix).to_owned());
+        } else {
+            return;
+        }
+
+        earlier_keys.sort_by(|k1, k2| cmp_keys(k1, k2));
+    }
+}
+
+/// Represents a [`ConfigurationSource`](crate::ConfigurationSource) that places all of
+/// the keys of another source under a section path.
+pub struct PrefixedConfigurationSource {
+    prefix: String,
+    inner: Box<dyn ConfigurationSource>,
+}
+
+impl PrefixedConfigurationSource {
+    /// Initializes a new prefixed configuration source.
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix` - The section path all keys are placed under
+    /// * `inner` - The inner [`ConfigurationSource`](crate::ConfigurationSource) to prefix
+    pub fn new<S: AsRef<str>>(prefix: S, inner: Box<dyn ConfigurationSource>) -> Self {
+        Self {
+            prefix: prefix.as_ref().to_owned(),
+            inner,
+        }
+    }
+
+    /// Gets the section path all keys are placed under.
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+}
+
+impl ConfigurationSource for PrefixedConfigurationSource {
+    fn build(&self, builder: &dyn ConfigurationBuilder) -> Box<dyn ConfigurationProvider> {
+        Box::new(PrefixedConfigurationProvider::new(
+            self.prefix.clone(),
+            self.inner.build(builder),
+        ))
+    }
+
+    fn priority(&self) -> i32 {
+        self.inner.priority()
+    }
+}
+
+pub mod ext {
+
+    use super::*;
+    use crate::DefaultConfigurationBuilder;
+
+    /// Defines extension methods for [`ConfigurationBuilder`](crate::ConfigurationBuilder).
+    pub trait PrefixedConfigurationBuilderExtensions {
+        /// Mounts all of the configured sources under a section path.
+        ///
+        /// # Arguments
+        ///
+        /// * `prefix` - The section path the configured sources are placed under
+        /// * `configure` - The function used to add the sources to mount
+        fn mount<F>(&mut self, prefix: &str, configure: F) -> &mut Self
+        where
+            F: FnOnce(&mut DefaultConfigurationBuilder);
+    }
+
+    impl PrefixedConfigurationBuilderExtensions for dyn ConfigurationBuilder + '_ {
+        fn mount<F>(&mut self, prefix: &str, configure: F) -> &mut Self
+        where
+            F: FnOnce(&mut DefaultConfigurationBuilder),
+        {
+            let mut builder = DefaultConfigurationBuilder::new();
+
+            configure(&mut builder);
+
+            for source in builder.sources {
+                self.add(Box::new(PrefixedConfigurationSource::new(prefix, source)));
+            }
+
+            self
+        }
+    }
+
+    impl<T: ConfigurationBuilder> PrefixedConfigurationBuilderExtensions for T {
+        fn mount<F>(&mut self, prefix: &str, configure: F) -> &mut Self
+        where
+            F: FnOnce(&mut DefaultConfigurationBuilder),
+        {
+            let mut builder = DefaultConfigurationBuilder::new();
+
+            configure(&mut builder);
+
+            for source in builder.sources {
+                self.add(Box::new(PrefixedConfigurationSource::new(prefix, source)));
+            }
+
+            self
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use crate::{ConfigurationBuilder, ConfigurationProvider, ConfigurationSource};
+
+/// Represents a [`ConfigurationSource`](crate::ConfigurationSource) with an explicit priority.
+///
+/// # Remarks
+///
+/// A source with a higher priority takes precedence over a source with a lower priority,
+/// regardless of the order they were added. Sources with the same priority take precedence in
+/// the order they were added. This is useful when sources are contributed by components that
+/// cannot control the order they are added, such as plugins.
+pub struct PrioritizedConfigurationSource {
+    priority: i32,
+    inner: Box<dyn ConfigurationSource>,
+}
+
+impl PrioritizedConfigurationSource {
+    /// Initializes a new prioritized configuration source.
+    ///
+    /// # Arguments
+    ///
+    /// * `priority` - The priority of the source
+    /// * `inner` - The inner [`ConfigurationSource`](crate::ConfigurationSource) to prioritize
+    pub fn new(priority: i32, inner: Box<dyn ConfigurationSource>) -> Self {
+        Self { priority, inner }
+    }
+}
+
+impl ConfigurationSource for PrioritizedConfigurationSource {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn build(&self, builder: &dyn ConfigurationBuilder) -> Box<dyn ConfigurationProvider> {
+        self.inner.build(builder)
+    }
+
+    fn priority(&self) -> i32 {
+        self.priority
+    }
+}
+
+pub mod ext {
+
+    use super::*;
+
+    /// Defines extension methods to prioritize a [`ConfigurationSource`](crate::ConfigurationSource).
+    pub trait PrioritizedConfigurationSourceExtensions {
+        /// Assigns the source a priority.
+        ///
+        /// # Arguments
+        ///
+        /// * `priority` - The priority of the source. The default priority is `0`.
+        fn with_priority(self, priority: i32) -> PrioritizedConfigurationSource;
+    }
+
+    impl PrioritizedConfigurationSourceExtensions for Box<dyn ConfigurationSource> {
+        fn with_priority(self, priority: i32) -> PrioritizedConfigurationSource {
+            PrioritizedConfigurationSource::new(priority, self)
+        }
+    }
+
+    impl<T: ConfigurationSource + 'static> PrioritizedConfigurationSourceExtensions for T {
+        fn with_priority(self, priority: i32) -> PrioritizedConfigurationSource {
+            PrioritizedConfigurationSource::new(priority, Box::new(self))
+        }
+    }
+}
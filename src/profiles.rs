@@ -0,0 +1,303 @@
+use crate::{
+    util::{cmp_keys, strip_prefix},
+    ConfigValue, ConfigurationBuilder, ConfigurationPairs, ConfigurationPath,
+    ConfigurationProvider, ConfigurationSource, HostEnvironment, LoadResult, Value, ValueRef,
+};
+use std::collections::HashMap;
+use tokens::ChangeToken;
+
+/// Gets the name of the section that contains the profiles of a configuration source.
+pub const PROFILES_SECTION: &str = "Profiles";
+
+// determines whether the key is the profiles section or one of its descendants
+fn is_profile_key(key: &str) -> bool {
+    let delimiter = ConfigurationPath::key_delimiter();
+    let len = PROFILES_SECTION.len();
+
+    key.len() >= len
+        && key.is_char_boundary(len)
+        && key[..len].eq_ignore_ascii_case(PROFILES_SECTION)
+        && (key.len() == len || key[len..].starts_with(delimiter))
+}
+
+/// Represents a [`ConfigurationProvider`](crate::ConfigurationProvider) that overlays the
+/// active profile of another provider onto its root keys.
+///
+/// # Remarks
+///
+/// A profile is a section under the `Profiles` section, such as `Profiles:Development`, whose
+/// keys override the same keys at the root. The `Profiles` section itself is not visible.
+pub struct ProfiledConfigurationProvider {
+    profile: Option<String>,
+    inner: Box<dyn ConfigurationProvider>,
+}
+
+impl ProfiledConfigurationProvider {
+    /// Initializes a new profiled configuration provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `profile` - The name of the active profile, if any
+    /// * `inner` - The inner [`ConfigurationProvider`](crate::ConfigurationProvider) to overlay
+    pub fn new(profile: Option<String>, inner: Box<dyn ConfigurationProvider>) -> Self {
+        Self { profile, inner }
+    }
+
+    fn profile_key(&self, key: &str) -> Option<String> {
+        self.profile
+            .as_ref()
+            .map(|profile| ConfigurationPath::combine(&[PROFILES_SECTION, profile, key]))
+    }
+
+    fn profile_path(&self, parent_path: Option<&str>) -> Option<String> {
+        match parent_path {
+            Some(path) => self.profile_key(path),
+            _ => self
+                .profile
+                .as_ref()
+                .map(|profile| ConfigurationPath::combine(&[PROFILES_SECTION, profile])),
+        }
+    }
+}
+
+impl ConfigurationProvider for ProfiledConfigurationProvider {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn get(&self, key: &str) -> Option<Value> {
+        if is_profile_key(key) {
+            return None;
+        }
+
+        self.profile_key(key)
+            .and_then(|profile_key| self.inner.get(&profile_key))
+            .or_else(|| self.inner.get(key))
+    }
+
+    fn get_str(&self, key: &str) -> Option<ValueRef<'_>> {
+        if is_profile_key(key) {
+            return None;
+        }
+
+        self.profile_key(key)
+            .and_then(|profile_key| self.inner.get(&profile_key).map(ValueRef::Shared))
+            .or_else(|| self.inner.get_str(key))
+    }
+
+    fn get_typed(&self, key: &str) -> Option<ConfigValue> {
+        if is_profile_key(key) {
+            return None;
+        }
+
+        self.profile_key(key)
+            .and_then(|profile_key| self.inner.get_typed(&profile_key))
+            .or_else(|| self.inner.get_typed(key))
+    }
+
+    fn is_removed(&self, key: &str) -> bool {
+        !is_profile_key(key) && self.inner.is_removed(key)
+    }
+
+    fn reload_token(&self) -> Box<dyn ChangeToken> {
+        self.inner.reload_token()
+    }
+
+    fn load(&mut self) -> LoadResult {
+        self.inner.load()
+    }
+
+    fn close(&mut self) {
+        self.inner.close()
+    }
+
+    fn save(&self) -> LoadResult {
+        self.inner.save()
+    }
+
+    fn try_iter(&self) -> Option<ConfigurationPairs<'_>> {
+        let profile = self.profile_path(None);
+        let mut pairs = HashMap::new();
+        let mut overrides = Vec::new();
+
+        for (key, value) in self.inner.try_iter()? {
+            if !is_profile_key(&key) {
+                pairs.insert(key.to_uppercase(), (key, value));
+            } else if let Some(subkey) = profile.as_ref().and_then(|path| strip_prefix(&key, path))
+            {
+                overrides.push((subkey.to_owned(), value));
+            }
+        }
+
+        // the values of the profile override the root values regardless of order
+        for (key, value) in overrides {
+            pairs.insert(key.to_uppercase(), (key, value));
+        }
+
+        Some(Box::new(pairs.into_values()))
+    }
+
+    fn child_keys(&self, earlier_keys: &mut Vec<String>, parent_path: Option<&str>) {
+        let mut keys = Vec::new();
+
+        match parent_path {
+            Some(path) if is_profile_key(path) => return,
+            Some(_) => self.inner.child_keys(&mut keys, parent_path),
+            _ => {
+                self.inner.child_keys(&mut keys, None);
+                keys.retain(|key| !key.eq_ignore_ascii_case(PROFILES_SECTION));
+            }
+        }
+
+        if let Some(path) = self.profile_path(parent_path) {
+            let mut overrides = Vec::new();
+
+            self.inner.child_keys(&mut overrides, Some(&path));
+
+            for key in overrides {
+                if !keys.iter().any(|other| other.eq_ignore_ascii_case(&key)) {
+                    keys.push(key);
+                }
+            }
+        }
+
+        if keys.is_empty() {
+            return;
+        }
+
+        earlier_keys.extend(keys);
+        earlier_keys.sort_by(|k1, k2| cmp_keys(k1, k2));
+    }
+}
+
+/// Represents a [`ConfigurationSource`](crate::ConfigurationSource) that overlays the active
+/// profile of another source onto its root keys.
+pub struct ProfiledConfigurationSource {
+    profile: Option<String>,
+    inner: Box<dyn ConfigurationSource>,
+}
+
+impl ProfiledConfigurationSource {
+    /// Initializes a new profiled configuration source.
+    ///
+    /// # Arguments
+    ///
+    /// * `profile` - The name of the active profile, if any
+    /// * `inner` - The inner [`ConfigurationSource`](crate::ConfigurationSource) to overlay
+    ///
+    /// # Remarks
+    ///
+    /// When a profile is not specified, the name of the
+    /// [`HostEnvironment`](crate::HostEnvironment) registered with the
+    /// [`ConfigurationBuilder`](crate::ConfigurationBuilder) is the active profile. If no
+    /// environment is registered, the environment is read from the current process.
+    pub fn new(profile: Option<&str>, inner: Box<dyn ConfigurationSource>) -> Self {
+        Self {
+            profile: profile.map(ToOwned::to_owned),
+            inner,
+        }
+    }
+}
+
+impl ConfigurationSource for ProfiledConfigurationSource {
+    fn build(&self, builder: &dyn ConfigurationBuilder) -> Box<dyn ConfigurationProvider> {
+        let profile = self.profile.clone().unwrap_or_else(|| {
+            HostEnvironment::of(builder)
+                .map(|environment| environment.name().to_owned())
+                .unwrap_or_else(|| HostEnvironment::from_env().name().to_owned())
+        });
+
+        Box::new(ProfiledConfigurationProvider::new(
+            Some(profile),
+            self.inner.build(builder),
+        ))
+    }
+
+    fn priority(&self) -> i32 {
+        self.inner.priority()
+    }
+}
+
+pub mod ext {
+
+    use super::*;
+    use crate::DefaultConfigurationBuilder;
+
+    /// Defines extension methods for [`ConfigurationBuilder`](crate::ConfigurationBuilder).
+    pub trait ProfiledConfigurationBuilderExtensions {
+        /// Overlays the profile of the host environment onto each of the configured sources.
+        ///
+        /// # Arguments
+        ///
+        /// * `configure` - The function used to add the sources with profiles
+        ///
+        /// # Remarks
+        ///
+        /// For example, in the `Development` environment, the value of
+        /// `Profiles:Development:Logging:Level` overrides the value of `Logging:Level` defined
+        /// by the same source.
+        fn with_profiles<F>(&mut self, configure: F) -> &mut Self
+        where
+            F: FnOnce(&mut DefaultConfigurationBuilder);
+
+        /// Overlays the specified profile onto each of the configured sources.
+        ///
+        /// # Arguments
+        ///
+        /// * `profile` - The name of the active profile
+        /// * `configure` - The function used to add the sources with profiles
+        fn with_profile<F>(&mut self, profile: &str, configure: F) -> &mut Self
+        where
+            F: FnOnce(&mut DefaultConfigurationBuilder);
+    }
+
+    fn add_profiled<B, F>(builder: &mut B, profile: Option<&str>, configure: F)
+    where
+        B: ConfigurationBuilder + ?Sized,
+        F: FnOnce(&mut DefaultConfigurationBuilder),
+    {
+        let mut sources = DefaultConfigurationBuilder::new();
+
+        configure(&mut sources);
+
+        for source in sources.sources {
+            builder.add(Box::new(ProfiledConfigurationSource::new(profile, source)));
+        }
+    }
+
+    impl ProfiledConfigurationBuilderExtensions for dyn ConfigurationBuilder + '_ {
+        fn with_profiles<F>(&mut self, configure: F) -> &mut Self
+        where
+            F: FnOnce(&mut DefaultConfigurationBuilder),
+        {
+            add_profiled(self, None, configure);
+            self
+        }
+
+        fn with_profile<F>(&mut self, profile: &str, configure: F) -> &mut Self
+        where
+            F: FnOnce(&mut DefaultConfigurationBuilder),
+        {
+            add_profiled(self, Some(profile), configure);
+            self
+        }
+    }
+
+    impl<T: ConfigurationBuilder> ProfiledConfigurationBuilderExtensions for T {
+        fn with_profiles<F>(&mut self, configure: F) -> &mut Self
+        where
+            F: FnOnce(&mut DefaultConfigurationBuilder),
+        {
+            add_profiled(self, None, configure);
+            self
+        }
+
+        fn with_profile<F>(&mut self, profile: &str, configure: F) -> &mut Self
+        where
+            F: FnOnce(&mut DefaultConfigurationBuilder),
+        {
+            add_profiled(self, Some(profile), configure);
+            self
+        }
+    }
+}
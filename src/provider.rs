@@ -1,11 +1,76 @@
-use std::fmt::{Debug, Formatter, Result as FormatResult};
-use std::{any::type_name, path::PathBuf};
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter, Result as FormatResult};
+use std::path::{Path, PathBuf};
+use std::{any::type_name, sync::Arc};
 use tokens::{ChangeToken, NeverChangeToken};
 
-use crate::Value;
+use crate::{ConfigValue, Value, ValueRef};
+use cfg_if::cfg_if;
+
+/// Represents the type alias for the underlying cause of a [`LoadError`].
+pub type LoadErrorSource = Arc<dyn Error + Send + Sync>;
+
+/// Represents an error that occurs when configuration content cannot be parsed.
+#[derive(Debug, Default)]
+pub struct ParseError {
+    /// Gets or sets the error message.
+    pub message: String,
+
+    /// Gets or sets the line where the error occurred, if known.
+    pub line: Option<usize>,
+
+    /// Gets or sets the column where the error occurred, if known.
+    pub column: Option<usize>,
+
+    /// Gets or sets the configuration key the error applies to, if known.
+    pub key: Option<String>,
+
+    /// Gets or sets the underlying cause of the error, if any.
+    pub source: Option<LoadErrorSource>,
+}
+
+impl ParseError {
+    /// Initializes a new parse error.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - The error message
+    pub fn new<S: Into<String>>(message: S) -> Self {
+        Self {
+            message: message.into(),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<String> for ParseError {
+    fn from(message: String) -> Self {
+        Self::new(message)
+    }
+}
+
+impl From<&str> for ParseError {
+    fn from(message: &str) -> Self {
+        Self::new(message)
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FormatResult {
+        f.write_str(&self.message)
+    }
+}
+
+impl Error for ParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
 
 /// Defines the possible load errors.
-#[derive(PartialEq, Clone)]
+#[derive(Clone)]
 pub enum LoadError {
     /// Indicates a generic load error with an error message.
     Generic(String),
@@ -17,6 +82,27 @@ pub enum LoadError {
 
         /// Gets the path of the file being loaded.
         path: PathBuf,
+
+        /// Gets the line in the file where the error occurred, if known.
+        line: Option<usize>,
+
+        /// Gets the column in the file where the error occurred, if known.
+        column: Option<usize>,
+
+        /// Gets the configuration key the error applies to, if known.
+        key: Option<String>,
+
+        /// Gets the underlying cause of the error, if any.
+        source: Option<LoadErrorSource>,
+    },
+
+    /// Indicates the command line contains arguments that are not expected.
+    UnexpectedArguments {
+        /// Gets the error message.
+        message: String,
+
+        /// Gets the unexpected arguments in the order they were specified.
+        args: Vec<String>,
     },
 }
 
@@ -26,6 +112,84 @@ impl LoadError {
         match self {
             Self::Generic(message) => message,
             Self::File { message, .. } => message,
+            Self::UnexpectedArguments { message, .. } => message,
+        }
+    }
+
+    /// Gets the path of the file being loaded, if any.
+    pub fn path(&self) -> Option<&Path> {
+        match self {
+            Self::File { path, .. } => Some(path),
+            _ => None,
+        }
+    }
+
+    /// Gets the line where the error occurred, if known.
+    pub fn line(&self) -> Option<usize> {
+        match self {
+            Self::File { line, .. } => *line,
+            _ => None,
+        }
+    }
+
+    /// Gets the column where the error occurred, if known.
+    pub fn column(&self) -> Option<usize> {
+        match self {
+            Self::File { column, .. } => *column,
+            _ => None,
+        }
+    }
+
+    /// Gets the configuration key the error applies to, if known.
+    pub fn key(&self) -> Option<&str> {
+        match self {
+            Self::File { key, .. } => key.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+impl PartialEq for LoadError {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Generic(message), Self::Generic(other)) => message == other,
+            (
+                Self::UnexpectedArguments { message, args },
+                Self::UnexpectedArguments {
+                    message: other_message,
+                    args: other_args,
+                },
+            ) => message == other_message && args == other_args,
+            (
+                Self::File {
+                    message,
+                    path,
+                    line,
+                    column,
+                    key,
+                    source,
+                },
+                Self::File {
+                    message: other_message,
+                    path: other_path,
+                    line: other_line,
+                    column: other_column,
+                    key: other_key,
+                    source: other_source,
+                },
+            ) => {
+                message == other_message
+                    && path == other_path
+                    && line == other_line
+                    && column == other_column
+                    && key == other_key
+                    && match (source, other_source) {
+                        (Some(source), Some(other)) => Arc::ptr_eq(source, other),
+                        (None, None) => true,
+                        _ => false,
+                    }
+            }
+            _ => false,
         }
     }
 }
@@ -35,44 +199,287 @@ impl Debug for LoadError {
         match self {
             Self::Generic(message) => f.write_str(message),
             Self::File { message, .. } => f.write_str(message),
+            Self::UnexpectedArguments { message, .. } => f.write_str(message),
         }
     }
 }
 
-/// Represents a configuration load result.
-pub type LoadResult = std::result::Result<(), LoadError>;
+impl Display for LoadError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FormatResult {
+        match self {
+            Self::Generic(message) => f.write_str(message),
+            Self::UnexpectedArguments { message, .. } => f.write_str(message),
+            Self::File {
+                message,
+                path,
+                line,
+                column,
+                key,
+                ..
+            } => {
+                write!(f, "{}", path.display())?;
 
-/// Defines the behavior of an object that provides configuration key/values for an application.
-pub trait ConfigurationProvider {
-    /// Gets the name of the provider.
-    fn name(&self) -> &str {
-        type_name::<Self>()
-    }
+                if let Some(line) = line {
+                    write!(f, ":{}", line)?;
 
-    /// Attempts to get a configuration value with the specified key.
-    ///
-    /// # Arguments
-    ///
-    /// * `key` - The key of the value to retrieve
-    fn get(&self, key: &str) -> Option<Value>;
+                    if let Some(column) = column {
+                        write!(f, ":{}", column)?;
+                    }
+                }
+
+                write!(f, ": {}", message)?;
 
-    /// Returns a [`ChangeToken`](tokens::ChangeToken) if this provider supports change tracking.
-    fn reload_token(&self) -> Box<dyn ChangeToken> {
-        Box::new(NeverChangeToken::new())
+                if let Some(key) = key {
+                    write!(f, " (Key: {})", key)?;
+                }
+
+                Ok(())
+            }
+        }
     }
+}
 
-    /// Loads the configuration values from the implemented source.
-    fn load(&mut self) -> LoadResult {
-        Ok(())
+impl Error for LoadError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::File {
+                source: Some(source),
+                ..
+            } => Some(&**source),
+            _ => None,
+        }
     }
+}
 
-    /// Gets the immediate descendent configuration keys for a given parent path based
-    /// on this [`ConfigurationProvider`] and the set of keys returned by all of the
-    /// preceding [`ConfigurationProvider`].
-    ///
-    /// # Arguments
-    ///
-    /// * `earlier_keys` - The sequence of keys returned by preceding provider for the same parent path
-    /// * `parent_path` - The optional parent path to evaluate
-    fn child_keys(&self, earlier_keys: &mut Vec<String>, parent_path: Option<&str>);
+/// Represents a configuration load result.
+pub type LoadResult = std::result::Result<(), LoadError>;
+
+/// Represents the type alias for an iterator over the key/value pairs of a
+/// [`ConfigurationProvider`].
+pub type ConfigurationPairs<'a> = Box<dyn Iterator<Item = (String, Value)> + 'a>;
+
+cfg_if! {
+    if #[cfg(feature = "async")] {
+        /// Defines the behavior of an object that provides configuration key/values for an application.
+        pub trait ConfigurationProvider: Send + Sync {
+            /// Gets the name of the provider.
+            fn name(&self) -> &str {
+                type_name::<Self>()
+            }
+
+            /// Attempts to get a configuration value with the specified key.
+            ///
+            /// # Arguments
+            ///
+            /// * `key` - The key of the value to retrieve
+            fn get(&self, key: &str) -> Option<Value>;
+
+            /// Attempts to get a reference to a configuration value with the specified key.
+            ///
+            /// # Arguments
+            ///
+            /// * `key` - The key of the value to retrieve
+            ///
+            /// # Remarks
+            ///
+            /// The default implementation defers to [`get`](ConfigurationProvider::get). Providers
+            /// that do not guard their data should override this method and borrow the value.
+            fn get_str(&self, key: &str) -> Option<ValueRef<'_>> {
+                self.get(key).map(ValueRef::Shared)
+            }
+
+            /// Attempts to get a typed configuration value with the specified key.
+            ///
+            /// # Arguments
+            ///
+            /// * `key` - The key of the value to retrieve
+            ///
+            /// # Remarks
+            ///
+            /// The default implementation defers to [`get`](ConfigurationProvider::get) and
+            /// always produces [`ConfigValue::String`](crate::ConfigValue::String).
+            fn get_typed(&self, key: &str) -> Option<ConfigValue> {
+                self.get(key).map(ConfigValue::String)
+            }
+
+            /// Gets a value indicating whether the specified key is removed by this provider.
+            ///
+            /// # Arguments
+            ///
+            /// * `key` - The key to evaluate
+            ///
+            /// # Remarks
+            ///
+            /// A removed key masks the value, and all descendants, set by any preceding
+            /// [`ConfigurationProvider`]. The default implementation never removes a key.
+            fn is_removed(&self, key: &str) -> bool {
+                let _ = key;
+                false
+            }
+
+            /// Returns a [`ChangeToken`](tokens::ChangeToken) if this provider supports change tracking.
+            fn reload_token(&self) -> Box<dyn ChangeToken> {
+                Box::new(NeverChangeToken::new())
+            }
+
+            /// Loads the configuration values from the implemented source.
+            fn load(&mut self) -> LoadResult {
+                Ok(())
+            }
+
+            /// Stops watching for changes and releases any background resources, such as threads.
+            ///
+            /// # Remarks
+            ///
+            /// The values that have been loaded remain available, but are no longer reloaded when
+            /// the underlying source changes. The default implementation does nothing.
+            fn close(&mut self) {}
+
+            /// Saves the current configuration values back to the underlying source.
+            ///
+            /// # Remarks
+            ///
+            /// Saving is opt-in and only supported by some providers, such as file-backed
+            /// providers whose format can be written. The default implementation does not support
+            /// saving and always returns an error.
+            fn save(&self) -> LoadResult {
+                Err(LoadError::Generic(format!(
+                    "The configuration provider '{}' does not support saving.",
+                    self.name()
+                )))
+            }
+
+            /// Attempts to iterate over all of the key/value pairs of this provider.
+            ///
+            /// # Remarks
+            ///
+            /// The pairs are in no particular order and each key is the original, fully-qualified
+            /// key. The default implementation returns `None`, which indicates the provider cannot
+            /// enumerate its values.
+            fn try_iter(&self) -> Option<ConfigurationPairs<'_>> {
+                None
+            }
+
+            /// Gets the immediate descendent configuration keys for a given parent path based
+            /// on this [`ConfigurationProvider`] and the set of keys returned by all of the
+            /// preceding [`ConfigurationProvider`].
+            ///
+            /// # Arguments
+            ///
+            /// * `earlier_keys` - The sequence of keys returned by preceding provider for the same parent path
+            /// * `parent_path` - The optional parent path to evaluate
+            fn child_keys(&self, earlier_keys: &mut Vec<String>, parent_path: Option<&str>);
+        }
+    } else {
+        /// Defines the behavior of an object that provides configuration key/values for an application.
+        pub trait ConfigurationProvider {
+            /// Gets the name of the provider.
+            fn name(&self) -> &str {
+                type_name::<Self>()
+            }
+
+            /// Attempts to get a configuration value with the specified key.
+            ///
+            /// # Arguments
+            ///
+            /// * `key` - The key of the value to retrieve
+            fn get(&self, key: &str) -> Option<Value>;
+
+            /// Attempts to get a reference to a configuration value with the specified key.
+            ///
+            /// # Arguments
+            ///
+            /// * `key` - The key of the value to retrieve
+            ///
+            /// # Remarks
+            ///
+            /// The default implementation defers to [`get`](ConfigurationProvider::get). Providers
+            /// that do not guard their data should override this method and borrow the value.
+            fn get_str(&self, key: &str) -> Option<ValueRef<'_>> {
+                self.get(key).map(ValueRef::Shared)
+            }
+
+            /// Attempts to get a typed configuration value with the specified key.
+            ///
+            /// # Arguments
+            ///
+            /// * `key` - The key of the value to retrieve
+            ///
+            /// # Remarks
+            ///
+            /// The default implementation defers to [`get`](ConfigurationProvider::get) and
+            /// always produces [`ConfigValue::String`](crate::ConfigValue::String).
+            fn get_typed(&self, key: &str) -> Option<ConfigValue> {
+                self.get(key).map(ConfigValue::String)
+            }
+
+            /// Gets a value indicating whether the specified key is removed by this provider.
+            ///
+            /// # Arguments
+            ///
+            /// * `key` - The key to evaluate
+            ///
+            /// # Remarks
+            ///
+            /// A removed key masks the value, and all descendants, set by any preceding
+            /// [`ConfigurationProvider`]. The default implementation never removes a key.
+            fn is_removed(&self, key: &str) -> bool {
+                let _ = key;
+                false
+            }
+
+            /// Returns a [`ChangeToken`](tokens::ChangeToken) if this provider supports change tracking.
+            fn reload_token(&self) -> Box<dyn ChangeToken> {
+                Box::new(NeverChangeToken::new())
+            }
+
+            /// Loads the configuration values from the implemented source.
+            fn load(&mut self) -> LoadResult {
+                Ok(())
+            }
+
+            /// Stops watching for changes and releases any background resources, such as threads.
+            ///
+            /// # Remarks
+            ///
+            /// The values that have been loaded remain available, but are no longer reloaded when
+            /// the underlying source changes. The default implementation does nothing.
+            fn close(&mut self) {}
+
+            /// Saves the current configuration values back to the underlying source.
+            ///
+            /// # Remarks
+            ///
+            /// Saving is opt-in and only supported by some providers, such as file-backed
+            /// providers whose format can be written. The default implementation does not support
+            /// saving and always returns an error.
+            fn save(&self) -> LoadResult {
+                Err(LoadError::Generic(format!(
+                    "The configuration provider '{}' does not support saving.",
+                    self.name()
+                )))
+            }
+
+            /// Attempts to iterate over all of the key/value pairs of this provider.
+            ///
+            /// # Remarks
+            ///
+            /// The pairs are in no particular order and each key is the original, fully-qualified
+            /// key. The default implementation returns `None`, which indicates the provider cannot
+            /// enumerate its values.
+            fn try_iter(&self) -> Option<ConfigurationPairs<'_>> {
+                None
+            }
+
+            /// Gets the immediate descendent configuration keys for a given parent path based
+            /// on this [`ConfigurationProvider`] and the set of keys returned by all of the
+            /// preceding [`ConfigurationProvider`].
+            ///
+            /// # Arguments
+            ///
+            /// * `earlier_keys` - The sequence of keys returned by preceding provider for the same parent path
+            /// * `parent_path` - The optional parent path to evaluate
+            fn child_keys(&self, earlier_keys: &mut Vec<String>, parent_path: Option<&str>);
+        }
+    }
 }
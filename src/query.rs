@@ -0,0 +1,110 @@
+use crate::{redact::is_match, Configuration, ConfigurationPath, ConfigurationSection, Value};
+
+// walks the sections whose keys match each segment of the pattern; each match is paired
+// with its key relative to the configuration
+fn matching(
+    configuration: &dyn Configuration,
+    pattern: &str,
+) -> Vec<(String, Box<dyn ConfigurationSection>)> {
+    let mut matches: Vec<(String, Option<Box<dyn ConfigurationSection>>)> =
+        vec![(String::new(), None)];
+
+    for segment in pattern.split(ConfigurationPath::key_delimiter()) {
+        let wildcard = segment.contains('*');
+        let mut next = Vec::new();
+
+        for (key, section) in matches {
+            let parent: &dyn Configuration = match &section {
+                Some(section) => &***section,
+                _ => configuration,
+            };
+            let children = parent.children().into_iter().filter(|child| {
+                if wildcard {
+                    is_match(segment, child.key())
+                } else {
+                    child.key().eq_ignore_ascii_case(segment)
+                }
+            });
+
+            for child in children {
+                let key = if key.is_empty() {
+                    child.key().to_owned()
+                } else {
+                    ConfigurationPath::combine(&[&key, child.key()])
+                };
+
+                next.push((key, Some(child)));
+            }
+        }
+
+        matches = next;
+    }
+
+    matches
+        .into_iter()
+        .filter_map(|(key, section)| section.map(|section| (key, section)))
+        .collect()
+}
+
+pub mod ext {
+
+    use super::*;
+
+    /// Defines extension methods to query a [`Configuration`](crate::Configuration) by pattern.
+    pub trait ConfigurationQueryExtensions {
+        /// Finds the configuration values whose keys match the specified pattern.
+        ///
+        /// # Arguments
+        ///
+        /// * `pattern` - The case-insensitive key pattern, such as `Logging:*:Level`
+        ///
+        /// # Remarks
+        ///
+        /// A `*` in a segment of the pattern matches any text within a single segment of a key.
+        /// The matching keys are relative to the configuration.
+        fn find(&self, pattern: &str) -> Vec<(String, Value)>;
+
+        /// Gets the configuration sections whose paths match the specified pattern.
+        ///
+        /// # Arguments
+        ///
+        /// * `pattern` - The case-insensitive path pattern, such as `Clients:*`
+        ///
+        /// # Remarks
+        ///
+        /// A `*` in a segment of the pattern matches any text within a single segment of a path.
+        fn sections_matching(&self, pattern: &str) -> Vec<Box<dyn ConfigurationSection>>;
+    }
+
+    impl ConfigurationQueryExtensions for dyn Configuration + '_ {
+        fn find(&self, pattern: &str) -> Vec<(String, Value)> {
+            matching(self, pattern)
+                .into_iter()
+                .filter_map(|(key, _)| self.get(&key).map(|value| (key, value)))
+                .collect()
+        }
+
+        fn sections_matching(&self, pattern: &str) -> Vec<Box<dyn ConfigurationSection>> {
+            matching(self, pattern)
+                .into_iter()
+                .map(|(_, section)| section)
+                .collect()
+        }
+    }
+
+    impl<T: Configuration> ConfigurationQueryExtensions for T {
+        fn find(&self, pattern: &str) -> Vec<(String, Value)> {
+            matching(self, pattern)
+                .into_iter()
+                .filter_map(|(key, _)| self.get(&key).map(|value| (key, value)))
+                .collect()
+        }
+
+        fn sections_matching(&self, pattern: &str) -> Vec<Box<dyn ConfigurationSection>> {
+            matching(self, pattern)
+                .into_iter()
+                .map(|(_, section)| section)
+                .collect()
+        }
+    }
+}
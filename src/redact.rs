@@ -0,0 +1,28 @@
+/// Represents the text displayed in place of a sensitive configuration value.
+pub const REDACTED: &str = "***";
+
+// determines whether the key matches the case-insensitive pattern, where * matches any text
+pub(crate) fn is_match(pattern: &str, key: &str) -> bool {
+    let pattern: Vec<_> = pattern.chars().flat_map(char::to_lowercase).collect();
+    let key: Vec<_> = key.chars().flat_map(char::to_lowercase).collect();
+    let (mut p, mut k) = (0, 0);
+    let mut backtrack = None;
+
+    while k < key.len() {
+        if p < pattern.len() && pattern[p] == '*' {
+            backtrack = Some((p, k));
+            p += 1;
+        } else if p < pattern.len() && pattern[p] == key[k] {
+            p += 1;
+            k += 1;
+        } else if let Some((star, matched)) = backtrack {
+            p = star + 1;
+            k = matched + 1;
+            backtrack = Some((star, matched + 1));
+        } else {
+            return false;
+        }
+    }
+
+    pattern[p..].iter().all(|c| *c == '*')
+}
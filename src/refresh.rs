@@ -0,0 +1,388 @@
+use crate::{
+    ConfigValue, ConfigurationBuilder, ConfigurationPairs, ConfigurationProvider,
+    ConfigurationSource, LoadResult, Value, ValueRef,
+};
+use std::sync::{Arc, RwLock, Weak};
+use tokens::{ChangeToken, CompositeChangeToken, SharedChangeToken, SingleChangeToken};
+
+#[cfg(feature = "async")]
+use {
+    crate::{file::RELOAD_ERROR_CALLBACK, trace, ReloadErrorCallback},
+    std::sync::mpsc::{channel, RecvTimeoutError, Sender},
+    std::thread::{spawn, JoinHandle},
+    std::time::Duration,
+};
+
+struct Inner {
+    provider: RwLock<Box<dyn ConfigurationProvider>>,
+    token: RwLock<SharedChangeToken<SingleChangeToken>>,
+}
+
+impl Inner {
+    fn refresh(&self) -> LoadResult {
+        self.provider.write().unwrap().load()?;
+
+        let previous = std::mem::take(&mut *self.token.write().unwrap());
+
+        previous.notify();
+        Ok(())
+    }
+}
+
+// reloads a provider at a fixed interval on a background thread until it is dropped
+#[cfg(feature = "async")]
+struct Timer {
+    stop: Option<Sender<()>>,
+    handle: Option<JoinHandle<()>>,
+}
+
+#[cfg(feature = "async")]
+impl Timer {
+    fn start(
+        inner: &Arc<Inner>,
+        interval: Duration,
+        on_error: Option<ReloadErrorCallback>,
+    ) -> Self {
+        let name = inner.provider.read().unwrap().name().to_owned();
+        let provider = Arc::downgrade(inner);
+        let (sender, receiver) = channel::<()>();
+        let handle = spawn(move || {
+            while let Err(RecvTimeoutError::Timeout) = receiver.recv_timeout(interval) {
+                let provider = match provider.upgrade() {
+                    Some(provider) => provider,
+                    _ => break,
+                };
+
+                // the previously loaded configuration is retained when the provider fails to
+                // reload, which is the same behavior as a file that fails to reload
+                if let Err(error) = provider.refresh() {
+                    trace::reload_failed(&name, &error);
+
+                    if let Some(callback) = &on_error {
+                        callback(&name, error);
+                    }
+                }
+            }
+        });
+
+        Self {
+            stop: Some(sender),
+            handle: Some(handle),
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl Drop for Timer {
+    fn drop(&mut self) {
+        // dropping the sender disconnects the channel, which stops the timer
+        let _ = self.stop.take();
+
+        if let Some(handle) = self.handle.take() {
+            handle.join().ok();
+        }
+    }
+}
+
+/// Represents a handle used to reload a specific configuration provider on demand.
+///
+/// # Remarks
+///
+/// Refreshing only reloads the providers built from the associated
+/// [`RefreshableConfigurationSource`] rather than every provider in the
+/// [`ConfigurationRoot`](crate::ConfigurationRoot).
+#[derive(Clone, Default)]
+pub struct RefreshHandle {
+    providers: Arc<RwLock<Vec<Weak<Inner>>>>,
+}
+
+impl RefreshHandle {
+    /// Reloads the associated configuration providers.
+    ///
+    /// # Remarks
+    ///
+    /// The reload token of each successfully reloaded provider is triggered. Providers
+    /// that have been dropped are ignored.
+    pub fn refresh(&self) -> LoadResult {
+        let mut providers = self.providers.write().unwrap();
+
+        providers.retain(|provider| provider.strong_count() > 0);
+
+        for provider in providers.iter().filter_map(Weak::upgrade) {
+            provider.refresh()?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Represents a [`ConfigurationProvider`](crate::ConfigurationProvider) that can be reloaded
+/// on demand with a [`RefreshHandle`].
+pub struct RefreshableConfigurationProvider {
+    inner: Arc<Inner>,
+
+    #[cfg(feature = "async")]
+    timer: Option<Timer>,
+}
+
+impl RefreshableConfigurationProvider {
+    /// Initializes a new refreshable configuration provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `inner` - The inner [`ConfigurationProvider`](crate::ConfigurationProvider) to refresh
+    /// * `handle` - The [`RefreshHandle`] used to refresh the provider
+    pub fn new(inner: Box<dyn ConfigurationProvider>, handle: &RefreshHandle) -> Self {
+        let inner = Arc::new(Inner {
+            provider: RwLock::new(inner),
+            token: Default::default(),
+        });
+
+        handle
+            .providers
+            .write()
+            .unwrap()
+            .push(Arc::downgrade(&inner));
+
+        Self {
+            inner,
+            #[cfg(feature = "async")]
+            timer: None,
+        }
+    }
+
+    /// Initializes a new configuration provider that is reloaded at a fixed interval.
+    ///
+    /// # Arguments
+    ///
+    /// * `inner` - The inner [`ConfigurationProvider`](crate::ConfigurationProvider) to refresh
+    /// * `handle` - The [`RefreshHandle`] used to refresh the provider
+    /// * `interval` - The interval at which the provider is reloaded
+    /// * `on_error` - The function invoked when the provider fails to reload, if any
+    ///
+    /// # Remarks
+    ///
+    /// The provider is reloaded on a background thread, which stops when the provider is
+    /// dropped. The reload token of the provider is triggered after each successful reload.
+    #[cfg(feature = "async")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub fn with_refresh_interval(
+        inner: Box<dyn ConfigurationProvider>,
+        handle: &RefreshHandle,
+        interval: Duration,
+        on_error: Option<ReloadErrorCallback>,
+    ) -> Self {
+        let mut provider = Self::new(inner, handle);
+        provider.timer = Some(Timer::start(&provider.inner, interval, on_error));
+        provider
+    }
+}
+
+impl ConfigurationProvider for RefreshableConfigurationProvider {
+    fn get(&self, key: &str) -> Option<Value> {
+        self.inner.provider.read().unwrap().get(key)
+    }
+
+    fn get_str(&self, key: &str) -> Option<ValueRef<'_>> {
+        self.get(key).map(ValueRef::Shared)
+    }
+
+    fn get_typed(&self, key: &str) -> Option<ConfigValue> {
+        self.inner.provider.read().unwrap().get_typed(key)
+    }
+
+    fn is_removed(&self, key: &str) -> bool {
+        self.inner.provider.read().unwrap().is_removed(key)
+    }
+
+    fn reload_token(&self) -> Box<dyn ChangeToken> {
+        let tokens: Vec<Box<dyn ChangeToken>> = vec![
+            self.inner.provider.read().unwrap().reload_token(),
+            Box::new(self.inner.token.read().unwrap().clone()),
+        ];
+
+        Box::new(CompositeChangeToken::new(tokens.into_iter()))
+    }
+
+    fn load(&mut self) -> LoadResult {
+        self.inner.provider.write().unwrap().load()
+    }
+
+    fn close(&mut self) {
+        #[cfg(feature = "async")]
+        {
+            self.timer = None;
+        }
+
+        self.inner.provider.write().unwrap().close()
+    }
+
+    fn save(&self) -> LoadResult {
+        self.inner.provider.read().unwrap().save()
+    }
+
+    fn try_iter(&self) -> Option<ConfigurationPairs<'_>> {
+        let pairs: Vec<_> = self.inner.provider.read().unwrap().try_iter()?.collect();
+        Some(Box::new(pairs.into_iter()))
+    }
+
+    fn child_keys(&self, earlier_keys: &mut Vec<String>, parent_path: Option<&str>) {
+        self.inner
+            .provider
+            .read()
+            .unwrap()
+            .child_keys(earlier_keys, parent_path)
+    }
+}
+
+/// Represents a [`ConfigurationSource`](crate::ConfigurationSource) whose providers can be
+/// reloaded on demand with a [`RefreshHandle`].
+pub struct RefreshableConfigurationSource {
+    inner: Box<dyn ConfigurationSource>,
+    handle: RefreshHandle,
+
+    #[cfg(feature = "async")]
+    interval: Option<Duration>,
+}
+
+impl RefreshableConfigurationSource {
+    /// Initializes a new refreshable configuration source.
+    ///
+    /// # Arguments
+    ///
+    /// * `inner` - The inner [`ConfigurationSource`](crate::ConfigurationSource) to refresh
+    pub fn new(inner: Box<dyn ConfigurationSource>) -> Self {
+        Self {
+            inner,
+            handle: Default::default(),
+            #[cfg(feature = "async")]
+            interval: None,
+        }
+    }
+
+    /// Reloads the providers built from this source at a fixed interval.
+    ///
+    /// # Arguments
+    ///
+    /// * `interval` - The interval at which the providers are reloaded
+    ///
+    /// # Remarks
+    ///
+    /// A provider that fails to reload retains its previous configuration and the error is
+    /// reported to the function registered with
+    /// [`DefaultConfigurationBuilder::on_reload_error`](crate::DefaultConfigurationBuilder::on_reload_error),
+    /// if any.
+    #[cfg(feature = "async")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub fn with_refresh_interval(mut self, interval: Duration) -> Self {
+        self.interval = Some(interval);
+        self
+    }
+
+    /// Gets the handle used to refresh the providers built from this source.
+    pub fn handle(&self) -> RefreshHandle {
+        self.handle.clone()
+    }
+}
+
+impl ConfigurationSource for RefreshableConfigurationSource {
+    fn build(&self, builder: &dyn ConfigurationBuilder) -> Box<dyn ConfigurationProvider> {
+        #[cfg(feature = "async")]
+        if let Some(interval) = self.interval {
+            let on_error = builder
+                .property::<ReloadErrorCallback>(RELOAD_ERROR_CALLBACK)
+                .cloned();
+
+            return Box::new(RefreshableConfigurationProvider::with_refresh_interval(
+                self.inner.build(builder),
+                &self.handle,
+                interval,
+                on_error,
+            ));
+        }
+
+        Box::new(RefreshableConfigurationProvider::new(
+            self.inner.build(builder),
+            &self.handle,
+        ))
+    }
+
+    fn priority(&self) -> i32 {
+        self.inner.priority()
+    }
+}
+
+pub mod ext {
+
+    use super::*;
+
+    /// Defines extension methods for [`ConfigurationBuilder`](crate::ConfigurationBuilder).
+    pub trait RefreshableConfigurationBuilderExtensions {
+        /// Adds a configuration source that can be reloaded on demand and returns the
+        /// [`RefreshHandle`] used to reload it.
+        ///
+        /// # Arguments
+        ///
+        /// * `source` - The [`ConfigurationSource`](crate::ConfigurationSource) to add
+        fn add_refreshable(&mut self, source: Box<dyn ConfigurationSource>) -> RefreshHandle;
+
+        /// Adds a configuration source that is reloaded at a fixed interval and returns the
+        /// [`RefreshHandle`] used to reload it on demand.
+        ///
+        /// # Arguments
+        ///
+        /// * `source` - The [`ConfigurationSource`](crate::ConfigurationSource) to add
+        /// * `interval` - The interval at which the source is reloaded
+        #[cfg(feature = "async")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+        fn add_with_refresh_interval(
+            &mut self,
+            source: Box<dyn ConfigurationSource>,
+            interval: Duration,
+        ) -> RefreshHandle;
+    }
+
+    impl RefreshableConfigurationBuilderExtensions for dyn ConfigurationBuilder + '_ {
+        fn add_refreshable(&mut self, source: Box<dyn ConfigurationSource>) -> RefreshHandle {
+            let source = RefreshableConfigurationSource::new(source);
+            let handle = source.handle();
+            self.add(Box::new(source));
+            handle
+        }
+
+        #[cfg(feature = "async")]
+        fn add_with_refresh_interval(
+            &mut self,
+            source: Box<dyn ConfigurationSource>,
+            interval: Duration,
+        ) -> RefreshHandle {
+            let source =
+                RefreshableConfigurationSource::new(source).with_refresh_interval(interval);
+            let handle = source.handle();
+            self.add(Box::new(source));
+            handle
+        }
+    }
+
+    impl<T: ConfigurationBuilder> RefreshableConfigurationBuilderExtensions for T {
+        fn add_refreshable(&mut self, source: Box<dyn ConfigurationSource>) -> RefreshHandle {
+            let source = RefreshableConfigurationSource::new(source);
+            let handle = source.handle();
+            self.add(Box::new(source));
+            handle
+        }
+
+        #[cfg(feature = "async")]
+        fn add_with_refresh_interval(
+            &mut self,
+            source: Box<dyn ConfigurationSource>,
+            interval: Duration,
+        ) -> RefreshHandle {
+            let source =
+                RefreshableConfigurationSource::new(source).with_refresh_interval(interval);
+            let handle = source.handle();
+            self.add(Box::new(source));
+            handle
+        }
+    }
+}
@@ -0,0 +1,171 @@
+use crate::{
+    ext::*, Configuration, ConfigurationPath, ConfigurationRoot, ConfigurationSection, Value,
+};
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FormatResult};
+
+/// Defines the possible configuration errors.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConfigError {
+    /// Indicates a required configuration key or section was not found.
+    Missing {
+        /// Gets the full path of the missing key or section.
+        path: String,
+
+        /// Gets the names of the providers that were consulted, if known.
+        providers: Vec<String>,
+    },
+
+    /// Indicates a configuration value is invalid.
+    Invalid {
+        /// Gets the full path of the invalid value.
+        path: String,
+
+        /// Gets the invalid value.
+        value: String,
+
+        /// Gets the message that describes why the value is invalid.
+        message: String,
+    },
+}
+
+impl ConfigError {
+    /// Gets the full path of the configuration key or section the error applies to.
+    pub fn path(&self) -> &str {
+        match self {
+            Self::Missing { path, .. } | Self::Invalid { path, .. } => path,
+        }
+    }
+}
+
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FormatResult {
+        match self {
+            Self::Missing { path, providers } => {
+                write!(
+                    f,
+                    "The required configuration key '{}' was not found.",
+                    path
+                )?;
+
+                if !providers.is_empty() {
+                    write!(f, " Providers consulted: {}.", providers.join(", "))?;
+                }
+
+                Ok(())
+            }
+            Self::Invalid {
+                path,
+                value,
+                message,
+            } => write!(
+                f,
+                "The value '{}' of configuration key '{}' is invalid: {}.",
+                value, path, message
+            ),
+        }
+    }
+}
+
+impl Error for ConfigError {}
+
+pub(crate) fn full_path(configuration: &dyn Configuration, key: &str) -> String {
+    match configuration.as_section() {
+        Some(section) => ConfigurationPath::combine(&[section.path(), key]),
+        _ => key.to_owned(),
+    }
+}
+
+fn get_required(
+    configuration: &dyn Configuration,
+    key: &str,
+    providers: impl FnOnce() -> Vec<String>,
+) -> Result<Value, ConfigError> {
+    configuration.get(key).ok_or_else(|| ConfigError::Missing {
+        path: full_path(configuration, key),
+        providers: providers(),
+    })
+}
+
+fn required_section(
+    configuration: &dyn Configuration,
+    key: &str,
+    providers: impl FnOnce() -> Vec<String>,
+) -> Result<Box<dyn ConfigurationSection>, ConfigError> {
+    let section = configuration.section(key);
+
+    if section.exists() {
+        Ok(section)
+    } else {
+        Err(ConfigError::Missing {
+            path: full_path(configuration, key),
+            providers: providers(),
+        })
+    }
+}
+
+fn provider_names(root: &dyn ConfigurationRoot) -> Vec<String> {
+    root.providers().map(|p| p.name().to_owned()).collect()
+}
+
+pub mod ext {
+
+    use super::*;
+
+    /// Defines extension methods for required [`Configuration`](crate::Configuration) values.
+    pub trait RequiredConfigurationExtensions {
+        /// Gets the configuration value with the specified key or an error if it does not exist.
+        ///
+        /// # Arguments
+        ///
+        /// * `key` - The key of the value to retrieve
+        fn get_required(&self, key: &str) -> Result<Value, ConfigError>;
+
+        /// Gets the configuration section with the specified key or an error if it does not exist.
+        ///
+        /// # Arguments
+        ///
+        /// * `key` - The key of the section to retrieve
+        fn required_section(&self, key: &str)
+            -> Result<Box<dyn ConfigurationSection>, ConfigError>;
+    }
+
+    impl RequiredConfigurationExtensions for dyn Configuration + '_ {
+        fn get_required(&self, key: &str) -> Result<Value, ConfigError> {
+            super::get_required(self, key, Vec::new)
+        }
+
+        fn required_section(
+            &self,
+            key: &str,
+        ) -> Result<Box<dyn ConfigurationSection>, ConfigError> {
+            super::required_section(self, key, Vec::new)
+        }
+    }
+
+    impl RequiredConfigurationExtensions for dyn ConfigurationRoot + '_ {
+        fn get_required(&self, key: &str) -> Result<Value, ConfigError> {
+            super::get_required(self.deref(), key, || provider_names(self))
+        }
+
+        fn required_section(
+            &self,
+            key: &str,
+        ) -> Result<Box<dyn ConfigurationSection>, ConfigError> {
+            super::required_section(self.deref(), key, || provider_names(self))
+        }
+    }
+
+    impl<T: ConfigurationRoot> RequiredConfigurationExtensions for T {
+        fn get_required(&self, key: &str) -> Result<Value, ConfigError> {
+            super::get_required(self.deref(), key, || provider_names(self))
+        }
+
+        fn required_section(
+            &self,
+            key: &str,
+        ) -> Result<Box<dyn ConfigurationSection>, ConfigError> {
+            super::required_section(self.deref(), key, || provider_names(self))
+        }
+    }
+}
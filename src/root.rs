@@ -1,5 +1,12 @@
-use crate::{Configuration, ConfigurationProvider, LoadError};
-use std::fmt::{Debug, Formatter, Result as FormatResult};
+use crate::{
+    Configuration, ConfigurationBuilder, ConfigurationOverlay, ConfigurationProvider,
+    ConfigurationTransaction, FrozenConfiguration, LoadError,
+};
+use std::any::type_name;
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter, Result as FormatResult};
+use std::sync::Arc;
+use std::time::SystemTime;
 use std::{borrow::Borrow, ops::Deref};
 
 /// Defines the possible reload errors.
@@ -9,8 +16,9 @@ pub enum ReloadError {
     Provider(Vec<(String, LoadError)>),
 
     /// Indicates reload cannot be performed because there
-    /// are borrowed references. The number of references
-    /// may be reported if known.
+    /// are borrowed references, such as when a configuration
+    /// is reloaded while it is being read on the same thread.
+    /// The number of references may be reported if known.
     Borrowed(Option<usize>),
 }
 
@@ -43,9 +51,53 @@ impl Debug for ReloadError {
     }
 }
 
+impl Display for ReloadError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FormatResult {
+        match self {
+            Self::Provider(errors) => {
+                if errors.len() == 1 {
+                    write!(f, "{} ({})", &errors[0].1, &errors[0].0)
+                } else {
+                    f.write_str("One or more load errors occurred:")?;
+
+                    for (i, (provider, error)) in errors.iter().enumerate() {
+                        write!(f, "\n  [{}]: {} ({})", (i + 1), error, provider)?;
+                    }
+
+                    Ok(())
+                }
+            }
+            Self::Borrowed(_) => Debug::fmt(self, f),
+        }
+    }
+}
+
+impl Error for ReloadError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Provider(errors) if errors.len() == 1 => Some(&errors[0].1),
+            _ => None,
+        }
+    }
+}
+
 /// Represents a configuration reload result.
 pub type ReloadResult = std::result::Result<(), ReloadError>;
 
+/// Represents a record of a configuration value that was read.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AccessRecord {
+    /// Gets the key of the value that was read.
+    pub key: String,
+
+    /// Gets the name of the [`ConfigurationProvider`](crate::ConfigurationProvider) that
+    /// resolved the value, if any.
+    pub provider: Option<String>,
+
+    /// Gets the time the value was read.
+    pub time: SystemTime,
+}
+
 /// Represents the root of a [`Configuration`](crate::Configuration) hierarchy.
 pub trait ConfigurationRoot:
     Configuration
@@ -56,13 +108,127 @@ pub trait ConfigurationRoot:
 {
     /// Force the configuration values to be reloaded from the underlying
     /// [`ConfigurationProvider`](crate::ConfigurationProvider) collection.
-    fn reload(&mut self) -> ReloadResult;
+    ///
+    /// # Remarks
+    ///
+    /// A reload does not require exclusive access to the configuration, so it can be performed
+    /// while sections and other references to the configuration are alive. A reload waits for
+    /// any reads in progress and each subsequent read observes the reloaded values.
+    fn reload(&self) -> ReloadResult;
+
+    /// Replaces the configuration providers with the providers built from the sources of a
+    /// builder.
+    ///
+    /// # Arguments
+    ///
+    /// * `builder` - The [`ConfigurationBuilder`](crate::ConfigurationBuilder) with the new sources
+    ///
+    /// # Remarks
+    ///
+    /// The providers are only replaced if every new provider loads successfully, after which the
+    /// [`reload_token`](crate::Configuration::reload_token) of the configuration is signaled.
+    /// Only the sources of the builder are used; the settings of the configuration, such as key
+    /// aliases, are retained. The default implementation does not support replacing providers and
+    /// always returns an error.
+    fn replace_sources(&self, builder: &dyn ConfigurationBuilder) -> ReloadResult {
+        let _ = builder;
+        Err(ReloadError::Provider(vec![(
+            type_name::<Self>().to_owned(),
+            LoadError::Generic("The configuration does not support replacing its sources.".into()),
+        )]))
+    }
 
     /// Gets the [`ConfigurationProvider`](crate::ConfigurationProvider) sequence for this configuration.
     fn providers(&self) -> Box<dyn ConfigurationProviderIterator + '_>;
 
     /// Converts the [`ConfigurationRoot`] into a [`Configuration`](crate::Configuration).
     fn as_config(&self) -> Box<dyn Configuration>;
+
+    /// Ensures that every [`ConfigurationProvider`](crate::ConfigurationProvider) has been loaded.
+    ///
+    /// # Remarks
+    ///
+    /// The default implementation does nothing because providers are loaded when the
+    /// configuration is built. A configuration whose providers are loaded on first access
+    /// loads any remaining providers and reports their errors.
+    fn ensure_loaded(&self) -> ReloadResult {
+        Ok(())
+    }
+
+    /// Populates any internal caches, such as the child keys of every section, ahead of time.
+    ///
+    /// # Remarks
+    ///
+    /// Prewarming moves the cost of the first reads to a well-known point, such as application
+    /// startup, and does not count as reading any configuration value. The caches are populated
+    /// again on demand after a reload. The default implementation does nothing.
+    fn prewarm(&self) {}
+
+    /// Stops watching for changes in every [`ConfigurationProvider`](crate::ConfigurationProvider)
+    /// and releases their background resources, such as file watchers and reload threads.
+    ///
+    /// # Remarks
+    ///
+    /// Closing is deterministic, so it is typically used before unmounting a directory or during
+    /// application shutdown. The configuration values remain available, but are no longer
+    /// reloaded automatically. The default implementation does nothing.
+    fn close(&self) {}
+
+    /// Gets the keys of the configuration values that have never been read.
+    ///
+    /// # Remarks
+    ///
+    /// A key that has never been read often indicates a typo, such as `Databse:Url`, that
+    /// causes a value to silently fall back to its default. The default implementation does
+    /// not track which values are read and always returns an empty list.
+    fn unused_keys(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Gets the record of every configuration value read, in the order the values were read.
+    ///
+    /// # Remarks
+    ///
+    /// Recording is opt-in because every read is retained. The default implementation does not
+    /// record reads and always returns an empty list.
+    fn access_log(&self) -> Vec<AccessRecord> {
+        Vec::new()
+    }
+
+    /// Gets the overlay used to override configuration values at runtime, if supported.
+    ///
+    /// # Remarks
+    ///
+    /// The values set or removed with the [`ConfigurationOverlay`](crate::ConfigurationOverlay)
+    /// take precedence over all providers. The default implementation does not support an
+    /// overlay and always returns `None`.
+    fn overlay(&self) -> Option<ConfigurationOverlay> {
+        None
+    }
+
+    /// Creates an immutable snapshot of the current configuration.
+    ///
+    /// # Remarks
+    ///
+    /// The [`FrozenConfiguration`](crate::FrozenConfiguration) is not affected by subsequent
+    /// reloads and does not require any locking to read a value, which makes it well-suited
+    /// for hot paths that do not need reload semantics.
+    fn snapshot(&self) -> FrozenConfiguration {
+        FrozenConfiguration::new(self.deref())
+    }
+
+    /// Converts the [`ConfigurationRoot`] into a [`Configuration`](crate::Configuration) that
+    /// can be shared across threads.
+    ///
+    /// # Remarks
+    ///
+    /// The default implementation shares a [`snapshot`](ConfigurationRoot::snapshot) of the
+    /// configuration, which does not reload. A configuration that is safe to share across
+    /// threads, such as [`DefaultConfigurationRoot`](crate::DefaultConfigurationRoot) with
+    /// the **async** feature, shares itself instead.
+    fn into_shared(self: Box<Self>) -> Arc<dyn Configuration + Send + Sync> {
+        Arc::new(self.snapshot())
+    }
 }
 
 /// Defines the behavior of an iterator over a
@@ -73,3 +239,46 @@ pub trait ConfigurationProviderIterator<'a>:
     + DoubleEndedIterator<Item = Box<dyn ConfigurationProvider + 'a>>
 {
 }
+
+pub mod ext {
+
+    use super::*;
+
+    /// Defines extension methods for [`ConfigurationRoot`].
+    pub trait ConfigurationRootExtensions {
+        /// Applies several configuration changes at once.
+        ///
+        /// # Arguments
+        ///
+        /// * `action` - The function that makes the changes with a [`ConfigurationTransaction`]
+        ///
+        /// # Remarks
+        ///
+        /// The changes are written through the [overlay](ConfigurationRoot::overlay) of the
+        /// configuration atomically and the reload token is triggered once. Returns `false` if
+        /// the configuration does not support an overlay.
+        fn transaction<F: FnOnce(&mut ConfigurationTransaction<'_>)>(&self, action: F) -> bool;
+    }
+
+    impl ConfigurationRootExtensions for dyn ConfigurationRoot + '_ {
+        fn transaction<F: FnOnce(&mut ConfigurationTransaction<'_>)>(&self, action: F) -> bool {
+            if let Some(overlay) = self.overlay() {
+                overlay.transaction(action);
+                true
+            } else {
+                false
+            }
+        }
+    }
+
+    impl<T: ConfigurationRoot> ConfigurationRootExtensions for T {
+        fn transaction<F: FnOnce(&mut ConfigurationTransaction<'_>)>(&self, action: F) -> bool {
+            if let Some(overlay) = self.overlay() {
+                overlay.transaction(action);
+                true
+            } else {
+                false
+            }
+        }
+    }
+}
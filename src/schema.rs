@@ -0,0 +1,793 @@
+use crate::{ext::*, Configuration, ConfigurationPath, ConfigurationSection};
+use serde::de::{
+    self, DeserializeOwned, DeserializeSeed, EnumAccess, MapAccess, SeqAccess, VariantAccess,
+    Visitor,
+};
+use serde_json::{json, Map, Value as Json};
+use std::fmt::{self, Display, Formatter};
+
+/// Represents the type of a configuration value in a [`ConfigurationSchema`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum SchemaType {
+    /// Indicates a value of any type.
+    Any,
+
+    /// Indicates a boolean value.
+    Boolean,
+
+    /// Indicates an integral value.
+    Integer,
+
+    /// Indicates a numeric value.
+    Number,
+
+    /// Indicates a text value.
+    String,
+
+    /// Indicates a text value that must be one of the specified variants.
+    Enum(Vec<String>),
+
+    /// Indicates a sequence of values with the specified element type.
+    Array(Box<SchemaType>),
+
+    /// Indicates a section of arbitrary keys with the specified value type.
+    Map(Box<SchemaType>),
+
+    /// Indicates a section with the specified properties.
+    Object(Vec<SchemaProperty>),
+}
+
+impl SchemaType {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Any => "any",
+            Self::Boolean => "boolean",
+            Self::Integer => "integer",
+            Self::Number => "number",
+            Self::String => "string",
+            Self::Enum(_) => "enum",
+            Self::Array(_) => "array",
+            Self::Map(_) | Self::Object(_) => "object",
+        }
+    }
+
+    fn to_json(&self) -> Json {
+        match self {
+            Self::Any => json!({}),
+            Self::Enum(variants) => json!({ "type": "string", "enum": variants }),
+            Self::Array(items) => json!({ "type": "array", "items": items.to_json() }),
+            Self::Map(values) => json!({
+                "type": "object",
+                "additionalProperties": values.to_json()
+            }),
+            Self::Object(properties) => {
+                let mut map = Map::new();
+                let required: Vec<_> = properties
+                    .iter()
+                    .filter(|p| p.required)
+                    .map(|p| p.name.clone())
+                    .collect();
+
+                for property in properties {
+                    map.insert(property.name.clone(), property.kind.to_json());
+                }
+
+                json!({
+                    "type": "object",
+                    "properties": map,
+                    "required": required,
+                    "additionalProperties": false
+                })
+            }
+            other => json!({ "type": other.name() }),
+        }
+    }
+
+    // unsupported keywords are ignored and an unknown type is treated as any type
+    fn from_json(json: &Json) -> Self {
+        if let Some(variants) = json.get("enum").and_then(Json::as_array) {
+            return Self::Enum(
+                variants
+                    .iter()
+                    .map(|variant| match variant {
+                        Json::String(text) => text.clone(),
+                        other => other.to_string(),
+                    })
+                    .collect(),
+            );
+        }
+
+        // a nullable type, such as ["string", "null"], is described by its other type
+        let kind = match json.get("type") {
+            Some(Json::String(kind)) => kind.as_str(),
+            Some(Json::Array(kinds)) => kinds
+                .iter()
+                .filter_map(Json::as_str)
+                .find(|kind| *kind != "null")
+                .unwrap_or_default(),
+            _ => "",
+        };
+
+        match kind {
+            "boolean" => Self::Boolean,
+            "integer" => Self::Integer,
+            "number" => Self::Number,
+            "string" => Self::String,
+            "array" => Self::Array(Box::new(
+                json.get("items").map(Self::from_json).unwrap_or(Self::Any),
+            )),
+            "object" => {
+                if let Some(properties) = json.get("properties").and_then(Json::as_object) {
+                    let required: Vec<_> = json
+                        .get("required")
+                        .and_then(Json::as_array)
+                        .map(|names| names.iter().filter_map(Json::as_str).collect())
+                        .unwrap_or_default();
+
+                    Self::Object(
+                        properties
+                            .iter()
+                            .map(|(name, kind)| SchemaProperty {
+                                name: name.clone(),
+                                kind: Self::from_json(kind),
+                                required: required.contains(&name.as_str()),
+                            })
+                            .collect(),
+                    )
+                } else {
+                    Self::Map(Box::new(
+                        json.get("additionalProperties")
+                            .filter(|values| values.is_object())
+                            .map(Self::from_json)
+                            .unwrap_or(Self::Any),
+                    ))
+                }
+            }
+            _ => Self::Any,
+        }
+    }
+}
+
+/// Represents a property of an object in a [`ConfigurationSchema`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct SchemaProperty {
+    /// Gets the name of the property.
+    pub name: String,
+
+    /// Gets the type of the property.
+    pub kind: SchemaType,
+
+    /// Gets a value indicating whether the property is required.
+    pub required: bool,
+}
+
+/// Represents a value whose type does not match a [`ConfigurationSchema`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct TypeMismatch {
+    /// Gets the full path of the mismatched value.
+    pub key: String,
+
+    /// Gets the name of the expected type.
+    pub expected: String,
+
+    /// Gets the configured value.
+    pub value: String,
+}
+
+/// Represents the result of validating a configuration against a [`ConfigurationSchema`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SchemaReport {
+    /// Gets the full paths of configured keys that are not defined by the schema.
+    pub unknown_keys: Vec<String>,
+
+    /// Gets the full paths of required keys that are not configured.
+    pub missing_keys: Vec<String>,
+
+    /// Gets the configured values whose type does not match the schema.
+    pub mismatches: Vec<TypeMismatch>,
+}
+
+impl SchemaReport {
+    /// Gets a value indicating whether the configuration is valid.
+    pub fn is_valid(&self) -> bool {
+        self.unknown_keys.is_empty() && self.missing_keys.is_empty() && self.mismatches.is_empty()
+    }
+}
+
+/// Represents the schema of a configuration bound to a strongly-typed value.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConfigurationSchema {
+    root: SchemaType,
+}
+
+impl ConfigurationSchema {
+    /// Initializes a new configuration schema.
+    ///
+    /// # Arguments
+    ///
+    /// * `root` - The [`SchemaType`] of the configuration root
+    pub fn new(root: SchemaType) -> Self {
+        Self { root }
+    }
+
+    /// Creates a configuration schema from the specified deserializable type.
+    ///
+    /// # Remarks
+    ///
+    /// The schema is extracted by observing how the type deserializes itself. A field is
+    /// required unless it is an [`Option`] or has a default value. A structure with
+    /// `#[serde(flatten)]` fields is represented as a section of arbitrary keys. Types that
+    /// reject the placeholder values used during extraction are only partially described.
+    pub fn of<T: DeserializeOwned>() -> Self {
+        let mut root = SchemaType::Any;
+        let _ = T::deserialize(Tracer::new(&mut root, String::new(), None));
+        mark_required::<T>(&mut root, String::new());
+        Self { root }
+    }
+
+    /// Gets the type of the configuration root.
+    pub fn root(&self) -> &SchemaType {
+        &self.root
+    }
+
+    /// Validates the specified configuration against the schema.
+    ///
+    /// # Arguments
+    ///
+    /// * `configuration` - The [`Configuration`](crate::Configuration) to validate
+    ///
+    /// # Remarks
+    ///
+    /// Keys are compared to property names the same way they are matched when binding.
+    pub fn validate(&self, configuration: &dyn Configuration) -> SchemaReport {
+        let mut report = SchemaReport::default();
+        let path = configuration
+            .as_section()
+            .map(|section| section.path().to_owned())
+            .unwrap_or_default();
+
+        validate_children(&self.root, configuration, &path, &mut report);
+        report
+    }
+
+    /// Generates a JSON schema document that describes the configuration.
+    pub fn to_json_schema(&self) -> String {
+        let mut schema = self.root.to_json();
+
+        if let Json::Object(map) = &mut schema {
+            map.insert(
+                "$schema".into(),
+                "http://json-schema.org/draft-07/schema#".into(),
+            );
+        }
+
+        serde_json::to_string_pretty(&schema).unwrap_or_default()
+    }
+
+    /// Creates a configuration schema from the specified JSON schema document.
+    ///
+    /// # Arguments
+    ///
+    /// * `schema` - The JSON schema document
+    ///
+    /// # Remarks
+    ///
+    /// Only the keywords produced by [`to_json_schema`](ConfigurationSchema::to_json_schema) are
+    /// supported. Other keywords are ignored and a value without a supported type can be of any
+    /// type.
+    pub fn from_json_schema(schema: &str) -> Result<Self, serde_json::Error> {
+        let json: Json = serde_json::from_str(schema)?;
+        Ok(Self::new(SchemaType::from_json(&json)))
+    }
+}
+
+fn combine(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_owned()
+    } else {
+        ConfigurationPath::combine(&[path, key])
+    }
+}
+
+// a field is required if omitting it, and only it, fails with a missing field error
+fn mark_required<T: DeserializeOwned>(kind: &mut SchemaType, path: String) {
+    match kind {
+        SchemaType::Object(properties) => {
+            for property in properties {
+                let path = combine(&path, &property.name);
+                let mut ignored = SchemaType::Any;
+                let omit = Some(path.as_str());
+
+                property.required = matches!(
+                    T::deserialize(Tracer::new(&mut ignored, String::new(), omit)),
+                    Err(TraceError::Missing)
+                );
+                mark_required::<T>(&mut property.kind, path);
+            }
+        }
+        SchemaType::Array(items) => mark_required::<T>(items, combine(&path, "0")),
+        SchemaType::Map(values) => mark_required::<T>(values, combine(&path, "")),
+        _ => {}
+    }
+}
+
+fn validate_children(
+    kind: &SchemaType,
+    configuration: &dyn Configuration,
+    path: &str,
+    report: &mut SchemaReport,
+) {
+    match kind {
+        SchemaType::Object(properties) => {
+            for child in configuration.children() {
+                if !properties.iter().any(|p| p.name == child.key()) {
+                    report.unknown_keys.push(child.path().to_owned());
+                }
+            }
+
+            for property in properties {
+                let section = configuration.section(&property.name);
+
+                if section.exists() {
+                    validate(&property.kind, &*section, report);
+                } else if property.required {
+                    report.missing_keys.push(combine(path, &property.name));
+                }
+            }
+        }
+        SchemaType::Array(items) => {
+            for child in configuration.children() {
+                if child.key().parse::<usize>().is_err() {
+                    report.unknown_keys.push(child.path().to_owned());
+                } else {
+                    validate(items, &*child, report);
+                }
+            }
+        }
+        SchemaType::Map(values) => {
+            for child in configuration.children() {
+                validate(values, &*child, report);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn validate(kind: &SchemaType, section: &dyn ConfigurationSection, report: &mut SchemaReport) {
+    let children = section.children();
+    let value = section.value();
+    let valid = match kind {
+        SchemaType::Any => true,
+        SchemaType::Boolean => children.is_empty() && value.parse::<bool>().is_ok(),
+        SchemaType::Integer => {
+            children.is_empty() && (value.parse::<i64>().is_ok() || value.parse::<u64>().is_ok())
+        }
+        SchemaType::Number => children.is_empty() && value.parse::<f64>().is_ok(),
+        SchemaType::String => children.is_empty(),
+        SchemaType::Enum(variants) => {
+            if children.is_empty() {
+                variants.iter().any(|v| v == value.as_str())
+            } else {
+                children.len() == 1 && variants.iter().any(|v| v == children[0].key())
+            }
+        }
+        SchemaType::Array(_) | SchemaType::Map(_) | SchemaType::Object(_) => {
+            if children.is_empty() && !value.is_empty() {
+                false
+            } else {
+                validate_children(kind, section.deref(), section.path(), report);
+                true
+            }
+        }
+    };
+
+    if !valid {
+        report.mismatches.push(TypeMismatch {
+            key: section.path().to_owned(),
+            expected: kind.name().to_owned(),
+            value: value.to_string(),
+        });
+    }
+}
+
+#[derive(Debug)]
+enum TraceError {
+    Missing,
+    Custom(String),
+}
+
+impl de::Error for TraceError {
+    fn custom<T: Display>(message: T) -> Self {
+        Self::Custom(message.to_string())
+    }
+
+    fn missing_field(_field: &'static str) -> Self {
+        Self::Missing
+    }
+}
+
+impl Display for TraceError {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Missing => formatter.write_str("missing field"),
+            Self::Custom(message) => formatter.write_str(message),
+        }
+    }
+}
+
+impl std::error::Error for TraceError {}
+
+// records the shape of a type as it deserializes itself from placeholder values
+struct Tracer<'a, 'b> {
+    kind: &'a mut SchemaType,
+    path: String,
+    omit: Option<&'b str>,
+}
+
+impl<'a, 'b> Tracer<'a, 'b> {
+    fn new(kind: &'a mut SchemaType, path: String, omit: Option<&'b str>) -> Self {
+        Self { kind, path, omit }
+    }
+}
+
+macro_rules! trace_values {
+    ($($method:ident => $kind:ident, $visit:ident($value:expr),)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+                *self.kind = SchemaType::$kind;
+                visitor.$visit($value)
+            }
+        )*
+    }
+}
+
+impl<'de, 'a, 'b> de::Deserializer<'de> for Tracer<'a, 'b> {
+    type Error = TraceError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        *self.kind = SchemaType::Any;
+        visitor.visit_unit()
+    }
+
+    trace_values! {
+        deserialize_bool => Boolean, visit_bool(false),
+        deserialize_i8 => Integer, visit_i8(0),
+        deserialize_i16 => Integer, visit_i16(0),
+        deserialize_i32 => Integer, visit_i32(0),
+        deserialize_i64 => Integer, visit_i64(0),
+        deserialize_u8 => Integer, visit_u8(0),
+        deserialize_u16 => Integer, visit_u16(0),
+        deserialize_u32 => Integer, visit_u32(0),
+        deserialize_u64 => Integer, visit_u64(0),
+        deserialize_f32 => Number, visit_f32(0.0),
+        deserialize_f64 => Number, visit_f64(0.0),
+        deserialize_char => String, visit_char(' '),
+        deserialize_str => String, visit_str(""),
+        deserialize_string => String, visit_string(String::new()),
+        deserialize_bytes => String, visit_bytes(&[]),
+        deserialize_byte_buf => String, visit_byte_buf(Vec::new()),
+        deserialize_identifier => String, visit_str(""),
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        *self.kind = SchemaType::Any;
+        visitor.visit_unit()
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.deserialize_unit(visitor)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        self.deserialize_tuple(1, visitor)
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        self,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        *self.kind = SchemaType::Array(Box::new(SchemaType::Any));
+
+        if let SchemaType::Array(items) = self.kind {
+            visitor.visit_seq(Elements {
+                kind: items,
+                path: combine(&self.path, "0"),
+                omit: self.omit,
+                remaining: len,
+            })
+        } else {
+            unreachable!()
+        }
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.deserialize_tuple(len, visitor)
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        *self.kind = SchemaType::Map(Box::new(SchemaType::Any));
+
+        if let SchemaType::Map(values) = self.kind {
+            visitor.visit_map(Entries {
+                kind: values,
+                path: combine(&self.path, ""),
+                omit: self.omit,
+                remaining: 1,
+            })
+        } else {
+            unreachable!()
+        }
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        // std::time::{Duration, SystemTime} are bound from text; see crate::de
+        if let ("Duration", ["secs", "nanos"])
+        | ("SystemTime", ["secs_since_epoch", "nanos_since_epoch"]) = (name, fields)
+        {
+            *self.kind = SchemaType::String;
+            let mut ignored = SchemaType::Any;
+            return visitor.visit_map(Fields {
+                properties: &mut Vec::new(),
+                fields,
+                ignored: &mut ignored,
+                path: self.path,
+                omit: None,
+                index: 0,
+            });
+        }
+
+        let properties = fields
+            .iter()
+            .map(|name| SchemaProperty {
+                name: (*name).to_owned(),
+                kind: SchemaType::Any,
+                required: true,
+            })
+            .collect();
+
+        *self.kind = SchemaType::Object(properties);
+
+        if let SchemaType::Object(properties) = self.kind {
+            let mut ignored = SchemaType::Any;
+            visitor.visit_map(Fields {
+                properties,
+                fields,
+                ignored: &mut ignored,
+                path: self.path,
+                omit: self.omit,
+                index: 0,
+            })
+        } else {
+            unreachable!()
+        }
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        *self.kind = SchemaType::Enum(variants.iter().map(|v| (*v).to_owned()).collect());
+        visitor.visit_enum(Variant(variants.first().copied().unwrap_or_default()))
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        self.deserialize_any(visitor)
+    }
+}
+
+struct Elements<'a, 'b> {
+    kind: &'a mut SchemaType,
+    path: String,
+    omit: Option<&'b str>,
+    remaining: usize,
+}
+
+impl<'de, 'a, 'b> SeqAccess<'de> for Elements<'a, 'b> {
+    type Error = TraceError;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Self::Error> {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+
+        self.remaining -= 1;
+        let tracer = Tracer::new(self.kind, self.path.clone(), self.omit);
+        seed.deserialize(tracer).map(Some)
+    }
+}
+
+struct Entries<'a, 'b> {
+    kind: &'a mut SchemaType,
+    path: String,
+    omit: Option<&'b str>,
+    remaining: usize,
+}
+
+impl<'de, 'a, 'b> MapAccess<'de> for Entries<'a, 'b> {
+    type Error = TraceError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Self::Error> {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+
+        self.remaining -= 1;
+        let mut ignored = SchemaType::Any;
+        seed.deserialize(Tracer::new(&mut ignored, String::new(), None))
+            .map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, Self::Error> {
+        seed.deserialize(Tracer::new(self.kind, self.path.clone(), self.omit))
+    }
+}
+
+struct Fields<'a, 'b> {
+    properties: &'a mut Vec<SchemaProperty>,
+    fields: &'static [&'static str],
+    ignored: &'a mut SchemaType,
+    path: String,
+    omit: Option<&'b str>,
+    index: usize,
+}
+
+impl<'a, 'b> Fields<'a, 'b> {
+    fn field_path(&self) -> String {
+        combine(&self.path, self.fields[self.index])
+    }
+}
+
+impl<'de, 'a, 'b> MapAccess<'de> for Fields<'a, 'b> {
+    type Error = TraceError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Self::Error> {
+        if self.index < self.fields.len() && self.omit == Some(self.field_path().as_str()) {
+            self.index += 1;
+        }
+
+        if self.index < self.fields.len() {
+            let field = self.fields[self.index];
+            seed.deserialize(de::value::BorrowedStrDeserializer::new(field))
+                .map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, Self::Error> {
+        let path = self.field_path();
+        let index = self.index;
+        self.index += 1;
+        let kind = match self.properties.get_mut(index) {
+            Some(property) => &mut property.kind,
+            _ => &mut *self.ignored,
+        };
+
+        seed.deserialize(Tracer::new(kind, path, self.omit))
+    }
+}
+
+struct Variant(&'static str);
+
+impl<'de> EnumAccess<'de> for Variant {
+    type Error = TraceError;
+    type Variant = Self;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, Self::Variant), Self::Error> {
+        let value = seed.deserialize(de::value::BorrowedStrDeserializer::new(self.0))?;
+        Ok((value, self))
+    }
+}
+
+impl<'de> VariantAccess<'de> for Variant {
+    type Error = TraceError;
+
+    fn unit_variant(self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(
+        self,
+        seed: T,
+    ) -> Result<T::Value, Self::Error> {
+        let mut ignored = SchemaType::Any;
+        seed.deserialize(Tracer::new(&mut ignored, String::new(), None))
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(
+        self,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        let mut ignored = SchemaType::Any;
+        de::Deserializer::deserialize_tuple(
+            Tracer::new(&mut ignored, String::new(), None),
+            len,
+            visitor,
+        )
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        let mut ignored = SchemaType::Any;
+        de::Deserializer::deserialize_struct(
+            Tracer::new(&mut ignored, String::new(), None),
+            "",
+            fields,
+            visitor,
+        )
+    }
+}
+
+pub mod ext {
+
+    use super::*;
+
+    /// Defines schema extension methods for [`Configuration`](crate::Configuration).
+    pub trait SchemaExtensions {
+        /// Validates the configuration against the schema of the specified type.
+        fn validate_against<T: DeserializeOwned>(&self) -> SchemaReport;
+    }
+
+    impl SchemaExtensions for dyn Configuration + '_ {
+        fn validate_against<T: DeserializeOwned>(&self) -> SchemaReport {
+            ConfigurationSchema::of::<T>().validate(self)
+        }
+    }
+
+    impl<C: Configuration> SchemaExtensions for C {
+        fn validate_against<T: DeserializeOwned>(&self) -> SchemaReport {
+            ConfigurationSchema::of::<T>().validate(self)
+        }
+    }
+}
@@ -0,0 +1,248 @@
+use crate::{
+    util::ConfigurationData, ConfigValue, ConfigurationProvider, ConfigurationRoot,
+    ConfigurationSection, DefaultConfigurationRoot, Value,
+};
+use std::ops::Deref;
+use std::sync::{Arc, RwLock};
+use tokens::{ChangeToken, SharedChangeToken, SingleChangeToken};
+
+// provides the merged values of a section with keys relative to the section
+struct SectionProvider {
+    section: Box<dyn ConfigurationSection>,
+}
+
+impl ConfigurationProvider for SectionProvider {
+    fn name(&self) -> &str {
+        self.section.path()
+    }
+
+    fn get(&self, key: &str) -> Option<Value> {
+        self.section.get(key)
+    }
+
+    fn get_typed(&self, key: &str) -> Option<ConfigValue> {
+        self.section.get_typed(key)
+    }
+
+    fn reload_token(&self) -> Box<dyn ChangeToken> {
+        self.section.reload_token()
+    }
+
+    fn child_keys(&self, earlier_keys: &mut Vec<String>, parent_path: Option<&str>) {
+        let children = match parent_path {
+            Some(path) => self.section.section(path).children(),
+            _ => self.section.children(),
+        };
+
+        earlier_keys.extend(children.iter().map(|child| child.key().to_owned()));
+    }
+}
+
+// provides the merged values of the configuration that is scoped
+struct RootProvider {
+    root: Arc<dyn ConfigurationRoot>,
+}
+
+impl ConfigurationProvider for RootProvider {
+    fn name(&self) -> &str {
+        "Base"
+    }
+
+    fn get(&self, key: &str) -> Option<Value> {
+        self.root.get(key)
+    }
+
+    fn get_typed(&self, key: &str) -> Option<ConfigValue> {
+        self.root.get_typed(key)
+    }
+
+    fn reload_token(&self) -> Box<dyn ChangeToken> {
+        self.root.reload_token()
+    }
+
+    fn child_keys(&self, earlier_keys: &mut Vec<String>, parent_path: Option<&str>) {
+        let children = match parent_path {
+            Some(path) => self.root.section(path).children(),
+            _ => self.root.children(),
+        };
+
+        earlier_keys.extend(children.iter().map(|child| child.key().to_owned()));
+    }
+}
+
+// the innermost scope is last
+#[derive(Default)]
+struct Scopes {
+    layers: Vec<ConfigurationData>,
+    token: SharedChangeToken<SingleChangeToken>,
+}
+
+// provides the values of the innermost scope that defines a key
+#[derive(Clone, Default)]
+struct ScopeProvider {
+    scopes: Arc<RwLock<Scopes>>,
+}
+
+impl ScopeProvider {
+    fn change<F: FnOnce(&mut Vec<ConfigurationData>) -> bool>(&self, action: F) -> bool {
+        let mut scopes = self.scopes.write().unwrap();
+
+        if !action(&mut scopes.layers) {
+            return false;
+        }
+
+        let previous = std::mem::take(&mut scopes.token);
+
+        drop(scopes);
+        previous.notify();
+        true
+    }
+}
+
+impl ConfigurationProvider for ScopeProvider {
+    fn name(&self) -> &str {
+        "Scopes"
+    }
+
+    fn get(&self, key: &str) -> Option<Value> {
+        self.scopes
+            .read()
+            .unwrap()
+            .layers
+            .iter()
+            .rev()
+            .find_map(|layer| layer.get(key))
+    }
+
+    fn reload_token(&self) -> Box<dyn ChangeToken> {
+        Box::new(self.scopes.read().unwrap().token.clone())
+    }
+
+    fn child_keys(&self, earlier_keys: &mut Vec<String>, parent_path: Option<&str>) {
+        for layer in &self.scopes.read().unwrap().layers {
+            layer.child_keys(earlier_keys, parent_path);
+        }
+    }
+}
+
+/// Represents a configuration whose values can be temporarily overridden by nested scopes.
+///
+/// # Remarks
+///
+/// A scoped configuration reads through to the configuration it wraps, which is never modified.
+/// Each [`push_overrides`](ScopedConfiguration::push_overrides) masks the values of the wrapped
+/// configuration and any outer scope until the matching [`pop`](ScopedConfiguration::pop). This
+/// is useful for tests or per-request overrides without rebuilding the configuration. Pushing
+/// or popping a scope triggers the reload token of the configuration.
+pub struct ScopedConfiguration {
+    root: DefaultConfigurationRoot,
+    scopes: ScopeProvider,
+}
+
+impl ScopedConfiguration {
+    /// Initializes a new scoped configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `configuration` - The [`ConfigurationRoot`](crate::ConfigurationRoot) to scope
+    pub fn new(configuration: Arc<dyn ConfigurationRoot>) -> Self {
+        let conversion = configuration.value_conversion();
+        let policy = configuration.sparse_array_policy();
+        let scopes = ScopeProvider::default();
+        let providers: Vec<Box<dyn ConfigurationProvider>> = vec![
+            Box::new(RootProvider {
+                root: configuration,
+            }),
+            Box::new(scopes.clone()),
+        ];
+
+        // loading either provider never fails
+        let root = DefaultConfigurationRoot::new(providers)
+            .unwrap()
+            .with_value_conversion(conversion)
+            .with_sparse_array_policy(policy);
+
+        Self { root, scopes }
+    }
+
+    /// Pushes a new scope that overrides the specified values.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - The key/value pairs to override
+    pub fn push_overrides<S: AsRef<str>>(&self, values: &[(S, S)]) {
+        let layer = ConfigurationData::new(
+            values
+                .iter()
+                .map(|(key, value)| {
+                    (
+                        key.as_ref().to_uppercase(),
+                        (key.as_ref().to_owned(), value.as_ref().to_owned().into()),
+                    )
+                })
+                .collect(),
+        );
+
+        self.scopes.change(|layers| {
+            layers.push(layer);
+            true
+        });
+    }
+
+    /// Pops the innermost scope, restoring the values it overrode.
+    ///
+    /// # Remarks
+    ///
+    /// Returns `false` if there is no scope to pop.
+    pub fn pop(&self) -> bool {
+        self.scopes.change(|layers| layers.pop().is_some())
+    }
+
+    /// Gets the number of scopes that have been pushed.
+    pub fn depth(&self) -> usize {
+        self.scopes.scopes.read().unwrap().layers.len()
+    }
+}
+
+impl Deref for ScopedConfiguration {
+    type Target = DefaultConfigurationRoot;
+
+    fn deref(&self) -> &Self::Target {
+        &self.root
+    }
+}
+
+pub mod ext {
+
+    use super::*;
+
+    /// Defines extension methods to scope a [`ConfigurationSection`](crate::ConfigurationSection).
+    pub trait ScopedConfigurationExtensions {
+        /// Converts the section into an independent [`ConfigurationRoot`](crate::ConfigurationRoot)
+        /// whose keys are relative to the section.
+        ///
+        /// # Remarks
+        ///
+        /// The root has a single provider, named after the path of the section, that reads the
+        /// values of the section from the original configuration. Its reload token signals when
+        /// the original configuration changes. Reloading the root does not reload the original
+        /// configuration.
+        fn into_root(self) -> Box<dyn ConfigurationRoot>;
+    }
+
+    impl ScopedConfigurationExtensions for Box<dyn ConfigurationSection> {
+        fn into_root(self) -> Box<dyn ConfigurationRoot> {
+            let conversion = self.value_conversion();
+            let policy = self.sparse_array_policy();
+            let provider = SectionProvider { section: self };
+
+            // loading a section provider never fails
+            let root = DefaultConfigurationRoot::new(vec![Box::new(provider)])
+                .unwrap()
+                .with_value_conversion(conversion)
+                .with_sparse_array_policy(policy);
+
+            Box::new(root)
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{Configuration, Value};
+use crate::{ConfigValue, Configuration, ConfigurationOverlay, ConfigurationPath, Value};
 use std::{borrow::Borrow, ops::Deref};
 
 /// Defines the behavior for a section of application configuration values.
@@ -17,8 +17,44 @@ pub trait ConfigurationSection:
     /// Gets the section value.
     fn value(&self) -> Value;
 
+    /// Gets the typed section value.
+    ///
+    /// # Remarks
+    ///
+    /// The default implementation always produces [`ConfigValue::String`](crate::ConfigValue::String).
+    fn typed_value(&self) -> ConfigValue {
+        ConfigValue::String(self.value())
+    }
+
+    /// Gets a value indicating whether the section value is explicitly null.
+    ///
+    /// # Remarks
+    ///
+    /// A null value is distinct from an empty value or a nonexistent section.
+    fn is_null(&self) -> bool {
+        self.typed_value().is_null()
+    }
+
     /// Converts the [`ConfigurationSection`] into a [`Configuration`](crate::Configuration).
     fn as_config(&self) -> Box<dyn Configuration>;
+
+    /// Gets the overlay of the configuration this section belongs to, if supported.
+    ///
+    /// # Remarks
+    ///
+    /// The default implementation does not support an overlay and always returns `None`.
+    fn overlay(&self) -> Option<ConfigurationOverlay> {
+        None
+    }
+}
+
+// the absolute key of a key relative to the section; an empty key is the section itself
+fn absolute_key(section: &(impl ConfigurationSection + ?Sized), key: &str) -> String {
+    if key.is_empty() {
+        section.path().to_owned()
+    } else {
+        ConfigurationPath::combine(&[section.path(), key])
+    }
 }
 
 pub mod ext {
@@ -34,17 +70,80 @@ pub mod ext {
         /// A configuration section is considered nonexistent if it has no
         /// value and no children
         fn exists(&self) -> bool;
+
+        /// Sets a configuration value relative to the section.
+        ///
+        /// # Arguments
+        ///
+        /// * `key` - The key of the value to set, relative to the section. An empty key sets the
+        ///   value of the section itself.
+        /// * `value` - The value to set
+        ///
+        /// # Remarks
+        ///
+        /// The value is written through the [overlay](ConfigurationSection::overlay) of the
+        /// configuration and takes precedence over all providers. Returns `false` if the section
+        /// does not support an overlay.
+        fn set(&self, key: &str, value: &str) -> bool;
+
+        /// Removes a configuration value relative to the section, including all of its descendants.
+        ///
+        /// # Arguments
+        ///
+        /// * `key` - The key of the value to remove, relative to the section. An empty key removes
+        ///   the section itself.
+        ///
+        /// # Remarks
+        ///
+        /// Returns `false` if the section does not support an overlay.
+        fn remove(&self, key: &str) -> bool;
     }
 
     impl ConfigurationSectionExtensions for dyn ConfigurationSection + '_ {
         fn exists(&self) -> bool {
             !self.value().is_empty() || !self.children().is_empty()
         }
+
+        fn set(&self, key: &str, value: &str) -> bool {
+            if let Some(overlay) = self.overlay() {
+                overlay.set(&absolute_key(self, key), value);
+                true
+            } else {
+                false
+            }
+        }
+
+        fn remove(&self, key: &str) -> bool {
+            if let Some(overlay) = self.overlay() {
+                overlay.remove(&absolute_key(self, key));
+                true
+            } else {
+                false
+            }
+        }
     }
 
     impl<T: ConfigurationSection> ConfigurationSectionExtensions for T {
         fn exists(&self) -> bool {
             !self.value().is_empty() || !self.children().is_empty()
         }
+
+        fn set(&self, key: &str, value: &str) -> bool {
+            if let Some(overlay) = self.overlay() {
+                overlay.set(&absolute_key(self, key), value);
+                true
+            } else {
+                false
+            }
+        }
+
+        fn remove(&self, key: &str) -> bool {
+            if let Some(overlay) = self.overlay() {
+                overlay.remove(&absolute_key(self, key));
+                true
+            } else {
+                false
+            }
+        }
     }
 }
@@ -1,11 +1,60 @@
 use crate::{ConfigurationProvider, ConfigurationBuilder};
+use std::any::{type_name, TypeId};
 
 /// Represents a source of configuration key/value pairs for an application.
 pub trait ConfigurationSource {
+    /// Gets the name of the source.
+    ///
+    /// # Remarks
+    ///
+    /// The default implementation returns the name of the implementing type.
+    fn name(&self) -> &str {
+        type_name::<Self>()
+    }
+
     /// Builds the [`ConfigurationProvider`](crate::ConfigurationProvider) for this source.
     /// 
     /// # Arguments
     /// 
     /// * `builder` - The [`ConfigurationBuilder`](crate::ConfigurationBuilder) used to build the provider
     fn build(&self, builder: &dyn ConfigurationBuilder) -> Box<dyn ConfigurationProvider>;
+
+    /// Gets the priority of the source.
+    ///
+    /// # Remarks
+    ///
+    /// Sources are ordered by priority and then by the order they were added. A source with a
+    /// higher priority takes precedence over a source with a lower priority, regardless of the
+    /// order they were added. The default implementation returns `0`.
+    fn priority(&self) -> i32 {
+        0
+    }
+
+    /// Gets the type of the source.
+    ///
+    /// # Remarks
+    ///
+    /// The type identifies the kind of source regardless of its
+    /// [name](ConfigurationSource::name), such as when a source is
+    /// [replaced](crate::DefaultConfigurationBuilder::replace). A source that only decorates
+    /// another source can return the type of the source it decorates. The default
+    /// implementation returns the identifier of the implementing type.
+    fn source_type(&self) -> TypeId
+    where
+        Self: 'static,
+    {
+        TypeId::of::<Self>()
+    }
+}
+
+// orders sources by priority; the sort is stable so sources with the same priority retain the
+// order they were added
+#[cfg(feature = "std")]
+pub(crate) fn prioritize<'a, I>(sources: I) -> Vec<&'a dyn ConfigurationSource>
+where
+    I: Iterator<Item = &'a Box<dyn ConfigurationSource>>,
+{
+    let mut sources: Vec<_> = sources.map(|source| source.as_ref()).collect();
+    sources.sort_by_key(|source| source.priority());
+    sources
 }
\ No newline at end of file
@@ -0,0 +1,175 @@
+use crate::{
+    util::ConfigurationData, ConfigurationBuilder, ConfigurationPairs, ConfigurationProvider,
+    ConfigurationSource, LoadError, LoadResult, Value, ValueRef,
+};
+use std::cell::RefCell;
+use std::io::Read;
+use std::sync::Arc;
+
+type Parser = Arc<dyn Fn(&[u8]) -> Result<ConfigurationData, String> + Send + Sync>;
+
+/// Represents a [`ConfigurationProvider`](crate::ConfigurationProvider) for content read
+/// from a stream.
+pub struct StreamConfigurationProvider {
+    content: Arc<[u8]>,
+    error: Option<String>,
+    parser: Parser,
+    data: ConfigurationData,
+}
+
+impl StreamConfigurationProvider {
+    /// Initializes a new stream configuration provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - The content read from the stream
+    /// * `parser` - The function used to parse the content into configuration data
+    pub fn new(content: Arc<[u8]>, parser: Parser) -> Self {
+        Self {
+            content,
+            error: None,
+            parser,
+            data: Default::default(),
+        }
+    }
+
+    fn failed(error: String, parser: Parser) -> Self {
+        Self {
+            content: Arc::new([]),
+            error: Some(error),
+            parser,
+            data: Default::default(),
+        }
+    }
+}
+
+impl ConfigurationProvider for StreamConfigurationProvider {
+    fn get(&self, key: &str) -> Option<Value> {
+        self.data.get(key)
+    }
+
+    fn get_str(&self, key: &str) -> Option<ValueRef<'_>> {
+        self.data.get_str(key).map(ValueRef::Borrowed)
+    }
+
+    fn load(&mut self) -> LoadResult {
+        if let Some(error) = &self.error {
+            return Err(LoadError::Generic(format!(
+                "The configuration stream could not be read. {}",
+                error
+            )));
+        }
+
+        self.data = (self.parser)(&self.content).map_err(LoadError::Generic)?;
+        Ok(())
+    }
+
+    fn try_iter(&self) -> Option<ConfigurationPairs<'_>> {
+        Some(self.data.pairs())
+    }
+
+    fn child_keys(&self, earlier_keys: &mut Vec<String>, parent_path: Option<&str>) {
+        self.data.child_keys(earlier_keys, parent_path)
+    }
+}
+
+/// Represents a [`ConfigurationSource`](crate::ConfigurationSource) for content read
+/// from a stream.
+pub struct StreamConfigurationSource {
+    stream: RefCell<Option<Box<dyn Read>>>,
+    content: RefCell<Option<Result<Arc<[u8]>, String>>>,
+    parser: Parser,
+}
+
+impl StreamConfigurationSource {
+    /// Initializes a new stream configuration source.
+    ///
+    /// # Arguments
+    ///
+    /// * `stream` - The [`Read`](std::io::Read) stream to read the content from
+    /// * `parser` - The function used to parse the content into configuration data
+    ///
+    /// # Remarks
+    ///
+    /// The stream is read to its end once, when the first provider is built, and the
+    /// content is reused by any subsequently built providers.
+    pub fn new<R, F>(stream: R, parser: F) -> Self
+    where
+        R: Read + 'static,
+        F: Fn(&[u8]) -> Result<ConfigurationData, String> + Send + Sync + 'static,
+    {
+        Self {
+            stream: RefCell::new(Some(Box::new(stream))),
+            content: Default::default(),
+            parser: Arc::new(parser),
+        }
+    }
+}
+
+impl ConfigurationSource for StreamConfigurationSource {
+    fn build(&self, _builder: &dyn ConfigurationBuilder) -> Box<dyn ConfigurationProvider> {
+        let mut content = self.content.borrow_mut();
+
+        if content.is_none() {
+            if let Some(mut stream) = self.stream.borrow_mut().take() {
+                let mut buffer = Vec::new();
+                *content = Some(match stream.read_to_end(&mut buffer) {
+                    Ok(_) => Ok(buffer.into()),
+                    Err(error) => Err(error.to_string()),
+                });
+            }
+        }
+
+        match content.as_ref().unwrap() {
+            Ok(content) => Box::new(StreamConfigurationProvider::new(
+                content.clone(),
+                self.parser.clone(),
+            )),
+            Err(error) => Box::new(StreamConfigurationProvider::failed(
+                error.clone(),
+                self.parser.clone(),
+            )),
+        }
+    }
+}
+
+pub mod ext {
+
+    use super::*;
+
+    /// Defines extension methods for [`ConfigurationBuilder`](crate::ConfigurationBuilder).
+    pub trait StreamConfigurationBuilderExtensions {
+        /// Adds content read from a stream as a configuration source.
+        ///
+        /// # Arguments
+        ///
+        /// * `stream` - The [`Read`](std::io::Read) stream to read the content from
+        /// * `parser` - The function used to parse the content into configuration data
+        fn add_stream<R, F>(&mut self, stream: R, parser: F) -> &mut Self
+        where
+            R: Read + 'static,
+            F: Fn(&[u8]) -> Result<ConfigurationData, String> + Send + Sync + 'static;
+    }
+
+    impl StreamConfigurationBuilderExtensions for dyn ConfigurationBuilder + '_ {
+        fn add_stream<R, F>(&mut self, stream: R, parser: F) -> &mut Self
+        where
+            R: Read + 'static,
+            F: Fn(&[u8]) -> Result<ConfigurationData, String> + Send + Sync + 'static,
+        {
+            self.add(Box::new(StreamConfigurationSource::new(stream, parser)));
+            self
+        }
+    }
+
+    impl<T: ConfigurationBuilder> StreamConfigurationBuilderExtensions for T {
+        fn add_stream<R, F>(&mut self, stream: R, parser: F) -> &mut Self
+        where
+            R: Read + 'static,
+            F: Fn(&[u8]) -> Result<ConfigurationData, String> + Send + Sync + 'static,
+        {
+            self.add(Box::new(StreamConfigurationSource::new(stream, parser)));
+            self
+        }
+    }
+}
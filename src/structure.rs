@@ -0,0 +1,575 @@
+use crate::{
+    util::*, ConfigValue, ConfigurationBuilder, ConfigurationPairs, ConfigurationProvider,
+    ConfigurationSource, LoadError, LoadResult, Value,
+};
+use serde::ser::{self, Serialize};
+use std::convert::TryFrom;
+use std::fmt::{Display, Formatter, Result as FormatResult};
+use std::sync::{Arc, RwLock};
+use tokens::{ChangeToken, SharedChangeToken, SingleChangeToken};
+
+#[derive(Debug)]
+struct Error(String);
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FormatResult {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl ser::Error for Error {
+    fn custom<T: Display>(msg: T) -> Self {
+        Self(msg.to_string())
+    }
+}
+
+type Result<T> = std::result::Result<T, Error>;
+
+fn typed<T: ToString>(value: T, typed: ConfigValue) -> ConfigurationNode {
+    ConfigurationNode::Typed(value.to_string(), typed)
+}
+
+fn variant(name: &str, node: ConfigurationNode) -> ConfigurationNode {
+    ConfigurationNode::Object(vec![(name.to_owned(), node)])
+}
+
+struct NodeSerializer;
+
+impl ser::Serializer for NodeSerializer {
+    type Ok = ConfigurationNode;
+    type Error = Error;
+    type SerializeSeq = SeqSerializer;
+    type SerializeTuple = SeqSerializer;
+    type SerializeTupleStruct = SeqSerializer;
+    type SerializeTupleVariant = SeqSerializer;
+    type SerializeMap = MapSerializer;
+    type SerializeStruct = MapSerializer;
+    type SerializeStructVariant = MapSerializer;
+
+    fn serialize_bool(self, v: bool) -> Result<Self::Ok> {
+        Ok(typed(v, ConfigValue::Bool(v)))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Self::Ok> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Self::Ok> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Self::Ok> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Self::Ok> {
+        Ok(typed(v, ConfigValue::Int(v)))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Self::Ok> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Self::Ok> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Self::Ok> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Self::Ok> {
+        if let Ok(number) = i64::try_from(v) {
+            self.serialize_i64(number)
+        } else {
+            Ok(ConfigurationNode::Text(v.to_string()))
+        }
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Self::Ok> {
+        Ok(typed(v, ConfigValue::Float(v as f64)))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Self::Ok> {
+        Ok(typed(v, ConfigValue::Float(v)))
+    }
+
+    fn serialize_char(self, v: char) -> Result<Self::Ok> {
+        Ok(ConfigurationNode::Text(v.to_string()))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Self::Ok> {
+        Ok(ConfigurationNode::Text(v.to_owned()))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Self::Ok> {
+        Ok(ConfigurationNode::Array(
+            v.iter()
+                .map(|b| typed(b, ConfigValue::Int(*b as i64)))
+                .collect(),
+        ))
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok> {
+        self.serialize_unit()
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<Self::Ok> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok> {
+        Ok(typed("", ConfigValue::Null))
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok> {
+        self.serialize_unit()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Self::Ok> {
+        self.serialize_str(variant)
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok> {
+        Ok(variant(name, value.serialize(self)?))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq> {
+        Ok(SeqSerializer::new(None, len.unwrap_or_default()))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple> {
+        Ok(SeqSerializer::new(None, len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleStruct> {
+        Ok(SeqSerializer::new(None, len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleVariant> {
+        Ok(SeqSerializer::new(Some(name), len))
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap> {
+        Ok(MapSerializer::new(None, len.unwrap_or_default()))
+    }
+
+    fn serialize_struct(self, _name: &'static str, len: usize) -> Result<Self::SerializeStruct> {
+        Ok(MapSerializer::new(None, len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStructVariant> {
+        Ok(MapSerializer::new(Some(name), len))
+    }
+}
+
+struct SeqSerializer {
+    variant: Option<&'static str>,
+    elements: Vec<ConfigurationNode>,
+}
+
+impl SeqSerializer {
+    fn new(variant: Option<&'static str>, len: usize) -> Self {
+        Self {
+            variant,
+            elements: Vec::with_capacity(len),
+        }
+    }
+
+    fn push<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        self.elements.push(value.serialize(NodeSerializer)?);
+        Ok(())
+    }
+
+    fn finish(self) -> Result<ConfigurationNode> {
+        let array = ConfigurationNode::Array(self.elements);
+
+        Ok(match self.variant {
+            Some(name) => variant(name, array),
+            _ => array,
+        })
+    }
+}
+
+impl ser::SerializeSeq for SeqSerializer {
+    type Ok = ConfigurationNode;
+    type Error = Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTuple for SeqSerializer {
+    type Ok = ConfigurationNode;
+    type Error = Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleStruct for SeqSerializer {
+    type Ok = ConfigurationNode;
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleVariant for SeqSerializer {
+    type Ok = ConfigurationNode;
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok> {
+        self.finish()
+    }
+}
+
+struct MapSerializer {
+    variant: Option<&'static str>,
+    key: Option<String>,
+    members: Vec<(String, ConfigurationNode)>,
+}
+
+impl MapSerializer {
+    fn new(variant: Option<&'static str>, len: usize) -> Self {
+        Self {
+            variant,
+            key: None,
+            members: Vec::with_capacity(len),
+        }
+    }
+
+    fn push<T: ?Sized + Serialize>(&mut self, key: String, value: &T) -> Result<()> {
+        self.members.push((key, value.serialize(NodeSerializer)?));
+        Ok(())
+    }
+
+    fn finish(self) -> Result<ConfigurationNode> {
+        let object = ConfigurationNode::Object(self.members);
+
+        Ok(match self.variant {
+            Some(name) => variant(name, object),
+            _ => object,
+        })
+    }
+}
+
+impl ser::SerializeMap for MapSerializer {
+    type Ok = ConfigurationNode;
+    type Error = Error;
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<()> {
+        match key.serialize(NodeSerializer)? {
+            ConfigurationNode::Text(key) | ConfigurationNode::Typed(key, _) => {
+                self.key = Some(key);
+                Ok(())
+            }
+            _ => Err(Error("A map key must be a scalar value.".into())),
+        }
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        let key = self.key.take().unwrap_or_default();
+        self.push(key, value)
+    }
+
+    fn end(self) -> Result<Self::Ok> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStruct for MapSerializer {
+    type Ok = ConfigurationNode;
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        self.push(key.to_owned(), value)
+    }
+
+    fn end(self) -> Result<Self::Ok> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStructVariant for MapSerializer {
+    type Ok = ConfigurationNode;
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        self.push(key.to_owned(), value)
+    }
+
+    fn end(self) -> Result<Self::Ok> {
+        self.finish()
+    }
+}
+
+struct State {
+    tree: std::result::Result<ConfigurationNode, String>,
+    data: ConfigurationData,
+    token: SharedChangeToken<SingleChangeToken>,
+}
+
+/// Represents a handle used to update the value of a struct configuration after it has been
+/// built.
+///
+/// # Remarks
+///
+/// Every update triggers the reload token of the associated
+/// [`StructConfigurationProvider`](crate::StructConfigurationProvider).
+#[derive(Clone)]
+pub struct StructConfigurationHandle {
+    state: Arc<RwLock<State>>,
+}
+
+impl StructConfigurationHandle {
+    fn new<T: Serialize>(value: &T) -> Self {
+        Self {
+            state: Arc::new(RwLock::new(State {
+                tree: value.serialize(NodeSerializer).map_err(|e| e.0),
+                data: Default::default(),
+                token: Default::default(),
+            })),
+        }
+    }
+
+    /// Updates the value that configuration is provided for.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The new value to provide configuration for
+    ///
+    /// # Remarks
+    ///
+    /// The current configuration is retained if the new value cannot be serialized.
+    pub fn update<T: Serialize>(&self, value: &T) -> LoadResult {
+        let tree = value.serialize(NodeSerializer).map_err(|e| error(&e.0))?;
+        let data = FlattenVisitor::new().visit(tree.clone());
+        let mut state = self.state.write().unwrap();
+
+        state.tree = Ok(tree);
+        state.data = data;
+
+        let previous = std::mem::take(&mut state.token);
+
+        drop(state);
+        previous.notify();
+        Ok(())
+    }
+}
+
+fn error(message: &str) -> LoadError {
+    LoadError::Generic(format!("The value could not be serialized. {}", message))
+}
+
+/// Represents a [`ConfigurationProvider`](crate::ConfigurationProvider) for a serializable struct.
+pub struct StructConfigurationProvider {
+    handle: StructConfigurationHandle,
+}
+
+impl StructConfigurationProvider {
+    /// Initializes a new struct configuration provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value to provide configuration for
+    ///
+    /// # Remarks
+    ///
+    /// The value is serialized with [serde](https://serde.rs) so that all of the rename,
+    /// skip, and flatten attributes are honored. Unit enum variants are represented as
+    /// their name and all other enum variants are keyed by their name.
+    pub fn new<T: Serialize>(value: &T) -> Self {
+        Self::from_handle(StructConfigurationHandle::new(value))
+    }
+
+    /// Initializes a new struct configuration provider whose value can be updated.
+    ///
+    /// # Arguments
+    ///
+    /// * `handle` - The [`StructConfigurationHandle`] used to update the value
+    pub fn from_handle(handle: StructConfigurationHandle) -> Self {
+        Self { handle }
+    }
+}
+
+impl ConfigurationProvider for StructConfigurationProvider {
+    fn get(&self, key: &str) -> Option<Value> {
+        self.handle.state.read().unwrap().data.get(key)
+    }
+
+    fn get_typed(&self, key: &str) -> Option<ConfigValue> {
+        self.handle.state.read().unwrap().data.get_typed(key)
+    }
+
+    fn reload_token(&self) -> Box<dyn ChangeToken> {
+        Box::new(self.handle.state.read().unwrap().token.clone())
+    }
+
+    fn load(&mut self) -> LoadResult {
+        let mut state = self.handle.state.write().unwrap();
+
+        match &state.tree {
+            Ok(tree) => {
+                state.data = FlattenVisitor::new().visit(tree.clone());
+                Ok(())
+            }
+            Err(message) => Err(error(message)),
+        }
+    }
+
+    fn try_iter(&self) -> Option<ConfigurationPairs<'_>> {
+        let pairs: Vec<_> = self.handle.state.read().unwrap().data.pairs().collect();
+        Some(Box::new(pairs.into_iter()))
+    }
+
+    fn child_keys(&self, earlier_keys: &mut Vec<String>, parent_path: Option<&str>) {
+        let state = self.handle.state.read().unwrap();
+        state.data.child_keys(earlier_keys, parent_path)
+    }
+}
+
+/// Represents a [`ConfigurationSource`](crate::ConfigurationSource) for a serializable struct.
+pub struct StructConfigurationSource {
+    handle: StructConfigurationHandle,
+}
+
+impl StructConfigurationSource {
+    /// Initializes a new struct configuration source.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value to provide configuration for
+    pub fn new<T: Serialize>(value: &T) -> Self {
+        Self {
+            handle: StructConfigurationHandle::new(value),
+        }
+    }
+
+    /// Gets a handle that can be used to update the value after it has been built.
+    ///
+    /// # Remarks
+    ///
+    /// All providers built from this source share the same handle.
+    pub fn handle(&self) -> StructConfigurationHandle {
+        self.handle.clone()
+    }
+}
+
+impl ConfigurationSource for StructConfigurationSource {
+    fn build(&self, _builder: &dyn ConfigurationBuilder) -> Box<dyn ConfigurationProvider> {
+        Box::new(StructConfigurationProvider::from_handle(
+            self.handle.clone(),
+        ))
+    }
+}
+
+#[cfg(feature = "std")]
+impl crate::DefaultConfigurationBuilder {
+    /// Adds a serializable struct that provides default values.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value that provides the defaults
+    ///
+    /// # Remarks
+    ///
+    /// Defaults are overridden by all other sources regardless of the order in which
+    /// they are added.
+    pub fn add_struct_defaults<T: Serialize>(&mut self, value: &T) -> &mut Self {
+        self.add_default(Box::new(StructConfigurationSource::new(value)))
+    }
+}
+
+pub mod ext {
+
+    use super::*;
+
+    /// Defines extension methods for [`ConfigurationBuilder`](crate::ConfigurationBuilder).
+    pub trait StructConfigurationBuilderExtensions {
+        /// Adds a serializable struct as a configuration source.
+        ///
+        /// # Arguments
+        ///
+        /// * `value` - The value to provide configuration for
+        fn add_struct<T: Serialize>(&mut self, value: &T) -> &mut Self;
+    }
+
+    impl StructConfigurationBuilderExtensions for dyn ConfigurationBuilder + '_ {
+        fn add_struct<T: Serialize>(&mut self, value: &T) -> &mut Self {
+            self.add(Box::new(StructConfigurationSource::new(value)));
+            self
+        }
+    }
+
+    impl<T: ConfigurationBuilder> StructConfigurationBuilderExtensions for T {
+        fn add_struct<V: Serialize>(&mut self, value: &V) -> &mut Self {
+            self.add(Box::new(StructConfigurationSource::new(value)));
+            self
+        }
+    }
+}
@@ -0,0 +1,190 @@
+use crate::{
+    util::first_segment, ConfigValue, ConfigurationPath, ConfigurationProvider, ConfigurationRoot,
+    DefaultConfigurationRoot, Value,
+};
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use tokens::ChangeToken;
+
+// provides the shared values of a configuration overridden by the values of a tenant
+struct TenantProvider {
+    root: Arc<dyn ConfigurationRoot>,
+    section: String,
+    tenant: String,
+}
+
+impl TenantProvider {
+    fn tenant_key(&self, key: &str) -> String {
+        ConfigurationPath::combine(&[&self.section, &self.tenant, key])
+    }
+
+    // the tenants section is never visible to a tenant
+    fn is_hidden(&self, key: &str) -> bool {
+        first_segment(key).eq_ignore_ascii_case(&self.section)
+    }
+}
+
+impl ConfigurationProvider for TenantProvider {
+    fn name(&self) -> &str {
+        &self.tenant
+    }
+
+    fn get(&self, key: &str) -> Option<Value> {
+        if self.is_hidden(key) {
+            None
+        } else {
+            self.root
+                .get(&self.tenant_key(key))
+                .or_else(|| self.root.get(key))
+        }
+    }
+
+    fn get_typed(&self, key: &str) -> Option<ConfigValue> {
+        if self.is_hidden(key) {
+            None
+        } else {
+            self.root
+                .get_typed(&self.tenant_key(key))
+                .or_else(|| self.root.get_typed(key))
+        }
+    }
+
+    fn reload_token(&self) -> Box<dyn ChangeToken> {
+        self.root.reload_token()
+    }
+
+    fn child_keys(&self, earlier_keys: &mut Vec<String>, parent_path: Option<&str>) {
+        let (shared, tenant) = match parent_path {
+            Some(path) if self.is_hidden(path) => return,
+            Some(path) => (
+                self.root.section(path).children(),
+                self.root.section(&self.tenant_key(path)).children(),
+            ),
+            _ => (
+                self.root.children(),
+                self.root
+                    .section(&ConfigurationPath::combine(&[&self.section, &self.tenant]))
+                    .children(),
+            ),
+        };
+
+        earlier_keys.extend(
+            shared
+                .iter()
+                .chain(tenant.iter())
+                .map(|child| child.key())
+                .filter(|key| parent_path.is_some() || !self.is_hidden(key))
+                .map(ToOwned::to_owned),
+        );
+    }
+}
+
+#[derive(Default)]
+struct Cache {
+    token: Option<Box<dyn ChangeToken>>,
+    tenants: HashMap<String, Arc<dyn ConfigurationRoot>>,
+}
+
+/// Represents a configuration that resolves a view of the configuration for each tenant.
+///
+/// # Remarks
+///
+/// The values of a tenant are stored in the `Tenants:{id}` section of the configuration, by
+/// default, and override the shared values with the same relative key. For example, the value of
+/// `Tenants:contoso:Logging:Level` overrides `Logging:Level` for the `contoso` tenant. The tenants
+/// section itself is not visible in the view of a tenant. Tenant views are cached until the
+/// configuration is reloaded.
+pub struct TenantConfiguration {
+    root: Arc<dyn ConfigurationRoot>,
+    section: String,
+    cache: RwLock<Cache>,
+}
+
+impl TenantConfiguration {
+    /// Initializes a new tenant configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `configuration` - The [`ConfigurationRoot`](crate::ConfigurationRoot) containing the shared
+    ///   and tenant-specific values
+    pub fn new(configuration: Arc<dyn ConfigurationRoot>) -> Self {
+        Self {
+            root: configuration,
+            section: "Tenants".into(),
+            cache: Default::default(),
+        }
+    }
+
+    /// Sets the key of the section that contains the tenant-specific values.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the tenants section. The default value is `Tenants`.
+    pub fn with_section(mut self, key: &str) -> Self {
+        self.section = key.into();
+        self
+    }
+
+    /// Gets the sorted identifiers of the configured tenants.
+    pub fn tenants(&self) -> Vec<String> {
+        let mut tenants: Vec<_> = self
+            .root
+            .section(&self.section)
+            .children()
+            .iter()
+            .map(|child| child.key().to_owned())
+            .collect();
+
+        tenants.sort_by_key(|tenant| tenant.to_uppercase());
+        tenants
+    }
+
+    /// Gets the view of the configuration for the specified tenant.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The identifier of the tenant
+    ///
+    /// # Remarks
+    ///
+    /// A tenant without any specific values observes the shared values.
+    pub fn tenant(&self, id: &str) -> Arc<dyn ConfigurationRoot> {
+        let normalized = id.to_uppercase();
+        {
+            let cache = self.cache.read().unwrap();
+
+            if cache.token.as_ref().map_or(false, |token| !token.changed()) {
+                if let Some(tenant) = cache.tenants.get(&normalized) {
+                    return tenant.clone();
+                }
+            }
+        }
+
+        let mut cache = self.cache.write().unwrap();
+
+        if cache.token.as_ref().map_or(true, |token| token.changed()) {
+            cache.tenants.clear();
+            cache.token = Some(self.root.reload_token());
+        }
+
+        cache
+            .tenants
+            .entry(normalized)
+            .or_insert_with(|| {
+                let provider = TenantProvider {
+                    root: self.root.clone(),
+                    section: self.section.clone(),
+                    tenant: id.to_owned(),
+                };
+
+                // loading a tenant provider never fails
+                let root = DefaultConfigurationRoot::new(vec![Box::new(provider)])
+                    .unwrap()
+                    .with_value_conversion(self.root.value_conversion())
+                    .with_sparse_array_policy(self.root.sparse_array_policy());
+
+                Arc::new(root)
+            })
+            .clone()
+    }
+}
@@ -0,0 +1,234 @@
+use crate::{
+    util::ConfigurationData, ConfigurationBuilder, ConfigurationPairs, ConfigurationProvider,
+    ConfigurationSource, LoadError, LoadResult, Value,
+};
+use std::collections::HashMap;
+use std::env::temp_dir;
+use std::fs::{remove_file, write};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, RwLock};
+use tokens::{ChangeToken, SharedChangeToken, SingleChangeToken};
+
+#[derive(Default)]
+struct State {
+    data: ConfigurationData,
+    token: SharedChangeToken<SingleChangeToken>,
+    load_error: Option<LoadError>,
+    loads: usize,
+}
+
+/// Represents a scriptable [`ConfigurationProvider`](crate::ConfigurationProvider) for tests.
+///
+/// # Remarks
+///
+/// Clones share the same state, so a clone can be added to a
+/// [`ConfigurationBuilder`](crate::ConfigurationBuilder) as a
+/// [`ConfigurationSource`](crate::ConfigurationSource) while the original is used to change
+/// the values, signal the reload token, or fail the next load.
+#[derive(Clone, Default)]
+pub struct FakeConfigurationProvider {
+    state: Arc<RwLock<State>>,
+}
+
+impl FakeConfigurationProvider {
+    /// Initializes a new fake configuration provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - The initial key/value pairs
+    pub fn new<S: AsRef<str>>(values: &[(S, S)]) -> Self {
+        let provider = Self::default();
+
+        provider.change(|data| {
+            for (key, value) in values {
+                data.insert(
+                    key.as_ref().to_uppercase(),
+                    (key.as_ref().to_owned(), value.as_ref().to_owned().into()),
+                );
+            }
+        });
+
+        provider
+    }
+
+    /// Sets a configuration value without signaling the reload token.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the value to set
+    /// * `value` - The value to set
+    pub fn set(&self, key: &str, value: &str) {
+        self.change(|data| {
+            data.insert(
+                key.to_uppercase(),
+                (key.to_owned(), value.to_owned().into()),
+            );
+        })
+    }
+
+    /// Removes a configuration value without signaling the reload token.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the value to remove
+    pub fn remove(&self, key: &str) {
+        self.change(|data| {
+            data.remove(&key.to_uppercase());
+        })
+    }
+
+    /// Signals the reload token of the provider.
+    pub fn trigger(&self) {
+        let previous = std::mem::take(&mut self.state.write().unwrap().token);
+        previous.notify();
+    }
+
+    /// Sets the error returned by subsequent loads.
+    ///
+    /// # Arguments
+    ///
+    /// * `error` - The [`LoadError`](crate::LoadError) to return or `None` to load successfully
+    pub fn set_load_error(&self, error: Option<LoadError>) {
+        self.state.write().unwrap().load_error = error;
+    }
+
+    /// Gets the number of times the provider has been loaded.
+    pub fn load_count(&self) -> usize {
+        self.state.read().unwrap().loads
+    }
+
+    fn change<F: FnOnce(&mut HashMap<String, (String, Value)>)>(&self, action: F) {
+        let mut state = self.state.write().unwrap();
+        let mut data = state.data.as_map().clone();
+
+        action(&mut data);
+        state.data = data.into();
+    }
+}
+
+impl ConfigurationProvider for FakeConfigurationProvider {
+    fn get(&self, key: &str) -> Option<Value> {
+        self.state.read().unwrap().data.get(key)
+    }
+
+    fn reload_token(&self) -> Box<dyn ChangeToken> {
+        Box::new(self.state.read().unwrap().token.clone())
+    }
+
+    fn load(&mut self) -> LoadResult {
+        let mut state = self.state.write().unwrap();
+
+        state.loads += 1;
+
+        match &state.load_error {
+            Some(error) => Err(error.clone()),
+            _ => Ok(()),
+        }
+    }
+
+    fn try_iter(&self) -> Option<ConfigurationPairs<'_>> {
+        let pairs: Vec<_> = self.state.read().unwrap().data.pairs().collect();
+        Some(Box::new(pairs.into_iter()))
+    }
+
+    fn child_keys(&self, earlier_keys: &mut Vec<String>, parent_path: Option<&str>) {
+        self.state
+            .read()
+            .unwrap()
+            .data
+            .child_keys(earlier_keys, parent_path)
+    }
+}
+
+impl ConfigurationSource for FakeConfigurationProvider {
+    fn build(&self, _builder: &dyn ConfigurationBuilder) -> Box<dyn ConfigurationProvider> {
+        Box::new(self.clone())
+    }
+}
+
+/// Represents a temporary configuration file that is deleted when it is dropped.
+pub struct TempConfigFile {
+    path: PathBuf,
+}
+
+impl TempConfigFile {
+    /// Initializes a new temporary configuration file.
+    ///
+    /// # Arguments
+    ///
+    /// * `extension` - The extension of the file, such as `json`
+    /// * `content` - The content of the file
+    ///
+    /// # Remarks
+    ///
+    /// The file is created in the temporary directory with a unique name.
+    pub fn new(extension: &str, content: &str) -> Self {
+        static NEXT: AtomicUsize = AtomicUsize::new(0);
+
+        let name = format!(
+            "more-config-{}-{}.{}",
+            process::id(),
+            NEXT.fetch_add(1, Ordering::Relaxed),
+            extension
+        );
+        let file = Self {
+            path: temp_dir().join(name),
+        };
+
+        file.write(content);
+        file
+    }
+
+    /// Gets the path of the file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Replaces the content of the file.
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - The new content of the file
+    pub fn write(&self, content: &str) {
+        write(&self.path, content).unwrap();
+    }
+}
+
+impl AsRef<Path> for TempConfigFile {
+    fn as_ref(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for TempConfigFile {
+    fn drop(&mut self) {
+        remove_file(&self.path).ok();
+    }
+}
+
+/// Asserts that a configuration has the expected key/value pairs.
+///
+/// # Arguments
+///
+/// * `configuration` - The [`Configuration`](crate::Configuration) to evaluate
+/// * `expected` - The expected key/value pairs, such as `&[("Logging:Level", "Debug")]`
+#[macro_export]
+macro_rules! assert_config_eq {
+    ($configuration:expr, $expected:expr) => {{
+        #[allow(unused_imports)]
+        use $crate::Configuration as _;
+
+        let configuration = &$configuration;
+
+        for (key, value) in $expected.iter() {
+            assert_eq!(
+                configuration.get(key).as_deref().map(String::as_str),
+                Some(*value),
+                "The value of configuration key '{}' is unexpected.",
+                key
+            );
+        }
+    }};
+}
@@ -0,0 +1,229 @@
+use std::time::Duration;
+
+const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+fn unit_nanos(unit: &str) -> Option<u128> {
+    Some(match unit {
+        "ns" => 1,
+        "us" | "µs" => 1_000,
+        "ms" => 1_000_000,
+        "s" => NANOS_PER_SEC,
+        "m" => 60 * NANOS_PER_SEC,
+        "h" => 60 * 60 * NANOS_PER_SEC,
+        "d" => 24 * 60 * 60 * NANOS_PER_SEC,
+        _ => return None,
+    })
+}
+
+fn to_duration(nanos: u128) -> Duration {
+    Duration::new(
+        (nanos / NANOS_PER_SEC) as u64,
+        (nanos % NANOS_PER_SEC) as u32,
+    )
+}
+
+/// Parses a human-friendly duration such as `30s`, `5m`, `1h30m`, or `250ms`.
+///
+/// # Remarks
+///
+/// A number without a unit is in seconds.
+pub(crate) fn parse_duration(text: &str) -> Result<Duration, String> {
+    let error = || format!("'{}' is not a valid duration", text);
+    let text = text.trim();
+
+    if let Ok(secs) = text.parse::<u64>() {
+        return Ok(Duration::from_secs(secs));
+    }
+
+    let mut rest = text;
+    let mut total = 0u128;
+
+    if rest.is_empty() {
+        return Err(error());
+    }
+
+    while !rest.is_empty() {
+        let number_len = rest
+            .find(|c: char| !c.is_ascii_digit() && c != '.')
+            .ok_or_else(error)?;
+        let (number, tail) = rest.split_at(number_len);
+        let unit_len = tail
+            .find(|c: char| c.is_ascii_digit() || c.is_whitespace())
+            .unwrap_or(tail.len());
+        let (unit, tail) = tail.split_at(unit_len);
+        let unit = unit_nanos(unit).ok_or_else(error)?;
+        let (whole, fraction) = match number.find('.') {
+            Some(index) => (&number[..index], &number[(index + 1)..]),
+            _ => (number, ""),
+        };
+
+        if whole.is_empty() && fraction.is_empty() {
+            return Err(error());
+        }
+
+        let whole: u128 = if whole.is_empty() {
+            0
+        } else {
+            whole.parse().map_err(|_| error())?
+        };
+
+        total += whole * unit;
+
+        if !fraction.is_empty() {
+            let digits = fraction.len().min(18) as u32;
+            let fraction: u128 = fraction[..digits as usize].parse().map_err(|_| error())?;
+            total += fraction * unit / 10u128.pow(digits);
+        }
+
+        rest = tail.trim_start();
+    }
+
+    Ok(to_duration(total))
+}
+
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    // REF: http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = if year >= 0 { year } else { year - 399 } / 400;
+    let yoe = year - era * 400;
+    let doy = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+
+    era * 146097 + doe - 719468
+}
+
+fn number(text: &str, range: std::ops::Range<usize>) -> Option<i64> {
+    let digits = text.get(range)?;
+
+    if digits.bytes().all(|b| b.is_ascii_digit()) {
+        digits.parse().ok()
+    } else {
+        None
+    }
+}
+
+/// Parses an RFC 3339 timestamp, such as `2024-01-02T03:04:05Z`, into the duration
+/// since the Unix epoch.
+pub(crate) fn parse_timestamp(text: &str) -> Result<Duration, String> {
+    let error = || format!("'{}' is not a valid RFC 3339 timestamp", text);
+    let value = text.trim();
+    let bytes = value.as_bytes();
+
+    if bytes.len() < 20
+        || bytes[4] != b'-'
+        || bytes[7] != b'-'
+        || !matches!(bytes[10], b'T' | b't' | b' ')
+        || bytes[13] != b':'
+        || bytes[16] != b':'
+    {
+        return Err(error());
+    }
+
+    let year = number(value, 0..4).ok_or_else(error)?;
+    let month = number(value, 5..7).ok_or_else(error)?;
+    let day = number(value, 8..10).ok_or_else(error)?;
+    let hour = number(value, 11..13).ok_or_else(error)?;
+    let minute = number(value, 14..16).ok_or_else(error)?;
+    let second = number(value, 17..19).ok_or_else(error)?;
+
+    if !(1..=12).contains(&month)
+        || !(1..=31).contains(&day)
+        || hour > 23
+        || minute > 59
+        || second > 60
+    {
+        return Err(error());
+    }
+
+    let mut rest = &value[19..];
+    let mut nanos = 0u128;
+
+    if let Some(fraction) = rest.strip_prefix('.') {
+        let len = fraction
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(fraction.len());
+
+        if len == 0 {
+            return Err(error());
+        }
+
+        let digits = len.min(9);
+        nanos = fraction[..digits].parse::<u128>().map_err(|_| error())?
+            * 10u128.pow(9 - digits as u32);
+        rest = &fraction[len..];
+    }
+
+    let offset = match rest {
+        "Z" | "z" => 0,
+        _ if rest.len() == 6 && rest.as_bytes()[3] == b':' => {
+            let sign = match rest.as_bytes()[0] {
+                b'+' => 1,
+                b'-' => -1,
+                _ => return Err(error()),
+            };
+            let hours = number(rest, 1..3).ok_or_else(error)?;
+            let minutes = number(rest, 4..6).ok_or_else(error)?;
+            sign * (hours * 3600 + minutes * 60)
+        }
+        _ => return Err(error()),
+    };
+
+    let secs =
+        days_from_civil(year, month, day) * 86400 + hour * 3600 + minute * 60 + second - offset;
+
+    if secs < 0 {
+        return Err(format!("'{}' is before the Unix epoch", text));
+    }
+
+    Ok(to_duration(secs as u128 * NANOS_PER_SEC + nanos))
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("30", Duration::from_secs(30) ; "seconds without unit")]
+    #[test_case("30s", Duration::from_secs(30) ; "seconds")]
+    #[test_case("5m", Duration::from_secs(300) ; "minutes")]
+    #[test_case("1h30m", Duration::from_secs(5400) ; "hours and minutes")]
+    #[test_case("1.5h", Duration::from_secs(5400) ; "fractional hours")]
+    #[test_case("250ms", Duration::from_millis(250) ; "milliseconds")]
+    #[test_case("2d 4h", Duration::from_secs(187_200) ; "days and hours")]
+    fn parse_duration_should_return_expected_value(text: &str, expected: Duration) {
+        // arrange
+
+        // act
+        let duration = parse_duration(text);
+
+        // assert
+        assert_eq!(duration, Ok(expected));
+    }
+
+    #[test_case("" ; "empty")]
+    #[test_case("5x" ; "unknown unit")]
+    #[test_case("m" ; "missing number")]
+    fn parse_duration_should_fail_for_invalid_value(text: &str) {
+        // arrange
+
+        // act
+        let duration = parse_duration(text);
+
+        // assert
+        assert!(duration.is_err());
+    }
+
+    #[test_case("1970-01-01T00:00:00Z", Duration::ZERO ; "epoch")]
+    #[test_case("2024-02-29T12:30:45Z", Duration::from_secs(1_709_209_845) ; "utc")]
+    #[test_case("2024-02-29T14:30:45.5+02:00", Duration::from_millis(1_709_209_845_500) ; "offset")]
+    fn parse_timestamp_should_return_expected_value(text: &str, expected: Duration) {
+        // arrange
+
+        // act
+        let timestamp = parse_timestamp(text);
+
+        // assert
+        assert_eq!(timestamp, Ok(expected));
+    }
+}
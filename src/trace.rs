@@ -0,0 +1,75 @@
+use cfg_if::cfg_if;
+
+#[cfg(feature = "std")]
+use crate::{ConfigurationProvider, LoadError, LoadResult};
+
+cfg_if! {
+    if #[cfg(feature = "tracing")] {
+        // loads a provider within a span and reports the outcome with the number of keys loaded
+        #[cfg(feature = "std")]
+        pub(crate) fn load(provider: &mut dyn ConfigurationProvider) -> LoadResult {
+            let name = provider.name().to_owned();
+            let span = tracing::info_span!("load", provider = %name);
+            let _entered = span.enter();
+            let result = provider.load();
+
+            match &result {
+                Ok(_) => tracing::debug!(
+                    provider = %name,
+                    keys = crate::util::all_keys(provider).len(),
+                    "Configuration provider loaded"
+                ),
+                Err(error) => tracing::warn!(
+                    provider = %name,
+                    error = error.message(),
+                    "Configuration provider failed to load"
+                ),
+            }
+
+            result
+        }
+
+        #[cfg(feature = "std")]
+        pub(crate) fn reloaded(providers: usize, errors: &[(String, LoadError)]) {
+            if errors.is_empty() {
+                tracing::info!(providers, "Configuration reloaded");
+            } else {
+                let failed: Vec<_> = errors.iter().map(|(name, _)| name.as_str()).collect();
+
+                tracing::warn!(
+                    providers,
+                    failed = %failed.join(", "),
+                    "Configuration failed to reload"
+                );
+            }
+        }
+
+        #[cfg(feature = "file")]
+        pub(crate) fn file_changed(provider: &str, path: &std::path::Path) {
+            tracing::debug!(provider, path = %path.display(), "Configuration file changed");
+        }
+
+        #[cfg(any(feature = "file", all(feature = "refresh", feature = "async")))]
+        pub(crate) fn reload_failed(provider: &str, error: &crate::LoadError) {
+            tracing::warn!(
+                provider,
+                error = error.message(),
+                "Configuration provider failed to reload in the background"
+            );
+        }
+    } else {
+        #[cfg(feature = "std")]
+        pub(crate) fn load(provider: &mut dyn ConfigurationProvider) -> LoadResult {
+            provider.load()
+        }
+
+        #[cfg(feature = "std")]
+        pub(crate) fn reloaded(_providers: usize, _errors: &[(String, LoadError)]) {}
+
+        #[cfg(feature = "file")]
+        pub(crate) fn file_changed(_provider: &str, _path: &std::path::Path) {}
+
+        #[cfg(any(feature = "file", all(feature = "refresh", feature = "async")))]
+        pub(crate) fn reload_failed(_provider: &str, _error: &crate::LoadError) {}
+    }
+}
@@ -3,7 +3,6 @@ use std::cmp::{min, Ordering};
 use std::collections::HashMap;
 use std::fmt::{Formatter, Result as FormatResult, Write};
 
-#[cfg(feature = "json")]
 pub(crate) fn to_pascal_case<T: AsRef<str>>(text: T) -> String {
     let mut chars = text.as_ref().chars();
 
@@ -14,7 +13,7 @@ pub(crate) fn to_pascal_case<T: AsRef<str>>(text: T) -> String {
     }
 }
 
-#[cfg(feature = "cmd")]
+#[cfg(any(feature = "cmd", feature = "env"))]
 pub(crate) fn to_pascal_case_parts<T: AsRef<str>>(text: T, sep: char) -> String {
     let parts = text.as_ref().split(sep);
     let mut pascal_case = String::with_capacity(text.as_ref().len());
@@ -31,6 +30,28 @@ pub(crate) fn to_pascal_case_parts<T: AsRef<str>>(text: T, sep: char) -> String
     pascal_case
 }
 
+// strips the prefix from a key that is a descendant of the prefix
+#[cfg(any(feature = "chained", feature = "prefix", feature = "profiles"))]
+pub(crate) fn strip_prefix<'a>(key: &'a str, prefix: &str) -> Option<&'a str> {
+    let delimiter = ConfigurationPath::key_delimiter();
+
+    if key.len() > prefix.len()
+        && key.is_char_boundary(prefix.len())
+        && key[..prefix.len()].eq_ignore_ascii_case(prefix)
+        && key[prefix.len()..].starts_with(delimiter)
+    {
+        Some(&key[(prefix.len() + delimiter.len())..])
+    } else {
+        None
+    }
+}
+
+// gets the first segment of a path
+#[cfg(any(feature = "std", feature = "chained"))]
+pub(crate) fn first_segment(path: &str) -> &str {
+    ConfigurationPath::segments(path).next().unwrap_or(path)
+}
+
 /// Compares two configuration keys.
 ///
 /// # Arguments
@@ -79,6 +100,497 @@ pub fn cmp_keys(key: &str, other_key: &str) -> Ordering {
     parts_1.len().cmp(&parts_2.len())
 }
 
+#[derive(Clone, Default)]
+struct KeyNode {
+    // the number of keys at or below the node
+    keys: usize,
+    children: HashMap<String, (String, KeyNode)>,
+}
+
+impl KeyNode {
+    // adds the path of the original key; the casing of each segment is updated when the key
+    // already exists
+    fn insert(&mut self, key: &str, exists: bool) {
+        let mut node = self;
+
+        for segment in ConfigurationPath::segments(key) {
+            let child = node
+                .children
+                .entry(segment.to_uppercase())
+                .or_insert_with(|| (segment.to_owned(), KeyNode::default()));
+
+            if exists {
+                child.0 = segment.to_owned();
+            } else {
+                child.1.keys += 1;
+            }
+
+            node = &mut child.1;
+        }
+    }
+
+    // removes the node at the normalized path, including its descendants, and returns the
+    // number of keys that were removed
+    fn remove(&mut self, segments: &[&str]) -> usize {
+        let (segment, rest) = match segments.split_first() {
+            Some(parts) => parts,
+            _ => return 0,
+        };
+        let child = match self.children.get_mut(*segment) {
+            Some((_, child)) => child,
+            _ => return 0,
+        };
+        let removed = if rest.is_empty() {
+            child.keys
+        } else {
+            child.remove(rest)
+        };
+
+        child.keys -= removed;
+
+        if child.keys == 0 {
+            self.children.remove(*segment);
+        }
+
+        removed
+    }
+}
+
+// indexes the original keys by their path segments
+fn index(data: &HashMap<String, (String, Value)>) -> KeyNode {
+    let mut root = KeyNode::default();
+
+    for (key, _) in data.values() {
+        root.insert(key, false);
+    }
+
+    root
+}
+
+// an intermediate node used to convert flattened data back into a configuration tree
+#[derive(Default)]
+struct TreeBuilder {
+    value: Option<ConfigurationNode>,
+    members: Vec<(String, TreeBuilder)>,
+}
+
+impl TreeBuilder {
+    fn member(&mut self, name: &str) -> &mut TreeBuilder {
+        let index = match self
+            .members
+            .iter()
+            .position(|(other, _)| other.eq_ignore_ascii_case(name))
+        {
+            Some(index) => index,
+            _ => {
+                self.members.push((name.to_owned(), TreeBuilder::default()));
+                self.members.len() - 1
+            }
+        };
+
+        &mut self.members[index].1
+    }
+
+    fn build(self, path: &str) -> Result<ConfigurationNode, String> {
+        if self.members.is_empty() {
+            return Ok(self
+                .value
+                .unwrap_or_else(|| ConfigurationNode::Object(Vec::new())));
+        }
+
+        if self.value.is_some() {
+            return Err(path.to_owned());
+        }
+
+        let mut members = Vec::with_capacity(self.members.len());
+
+        for (name, member) in self.members {
+            let child = if path.is_empty() {
+                name.clone()
+            } else {
+                ConfigurationPath::combine(&[path, &name])
+            };
+            let node = member.build(&child)?;
+            members.push((name, node));
+        }
+
+        let mut indexes: Vec<_> = members
+            .iter()
+            .map(|(name, _)| name.parse::<usize>().ok())
+            .collect::<Option<_>>()
+            .unwrap_or_default();
+
+        indexes.sort_unstable();
+
+        // members named by every index from zero are elements of an array
+        if !indexes.is_empty() && indexes.iter().enumerate().all(|(i, index)| i == *index) {
+            members.sort_by_key(|(name, _)| name.parse::<usize>().unwrap_or_default());
+            Ok(ConfigurationNode::Array(
+                members.into_iter().map(|(_, node)| node).collect(),
+            ))
+        } else {
+            // the order keys were added is not recorded; see ConfigurationData::to_tree
+            members.sort_by(|(name, _), (other, _)| name.cmp(other));
+            Ok(ConfigurationNode::Object(members))
+        }
+    }
+}
+
+/// Represents flattened configuration data that is indexed by key path segments.
+///
+/// # Remarks
+///
+/// The data key is normalized to uppercase. The value is a tuple where the
+/// first item is the originally-cased key and the second item is value. The
+/// key path segments are indexed as a prefix trie so that the child keys of a
+/// section can be resolved without scanning every key.
+#[derive(Clone, Default)]
+pub struct ConfigurationData {
+    data: HashMap<String, (String, Value)>,
+    types: HashMap<String, ConfigValue>,
+    root: KeyNode,
+}
+
+impl PartialEq for ConfigurationData {
+    fn eq(&self, other: &Self) -> bool {
+        // the key index is derived from the data so it does not need to be compared
+        self.data == other.data && self.types == other.types
+    }
+}
+
+impl ConfigurationData {
+    /// Initializes new configuration data.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The source hash map where the key is normalized to uppercase and the
+    ///   value is a tuple containing the originally cased key and value
+    pub fn new(data: HashMap<String, (String, Value)>) -> Self {
+        let root = index(&data);
+
+        Self {
+            data,
+            types: HashMap::with_capacity(0),
+            root,
+        }
+    }
+
+    /// Sets the typed values for keys that are not text.
+    ///
+    /// # Arguments
+    ///
+    /// * `types` - The typed values where the key is normalized to uppercase
+    pub fn with_types(mut self, types: HashMap<String, ConfigValue>) -> Self {
+        self.types = types;
+        self
+    }
+
+    /// Gets the typed configuration value for the specified key, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The case-insensitive key of the value to retrieve
+    pub fn get_typed(&self, key: &str) -> Option<ConfigValue> {
+        let key = key.to_uppercase();
+
+        if let Some(value) = self.types.get(&key) {
+            Some(value.clone())
+        } else {
+            self.data
+                .get(&key)
+                .map(|t| ConfigValue::String(t.1.clone()))
+        }
+    }
+
+    /// Gets the configuration value for the specified key, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The case-insensitive key of the value to retrieve
+    pub fn get(&self, key: &str) -> Option<Value> {
+        self.data.get(&key.to_uppercase()).map(|t| t.1.clone())
+    }
+
+    /// Gets a borrowed configuration value for the specified key, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The case-insensitive key of the value to retrieve
+    pub fn get_str(&self, key: &str) -> Option<&str> {
+        self.data.get(&key.to_uppercase()).map(|t| t.1.as_str())
+    }
+
+    /// Gets the number of key/value pairs.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Gets a value indicating whether there are no key/value pairs.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Gets the underlying hash map of key/value pairs.
+    pub fn as_map(&self) -> &HashMap<String, (String, Value)> {
+        &self.data
+    }
+
+    /// Sets a configuration value.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the value to set
+    /// * `value` - The value to set
+    pub fn set(&mut self, key: &str, value: Value) {
+        let normalized = key.to_uppercase();
+
+        self.types.remove(&normalized);
+
+        let exists = self
+            .data
+            .insert(normalized, (key.to_owned(), value))
+            .is_some();
+
+        self.root.insert(key, exists);
+    }
+
+    /// Removes a configuration value, including all of its descendants.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the value to remove
+    pub fn remove(&mut self, key: &str) {
+        let normalized = key.to_uppercase();
+        let segments: Vec<_> = ConfigurationPath::segments(&normalized).collect();
+        let removed = self.root.remove(&segments);
+
+        // a key without descendants is removed without scanning all of the data
+        if removed == 0 {
+            return;
+        } else if removed == 1 && self.data.remove(&normalized).is_some() {
+            self.types.remove(&normalized);
+            return;
+        }
+
+        let descendant = format!("{}{}", normalized, ConfigurationPath::key_delimiter());
+        let removed = |other: &String| other == &normalized || other.starts_with(&descendant);
+
+        self.data.retain(|other, _| !removed(other));
+        self.types.retain(|other, _| !removed(other));
+    }
+
+    /// Converts the configuration data into a hierarchical configuration tree.
+    ///
+    /// # Remarks
+    ///
+    /// Object members are ordered by name and members whose names are every index from zero
+    /// become the elements of an array. A key that has both a value and descendants cannot be
+    /// represented in a tree, in which case the key is returned as the error.
+    ///
+    /// The order in which keys were added cannot be kept because the data is indexed by the
+    /// normalized key and does not record it. The order of a JSON document is already lost when
+    /// it is read because its members are read into a map ordered by name. Ordering members by
+    /// name keeps the written content the same for the same data.
+    pub fn to_tree(&self) -> Result<ConfigurationNode, String> {
+        let mut root = TreeBuilder::default();
+
+        for (normalized, (key, value)) in &self.data {
+            let mut node = &mut root;
+
+            for segment in ConfigurationPath::segments(key) {
+                node = node.member(&ConfigurationPath::unescape(segment));
+            }
+
+            node.value = Some(match self.types.get(normalized) {
+                Some(ConfigValue::String(text)) => ConfigurationNode::Text(text.to_string()),
+                Some(typed) => ConfigurationNode::Typed(value.to_string(), typed.clone()),
+                _ => ConfigurationNode::Text(value.to_string()),
+            });
+        }
+
+        root.build("")
+    }
+
+    /// Gets an iterator over the key/value pairs using the original keys.
+    pub fn pairs(&self) -> ConfigurationPairs<'_> {
+        Box::new(
+            self.data
+                .values()
+                .map(|(key, value)| (key.clone(), value.clone())),
+        )
+    }
+
+    /// Accumulates the immediate child keys of the specified parent path.
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - The accumulated keys
+    /// * `parent_path` - The parent path
+    pub fn child_keys(&self, keys: &mut Vec<String>, parent_path: Option<&str>) {
+        let mut node = &self.root;
+
+        if let Some(path) = parent_path {
+            for segment in ConfigurationPath::segments(path) {
+                if let Some(child) = node.children.get(&segment.to_uppercase()) {
+                    node = &child.1;
+                } else {
+                    return;
+                }
+            }
+        }
+
+        if node.children.is_empty() {
+            return;
+        }
+
+        keys.extend(node.children.values().map(|child| child.0.clone()));
+        keys.sort_by(|k1, k2| cmp_keys(k1, k2));
+    }
+}
+
+impl From<HashMap<String, (String, Value)>> for ConfigurationData {
+    fn from(value: HashMap<String, (String, Value)>) -> Self {
+        Self::new(value)
+    }
+}
+
+/// Represents a node in a hierarchical configuration document.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConfigurationNode {
+    /// Indicates a text value.
+    Text(String),
+
+    /// Indicates a typed value and its text representation.
+    Typed(String, ConfigValue),
+
+    /// Indicates an object with named members.
+    Object(Vec<(String, ConfigurationNode)>),
+
+    /// Indicates an array of elements.
+    Array(Vec<ConfigurationNode>),
+}
+
+/// Defines the behavior of a document that can be converted into a configuration tree.
+pub trait IntoConfigurationTree {
+    /// Converts the document into the root [`ConfigurationNode`].
+    fn into_tree(self) -> ConfigurationNode;
+}
+
+impl IntoConfigurationTree for ConfigurationNode {
+    fn into_tree(self) -> ConfigurationNode {
+        self
+    }
+}
+
+/// Represents a visitor that flattens a configuration tree into [`ConfigurationData`].
+///
+/// # Remarks
+///
+/// Object members are combined with the [key delimiter](crate::ConfigurationPath::key_delimiter)
+/// and array elements are keyed by their zero-based index. An empty object produces an
+/// empty value whereas an empty array produces no value. A typed value is preserved so
+/// that it can be retrieved with [`get_typed`](crate::ConfigurationProvider::get_typed).
+#[derive(Default)]
+pub struct FlattenVisitor {
+    pascal_case: bool,
+    data: HashMap<String, (String, Value)>,
+    types: HashMap<String, ConfigValue>,
+    paths: Vec<String>,
+}
+
+impl FlattenVisitor {
+    /// Initializes a new flatten visitor.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets a value indicating whether object member names are converted to Pascal case.
+    ///
+    /// # Arguments
+    ///
+    /// * `pascal_case` - `true` to convert the first character of member names to uppercase
+    pub fn pascal_case(mut self, pascal_case: bool) -> Self {
+        self.pascal_case = pascal_case;
+        self
+    }
+
+    /// Flattens the specified document into configuration data.
+    ///
+    /// # Arguments
+    ///
+    /// * `document` - The document to flatten
+    pub fn visit<T: IntoConfigurationTree>(mut self, document: T) -> ConfigurationData {
+        match document.into_tree() {
+            ConfigurationNode::Object(members) => self.visit_members(members),
+            ConfigurationNode::Array(elements) => self.visit_elements(elements),
+            _ => {}
+        }
+
+        self.data.shrink_to_fit();
+        self.types.shrink_to_fit();
+        ConfigurationData::new(self.data).with_types(self.types)
+    }
+
+    fn visit_node(&mut self, node: ConfigurationNode) {
+        match node {
+            ConfigurationNode::Object(members) => {
+                if members.is_empty() {
+                    self.add_value(String::new());
+                } else {
+                    self.visit_members(members);
+                }
+            }
+            ConfigurationNode::Array(elements) => self.visit_elements(elements),
+            ConfigurationNode::Text(text) => self.add_value(text),
+            ConfigurationNode::Typed(text, value) => {
+                let key = self.paths.last().unwrap().to_uppercase();
+                self.types.insert(key, value);
+                self.add_value(text);
+            }
+        }
+    }
+
+    fn visit_members(&mut self, members: Vec<(String, ConfigurationNode)>) {
+        for (name, node) in members {
+            let name = if self.pascal_case {
+                to_pascal_case(name)
+            } else {
+                name
+            };
+
+            self.enter_context(name);
+            self.visit_node(node);
+            self.exit_context();
+        }
+    }
+
+    fn visit_elements(&mut self, elements: Vec<ConfigurationNode>) {
+        for (index, node) in elements.into_iter().enumerate() {
+            self.enter_context(index.to_string());
+            self.visit_node(node);
+            self.exit_context();
+        }
+    }
+
+    fn add_value(&mut self, value: String) {
+        let key = self.paths.last().unwrap().clone();
+        self.data.insert(key.to_uppercase(), (key, value.into()));
+    }
+
+    fn enter_context(&mut self, context: String) {
+        if let Some(parent) = self.paths.last() {
+            let path = ConfigurationPath::combine(&[parent, &context]);
+            self.paths.push(path);
+        } else {
+            self.paths.push(context);
+        }
+    }
+
+    fn exit_context(&mut self) {
+        self.paths.pop();
+    }
+}
+
 /// Accumulates child keys based on the specified hash map.
 ///
 /// # Arguments
@@ -114,16 +626,53 @@ pub fn accumulate_child_keys(
     keys.sort_by(|k1, k2| cmp_keys(k1, k2));
 }
 
-fn segment(key: &str, start: usize) -> &str {
-    let subkey = &key[start..];
+/// Gets all of the keys, which have a value, in the specified provider.
+///
+/// # Arguments
+///
+/// * `provider` - The [`ConfigurationProvider`] to get the keys from
+#[cfg(any(feature = "mapped", all(feature = "tracing", feature = "std")))]
+pub(crate) fn all_keys(provider: &dyn ConfigurationProvider) -> Vec<String> {
+    let mut keys = Vec::new();
+    visit_keys(provider, None, &mut keys);
+    keys
+}
 
-    if let Some(index) = subkey.find(ConfigurationPath::key_delimiter()) {
-        &subkey[..index]
-    } else {
-        subkey
+#[cfg(any(feature = "mapped", all(feature = "tracing", feature = "std")))]
+fn visit_keys(
+    provider: &dyn ConfigurationProvider,
+    parent_path: Option<&str>,
+    keys: &mut Vec<String>,
+) {
+    let mut child_keys = Vec::new();
+    let mut visited = std::collections::HashSet::new();
+
+    provider.child_keys(&mut child_keys, parent_path);
+
+    for child_key in child_keys {
+        if !visited.insert(child_key.to_uppercase()) {
+            continue;
+        }
+
+        let path = if let Some(parent) = parent_path {
+            ConfigurationPath::combine(&[parent, &child_key])
+        } else {
+            child_key
+        };
+
+        if provider.get(&path).is_some() {
+            keys.push(path.clone());
+        }
+
+        visit_keys(provider, Some(&path), keys);
     }
 }
 
+fn segment(key: &str, start: usize) -> &str {
+    let subkey = &key[start..];
+    ConfigurationPath::segments(subkey).next().unwrap_or(subkey)
+}
+
 /// Formats a debug view of an entire configuration hierarchy.
 ///
 /// # Arguments
@@ -152,7 +701,13 @@ fn recurse_children<T: ConfigurationRoot>(
         for provider in root.providers().rev() {
             if let Some(value) = provider.get(child.path()) {
                 formatter.write_char('=')?;
-                formatter.write_str(&value)?;
+
+                if root.is_sensitive(child.path()) {
+                    formatter.write_str(REDACTED)?;
+                } else {
+                    formatter.write_str(&value)?;
+                }
+
                 formatter.write_str(" (")?;
                 formatter.write_str(provider.name())?;
                 formatter.write_char(')')?;
@@ -195,6 +750,89 @@ mod tests {
         assert_eq!(pascal_case, "NoBuild");
     }
 
+    fn data(keys: &[&str]) -> ConfigurationData {
+        keys.iter()
+            .map(|k| (k.to_uppercase(), (k.to_string(), Value::new(String::new()))))
+            .collect::<HashMap<_, _>>()
+            .into()
+    }
+
+    #[test]
+    fn child_keys_should_return_immediate_children_of_root() {
+        // arrange
+        let data = data(&["Mem1:KeyInMem1", "Mem1:KeyInMem1:Deep1", "Mem2:KeyInMem2"]);
+        let mut keys = Vec::new();
+
+        // act
+        data.child_keys(&mut keys, None);
+        keys.sort();
+
+        // assert
+        assert_eq!(keys, vec!["Mem1", "Mem2"]);
+    }
+
+    #[test]
+    fn child_keys_should_return_immediate_children_of_parent_path() {
+        // arrange
+        let data = data(&["Mem1:Key1", "Mem1:Key1:Deep1", "Mem1:Key2", "Mem2:Key3"]);
+        let mut keys = Vec::new();
+
+        // act
+        data.child_keys(&mut keys, Some("MEM1"));
+        keys.sort();
+
+        // assert
+        assert_eq!(keys, vec!["Key1", "Key2"]);
+    }
+
+    #[test]
+    fn child_keys_should_return_nothing_for_unknown_parent_path() {
+        // arrange
+        let data = data(&["Mem1:Key1"]);
+        let mut keys = Vec::new();
+
+        // act
+        data.child_keys(&mut keys, Some("Mem1:Key1:Deep1"));
+
+        // assert
+        assert!(keys.is_empty());
+    }
+
+    #[test]
+    fn child_keys_should_include_key_that_is_set() {
+        // arrange
+        let mut data = data(&["Mem1:Key1"]);
+        let mut keys = Vec::new();
+
+        // act
+        data.set("Mem1:Key2", "value".to_owned().into());
+        data.set("mem1:key1", "value".to_owned().into());
+        data.child_keys(&mut keys, Some("Mem1"));
+        keys.sort();
+
+        // assert
+        assert_eq!(keys, vec!["Key2", "key1"]);
+    }
+
+    #[test]
+    fn child_keys_should_exclude_key_that_is_removed() {
+        // arrange
+        let mut data = data(&["Mem1:Key1", "Mem1:Key1:Deep1", "Mem1:Key2", "Mem2:Key3"]);
+        let mut root = Vec::new();
+        let mut children = Vec::new();
+
+        // act
+        data.remove("Mem1:Key1");
+        data.remove("Mem2:Key3");
+        data.child_keys(&mut root, None);
+        data.child_keys(&mut children, Some("Mem1"));
+
+        // assert
+        assert_eq!(root, vec!["Mem1"]);
+        assert_eq!(children, vec!["Key2"]);
+        assert_eq!(data.len(), 1);
+    }
+
     #[test]
     fn to_pascal_case_parts_should_normalize_argument_name() {
         // arrange
@@ -206,4 +844,55 @@ mod tests {
         // assert
         assert_eq!(pascal_case, "NoBuild");
     }
+
+    #[test]
+    fn flatten_visitor_should_flatten_objects_and_arrays() {
+        // arrange
+        let tree = ConfigurationNode::Object(vec![
+            (
+                "service".into(),
+                ConfigurationNode::Object(vec![
+                    ("name".into(), ConfigurationNode::Text("demo".into())),
+                    (
+                        "ports".into(),
+                        ConfigurationNode::Array(vec![
+                            ConfigurationNode::Typed("80".into(), ConfigValue::Int(80)),
+                            ConfigurationNode::Typed("443".into(), ConfigValue::Int(443)),
+                        ]),
+                    ),
+                ]),
+            ),
+            ("empty".into(), ConfigurationNode::Object(Vec::new())),
+            ("none".into(), ConfigurationNode::Array(Vec::new())),
+        ]);
+
+        // act
+        let data = FlattenVisitor::new().pascal_case(true).visit(tree);
+
+        // assert
+        assert_eq!(data.len(), 4);
+        assert_eq!(data.get_str("Service:Name"), Some("demo"));
+        assert_eq!(
+            data.get_typed("Service:Ports:1"),
+            Some(ConfigValue::Int(443))
+        );
+        assert_eq!(data.get_str("Empty"), Some(""));
+        assert!(data.get("None").is_none());
+        assert_eq!(data.as_map()["SERVICE:PORTS:0"].0, "Service:Ports:0");
+    }
+
+    #[test]
+    fn flatten_visitor_should_preserve_member_case_by_default() {
+        // arrange
+        let tree = ConfigurationNode::Object(vec![(
+            "logLevel".into(),
+            ConfigurationNode::Text("Debug".into()),
+        )]);
+
+        // act
+        let data = FlattenVisitor::new().visit(tree);
+
+        // assert
+        assert_eq!(data.as_map()["LOGLEVEL"].0, "logLevel");
+    }
 }
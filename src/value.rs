@@ -0,0 +1,144 @@
+use crate::Value;
+use std::fmt::{Debug, Display, Formatter, Result as FormatResult};
+use std::ops::Deref;
+
+/// Represents a reference to a configuration value.
+///
+/// # Remarks
+///
+/// A value reference borrows the value directly from its source when possible and
+/// only holds onto a shared [`Value`](crate::Value) otherwise.
+#[derive(Clone)]
+pub enum ValueRef<'a> {
+    /// Indicates a value borrowed from its source.
+    Borrowed(&'a str),
+
+    /// Indicates a shared value.
+    Shared(Value),
+}
+
+impl ValueRef<'_> {
+    /// Gets the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Borrowed(value) => value,
+            Self::Shared(value) => value.as_str(),
+        }
+    }
+
+    /// Converts the reference into an owned [`Value`](crate::Value).
+    pub fn into_value(self) -> Value {
+        match self {
+            Self::Borrowed(value) => Value::new(value.to_owned()),
+            Self::Shared(value) => value,
+        }
+    }
+}
+
+impl Deref for ValueRef<'_> {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for ValueRef<'_> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl PartialEq<str> for ValueRef<'_> {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for ValueRef<'_> {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl Debug for ValueRef<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FormatResult {
+        Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl Display for ValueRef<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FormatResult {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ValueRef<'a> {
+    fn from(value: &'a str) -> Self {
+        Self::Borrowed(value)
+    }
+}
+
+impl From<Value> for ValueRef<'_> {
+    fn from(value: Value) -> Self {
+        Self::Shared(value)
+    }
+}
+
+/// Represents a typed configuration value.
+///
+/// # Remarks
+///
+/// Sources that only provide text, such as command line arguments or environment
+/// variables, always produce [`ConfigValue::String`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConfigValue {
+    /// Indicates a text value.
+    String(Value),
+
+    /// Indicates a Boolean value.
+    Bool(bool),
+
+    /// Indicates a signed integer value.
+    Int(i64),
+
+    /// Indicates a floating point value.
+    Float(f64),
+
+    /// Indicates an explicit null value.
+    Null,
+}
+
+impl ConfigValue {
+    /// Gets a value indicating whether the value is null.
+    pub fn is_null(&self) -> bool {
+        matches!(self, Self::Null)
+    }
+
+    /// Converts the typed value into its text [`Value`](crate::Value).
+    pub fn into_value(self) -> Value {
+        match self {
+            Self::String(value) => value,
+            Self::Null => Value::new(String::new()),
+            other => Value::new(other.to_string()),
+        }
+    }
+}
+
+impl Display for ConfigValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FormatResult {
+        match self {
+            Self::String(value) => f.write_str(value),
+            Self::Bool(value) => Display::fmt(value, f),
+            Self::Int(value) => Display::fmt(value, f),
+            Self::Float(value) => Display::fmt(value, f),
+            Self::Null => Ok(()),
+        }
+    }
+}
+
+impl From<Value> for ConfigValue {
+    fn from(value: Value) -> Self {
+        Self::String(value)
+    }
+}
@@ -0,0 +1,168 @@
+use crate::de::{from_config, Error};
+use crate::Configuration;
+use serde::de::DeserializeOwned;
+use std::fmt::{Debug, Formatter, Result as FormatResult};
+use std::sync::{Arc, RwLock};
+use tokens::{on_change, Subscription};
+
+type ChangeCallback<T> = Box<dyn Fn(&T) + Send + Sync>;
+type ErrorCallback = Box<dyn Fn(&Error) + Send + Sync>;
+
+struct WatchedState<T> {
+    configuration: Arc<dyn Configuration + Send + Sync>,
+    key: Option<String>,
+    current: RwLock<Arc<T>>,
+    on_change: RwLock<Option<ChangeCallback<T>>>,
+    on_error: RwLock<Option<ErrorCallback>>,
+}
+
+impl<T: DeserializeOwned> WatchedState<T> {
+    fn bind(configuration: &dyn Configuration, key: Option<&str>) -> Result<T, Error> {
+        match key {
+            Some(key) => from_config(&**configuration.section(key)),
+            _ => from_config(configuration),
+        }
+    }
+
+    fn rebind(&self) {
+        match Self::bind(&*self.configuration, self.key.as_deref()) {
+            Ok(value) => {
+                let value = Arc::new(value);
+
+                *self.current.write().unwrap() = value.clone();
+
+                if let Some(callback) = &*self.on_change.read().unwrap() {
+                    (callback)(&value);
+                }
+            }
+            Err(error) => {
+                if let Some(callback) = &*self.on_error.read().unwrap() {
+                    (callback)(&error);
+                }
+            }
+        }
+    }
+}
+
+/// Represents a value bound to a [`Configuration`](crate::Configuration) that is bound again
+/// whenever the configuration changes.
+pub struct Watched<T> {
+    state: Arc<WatchedState<T>>,
+    _subscription: Box<dyn Subscription>,
+}
+
+impl<T: DeserializeOwned + Send + Sync + 'static> Watched<T> {
+    /// Initializes a new watched value.
+    ///
+    /// # Arguments
+    ///
+    /// * `configuration` - The shared [`Configuration`](crate::Configuration) to bind and watch
+    /// * `key` - The optional key of the configuration section to bind
+    ///
+    /// # Remarks
+    ///
+    /// An error is returned if the value cannot be bound initially. When the value cannot be
+    /// bound after a change, the current value is retained.
+    pub fn new(
+        configuration: Arc<dyn Configuration + Send + Sync>,
+        key: Option<&str>,
+    ) -> Result<Self, Error> {
+        let current = WatchedState::<T>::bind(&*configuration, key)?;
+        let producer = configuration.clone();
+        let state = Arc::new(WatchedState {
+            configuration,
+            key: key.map(ToOwned::to_owned),
+            current: RwLock::new(Arc::new(current)),
+            on_change: Default::default(),
+            on_error: Default::default(),
+        });
+        let subscription = on_change(
+            move || producer.reload_token(),
+            |state: Option<Arc<WatchedState<T>>>| state.unwrap().rebind(),
+            Some(state.clone()),
+        );
+
+        Ok(Self {
+            state,
+            _subscription: Box::new(subscription),
+        })
+    }
+}
+
+impl<T> Watched<T> {
+    /// Gets the current value.
+    pub fn current(&self) -> Arc<T> {
+        self.state.current.read().unwrap().clone()
+    }
+
+    /// Sets the function invoked with the new value after the configuration changes.
+    ///
+    /// # Arguments
+    ///
+    /// * `callback` - The function to invoke
+    pub fn on_change<F: Fn(&T) + Send + Sync + 'static>(self, callback: F) -> Self {
+        *self.state.on_change.write().unwrap() = Some(Box::new(callback));
+        self
+    }
+
+    /// Sets the function invoked when the value cannot be bound after the configuration changes.
+    ///
+    /// # Arguments
+    ///
+    /// * `callback` - The function to invoke
+    pub fn on_error<F: Fn(&Error) + Send + Sync + 'static>(self, callback: F) -> Self {
+        *self.state.on_error.write().unwrap() = Some(Box::new(callback));
+        self
+    }
+}
+
+impl<T: Debug> Debug for Watched<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FormatResult {
+        f.debug_tuple("Watched").field(&self.current()).finish()
+    }
+}
+
+pub mod ext {
+
+    use super::*;
+
+    /// Defines extension methods to bind a shared [`Configuration`](crate::Configuration) that
+    /// is bound again whenever it changes.
+    pub trait WatchedConfigurationBinder {
+        /// Creates and returns a watched structure bound to the configuration.
+        ///
+        /// # Remarks
+        ///
+        /// A shared configuration is typically created with
+        /// [`into_shared`](crate::ConfigurationRoot::into_shared). Changes are only observed when
+        /// the configuration supports them, such as with the **async** feature.
+        fn reify_watched<T>(&self) -> Result<Watched<T>, Error>
+        where
+            T: DeserializeOwned + Send + Sync + 'static;
+
+        /// Creates and returns a watched structure bound to the specified configuration section.
+        ///
+        /// # Arguments
+        ///
+        /// * `key` - The key of the configuration section to bind
+        fn reify_watched_at<T>(&self, key: impl AsRef<str>) -> Result<Watched<T>, Error>
+        where
+            T: DeserializeOwned + Send + Sync + 'static;
+    }
+
+    impl WatchedConfigurationBinder for Arc<dyn Configuration + Send + Sync> {
+        fn reify_watched<T>(&self) -> Result<Watched<T>, Error>
+        where
+            T: DeserializeOwned + Send + Sync + 'static,
+        {
+            Watched::new(self.clone(), None)
+        }
+
+        fn reify_watched_at<T>(&self, key: impl AsRef<str>) -> Result<Watched<T>, Error>
+        where
+            T: DeserializeOwned + Send + Sync + 'static,
+        {
+            Watched::new(self.clone(), Some(key.as_ref()))
+        }
+    }
+}
@@ -0,0 +1,308 @@
+use crate::{
+    ConfigurationBuilder, ConfigurationPairs, ConfigurationProvider, ConfigurationSource,
+    ConfigurationUpdate, ConfigurationUpdateSink, DynamicKeyValueFeed, FeedConfigurationProvider,
+    LoadError, LoadResult, Value,
+};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokens::ChangeToken;
+
+type Pairs = Vec<(String, String)>;
+
+/// Defines the behavior of a client that fetches configuration content over HTTP.
+///
+/// # Remarks
+///
+/// The client is typically implemented over the `fetch` API of the host, such as with `web-sys`
+/// in a browser or a worker, which keeps this crate independent of any particular HTTP client
+/// and allows it to be built for `wasm32-unknown-unknown`. Configuration is loaded
+/// synchronously, so a client running on a single thread usually returns content that was
+/// fetched before the configuration was built.
+pub trait WebClient: Send + Sync {
+    /// Fetches the content of a resource.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - The URL of the resource to fetch
+    ///
+    /// # Remarks
+    ///
+    /// `None` is returned if the resource does not exist, such as for a `404` response.
+    fn fetch(&self, url: &str) -> Result<Option<Vec<u8>>, LoadError>;
+
+    /// Polls a resource for changes.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - The URL of the resource to poll
+    /// * `interval` - The interval at which the resource is fetched
+    /// * `poller` - The [`WebPoller`] the fetched content is sent to
+    ///
+    /// # Remarks
+    ///
+    /// This method is called once, after the first successful load, and only when a poll
+    /// interval is configured. It should return immediately and send the content fetched at each
+    /// interval to the poller, such as from a timer of the host, until
+    /// [`send`](WebPoller::send) returns `false`.
+    fn poll(&self, url: &str, interval: Duration, poller: WebPoller);
+}
+
+// flattens JSON content into key/value pairs
+fn flatten(url: &str, content: &[u8]) -> Result<Pairs, LoadError> {
+    let data = crate::json::parse(content).map_err(|error| {
+        LoadError::Generic(format!(
+            "The configuration fetched from '{}' is invalid. {}",
+            url, error.message
+        ))
+    })?;
+
+    Ok(data
+        .as_map()
+        .values()
+        .map(|(key, value)| (key.clone(), value.to_string()))
+        .collect())
+}
+
+/// Represents the handle the content of a polled resource is sent to.
+#[derive(Clone)]
+pub struct WebPoller {
+    url: String,
+    content: Arc<Mutex<Option<Vec<u8>>>>,
+    sink: ConfigurationUpdateSink,
+}
+
+impl WebPoller {
+    /// Applies the fetched content and signals the reload token of the provider if it changed.
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - The fetched content or `None` if the resource no longer exists
+    ///
+    /// # Remarks
+    ///
+    /// `false` is returned if the provider has been dropped, which indicates polling should be
+    /// stopped. Content that is unchanged does not signal the reload token and content that
+    /// cannot be parsed is ignored, in which case the current values are retained.
+    pub fn send(&self, content: Option<Vec<u8>>) -> bool {
+        let mut current = self.content.lock().unwrap();
+
+        if *current == content {
+            return self.sink.is_alive();
+        }
+
+        let pairs = match &content {
+            Some(content) => match flatten(&self.url, content) {
+                Ok(pairs) => pairs,
+                _ => return self.sink.is_alive(),
+            },
+            _ => Vec::new(),
+        };
+
+        *current = content;
+        self.sink.send(ConfigurationUpdate::Snapshot(pairs))
+    }
+}
+
+// adapts the content fetched by a client to the feed that backs the provider
+struct ResourceFeed {
+    client: Arc<dyn WebClient>,
+    url: String,
+    optional: bool,
+    poll_interval: Option<Duration>,
+    content: Arc<Mutex<Option<Vec<u8>>>>,
+}
+
+impl DynamicKeyValueFeed for ResourceFeed {
+    fn snapshot(&self) -> Result<Pairs, LoadError> {
+        let content = self.client.fetch(&self.url)?;
+        let pairs = match &content {
+            Some(content) => flatten(&self.url, content)?,
+            None if self.optional => Vec::new(),
+            _ => {
+                return Err(LoadError::Generic(format!(
+                    "The configuration at '{}' was not found and is not optional.",
+                    self.url
+                )))
+            }
+        };
+
+        *self.content.lock().unwrap() = content;
+        Ok(pairs)
+    }
+
+    fn subscribe(&self, sink: ConfigurationUpdateSink) {
+        if let Some(interval) = self.poll_interval {
+            let poller = WebPoller {
+                url: self.url.clone(),
+                content: self.content.clone(),
+                sink,
+            };
+
+            self.client.poll(&self.url, interval, poller);
+        }
+    }
+}
+
+/// Represents a [`ConfigurationProvider`](crate::ConfigurationProvider) for JSON fetched over
+/// HTTP.
+pub struct WebConfigurationProvider(FeedConfigurationProvider);
+
+impl WebConfigurationProvider {
+    /// Initializes a new web configuration provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The [`WebClient`] used to fetch the content
+    /// * `url` - The URL of the JSON content
+    /// * `optional` - Indicates whether the content is optional
+    /// * `poll_interval` - The interval at which the content is polled for changes, if any
+    pub fn new(
+        client: Arc<dyn WebClient>,
+        url: &str,
+        optional: bool,
+        poll_interval: Option<Duration>,
+    ) -> Self {
+        let feed = ResourceFeed {
+            client,
+            url: url.to_owned(),
+            optional,
+            poll_interval,
+            content: Default::default(),
+        };
+
+        Self(FeedConfigurationProvider::new(Arc::new(feed)))
+    }
+}
+
+impl ConfigurationProvider for WebConfigurationProvider {
+    fn get(&self, key: &str) -> Option<Value> {
+        self.0.get(key)
+    }
+
+    fn reload_token(&self) -> Box<dyn ChangeToken> {
+        self.0.reload_token()
+    }
+
+    fn load(&mut self) -> LoadResult {
+        self.0.load()
+    }
+
+    fn close(&mut self) {
+        self.0.close()
+    }
+
+    fn try_iter(&self) -> Option<ConfigurationPairs<'_>> {
+        self.0.try_iter()
+    }
+
+    fn child_keys(&self, earlier_keys: &mut Vec<String>, parent_path: Option<&str>) {
+        self.0.child_keys(earlier_keys, parent_path)
+    }
+}
+
+/// Represents a [`ConfigurationSource`](crate::ConfigurationSource) for JSON fetched over HTTP.
+///
+/// # Remarks
+///
+/// The content is read the same way as a `*.json` file, but neither the file system nor file
+/// watching is used, so the source is suitable for browsers and serverless workers.
+pub struct WebConfigurationSource {
+    client: Arc<dyn WebClient>,
+    url: String,
+    optional: bool,
+    poll_interval: Option<Duration>,
+}
+
+impl WebConfigurationSource {
+    /// Initializes a new web configuration source.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The [`WebClient`] used to fetch the content
+    /// * `url` - The URL of the JSON content
+    pub fn new<C: WebClient + 'static>(client: C, url: &str) -> Self {
+        Self {
+            client: Arc::new(client),
+            url: url.to_owned(),
+            optional: false,
+            poll_interval: None,
+        }
+    }
+
+    /// Sets a value indicating whether the content is optional.
+    ///
+    /// # Arguments
+    ///
+    /// * `optional` - `true` if missing content is treated as having no data; otherwise, `false`
+    pub fn optional(mut self, optional: bool) -> Self {
+        self.optional = optional;
+        self
+    }
+
+    /// Indicates the content can be reloaded and polls it for changes at the specified interval.
+    pub fn poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = Some(interval);
+        self
+    }
+}
+
+impl ConfigurationSource for WebConfigurationSource {
+    fn build(&self, _builder: &dyn ConfigurationBuilder) -> Box<dyn ConfigurationProvider> {
+        Box::new(WebConfigurationProvider::new(
+            self.client.clone(),
+            &self.url,
+            self.optional,
+            self.poll_interval,
+        ))
+    }
+}
+
+pub mod ext {
+
+    use super::*;
+
+    /// Defines extension methods for [`ConfigurationBuilder`](crate::ConfigurationBuilder).
+    pub trait WebConfigurationBuilderExtensions {
+        /// Adds JSON fetched over HTTP as a configuration source.
+        ///
+        /// # Arguments
+        ///
+        /// * `client` - The [`WebClient`] used to fetch the content
+        /// * `url` - The URL of the JSON content
+        /// * `poll_interval` - The interval at which the content is polled for changes
+        fn add_web_json<C: WebClient + 'static>(
+            &mut self,
+            client: C,
+            url: &str,
+            poll_interval: Duration,
+        ) -> &mut Self;
+    }
+
+    impl WebConfigurationBuilderExtensions for dyn ConfigurationBuilder + '_ {
+        fn add_web_json<C: WebClient + 'static>(
+            &mut self,
+            client: C,
+            url: &str,
+            poll_interval: Duration,
+        ) -> &mut Self {
+            self.add(Box::new(
+                WebConfigurationSource::new(client, url).poll_interval(poll_interval),
+            ));
+            self
+        }
+    }
+
+    impl<T: ConfigurationBuilder> WebConfigurationBuilderExtensions for T {
+        fn add_web_json<C: WebClient + 'static>(
+            &mut self,
+            client: C,
+            url: &str,
+            poll_interval: Duration,
+        ) -> &mut Self {
+            self.add(Box::new(
+                WebConfigurationSource::new(client, url).poll_interval(poll_interval),
+            ));
+            self
+        }
+    }
+}
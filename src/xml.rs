@@ -1,32 +1,109 @@
 use crate::{
-    util::*, ConfigurationBuilder, ConfigurationPath, ConfigurationProvider, ConfigurationSource,
-    FileSource, LoadError, LoadResult, Value,
+    util::*, ConfigurationBuilder, ConfigurationFileParser, ConfigurationPairs, ConfigurationPath,
+    ConfigurationProvider, ConfigurationSource, FileConfigurationProvider, FileSource, LoadResult,
+    ParseError, Value,
 };
+use std::any::type_name;
 use std::cell::RefCell;
-use std::collections::HashMap;
-use std::fs::File;
-use std::io::BufReader;
+use std::collections::hash_map::{Entry, HashMap};
+use std::io::{BufReader, Read};
 use std::ops::Deref;
+use std::path::Path;
 use std::rc::Rc;
-use std::sync::{Arc, RwLock};
-use tokens::{ChangeToken, FileChangeToken, SharedChangeToken, SingleChangeToken, Subscription};
+use tokens::ChangeToken;
 use xml_rs::attribute::OwnedAttribute;
 use xml_rs::name::OwnedName;
 use xml_rs::reader::{EventReader, XmlEvent};
 
+/// Defines the possible behaviors for XML namespaces.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum XmlNamespaces {
+    /// Indicates that a namespaced element or attribute is an error.
+    Reject,
+
+    /// Indicates that namespaces are removed and only local names are used.
+    Strip,
+
+    /// Indicates that the namespace prefix, if any, is a parent segment of the key.
+    /// For example, `<app:Setting>` produces the key `app:Setting`.
+    Prefix,
+}
+
+impl Default for XmlNamespaces {
+    fn default() -> Self {
+        Self::Reject
+    }
+}
+
+/// Defines the possible behaviors when an XML document produces the same key more than once.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum XmlDuplicateKeys {
+    /// Indicates that a duplicate key is an error.
+    Error,
+
+    /// Indicates that the last value for a duplicate key is used.
+    LastWins,
+
+    /// Indicates that the values for a duplicate key become an array, such as `Key:0` and `Key:1`.
+    Array,
+}
+
+impl Default for XmlDuplicateKeys {
+    fn default() -> Self {
+        Self::Error
+    }
+}
+
+/// Represents the options used to load XML configuration.
+#[derive(Clone, Debug, Default)]
+pub struct XmlOptions {
+    /// Gets or sets the behavior for XML namespaces. The default value is
+    /// [`XmlNamespaces::Reject`].
+    pub namespaces: XmlNamespaces,
+
+    /// Gets or sets the behavior for duplicate keys. The default value is
+    /// [`XmlDuplicateKeys::Error`].
+    pub duplicate_keys: XmlDuplicateKeys,
+
+    /// Gets or sets the optional prefix, such as `@`, prepended to the key of an attribute so that
+    /// it does not collide with an equally named child element.
+    pub attribute_prefix: Option<String>,
+}
+
 trait LocalNameResolver {
-    fn local_name_or_error(&self, element: &OwnedName, line: usize) -> Result<String, String>;
+    fn local_name_or_error(
+        &self,
+        element: &OwnedName,
+        line: usize,
+        options: &XmlOptions,
+    ) -> Result<String, ParseError>;
 }
 
 impl LocalNameResolver for OwnedName {
-    fn local_name_or_error(&self, element: &OwnedName, line: usize) -> Result<String, String> {
+    fn local_name_or_error(
+        &self,
+        element: &OwnedName,
+        line: usize,
+        options: &XmlOptions,
+    ) -> Result<String, ParseError> {
         if self.namespace.is_none() {
-            Ok(self.local_name.clone())
-        } else {
-            Err(format!(
-                "XML namespaces are not supported. ({}, Line: {})",
-                &element.local_name, line
-            ))
+            return Ok(self.local_name.clone());
+        }
+
+        match options.namespaces {
+            XmlNamespaces::Strip => Ok(self.local_name.clone()),
+            XmlNamespaces::Prefix => Ok(match &self.prefix {
+                Some(prefix) => ConfigurationPath::combine(&[prefix, &self.local_name]),
+                _ => self.local_name.clone(),
+            }),
+            XmlNamespaces::Reject => Err(ParseError {
+                message: format!(
+                    "XML namespaces are not supported. ({}, Line: {})",
+                    &element.local_name, line
+                ),
+                line: Some(line),
+                ..Default::default()
+            }),
         }
     }
 }
@@ -67,9 +144,10 @@ impl Element {
         element_name: OwnedName,
         attributes: Vec<OwnedAttribute>,
         line: usize,
-    ) -> Result<Self, String> {
-        let name = get_name(&element_name, &attributes, line)?;
-        let local_name = element_name.local_name_or_error(&element_name, line)?;
+        options: &XmlOptions,
+    ) -> Result<Self, ParseError> {
+        let name = get_name(&element_name, &attributes, line, options)?;
+        let local_name = element_name.local_name_or_error(&element_name, line, options)?;
         let sibling_name = name
             .as_ref()
             .and_then(|n| {
@@ -91,11 +169,11 @@ impl Element {
                 .into_iter()
                 .map(|a| {
                     Ok(Attribute(
-                        a.name.local_name_or_error(&element_name, line)?,
+                        a.name.local_name_or_error(&element_name, line, options)?,
                         a.value,
                     ))
                 })
-                .collect::<Result<Vec<Attribute>, String>>()?,
+                .collect::<Result<Vec<Attribute>, ParseError>>()?,
         })
     }
 }
@@ -139,9 +217,10 @@ fn get_name(
     element: &OwnedName,
     attributes: &Vec<OwnedAttribute>,
     line: usize,
-) -> Result<Option<String>, String> {
+    options: &XmlOptions,
+) -> Result<Option<String>, ParseError> {
     for attribute in attributes {
-        let local_name = attribute.name.local_name_or_error(element, line)?;
+        let local_name = attribute.name.local_name_or_error(element, line, options)?;
 
         match local_name.as_str() {
             "name" | "Name" | "NAME" => {
@@ -157,8 +236,8 @@ fn get_name(
 fn process_element(
     prefix: &mut Prefix,
     element: &Element,
-    config: &mut HashMap<String, (String, Value)>,
-) -> Result<(), String> {
+    config: &mut Output,
+) -> Result<(), ParseError> {
     process_attributes(prefix, element, config)?;
     process_element_content(prefix, element, config)?;
     process_children(prefix, element, config)
@@ -167,8 +246,8 @@ fn process_element(
 fn process_element_content(
     prefix: &mut Prefix,
     element: &Element,
-    config: &mut HashMap<String, (String, Value)>,
-) -> Result<(), String> {
+    config: &mut Output,
+) -> Result<(), ParseError> {
     if let Some(ref value) = element.text {
         add_to_config(prefix.to_string(), value.clone(), element, config)
     } else {
@@ -180,8 +259,8 @@ fn process_element_child(
     prefix: &mut Prefix,
     child: &Element,
     index: Option<usize>,
-    config: &mut HashMap<String, (String, Value)>,
-) -> Result<(), String> {
+    config: &mut Output,
+) -> Result<(), ParseError> {
     prefix.push(&child.element_name);
 
     if let Some(ref name) = child.name {
@@ -209,10 +288,14 @@ fn process_element_child(
 fn process_attributes(
     prefix: &mut Prefix,
     element: &Element,
-    config: &mut HashMap<String, (String, Value)>,
-) -> Result<(), String> {
+    config: &mut Output,
+) -> Result<(), ParseError> {
     for attribute in &element.attributes {
-        prefix.push(&attribute.0);
+        match &config.options.attribute_prefix {
+            Some(attribute_prefix) => prefix.push(format!("{}{}", attribute_prefix, attribute.0)),
+            _ => prefix.push(&attribute.0),
+        }
+
         add_to_config(prefix.to_string(), attribute.1.clone(), element, config)?;
         prefix.pop();
     }
@@ -223,8 +306,8 @@ fn process_attributes(
 fn process_children(
     prefix: &mut Prefix,
     element: &Element,
-    config: &mut HashMap<String, (String, Value)>,
-) -> Result<(), String> {
+    config: &mut Output,
+) -> Result<(), ParseError> {
     for children in element.children.iter().map(|i| &i.1) {
         if children.len() == 1 {
             process_element_child(prefix, &children[0].deref().borrow(), None, config)?;
@@ -238,44 +321,96 @@ fn process_children(
     Ok(())
 }
 
+struct Output<'a> {
+    data: HashMap<String, (String, Value)>,
+    arrays: HashMap<String, usize>,
+    options: &'a XmlOptions,
+}
+
 fn add_to_config(
     key: String,
     value: String,
     element: &Element,
-    config: &mut HashMap<String, (String, Value)>,
-) -> Result<(), String> {
-    if let Some((dup_key, _)) = config.insert(key.to_uppercase(), (key, value.into())) {
-        Err(format!(
-            "A duplicate key '{}' was found. ({}, Line: {})",
-            &dup_key, &element.element_name, element.line
-        ))
-    } else {
-        Ok(())
+    config: &mut Output,
+) -> Result<(), ParseError> {
+    let normalized = key.to_uppercase();
+
+    // the key has already become an array; append the next item
+    if let Some(count) = config.arrays.get_mut(&normalized) {
+        let key = ConfigurationPath::combine(&[&key, &count.to_string()]);
+        *count += 1;
+        config.data.insert(key.to_uppercase(), (key, value.into()));
+        return Ok(());
     }
+
+    match config.data.entry(normalized) {
+        Entry::Vacant(entry) => {
+            entry.insert((key, value.into()));
+        }
+        Entry::Occupied(mut entry) => match config.options.duplicate_keys {
+            XmlDuplicateKeys::Error => {
+                let dup_key = entry.get().0.clone();
+
+                return Err(ParseError {
+                    message: format!(
+                        "A duplicate key '{}' was found. ({}, Line: {})",
+                        &dup_key, &element.element_name, element.line
+                    ),
+                    line: Some(element.line),
+                    key: Some(dup_key),
+                    ..Default::default()
+                });
+            }
+            XmlDuplicateKeys::LastWins => {
+                entry.insert((key, value.into()));
+            }
+            XmlDuplicateKeys::Array => {
+                let (normalized, (first_key, first_value)) = entry.remove_entry();
+                let first_key = ConfigurationPath::combine(&[&first_key, "0"]);
+                let key = ConfigurationPath::combine(&[&key, "1"]);
+
+                config
+                    .data
+                    .insert(first_key.to_uppercase(), (first_key, first_value));
+                config.data.insert(key.to_uppercase(), (key, value.into()));
+                config.arrays.insert(normalized, 2);
+            }
+        },
+    }
+
+    Ok(())
 }
 
 fn to_config(
     mut root: Option<Rc<RefCell<Element>>>,
-) -> Result<HashMap<String, (String, Value)>, String> {
+    options: &XmlOptions,
+) -> Result<HashMap<String, (String, Value)>, ParseError> {
     if let Some(cell) = root.take() {
         let element = &cell.deref().borrow();
-        let mut data = HashMap::new();
+        let mut output = Output {
+            data: HashMap::new(),
+            arrays: HashMap::new(),
+            options,
+        };
         let mut prefix = Prefix::default();
 
         if let Some(ref name) = element.name {
             prefix.push(name);
         }
 
-        process_element(&mut prefix, &element, &mut data)?;
-        data.shrink_to_fit();
-        Ok(data)
+        process_element(&mut prefix, &element, &mut output)?;
+        output.data.shrink_to_fit();
+        Ok(output.data)
     } else {
         Ok(HashMap::with_capacity(0))
     }
 }
 
-fn visit(file: File) -> Result<HashMap<String, (String, Value)>, String> {
-    let content = BufReader::new(file);
+fn visit<R: Read>(
+    content: R,
+    options: &XmlOptions,
+) -> Result<HashMap<String, (String, Value)>, ParseError> {
+    let content = BufReader::new(content);
     let events = EventReader::new(content);
     let mut has_content = false;
     let mut last_name = None;
@@ -291,7 +426,7 @@ fn visit(file: File) -> Result<HashMap<String, (String, Value)>, String> {
                 line += 1;
                 has_content = false;
                 last_name = Some(name.clone());
-                let element = Element::new(name.clone(), attributes, line)?;
+                let element = Element::new(name.clone(), attributes, line, options)?;
                 let key = element.sibling_name.clone();
                 let child = Rc::new(RefCell::new(element));
 
@@ -331,85 +466,27 @@ fn visit(file: File) -> Result<HashMap<String, (String, Value)>, String> {
         };
     }
 
-    to_config(root)
+    to_config(root, options)
 }
 
-struct InnerProvider {
-    file: FileSource,
-    data: RwLock<HashMap<String, (String, Value)>>,
-    token: RwLock<SharedChangeToken<SingleChangeToken>>,
+pub(crate) fn parse(content: &[u8], options: &XmlOptions) -> Result<ConfigurationData, ParseError> {
+    visit(content, options).map(Into::into)
 }
 
-impl InnerProvider {
-    fn new(file: FileSource) -> Self {
-        Self {
-            file,
-            data: RwLock::new(HashMap::with_capacity(0)),
-            token: Default::default(),
-        }
-    }
-
-    fn load(&self, reload: bool) -> LoadResult {
-        if !self.file.path.is_file() {
-            if self.file.optional || reload {
-                let mut data = self.data.write().unwrap();
-                if !data.is_empty() {
-                    *data = HashMap::with_capacity(0);
-                }
-
-                return Ok(());
-            } else {
-                return Err(LoadError::File {
-                    message: format!(
-                        "The configuration file '{}' was not found and is not optional.",
-                        self.file.path.display()
-                    ),
-                    path: self.file.path.clone(),
-                });
-            }
-        }
-
-        if let Ok(file) = File::open(&self.file.path) {
-            let data = visit(file).map_err(|e| LoadError::File {
-                message: e,
-                path: self.file.path.clone(),
-            })?;
-            *self.data.write().unwrap() = data;
-        } else {
-            *self.data.write().unwrap() = HashMap::with_capacity(0);
-        }
-
-        let previous = std::mem::replace(
-            &mut *self.token.write().unwrap(),
-            SharedChangeToken::default(),
-        );
-
-        previous.notify();
-        Ok(())
-    }
-
-    fn get(&self, key: &str) -> Option<Value> {
-        self.data
-            .read()
-            .unwrap()
-            .get(&key.to_uppercase())
-            .map(|t| t.1.clone())
-    }
-
-    fn reload_token(&self) -> Box<dyn ChangeToken> {
-        Box::new(self.token.read().unwrap().clone())
-    }
+#[derive(Clone)]
+struct XmlFileParser {
+    options: XmlOptions,
+}
 
-    fn child_keys(&self, earlier_keys: &mut Vec<String>, parent_path: Option<&str>) {
-        let data = self.data.read().unwrap();
-        accumulate_child_keys(&data, earlier_keys, parent_path)
+impl ConfigurationFileParser for XmlFileParser {
+    fn parse(&self, _path: &Path, content: &[u8]) -> Result<ConfigurationData, ParseError> {
+        parse(content, &self.options)
     }
 }
 
 /// Represents a [`ConfigurationProvider`](crate::ConfigurationProvider) for `*.xml` files.
 pub struct XmlConfigurationProvider {
-    inner: Arc<InnerProvider>,
-    _subscription: Option<Box<dyn Subscription>>,
+    inner: FileConfigurationProvider<XmlFileParser>,
 }
 
 impl XmlConfigurationProvider {
@@ -419,25 +496,22 @@ impl XmlConfigurationProvider {
     ///
     /// * `file` - The `*.xml` [`FileSource`](crate::FileSource) information
     pub fn new(file: FileSource) -> Self {
-        let path = file.path.clone();
-        let inner = Arc::new(InnerProvider::new(file));
-        let subscription: Option<Box<dyn Subscription>> = if inner.file.reload_on_change {
-            Some(Box::new(tokens::on_change(
-                move || FileChangeToken::new(path.clone()),
-                |state| {
-                    let provider = state.unwrap();
-                    std::thread::sleep(provider.file.reload_delay);
-                    provider.load(true).ok();
-                },
-                Some(inner.clone()),
-            )))
-        } else {
-            None
-        };
+        Self::with_options(file, Default::default())
+    }
 
+    /// Initializes a new `*.xml` file configuration provider with the specified options.
+    ///
+    /// # Arguments
+    ///
+    /// * `file` - The `*.xml` [`FileSource`](crate::FileSource) information
+    /// * `options` - The [`XmlOptions`] used to load the file
+    pub fn with_options(file: FileSource, options: XmlOptions) -> Self {
         Self {
-            inner,
-            _subscription: subscription,
+            inner: FileConfigurationProvider::named(
+                type_name::<Self>(),
+                file,
+                XmlFileParser { options },
+            ),
         }
     }
 }
@@ -452,7 +526,19 @@ impl ConfigurationProvider for XmlConfigurationProvider {
     }
 
     fn load(&mut self) -> LoadResult {
-        self.inner.load(false)
+        self.inner.load()
+    }
+
+    fn close(&mut self) {
+        self.inner.close()
+    }
+
+    fn save(&self) -> LoadResult {
+        self.inner.save()
+    }
+
+    fn try_iter(&self) -> Option<ConfigurationPairs<'_>> {
+        self.inner.try_iter()
     }
 
     fn child_keys(&self, earlier_keys: &mut Vec<String>, parent_path: Option<&str>) {
@@ -463,6 +549,7 @@ impl ConfigurationProvider for XmlConfigurationProvider {
 /// Represents a [`ConfigurationSource`](crate::ConfigurationSource) for `*.xml` files.
 pub struct XmlConfigurationSource {
     file: FileSource,
+    options: XmlOptions,
 }
 
 impl XmlConfigurationSource {
@@ -472,19 +559,34 @@ impl XmlConfigurationSource {
     ///
     /// * `file` - The `*.xml` [`FileSource`](crate::FileSource) information
     pub fn new(file: FileSource) -> Self {
-        Self { file }
+        Self::with_options(file, Default::default())
+    }
+
+    /// Initializes a new `*.xml` file configuration source with the specified options.
+    ///
+    /// # Arguments
+    ///
+    /// * `file` - The `*.xml` [`FileSource`](crate::FileSource) information
+    /// * `options` - The [`XmlOptions`] used to load the file
+    pub fn with_options(file: FileSource, options: XmlOptions) -> Self {
+        Self { file, options }
     }
 }
 
 impl ConfigurationSource for XmlConfigurationSource {
-    fn build(&self, _builder: &dyn ConfigurationBuilder) -> Box<dyn ConfigurationProvider> {
-        Box::new(XmlConfigurationProvider::new(self.file.clone()))
+    fn build(&self, builder: &dyn ConfigurationBuilder) -> Box<dyn ConfigurationProvider> {
+        Box::new(XmlConfigurationProvider::with_options(
+            self.file.configure(builder),
+            self.options.clone(),
+        ))
     }
 }
 
 pub mod ext {
 
     use super::*;
+    use crate::{HostEnvironment, StreamConfigurationSource};
+    use std::io::{Cursor, Read};
 
     /// Defines extension methods for [`ConfigurationBuilder`](crate::ConfigurationBuilder).
     pub trait XmlConfigurationExtensions {
@@ -494,6 +596,46 @@ pub mod ext {
         ///
         /// * `file` - The `*.xml` [`FileSource`](crate::FileSource) information
         fn add_xml_file<T: Into<FileSource>>(&mut self, file: T) -> &mut Self;
+
+        /// Adds a `*.xml` file and its variant for the host environment as configuration sources.
+        ///
+        /// # Arguments
+        ///
+        /// * `base` - The path of the file, relative to the content root and without an extension,
+        ///   such as `appsettings`
+        /// * `environment` - The [`HostEnvironment`](crate::HostEnvironment) used to resolve the files
+        ///
+        /// # Remarks
+        ///
+        /// The environment-specific file, such as `appsettings.Development.xml`, is optional and
+        /// overrides the values of the base file.
+        fn add_xml_file_for_env(&mut self, base: &str, environment: &HostEnvironment) -> &mut Self;
+
+        /// Adds a `*.xml` file with the specified options as a configuration source.
+        ///
+        /// # Arguments
+        ///
+        /// * `file` - The `*.xml` [`FileSource`](crate::FileSource) information
+        /// * `options` - The [`XmlOptions`] used to load the file
+        fn add_xml_file_with_options<T: Into<FileSource>>(
+            &mut self,
+            file: T,
+            options: XmlOptions,
+        ) -> &mut Self;
+
+        /// Adds XML read from a stream as a configuration source.
+        ///
+        /// # Arguments
+        ///
+        /// * `stream` - The [`Read`](std::io::Read) stream containing XML
+        fn add_xml_stream<R: Read + 'static>(&mut self, stream: R) -> &mut Self;
+
+        /// Adds embedded XML content as a configuration source.
+        ///
+        /// # Arguments
+        ///
+        /// * `content` - The XML content, which is typically from [`include_str`]
+        fn add_embedded_xml(&mut self, content: &str) -> &mut Self;
     }
 
     impl XmlConfigurationExtensions for dyn ConfigurationBuilder + '_ {
@@ -501,6 +643,36 @@ pub mod ext {
             self.add(Box::new(XmlConfigurationSource::new(file.into())));
             self
         }
+
+        fn add_xml_file_for_env(&mut self, base: &str, environment: &HostEnvironment) -> &mut Self {
+            let (file, variant) = environment.files(base, "xml");
+            self.add_xml_file(file)
+                .add_xml_file(FileSource::optional(variant))
+        }
+
+        fn add_xml_file_with_options<F: Into<FileSource>>(
+            &mut self,
+            file: F,
+            options: XmlOptions,
+        ) -> &mut Self {
+            self.add(Box::new(XmlConfigurationSource::with_options(
+                file.into(),
+                options,
+            )));
+            self
+        }
+
+        fn add_xml_stream<R: Read + 'static>(&mut self, stream: R) -> &mut Self {
+            self.add(Box::new(StreamConfigurationSource::new(
+                stream,
+                |content| parse(content, &Default::default()).map_err(|error| error.message),
+            )));
+            self
+        }
+
+        fn add_embedded_xml(&mut self, content: &str) -> &mut Self {
+            self.add_xml_stream(Cursor::new(content.to_owned()))
+        }
     }
 
     impl<T: ConfigurationBuilder> XmlConfigurationExtensions for T {
@@ -508,5 +680,35 @@ pub mod ext {
             self.add(Box::new(XmlConfigurationSource::new(file.into())));
             self
         }
+
+        fn add_xml_file_for_env(&mut self, base: &str, environment: &HostEnvironment) -> &mut Self {
+            let (file, variant) = environment.files(base, "xml");
+            self.add_xml_file(file)
+                .add_xml_file(FileSource::optional(variant))
+        }
+
+        fn add_xml_file_with_options<F: Into<FileSource>>(
+            &mut self,
+            file: F,
+            options: XmlOptions,
+        ) -> &mut Self {
+            self.add(Box::new(XmlConfigurationSource::with_options(
+                file.into(),
+                options,
+            )));
+            self
+        }
+
+        fn add_xml_stream<R: Read + 'static>(&mut self, stream: R) -> &mut Self {
+            self.add(Box::new(StreamConfigurationSource::new(
+                stream,
+                |content| parse(content, &Default::default()).map_err(|error| error.message),
+            )));
+            self
+        }
+
+        fn add_embedded_xml(&mut self, content: &str) -> &mut Self {
+            self.add_xml_stream(Cursor::new(content.to_owned()))
+        }
     }
 }
@@ -0,0 +1,56 @@
+use config::{ext::*, *};
+use std::time::SystemTime;
+
+#[test]
+fn access_log_should_record_reads_with_resolving_provider() {
+    // arrange
+    let start = SystemTime::now();
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder.add(Box::new(
+        MemoryConfigurationSource::new(&[("Name", "Base"), ("Port", "80")]).named("base"),
+    ));
+    builder.add(Box::new(
+        MemoryConfigurationSource::new(&[("Name", "Override")]).named("override"),
+    ));
+    builder.record_access();
+
+    let config = builder.build().unwrap();
+
+    // act
+    config.get("Name");
+    config.section("Port").value();
+    config.get("Missing");
+
+    // assert
+    let log = config.access_log();
+    let reads: Vec<_> = log
+        .iter()
+        .map(|record| (record.key.as_str(), record.provider.as_deref()))
+        .collect();
+
+    assert_eq!(
+        reads,
+        vec![
+            ("Name", Some("override")),
+            ("Port", Some("base")),
+            ("Missing", None),
+        ]
+    );
+    assert!(log.iter().all(|record| record.time >= start));
+}
+
+#[test]
+fn access_log_should_be_empty_when_not_recording() {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[("Name", "App")])
+        .build()
+        .unwrap();
+
+    // act
+    config.get("Name");
+
+    // assert
+    assert!(config.access_log().is_empty());
+}
@@ -0,0 +1,72 @@
+use config::{ext::*, *};
+use serde::Deserialize;
+use std::sync::{Arc, Mutex};
+
+#[derive(Deserialize)]
+#[serde(rename_all(deserialize = "PascalCase"))]
+struct DatabaseOptions {
+    url: String,
+    timeout: u32,
+}
+
+#[test]
+fn alias_should_read_deprecated_key_and_report_its_use() {
+    // arrange
+    let deprecated = Arc::new(Mutex::new(Vec::new()));
+    let log = deprecated.clone();
+    let config = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[("Old:Path", "value")])
+        .alias("Old:Path", "New:Path")
+        .on_deprecated_key(move |old, new| log.lock().unwrap().push(format!("{} -> {}", old, new)))
+        .build()
+        .unwrap();
+
+    // act
+    let first = config.get("New:Path");
+    let second = config.get("New:Path");
+
+    // assert
+    assert_eq!(first.unwrap().as_str(), "value");
+    assert_eq!(second.unwrap().as_str(), "value");
+    assert_eq!(*deprecated.lock().unwrap(), vec!["Old:Path -> New:Path"]);
+}
+
+#[test]
+fn alias_should_prefer_value_configured_with_new_key() {
+    // arrange
+    let deprecated = Arc::new(Mutex::new(0));
+    let count = deprecated.clone();
+    let config = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[("Old:Path", "old"), ("New:Path", "new")])
+        .alias("Old:Path", "New:Path")
+        .on_deprecated_key(move |_, _| *count.lock().unwrap() += 1)
+        .build()
+        .unwrap();
+
+    // act
+    let value = config.get("New:Path").unwrap();
+
+    // assert
+    assert_eq!(value.as_str(), "new");
+    assert_eq!(*deprecated.lock().unwrap(), 0);
+}
+
+#[test]
+fn alias_should_bind_section_from_deprecated_section() {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[("Db:Url", "postgres://localhost"), ("Db:Timeout", "30")])
+        .alias("Db", "Database")
+        .track_unused_keys()
+        .build()
+        .unwrap();
+
+    // act
+    let options: DatabaseOptions = config.section("Database").reify();
+
+    // assert
+    assert_eq!(options.url, "postgres://localhost");
+    assert_eq!(options.timeout, 30);
+    assert!(config.children().iter().any(|s| s.key() == "Database"));
+    assert!(config.unused_keys().is_empty());
+}
@@ -212,3 +212,160 @@ fn deserialization_should_preserve_case_in_ini_file() {
 
     assert!(settings.use_native_copy);
 }
+
+#[test]
+fn get_value_should_convert_human_friendly_boolean_when_lenient() {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[("primary", "yes"), ("secondary", "off")])
+        .convert_values(ValueConversion::Lenient)
+        .build()
+        .unwrap();
+
+    // act
+    let primary: Option<bool> = config.get_value("primary").unwrap();
+    let secondary: bool = config.get_value_or_default("secondary").unwrap();
+
+    // assert
+    assert_eq!(primary, Some(true));
+    assert!(!secondary);
+}
+
+#[test]
+fn get_value_should_not_convert_human_friendly_boolean_when_strict() {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[("primary", "yes")])
+        .build()
+        .unwrap();
+
+    // act
+    let result = config.get_value::<bool>("primary");
+
+    // assert
+    assert!(result.is_err());
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all(deserialize = "PascalCase"))]
+struct CacheOptions {
+    enabled: bool,
+    max_size: ByteSize,
+}
+
+#[test]
+fn reify_should_bind_human_friendly_values_when_lenient() {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[("Enabled", "on"), ("MaxSize", "4KiB")])
+        .convert_values(ValueConversion::Lenient)
+        .build()
+        .unwrap();
+
+    // act
+    let options: CacheOptions = config.reify();
+
+    // assert
+    assert!(options.enabled);
+    assert_eq!(options.max_size, ByteSize(4096));
+}
+
+#[test]
+fn get_value_should_parse_byte_size() {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[("limit", "10MB")])
+        .build()
+        .unwrap();
+
+    // act
+    let limit: Option<ByteSize> = config.get_value("limit").unwrap();
+
+    // assert
+    assert_eq!(limit, Some(ByteSize(10_000_000)));
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all(deserialize = "PascalCase"))]
+struct Server {
+    host: String,
+    port: u16,
+}
+
+#[test]
+fn get_vec_should_bind_elements_in_index_order() {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[
+            ("Servers:1:Host", "backup"),
+            ("Servers:1:Port", "8081"),
+            ("Servers:0:Host", "primary"),
+            ("Servers:0:Port", "8080"),
+            ("Ports:3", "3"),
+            ("Ports:2", "2"),
+            ("Ports:1", "1"),
+            ("Ports:0", "0"),
+        ])
+        .build()
+        .unwrap();
+
+    // act
+    let servers: Vec<Server> = config.get_vec("Servers").unwrap();
+    let ports: Vec<u8> = config.get_vec("Ports").unwrap();
+    let missing: Vec<u16> = config.get_vec("Missing").unwrap();
+
+    // assert
+    assert_eq!(
+        servers,
+        vec![
+            Server {
+                host: "primary".into(),
+                port: 8080
+            },
+            Server {
+                host: "backup".into(),
+                port: 8081
+            },
+        ]
+    );
+    assert_eq!(ports, vec![0, 1, 2, 3]);
+    assert!(missing.is_empty());
+}
+
+#[test]
+fn as_vec_should_return_error_for_missing_index() {
+    // arrange
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder.add_in_memory(&[("Ports:0", "80"), ("Ports:1", "443")]);
+    builder.add_in_memory(&[("Ports:3", "8080")]);
+
+    let config = builder.build().unwrap();
+
+    // act
+    let result = config.section("Ports").as_vec::<u16>();
+
+    // assert
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "the array index 2 is missing before 'Ports:3'"
+    );
+}
+
+#[test]
+fn as_vec_should_return_error_for_non_index_key() {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[("Ports:0", "80"), ("Ports:Default", "443")])
+        .build()
+        .unwrap();
+
+    // act
+    let result = config.section("Ports").as_vec::<u16>();
+
+    // assert
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "'Ports:Default' is not an array index"
+    );
+}
@@ -0,0 +1,59 @@
+use config::{ext::*, *};
+
+#[test]
+fn add_when_should_use_source_when_earlier_flag_is_set() {
+    // arrange
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder
+        .add_in_memory(&[("UseVault", "true"), ("Secret", "Default")])
+        .add_when(
+            |config| config.get("UseVault").as_deref().map(String::as_str) == Some("true"),
+            Box::new(MemoryConfigurationSource::new(&[("Secret", "Vault")])),
+        );
+
+    // act
+    let config = builder.build().unwrap();
+
+    // assert
+    assert_eq!(config.get("Secret").unwrap().as_str(), "Vault");
+}
+
+#[test]
+fn add_when_should_not_use_source_when_earlier_flag_is_not_set() {
+    // arrange
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder
+        .add_in_memory(&[("UseVault", "false"), ("Secret", "Default")])
+        .add_when(
+            |config| config.get("UseVault").as_deref().map(String::as_str) == Some("true"),
+            Box::new(MemoryConfigurationSource::new(&[("Secret", "Vault")])),
+        )
+        .add_in_memory(&[("UseVault", "true")]);
+
+    // act
+    let config = builder.build().unwrap();
+
+    // assert
+    assert_eq!(config.get("Secret").unwrap().as_str(), "Default");
+    assert_eq!(config.providers().count(), 3);
+}
+
+#[test]
+fn add_if_should_not_add_source_when_condition_is_false() {
+    // arrange
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder.add_in_memory(&[("Name", "App")]).add_if(
+        false,
+        Box::new(MemoryConfigurationSource::new(&[("Name", "Test")])),
+    );
+
+    // act
+    let config = builder.build().unwrap();
+
+    // assert
+    assert_eq!(config.get("Name").unwrap().as_str(), "App");
+    assert_eq!(config.providers().count(), 1);
+}
@@ -0,0 +1,39 @@
+use config::{ext::*, *};
+
+#[test]
+fn connection_string_should_return_value_from_connection_strings_section() {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[
+            ("ConnectionStrings:Default", "Server=localhost;Database=app"),
+            ("ConnectionStrings:Cache", "localhost:6379"),
+        ])
+        .build()
+        .unwrap();
+
+    // act
+    let value = config.connection_string("default");
+    let mut names = config.connection_strings().names();
+
+    // assert
+    names.sort();
+    assert_eq!(value.unwrap().as_str(), "Server=localhost;Database=app");
+    assert_eq!(names, vec!["Cache", "Default"]);
+    assert!(config.connection_string("Missing").is_none());
+}
+
+#[test]
+fn parse_should_split_connection_string_into_pairs() {
+    // arrange
+    let text = "Server=localhost; Database = app;Password=\"p@ss;word\";;Pooling";
+
+    // act
+    let connection = ConnectionString::parse(text);
+
+    // assert
+    assert_eq!(connection.len(), 4);
+    assert_eq!(connection.get("server"), Some("localhost"));
+    assert_eq!(connection.get("Database"), Some("app"));
+    assert_eq!(connection.get("Password"), Some("p@ss;word"));
+    assert_eq!(connection.get("Pooling"), Some(""));
+}
@@ -1,6 +1,6 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
-use config::{ext::*, ConfigurationBuilder, DefaultConfigurationBuilder};
+use config::{ext::*, ConfigurationBuilder, DefaultConfigurationBuilder, SparseArrayPolicy};
 use serde::Deserialize;
 
 #[derive(Deserialize, Debug, PartialEq)]
@@ -275,6 +275,57 @@ fn from_config_should_deserialize_nested_typed_map() {
     }
 }
 
+#[test]
+fn from_config_should_unescape_map_keys_containing_delimiter() {
+    // arrange
+    let root = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[
+            (r"Weights:http\://primary\:8080", "3"),
+            (r"Weights:http\://secondary\:8080", "1"),
+        ])
+        .build()
+        .unwrap();
+
+    // act
+    let result = from_config::<HashMap<String, usize>>(root.section("Weights").deref());
+
+    // assert
+    assert_eq!(
+        result.unwrap(),
+        HashMap::from([
+            ("http://primary:8080".to_owned(), 3),
+            ("http://secondary:8080".to_owned(), 1),
+        ])
+    );
+}
+
+#[test]
+fn from_config_should_preserve_original_casing_of_map_keys() {
+    // arrange
+    let root = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[
+            ("Machines:DB-01", "1"),
+            ("Machines:web-01", "1"),
+            ("Machines:cache.contoso.com", "3"),
+        ])
+        .add_json_stream(&br#"{"machines": {"Web-01": "2"}}"#[..])
+        .build()
+        .unwrap();
+
+    // act
+    let result = from_config::<HashMap<String, String>>(root.section("Machines").deref());
+
+    // assert
+    assert_eq!(
+        result.unwrap(),
+        HashMap::from([
+            ("DB-01".to_owned(), "1".to_owned()),
+            ("Web-01".to_owned(), "2".to_owned()),
+            ("cache.contoso.com".to_owned(), "3".to_owned()),
+        ])
+    );
+}
+
 #[test]
 fn from_config_should_deserialize_map_with_nested_vec() {
     // arrange
@@ -415,3 +466,372 @@ fn from_config_should_deserialize_deep_nested_map() {
         Err(e) => panic!("{:#?}", e),
     }
 }
+
+#[derive(Deserialize, Debug, PartialEq)]
+#[serde(rename_all(deserialize = "PascalCase"))]
+pub struct Endpoint {
+    host: String,
+    path: String,
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+#[serde(rename_all(deserialize = "PascalCase"))]
+pub struct Service {
+    name: String,
+    #[serde(flatten)]
+    endpoint: Endpoint,
+    #[serde(flatten)]
+    extra: HashMap<String, Endpoint>,
+}
+
+#[test]
+fn from_config_should_deserialize_flattened_struct_and_map() {
+    // arrange
+    let root = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[
+            ("Name", "Billing"),
+            ("Host", "localhost"),
+            ("Path", "/billing"),
+            ("Backup:Host", "remotehost"),
+            ("Backup:Path", "/billing"),
+        ])
+        .build()
+        .unwrap();
+
+    // act
+    let result = from_config::<Service>(root.deref());
+
+    // assert
+    match result {
+        Ok(actual) => {
+            assert_eq!(actual.name, "Billing");
+            assert_eq!(
+                actual.endpoint,
+                Endpoint {
+                    host: String::from("localhost"),
+                    path: String::from("/billing")
+                }
+            );
+            assert_eq!(actual.extra["Backup"].host, "remotehost");
+        }
+        Err(e) => panic!("{:#?}", e),
+    }
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+#[serde(tag = "Type")]
+pub enum Sink {
+    Console,
+    File { path: String },
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+#[serde(tag = "Kind", content = "Settings")]
+pub enum Cache {
+    Memory,
+    Redis { host: String },
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+#[serde(untagged)]
+pub enum Secret {
+    Vault { vault: String },
+    Plain(String),
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+pub enum Store {
+    File(String),
+    Database { name: String },
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+#[serde(rename_all(deserialize = "PascalCase"))]
+pub struct Polymorphic {
+    sinks: Vec<Sink>,
+    cache: Cache,
+    secrets: Vec<Secret>,
+    stores: Vec<Store>,
+}
+
+#[test]
+fn from_config_should_deserialize_tagged_enums() {
+    // arrange
+    let root = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[
+            ("Sinks:0:Type", "Console"),
+            ("Sinks:1:Type", "File"),
+            ("Sinks:1:path", "/var/log/app.log"),
+            ("Cache:Kind", "Redis"),
+            ("Cache:Settings:host", "localhost"),
+            ("Secrets:0:vault", "kv/app"),
+            ("Secrets:1", "p@ssw0rd"),
+            ("Stores:0:File", "data.db"),
+            ("Stores:1:Database:name", "app"),
+        ])
+        .build()
+        .unwrap();
+
+    // act
+    let result = from_config::<Polymorphic>(root.deref());
+
+    // assert
+    match result {
+        Ok(actual) => assert_eq!(
+            actual,
+            Polymorphic {
+                sinks: vec![
+                    Sink::Console,
+                    Sink::File {
+                        path: String::from("/var/log/app.log")
+                    }
+                ],
+                cache: Cache::Redis {
+                    host: String::from("localhost")
+                },
+                secrets: vec![
+                    Secret::Vault {
+                        vault: String::from("kv/app")
+                    },
+                    Secret::Plain(String::from("p@ssw0rd"))
+                ],
+                stores: vec![
+                    Store::File(String::from("data.db")),
+                    Store::Database {
+                        name: String::from("app")
+                    }
+                ],
+            }
+        ),
+        Err(e) => panic!("{:#?}", e),
+    }
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+#[serde(rename_all(deserialize = "PascalCase"))]
+pub struct Network {
+    timeout: std::time::Duration,
+    retry_delay: Option<std::time::Duration>,
+    expires: std::time::SystemTime,
+    root: std::path::PathBuf,
+    address: std::net::IpAddr,
+    endpoint: std::net::SocketAddr,
+}
+
+#[test]
+fn from_config_should_deserialize_common_value_types() {
+    // arrange
+    let root = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[
+            ("Timeout", "1m30s"),
+            ("RetryDelay", "250ms"),
+            ("Expires", "2024-01-02T03:04:05Z"),
+            ("Root", "/var/lib/app"),
+            ("Address", "10.0.0.1"),
+            ("Endpoint", "127.0.0.1:8080"),
+        ])
+        .build()
+        .unwrap();
+
+    // act
+    let result = from_config::<Network>(root.deref());
+
+    // assert
+    match result {
+        Ok(actual) => assert_eq!(
+            actual,
+            Network {
+                timeout: std::time::Duration::from_secs(90),
+                retry_delay: Some(std::time::Duration::from_millis(250)),
+                expires: std::time::UNIX_EPOCH + std::time::Duration::from_secs(1_704_164_645),
+                root: std::path::PathBuf::from("/var/lib/app"),
+                address: "10.0.0.1".parse().unwrap(),
+                endpoint: "127.0.0.1:8080".parse().unwrap(),
+            }
+        ),
+        Err(e) => panic!("{:#?}", e),
+    }
+}
+
+#[test]
+fn from_config_should_fail_with_invalid_duration() {
+    // arrange
+    let root = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[("Timeout", "soon")])
+        .build()
+        .unwrap();
+
+    // act
+    let result = from_config::<Network>(root.deref());
+
+    // assert
+    assert!(result.is_err());
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+#[serde(rename_all(deserialize = "PascalCase"))]
+struct Mirror {
+    url: String,
+    #[serde(default)]
+    retries: u8,
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+#[serde(rename_all(deserialize = "PascalCase"))]
+struct Endpoints {
+    ports: Vec<u16>,
+    endpoints: Vec<Option<Mirror>>,
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+#[serde(rename_all(deserialize = "PascalCase"))]
+struct Ports {
+    ports: Vec<u16>,
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+#[serde(rename_all(deserialize = "PascalCase"))]
+struct IndexedPorts {
+    ports: BTreeMap<usize, u16>,
+}
+
+fn sparse_arrays(policy: SparseArrayPolicy) -> Box<dyn config::ConfigurationRoot> {
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder.add_in_memory(&[
+        ("Ports:0", "80"),
+        ("Ports:1", "443"),
+        ("Endpoints:0:Url", "https://a.com"),
+    ]);
+    builder.add_in_memory(&[("Ports:3", "8080"), ("Endpoints:2:Url", "https://c.com")]);
+    builder.sparse_arrays(policy);
+    builder.build().unwrap()
+}
+
+#[test]
+fn from_config_should_compact_sparse_array_by_default() {
+    // arrange
+    let root = sparse_arrays(SparseArrayPolicy::default());
+
+    // act
+    let result = from_config::<Endpoints>(root.deref()).unwrap();
+
+    // assert
+    assert_eq!(result.ports, vec![80, 443, 8080]);
+    assert_eq!(result.endpoints.len(), 2);
+}
+
+#[test]
+fn from_config_should_fail_with_sparse_array_when_gaps_are_errors() {
+    // arrange
+    let root = sparse_arrays(SparseArrayPolicy::Error);
+
+    // act
+    let result = from_config::<Ports>(root.deref());
+
+    // assert
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "the array index 2 is missing before 'Ports:3'"
+    );
+}
+
+#[test]
+fn from_config_should_fill_sparse_array_with_empty_values() {
+    // arrange
+    let root = sparse_arrays(SparseArrayPolicy::Fill);
+
+    // act
+    let result = from_config::<Endpoints>(root.deref()).unwrap();
+
+    // assert
+    assert_eq!(result.ports, vec![80, 443, 0, 8080]);
+    assert_eq!(
+        result.endpoints,
+        vec![
+            Some(Mirror {
+                url: "https://a.com".into(),
+                retries: 0
+            }),
+            None,
+            Some(Mirror {
+                url: "https://c.com".into(),
+                retries: 0
+            }),
+        ]
+    );
+}
+
+#[test]
+fn from_config_should_deserialize_sparse_array_to_indexed_map() {
+    // arrange
+    let root = sparse_arrays(SparseArrayPolicy::Error);
+
+    // act
+    let result = from_config::<IndexedPorts>(root.deref()).unwrap();
+
+    // assert
+    assert_eq!(
+        result.ports.into_iter().collect::<Vec<_>>(),
+        vec![(0, 80), (1, 443), (3, 8080)]
+    );
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct Flag(String, bool);
+
+#[derive(Deserialize, Debug, PartialEq)]
+#[serde(rename_all(deserialize = "PascalCase"))]
+struct EndpointOptions {
+    endpoint: (String, u16),
+    address: [u8; 4],
+    flag: Flag,
+}
+
+#[test]
+fn from_config_should_deserialize_tuples_and_arrays_from_indexed_children() {
+    // arrange
+    let root = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[
+            ("Endpoint:0", "localhost"),
+            ("Endpoint:1", "8080"),
+            ("Address:0", "10"),
+            ("Address:1", "0"),
+            ("Address:2", "0"),
+            ("Address:3", "1"),
+            ("Flag:0", "Preview"),
+            ("Flag:1", "true"),
+        ])
+        .build()
+        .unwrap();
+
+    // act
+    let options = from_config::<EndpointOptions>(root.deref()).unwrap();
+    let endpoint = from_config::<(String, u16)>(root.section("Endpoint").deref()).unwrap();
+
+    // assert
+    assert_eq!(
+        options,
+        EndpointOptions {
+            endpoint: ("localhost".into(), 8080),
+            address: [10, 0, 0, 1],
+            flag: Flag("Preview".into(), true),
+        }
+    );
+    assert_eq!(endpoint, ("localhost".into(), 8080));
+}
+
+#[test]
+fn from_config_should_fail_when_tuple_is_missing_elements() {
+    // arrange
+    let root = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[("Endpoint:0", "localhost")])
+        .build()
+        .unwrap();
+
+    // act
+    let result = from_config::<(String, u16)>(root.section("Endpoint").deref());
+
+    // assert
+    assert!(result.is_err());
+}
@@ -0,0 +1,107 @@
+use config::{ext::*, *};
+
+fn reverse(value: &str) -> Option<String> {
+    value
+        .strip_prefix("enc:")
+        .map(|v| v.chars().rev().collect())
+}
+
+#[test]
+fn get_should_decrypt_encrypted_value() {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[
+            ("Database:Password", "enc:terces"),
+            ("Database:User", "admin"),
+        ])
+        .add_decryption(Box::new(reverse))
+        .build()
+        .unwrap();
+
+    // act
+    let password = config.get("Database:Password");
+
+    // assert
+    assert_eq!(password.unwrap().as_str(), "secret");
+    assert_eq!(config.get("Database:User").unwrap().as_str(), "admin");
+}
+
+#[test]
+fn add_decryption_should_not_decrypt_sources_added_afterward() {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[("Key1", "enc:1eulav")])
+        .add_decryption(Box::new(reverse))
+        .add_in_memory(&[("Key2", "enc:2eulav")])
+        .build()
+        .unwrap();
+
+    // act
+    let key1 = config.get("Key1");
+    let key2 = config.get("Key2");
+
+    // assert
+    assert_eq!(key1.unwrap().as_str(), "value1");
+    assert_eq!(key2.unwrap().as_str(), "enc:2eulav");
+}
+
+#[test]
+fn build_should_fail_when_value_cannot_be_decrypted() {
+    // arrange
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder
+        .add_in_memory(&[("Secret", "enc:corrupt"), ("Other", "enc:bad")])
+        .add_decryption(Box::new(|_: &str| None));
+
+    // act
+    let result = builder.build();
+
+    // assert
+    match result {
+        Err(ReloadError::Provider(errors)) => assert_eq!(
+            errors[0].1.message(),
+            "The configuration values for the following keys could not be decrypted: Other, Secret"
+        ),
+        _ => panic!("expected a provider error"),
+    }
+}
+
+#[test]
+fn get_should_not_fall_back_to_preceding_value_when_value_cannot_be_decrypted() {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[("Secret", "plain")])
+        .add_in_memory(&[("Secret", "enc:corrupt")])
+        .add_decryption(Box::new(|_: &str| None))
+        .build_lazy();
+
+    // act
+    let secret = config.get("Secret");
+
+    // assert
+    assert!(secret.is_none());
+}
+
+#[test]
+fn save_should_save_inner_provider() {
+    // arrange
+    let file = TempConfigFile::new("json", r#"{"Password":"enc:terces"}"#);
+    let mut provider = DecryptedConfigurationSource::new(
+        Box::new(JsonConfigurationSource::new(file.path().into())),
+        std::sync::Arc::new(reverse),
+    )
+    .build(&DefaultConfigurationBuilder::new());
+
+    provider.load().unwrap();
+
+    // act
+    let result = provider.save();
+
+    // assert
+    assert!(result.is_ok());
+    assert_eq!(
+        std::fs::read_to_string(file.path()).unwrap(),
+        "{\n  \"Password\": \"enc:terces\"\n}\n"
+    );
+}
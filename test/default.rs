@@ -1,5 +1,6 @@
 use config::{ext::*, ConfigurationPath::Relative, *};
 use std::collections::HashMap;
+use std::sync::Arc;
 use test_case::test_case;
 
 #[test]
@@ -68,6 +69,106 @@ fn add_configuration_should_chain_configurations() {
     assert!(config.get("Nonexistent").is_none());
 }
 
+#[test]
+fn add_configuration_at_should_chain_configuration_under_section() {
+    // arrange
+    let other = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[("Host", "localhost"), ("Retry:Count", "3")])
+        .build()
+        .unwrap();
+
+    // act
+    let config = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[("Name", "app")])
+        .add_configuration_at("Services:Database", other.as_config())
+        .build()
+        .unwrap();
+
+    // assert
+    let mut keys: Vec<_> = config
+        .children()
+        .iter()
+        .map(|c| c.key().to_owned())
+        .collect();
+    keys.sort();
+
+    let database = config.section("Services").section("Database");
+
+    assert_eq!(keys, vec!["Name", "Services"]);
+    assert_eq!(database.get("Host").unwrap().as_str(), "localhost");
+    assert_eq!(
+        config
+            .get("Services:Database:Retry:Count")
+            .unwrap()
+            .as_str(),
+        "3"
+    );
+    assert_eq!(database.children().len(), 2);
+    assert!(config.get("Host").is_none());
+}
+
+#[test]
+fn add_configuration_section_should_chain_section_keys() {
+    // arrange
+    let other = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[("Database:Host", "localhost"), ("Database:Port", "5432")])
+        .build()
+        .unwrap();
+
+    // act
+    let config = DefaultConfigurationBuilder::new()
+        .add_configuration_section(other.section("Database"))
+        .build()
+        .unwrap();
+
+    // assert
+    let provider = config.providers().next().unwrap();
+    let mut pairs: Vec<_> = provider
+        .try_iter()
+        .unwrap()
+        .map(|(key, value)| (key, value.as_str().to_owned()))
+        .collect();
+
+    pairs.sort();
+
+    assert_eq!(config.get("Host").unwrap().as_str(), "localhost");
+    assert_eq!(config.children().len(), 2);
+    assert_eq!(
+        pairs,
+        vec![
+            ("Host".to_owned(), "localhost".to_owned()),
+            ("Port".to_owned(), "5432".to_owned())
+        ]
+    );
+}
+
+#[test]
+fn add_configuration_weak_should_not_keep_configuration_alive() {
+    // arrange
+    let other: Arc<dyn Configuration> = Arc::from(
+        DefaultConfigurationBuilder::new()
+            .add_in_memory(&[("Host", "localhost")])
+            .build()
+            .unwrap()
+            .as_config(),
+    );
+    let config = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[("Name", "app")])
+        .add_configuration_weak(&other)
+        .build()
+        .unwrap();
+    let host = config.get("Host");
+
+    // act
+    drop(other);
+
+    // assert
+    assert_eq!(host.unwrap().as_str(), "localhost");
+    assert!(config.get("Host").is_none());
+    assert_eq!(config.get("Name").unwrap().as_str(), "app");
+    assert_eq!(config.children().len(), 1);
+}
+
 #[test_case(ConfigurationPath::Absolute ; "with original path")]
 #[test_case(ConfigurationPath::Relative ; "with relative path")]
 fn iter_should_flatten_into_hashmap(path: ConfigurationPath) {
@@ -432,3 +533,589 @@ fn key_ending_with_double_colon_has_section_with_empty_name() {
     assert_eq!(children[0].children()[0].children().len(), 1);
     assert_eq!(children[0].children()[0].children()[0].key(), "Key3");
 }
+
+#[test]
+fn get_str_should_return_value_from_last_provider() {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[("Key1", "Value1"), ("Key2", "Value2")])
+        .add_in_memory(&[("Key2", "Value3")])
+        .build()
+        .unwrap();
+
+    // act
+    let value1 = config.get_str("key1").unwrap();
+    let value2 = config.section("KEY2").value();
+
+    // assert
+    assert_eq!(value1, "Value1");
+    assert_eq!(value2.as_str(), "Value3");
+    assert!(config.get_str("Key3").is_none());
+}
+
+#[test]
+fn get_str_should_borrow_value_from_provider() {
+    // arrange
+    let source = MemoryConfigurationSource::new(&[("Key1", "Value1")]);
+    let mut provider = source.build(&DefaultConfigurationBuilder::new());
+
+    provider.load().unwrap();
+
+    // act
+    let value = provider.get_str("KEY1").unwrap();
+
+    // assert
+    assert!(matches!(value, ValueRef::Borrowed("Value1")));
+}
+
+#[test_case(ArrayMergePolicy::Merge, &["10.0.0.9", "10.0.0.2"] ; "merge")]
+#[test_case(ArrayMergePolicy::Replace, &["10.0.0.9"] ; "replace")]
+#[test_case(ArrayMergePolicy::Append, &["10.0.0.1", "10.0.0.2", "10.0.0.9"] ; "append")]
+fn indexed_sections_should_combine_according_to_array_merge_policy(
+    policy: ArrayMergePolicy,
+    expected: &[&str],
+) {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .merge_arrays(policy)
+        .add_in_memory(&[("Ip:0", "10.0.0.1"), ("Ip:1", "10.0.0.2")])
+        .add_in_memory(&[("Ip:0", "10.0.0.9")])
+        .build()
+        .unwrap();
+
+    // act
+    let mut children = config.section("Ip").children();
+
+    children.sort_by_key(|section| section.key().parse::<usize>().unwrap());
+
+    let values: Vec<_> = children.iter().map(|s| s.value().to_string()).collect();
+
+    // assert
+    assert_eq!(values, expected);
+}
+
+#[test]
+fn array_merge_policy_should_not_affect_objects() {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .merge_arrays(ArrayMergePolicy::Replace)
+        .add_in_memory(&[("Logging:Level", "Debug"), ("Logging:Format", "json")])
+        .add_in_memory(&[("Logging:Level", "Warning")])
+        .build()
+        .unwrap();
+
+    // act
+    let level = config.get("Logging:Level");
+    let format = config.get("Logging:Format");
+
+    // assert
+    assert_eq!(level.unwrap().as_str(), "Warning");
+    assert_eq!(format.unwrap().as_str(), "json");
+}
+
+#[test]
+fn append_array_merge_policy_should_offset_nested_indexed_sections() {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .merge_arrays(ArrayMergePolicy::Append)
+        .add_in_memory(&[("Servers:0:Host", "alpha")])
+        .add_in_memory(&[("Servers:0:Host", "beta"), ("Servers:0:Port", "8080")])
+        .build()
+        .unwrap();
+
+    // act
+    let host = config.get("Servers:1:Host");
+    let port = config.get("Servers:1:Port");
+
+    // assert
+    assert_eq!(config.get("Servers:0:Host").unwrap().as_str(), "alpha");
+    assert!(config.get("Servers:0:Port").is_none());
+    assert_eq!(host.unwrap().as_str(), "beta");
+    assert_eq!(port.unwrap().as_str(), "8080");
+}
+
+#[test]
+fn removed_key_should_mask_value_from_earlier_source() {
+    // arrange
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder.add_in_memory(&[("Feature:X", "true"), ("Feature:Y", "true")]);
+    builder.add(Box::new(
+        MemoryConfigurationSource::new::<&str>(&[]).with_removals(&["feature:x"]),
+    ));
+
+    let config = builder.build().unwrap();
+
+    // act
+    let children = config.section("Feature").children();
+
+    // assert
+    assert!(config.get("Feature:X").is_none());
+    assert_eq!(config.get("Feature:Y").unwrap().as_str(), "true");
+    assert_eq!(children.len(), 1);
+    assert_eq!(children[0].key(), "Y");
+}
+
+#[test]
+fn removed_section_should_mask_descendants_until_set_again() {
+    // arrange
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder.add_in_memory(&[("Proxy:Host", "localhost"), ("Proxy:Port", "8080")]);
+    builder.add(Box::new(
+        MemoryConfigurationSource::new::<&str>(&[]).with_removals(&["Proxy"]),
+    ));
+    builder.add_in_memory(&[("Proxy:Port", "9090")]);
+
+    let config = builder.build().unwrap();
+
+    // act
+    let children = config.section("Proxy").children();
+
+    // assert
+    assert!(config.get("Proxy:Host").is_none());
+    assert_eq!(config.get("Proxy:Port").unwrap().as_str(), "9090");
+    assert_eq!(children.len(), 1);
+}
+
+#[test]
+fn memory_handle_should_change_configuration() {
+    // arrange
+    let mut source = MemoryConfigurationSource::new(&[("Name", "Alpha"), ("Enabled", "true")]);
+    let handle = source.handle();
+    let mut builder = DefaultConfigurationBuilder::new();
+    builder.add(Box::new(source));
+    let config = builder.build().unwrap();
+    let token = config.reload_token();
+
+    // act
+    handle.set("Name", "Beta");
+    handle.remove("Enabled");
+
+    // assert
+    assert!(token.changed());
+    assert_eq!(config.get("Name").unwrap().as_str(), "Beta");
+    assert_eq!(config.get("Enabled"), None);
+    assert_eq!(config.children().len(), 1);
+}
+
+#[test]
+fn value_transforms_should_apply_to_every_value_in_order() {
+    // arrange
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder
+        .add_in_memory(&[("Name", "  Alpha  "), ("Service:Host", " localhost ")])
+        .add_value_transform(|_, value| Some(value.trim().to_owned()))
+        .add_value_transform(|key, value| {
+            if key.eq_ignore_ascii_case("Name") {
+                Some(value.to_uppercase())
+            } else {
+                None
+            }
+        });
+
+    let config = builder.build().unwrap();
+
+    // act
+    let name = config.get("Name").unwrap();
+    let host = config.section("Service").get("Host").unwrap();
+
+    // assert
+    assert_eq!(name.as_str(), "ALPHA");
+    assert_eq!(host.as_str(), "localhost");
+}
+
+#[test]
+fn snapshot_should_not_change_when_configuration_changes() {
+    // arrange
+    let mut source =
+        MemoryConfigurationSource::new(&[("Name", "Alpha"), ("Service:Host", "localhost")]);
+    let handle = source.handle();
+    let mut builder = DefaultConfigurationBuilder::new();
+    builder.add(Box::new(source));
+    let config = builder.build().unwrap();
+    let snapshot = config.snapshot();
+
+    // act
+    handle.set("Name", "Beta");
+
+    // assert
+    assert_eq!(config.get("Name").unwrap().as_str(), "Beta");
+    assert_eq!(snapshot.value("name"), Some("Alpha"));
+    assert_eq!(
+        snapshot.section("Service").get("Host").unwrap().as_str(),
+        "localhost"
+    );
+    assert_eq!(snapshot.len(), 2);
+}
+
+#[test]
+fn snapshot_should_be_shareable_across_threads() {
+    // arrange
+    fn assert_send_sync<T: Send + Sync>(_: &T) {}
+    let config = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[("Logging:Level", "Debug"), ("Logging:Targets:0", "console")])
+        .build()
+        .unwrap();
+    let snapshot = config.snapshot();
+
+    assert_send_sync(&snapshot);
+
+    // act
+    let children = std::thread::spawn(move || {
+        snapshot
+            .section("Logging")
+            .iter(Some(Relative))
+            .map(|(key, value)| (key, value.to_string()))
+            .collect::<HashMap<_, _>>()
+    })
+    .join()
+    .unwrap();
+
+    // assert
+    assert_eq!(children.len(), 3);
+    assert_eq!(children["Level"], "Debug");
+    assert_eq!(children["Targets:0"], "console");
+}
+
+#[test]
+fn into_shared_should_share_configuration_across_threads() {
+    // arrange
+    let mut source = MemoryConfigurationSource::new(&[("Name", "Alpha")]);
+    let handle = source.handle();
+    let mut builder = DefaultConfigurationBuilder::new();
+    builder.add(Box::new(source));
+    let config = builder.build().unwrap().into_shared();
+
+    handle.set("Name", "Beta");
+
+    // act
+    let name = std::thread::spawn(move || config.get("Name").unwrap().to_string())
+        .join()
+        .unwrap();
+
+    // assert
+    assert_eq!(name, "Beta");
+}
+
+#[test]
+fn defaults_should_be_overridden_by_sources_added_before_them() {
+    // arrange
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder
+        .add_in_memory(&[("Server:Port", "9090")])
+        .set_default("Server:Port", "8080")
+        .set_defaults(&[("Server:Host", "localhost"), ("Server:Secure", "false")]);
+
+    let config = builder.build().unwrap();
+
+    // act
+    let port = config.get("Server:Port").unwrap();
+    let host = config.get("Server:Host").unwrap();
+
+    // assert
+    assert_eq!(port.as_str(), "9090");
+    assert_eq!(host.as_str(), "localhost");
+    assert_eq!(config.section("Server").children().len(), 3);
+}
+
+#[test]
+fn insert_should_add_source_at_position() {
+    // arrange
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder
+        .add_in_memory(&[("Name", "Default")])
+        .add_in_memory(&[("Port", "80")]);
+
+    // act
+    builder.insert(
+        1,
+        Box::new(MemoryConfigurationSource::new(&[("Name", "Override")])),
+    );
+
+    let config = builder.build().unwrap();
+
+    // assert
+    assert_eq!(builder.sources.len(), 3);
+    assert_eq!(config.get("Name").unwrap().as_str(), "Override");
+    assert_eq!(config.get("Port").unwrap().as_str(), "80");
+}
+
+#[test]
+fn remove_where_should_remove_matching_sources() {
+    // arrange
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder.add_in_memory(&[("Name", "Memory")]).add_env_vars();
+
+    // act
+    builder.remove_where(|source| {
+        source
+            .name()
+            .ends_with("EnvironmentVariablesConfigurationSource")
+    });
+
+    // assert
+    assert_eq!(builder.sources.len(), 1);
+    assert_eq!(
+        builder.build().unwrap().get("Name").unwrap().as_str(),
+        "Memory"
+    );
+}
+
+#[test]
+fn replace_should_replace_source_of_type() {
+    // arrange
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder
+        .add_env_vars()
+        .add_in_memory(&[("Name", "Original")]);
+
+    // act
+    builder.replace::<MemoryConfigurationSource>(Box::new(MemoryConfigurationSource::new(&[(
+        "Name", "Replaced",
+    )])));
+
+    let config = builder.build().unwrap();
+
+    // assert
+    assert_eq!(builder.sources.len(), 2);
+    assert_eq!(config.get("Name").unwrap().as_str(), "Replaced");
+}
+
+#[test]
+fn replace_should_replace_named_source_of_type() {
+    // arrange
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder.add_env_vars().add(Box::new(
+        MemoryConfigurationSource::new(&[("Name", "Original")]).named("base settings"),
+    ));
+
+    // act
+    builder.replace::<MemoryConfigurationSource>(Box::new(MemoryConfigurationSource::new(&[(
+        "Name", "Replaced",
+    )])));
+
+    let config = builder.build().unwrap();
+
+    // assert
+    assert_eq!(builder.sources.len(), 2);
+    assert_eq!(config.get("Name").unwrap().as_str(), "Replaced");
+}
+
+#[test]
+fn iter_leaves_should_skip_sections_with_children() {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[
+            ("Server:Name", "web"),
+            ("Server:Limits:Connections", "100"),
+            ("Server:Limits:Timeout", "30"),
+        ])
+        .build()
+        .unwrap();
+
+    // act
+    let mut leaves: Vec<_> = config.iter_leaves(None).collect();
+    let relative: HashMap<_, _> = config
+        .section("Server")
+        .iter_leaves(Some(Relative))
+        .collect();
+
+    // assert
+    leaves.sort();
+    assert_eq!(
+        leaves
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+            .collect::<Vec<_>>(),
+        vec![
+            ("Server:Limits:Connections", "100"),
+            ("Server:Limits:Timeout", "30"),
+            ("Server:Name", "web"),
+        ]
+    );
+    assert_eq!(relative.len(), 3);
+    assert_eq!(relative["Limits:Timeout"].as_str(), "30");
+}
+
+#[test]
+fn iter_as_should_yield_values_that_can_be_parsed() {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[
+            ("Server:Name", "web"),
+            ("Server:Limits:Connections", "100"),
+            ("Server:Limits:Timeout", "30"),
+        ])
+        .build()
+        .unwrap();
+
+    // act
+    let values: HashMap<String, u32> = config.iter_as(None).collect();
+
+    // assert
+    assert_eq!(values.len(), 2);
+    assert_eq!(values["Server:Limits:Connections"], 100);
+    assert_eq!(values["Server:Limits:Timeout"], 30);
+}
+
+#[test]
+fn get_should_return_value_from_last_provider_after_changes() {
+    // arrange
+    let first = FakeConfigurationProvider::new(&[("Name", "first"), ("Port", "80")]);
+    let last = FakeConfigurationProvider::new(&[("Name", "last")]);
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder.add(Box::new(first.clone()));
+    builder.add(Box::new(last.clone()));
+
+    let config = builder.build().unwrap();
+    let overlay = config.overlay().unwrap();
+    let before = (config.get("Name"), config.get("Port"));
+
+    // act
+    last.set("Port", "443");
+    last.trigger();
+    overlay.remove("Name");
+
+    // assert
+    assert_eq!(before.0.unwrap().as_str(), "last");
+    assert_eq!(before.1.unwrap().as_str(), "80");
+    assert_eq!(config.get("Port").unwrap().as_str(), "443");
+    assert_eq!(
+        config.get_typed("Port"),
+        Some(ConfigValue::String("443".to_owned().into()))
+    );
+    assert!(config.get("Name").is_none());
+}
+
+#[test]
+fn try_iter_should_expose_key_values_of_each_provider() {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[("Name", "app"), ("Logging:Level", "Debug")])
+        .add_in_memory(&[("Logging:Level", "Warning")])
+        .build()
+        .unwrap();
+
+    // act
+    let providers: Vec<HashMap<_, _>> = config
+        .providers()
+        .map(|provider| {
+            provider
+                .try_iter()
+                .unwrap()
+                .map(|(key, value)| (key, value.as_str().to_owned()))
+                .collect()
+        })
+        .collect();
+
+    // assert
+    assert_eq!(providers.len(), 2);
+    assert_eq!(providers[0].len(), 2);
+    assert_eq!(providers[0]["Name"], "app");
+    assert_eq!(providers[0]["Logging:Level"], "Debug");
+    assert_eq!(providers[1].len(), 1);
+    assert_eq!(providers[1]["Logging:Level"], "Warning");
+}
+
+struct GreetingSource;
+
+impl ConfigurationSource for GreetingSource {
+    fn build(&self, builder: &dyn ConfigurationBuilder) -> Box<dyn ConfigurationProvider> {
+        let name = builder
+            .property::<String>("Name")
+            .cloned()
+            .unwrap_or_default();
+        let greeting = format!("Hello, {}!", name);
+
+        MemoryConfigurationSource::new(&[("Greeting", greeting.as_str())]).build(builder)
+    }
+}
+
+#[test]
+fn set_property_should_share_typed_value_with_sources() {
+    // arrange
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder.add(Box::new(GreetingSource));
+    builder.set_property("Name", "World".to_owned());
+
+    // act
+    let config = builder.build().unwrap();
+
+    // assert
+    assert_eq!(builder.property::<String>("Name").unwrap(), "World");
+    assert!(builder.property::<u32>("Name").is_none());
+    assert_eq!(config.get("Greeting").unwrap().as_str(), "Hello, World!");
+}
+
+struct VaultSource;
+
+impl ConfigurationSource for VaultSource {
+    fn build(&self, builder: &dyn ConfigurationBuilder) -> Box<dyn ConfigurationProvider> {
+        let address = builder
+            .bootstrap()
+            .and_then(|bootstrap| bootstrap.get("Vault:Address"))
+            .map(|address| address.as_str().to_owned())
+            .unwrap_or_default();
+
+        MemoryConfigurationSource::new(&[("Secret", address.as_str())]).build(builder)
+    }
+}
+
+#[test]
+fn with_bootstrap_should_share_bootstrap_configuration_with_sources() {
+    // arrange
+    let bootstrap = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[("Vault:Address", "https://vault:8200")])
+        .build()
+        .unwrap();
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder.with_bootstrap(bootstrap).add(Box::new(VaultSource));
+
+    // act
+    let config = builder.build().unwrap();
+
+    // assert
+    assert_eq!(config.get("Secret").unwrap().as_str(), "https://vault:8200");
+    assert!(config.get("Vault:Address").is_none());
+}
+
+#[test]
+fn section_should_not_split_escaped_delimiter() {
+    // arrange
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder.add_in_memory(&[
+        (r"Urls:http\://localhost:Port", "5000"),
+        (r"Paths:C\:\\Temp", "Scratch"),
+    ]);
+
+    let config = builder.build().unwrap();
+    let key = ConfigurationPath::join("Urls", "http://localhost");
+
+    // act
+    let section = config.section(&key);
+    let children = config.section("Urls").children();
+
+    // assert
+    assert_eq!(section.key(), r"http\://localhost");
+    assert_eq!(section.get("Port").unwrap().as_str(), "5000");
+    assert_eq!(children.len(), 1);
+    assert_eq!(children[0].path(), key);
+    assert_eq!(
+        config
+            .get(&ConfigurationPath::join("Paths", r"C:\Temp"))
+            .unwrap()
+            .as_str(),
+        "Scratch"
+    );
+}
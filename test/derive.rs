@@ -0,0 +1,225 @@
+use config::{ext::*, *};
+
+#[derive(Config)]
+struct ServerOptions {
+    host: String,
+    #[config(default = "8080", min = 1, max = 65535)]
+    port: u32,
+    #[config(key = "Tls")]
+    secure: Option<bool>,
+    tags: Vec<String>,
+    #[config(nested)]
+    limits: LimitOptions,
+}
+
+#[derive(Config)]
+struct LimitOptions {
+    #[config(default = "10", validate = "positive")]
+    max_connections: i32,
+    #[config(env = "MORE_CONFIG_DERIVE_TEST_TIMEOUT")]
+    request_timeout: Option<u64>,
+}
+
+fn positive(value: &i32) -> Result<(), String> {
+    if *value > 0 {
+        Ok(())
+    } else {
+        Err("must be positive".into())
+    }
+}
+
+#[test]
+fn derive_should_bind_configuration_to_struct() {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[
+            ("Server:Host", "localhost"),
+            ("Server:Tls", "true"),
+            ("Server:Tags:1", "api"),
+            ("Server:Tags:0", "web"),
+            ("Server:Limits:MaxConnections", "100"),
+        ])
+        .build()
+        .unwrap();
+
+    // act
+    let options = ServerOptions::from_config(&*config.section("Server")).unwrap();
+
+    // assert
+    assert_eq!(options.host, "localhost");
+    assert_eq!(options.port, 8080);
+    assert_eq!(options.secure, Some(true));
+    assert_eq!(options.tags, vec!["web", "api"]);
+    assert_eq!(options.limits.max_connections, 100);
+    assert_eq!(options.limits.request_timeout, None);
+}
+
+#[test]
+fn derive_should_report_missing_required_key() {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[("Server:Port", "80")])
+        .build()
+        .unwrap();
+
+    // act
+    let error = ServerOptions::from_config(&*config.section("Server"))
+        .err()
+        .unwrap();
+
+    // assert
+    assert_eq!(
+        error.to_string(),
+        "The required configuration key 'Server:Host' was not found."
+    );
+}
+
+#[test]
+fn derive_should_report_invalid_value() {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[("Host", "localhost"), ("Port", "eighty")])
+        .build()
+        .unwrap();
+
+    // act
+    let error = ServerOptions::from_config(config.as_config().as_ref())
+        .err()
+        .unwrap();
+
+    // assert
+    assert_eq!(
+        error.to_string(),
+        "The value 'eighty' of configuration key 'Port' is invalid: \
+         invalid digit found in string."
+    );
+}
+
+#[test]
+fn derive_should_report_validation_failure() {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[
+            ("Server:Host", "localhost"),
+            ("Server:Limits:MaxConnections", "0"),
+        ])
+        .build()
+        .unwrap();
+
+    // act
+    let error = ServerOptions::from_config(&*config.section("Server"))
+        .err()
+        .unwrap();
+
+    // assert
+    assert_eq!(
+        error,
+        ConfigError::Invalid {
+            path: "Server:Limits:MaxConnections".into(),
+            value: "0".into(),
+            message: "must be positive".into(),
+        }
+    );
+}
+
+#[test]
+fn derive_should_report_value_out_of_range() {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[("Host", "localhost"), ("Port", "70000")])
+        .build()
+        .unwrap();
+
+    // act
+    let error = ServerOptions::from_config(config.as_config().as_ref())
+        .err()
+        .unwrap();
+
+    // assert
+    assert_eq!(error.path(), "Port");
+    assert!(error.to_string().ends_with("must be at most 65535."));
+}
+
+#[test]
+fn derive_should_prefer_environment_variable() {
+    // arrange
+    std::env::set_var("MORE_CONFIG_DERIVE_TEST_TIMEOUT", "30");
+    let config = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[("RequestTimeout", "5")])
+        .build()
+        .unwrap();
+
+    // act
+    let options = LimitOptions::from_config(config.as_config().as_ref()).unwrap();
+
+    // assert
+    assert_eq!(options.request_timeout, Some(30));
+    std::env::remove_var("MORE_CONFIG_DERIVE_TEST_TIMEOUT");
+}
+
+#[test]
+fn derive_should_describe_keys() {
+    // act
+    let keys = ServerOptions::keys();
+
+    // assert
+    let names: Vec<_> = keys.iter().map(|k| k.key.as_str()).collect();
+    assert_eq!(
+        names,
+        vec![
+            "Host",
+            "Port",
+            "Tls",
+            "Tags",
+            "Limits:MaxConnections",
+            "Limits:RequestTimeout"
+        ]
+    );
+    assert!(keys[0].required);
+    assert_eq!(keys[1].default, Some("8080"));
+    assert_eq!(keys[5].env, Some("MORE_CONFIG_DERIVE_TEST_TIMEOUT"));
+}
+
+config_keys!("keys.json");
+
+#[test]
+fn config_keys_should_generate_constants_for_each_key_path() {
+    // arrange
+    let json = include_str!("keys.json");
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder.add_json_stream(json.as_bytes());
+
+    // act
+    let config = builder.build().unwrap();
+
+    // assert
+    assert_eq!(keys::DATABASE, "Database");
+    assert_eq!(
+        keys::DATABASE_CONNECTION_STRING,
+        "Database:ConnectionString"
+    );
+    assert_eq!(keys::DATABASE_COMMAND_TIMEOUT, "Database:commandTimeout");
+    assert_eq!(keys::SERVERS_1_HOST, "Servers:1:Host");
+    assert_eq!(keys::LOGGING_LOG_LEVEL_DEFAULT, "Logging:LogLevel:Default");
+    assert_eq!(
+        config.get(keys::SERVERS_1_HOST).unwrap().as_str(),
+        "secondary"
+    );
+}
+
+#[test]
+fn key_should_combine_segments_into_path() {
+    // arrange
+    const LEVEL: &str = key!("Logging" / "LogLevel" / "Default");
+    const HOST: &str = key!("Servers" / 0 / "Host");
+
+    // act
+    let url = key!("Urls" / "http://localhost");
+
+    // assert
+    assert_eq!(LEVEL, "Logging:LogLevel:Default");
+    assert_eq!(HOST, "Servers:0:Host");
+    assert_eq!(url, r"Urls:http\://localhost");
+    assert_eq!(url, ConfigurationPath::join("Urls", "http://localhost"));
+}
@@ -0,0 +1,66 @@
+use config::{ext::*, *};
+
+#[test]
+fn diff_should_report_added_removed_and_changed_keys() {
+    // arrange
+    let old = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[
+            ("Name", "App"),
+            ("Logging:Level", "Warning"),
+            ("Port", "80"),
+        ])
+        .build()
+        .unwrap();
+    let new = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[
+            ("name", "App"),
+            ("Logging:Level", "Debug"),
+            ("Secure", "true"),
+        ])
+        .build()
+        .unwrap();
+
+    // act
+    let entries = diff(&**old, &**new);
+
+    // assert
+    assert_eq!(
+        entries,
+        vec![
+            DiffEntry::Changed {
+                key: "Logging:Level".into(),
+                old: "Warning".into(),
+                new: "Debug".into(),
+            },
+            DiffEntry::Removed {
+                key: "Port".into(),
+                value: "80".into(),
+            },
+            DiffEntry::Added {
+                key: "Secure".into(),
+                value: "true".into(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn diff_should_compare_snapshot_with_reloaded_configuration() {
+    // arrange
+    let mut source = MemoryConfigurationSource::new(&[("Name", "Alpha")]);
+    let handle = source.handle();
+    let mut builder = DefaultConfigurationBuilder::new();
+    builder.add(Box::new(source));
+    let config = builder.build().unwrap();
+    let snapshot = config.snapshot();
+
+    handle.set("Name", "Beta");
+
+    // act
+    let entries = diff(&snapshot, &**config);
+
+    // assert
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].key(), "Name");
+    assert_eq!(entries[0].to_string(), "~ Name = Alpha -> Beta");
+}
@@ -1,5 +1,7 @@
 use config::{ext::*, *};
 use std::env::{set_var, var};
+use std::thread::sleep;
+use std::time::{Duration, Instant};
 
 #[test]
 fn add_env_vars_should_load_environment_variables() {
@@ -52,3 +54,155 @@ fn add_env_vars_should_translate_double_underscore_to_colon() {
     // assert
     assert_eq!(value.as_str(), expected);
 }
+
+#[test]
+fn add_env_vars_should_expose_nested_child_keys() {
+    // arrange
+    set_var("EnvNested__Servers__0", "a");
+    set_var("EnvNested__Servers__1", "b");
+
+    let config = DefaultConfigurationBuilder::new()
+        .add_env_vars_with_prefix("EnvNested__")
+        .build()
+        .unwrap();
+
+    // act
+    let servers = config.section("Servers").children();
+
+    // assert
+    assert_eq!(servers.len(), 2);
+}
+
+#[test]
+fn env_vars_should_split_list_into_indexed_children() {
+    // arrange
+    set_var("EnvList_Servers", "a, b,c");
+    set_var("EnvList_Name", "x,y");
+
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder.add(Box::new(
+        EnvironmentVariablesConfigurationSource::new("EnvList_").with_list("servers"),
+    ));
+
+    let config = builder.build().unwrap();
+
+    // act
+    let servers = config.section("Servers").children();
+
+    // assert
+    assert_eq!(servers.len(), 3);
+    assert_eq!(config.get("Servers:0").unwrap().as_str(), "a");
+    assert_eq!(config.get("Servers:1").unwrap().as_str(), "b");
+    assert_eq!(config.get("Servers:2").unwrap().as_str(), "c");
+    assert_eq!(config.get("Name").unwrap().as_str(), "x,y");
+}
+
+#[test]
+fn env_vars_should_split_list_matching_pattern_with_separator() {
+    // arrange
+    set_var("EnvPattern_Hosts__Primary", "a;b");
+    set_var("EnvPattern_Hosts__Secondary", "c");
+
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder.add(Box::new(
+        EnvironmentVariablesConfigurationSource::new("EnvPattern_")
+            .with_list("Hosts:*")
+            .with_list_separator(';'),
+    ));
+
+    let config = builder.build().unwrap();
+
+    // act
+    let primary = config.get("Hosts:Primary:1");
+    let secondary = config.get("Hosts:Secondary:0");
+
+    // assert
+    assert_eq!(primary.unwrap().as_str(), "b");
+    assert_eq!(secondary.unwrap().as_str(), "c");
+    assert!(config.get("Hosts:Primary").is_none());
+}
+
+#[test]
+fn env_vars_should_signal_reload_token_when_polled_variables_change() {
+    // arrange
+    set_var("EnvPoll_Name", "Before");
+
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder.add(Box::new(
+        EnvironmentVariablesConfigurationSource::new("EnvPoll_")
+            .with_poll_interval(Duration::from_millis(10)),
+    ));
+
+    let root = builder.build().unwrap();
+    let token = root.reload_token();
+    let start = Instant::now();
+
+    // act
+    set_var("EnvPoll_Name", "After");
+
+    while !token.changed() && start.elapsed() < Duration::from_secs(5) {
+        sleep(Duration::from_millis(10));
+    }
+
+    root.reload().unwrap();
+
+    // assert
+    assert!(token.changed());
+    assert_eq!(root.get("Name").unwrap().as_str(), "After");
+}
+
+#[test]
+fn env_vars_should_apply_key_casing() {
+    // arrange
+    set_var("EnvCasing_DATABASE__CONNECTION_STRING", "any");
+
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder.add(Box::new(
+        EnvironmentVariablesConfigurationSource::new("EnvCasing_")
+            .with_casing(KeyCasing::PascalCase),
+    ));
+
+    let config = builder.build().unwrap();
+
+    // act
+    let children = config.section("Database").children();
+
+    // assert
+    assert_eq!(children[0].key(), "ConnectionString");
+    assert_eq!(
+        config.get("Database:ConnectionString").unwrap().as_str(),
+        "any"
+    );
+}
+
+#[test]
+fn env_vars_should_strip_longest_matching_prefix() {
+    // arrange
+    set_var("MYAPP_Alternate1", "1");
+    set_var("MY_APP_Alternate2", "2");
+
+    let source = EnvironmentVariablesConfigurationSource::new("MYAPP_")
+        .with_prefix("MY_APP_")
+        .with_casing(KeyCasing::Lowercase);
+    let mut provider = EnvironmentVariablesConfigurationProvider::new("MY_".into())
+        .with_prefix("MY_APP_")
+        .with_casing(KeyCasing::Lowercase);
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder.add(Box::new(source));
+
+    let config = builder.build().unwrap();
+
+    // act
+    provider.load().unwrap();
+
+    // assert
+    assert_eq!(config.get("alternate1").unwrap().as_str(), "1");
+    assert_eq!(config.get("alternate2").unwrap().as_str(), "2");
+    assert_eq!(provider.get("app_alternate2"), None);
+    assert_eq!(provider.variable("alternate2"), Some("MY_APP_Alternate2"));
+}
@@ -0,0 +1,112 @@
+use config::{ext::*, *};
+use std::sync::{Arc, Mutex};
+
+// simulates the change callback of a third-party SDK
+#[derive(Clone, Default)]
+struct FakeFeed {
+    flags: Vec<(String, String)>,
+    sinks: Arc<Mutex<Vec<ConfigurationUpdateSink>>>,
+}
+
+impl FakeFeed {
+    fn new(flags: &[(&str, &str)]) -> Self {
+        Self {
+            flags: flags
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    fn on_change(&self, key: &str, value: Option<&str>) -> bool {
+        let update = match value {
+            Some(value) => ConfigurationUpdate::Set(key.into(), value.into()),
+            _ => ConfigurationUpdate::Remove(key.into()),
+        };
+
+        self.sinks.lock().unwrap()[0].send(update)
+    }
+}
+
+impl DynamicKeyValueFeed for FakeFeed {
+    fn snapshot(&self) -> Result<Vec<(String, String)>, LoadError> {
+        Ok(self.flags.clone())
+    }
+
+    fn subscribe(&self, sink: ConfigurationUpdateSink) {
+        self.sinks.lock().unwrap().push(sink);
+    }
+}
+
+#[test]
+fn add_feed_should_load_snapshot() {
+    // arrange
+    let feed = FakeFeed::new(&[("Flags:NewCheckout", "true"), ("Flags:DarkMode", "false")]);
+
+    // act
+    let config = DefaultConfigurationBuilder::new()
+        .add_feed(feed.clone())
+        .build()
+        .unwrap();
+
+    // assert
+    assert_eq!(config.get("Flags:NewCheckout").unwrap().as_str(), "true");
+    assert_eq!(config.section("Flags").children().len(), 2);
+    assert_eq!(feed.sinks.lock().unwrap().len(), 1);
+}
+
+#[test]
+fn feed_changes_should_override_earlier_sources_and_signal_reload_token() {
+    // arrange
+    let feed = FakeFeed::new(&[("Flags:NewCheckout", "false")]);
+    let config = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[("Flags:NewCheckout", "false"), ("Flags:DarkMode", "true")])
+        .add_feed(feed.clone())
+        .build()
+        .unwrap();
+    let token = config.reload_token();
+
+    // act
+    feed.on_change("Flags:NewCheckout", Some("true"));
+    feed.on_change("Flags:Beta", Some("on"));
+
+    // assert
+    assert!(token.changed());
+    assert_eq!(config.get("Flags:NewCheckout").unwrap().as_str(), "true");
+    assert_eq!(config.get("Flags:Beta").unwrap().as_str(), "on");
+    assert_eq!(config.get("Flags:DarkMode").unwrap().as_str(), "true");
+}
+
+#[test]
+fn feed_should_stop_when_provider_is_dropped() {
+    // arrange
+    let feed = FakeFeed::new(&[("Flags:NewCheckout", "true")]);
+    let config = DefaultConfigurationBuilder::new()
+        .add_feed(feed.clone())
+        .build()
+        .unwrap();
+
+    // act
+    drop(config);
+
+    // assert
+    assert!(!feed.on_change("Flags:NewCheckout", None));
+}
+
+#[test]
+fn close_should_reject_feed_changes() {
+    // arrange
+    let feed = FakeFeed::new(&[("Flags:NewCheckout", "true")]);
+    let config = DefaultConfigurationBuilder::new()
+        .add_feed(feed.clone())
+        .build()
+        .unwrap();
+
+    // act
+    config.close();
+
+    // assert
+    assert!(!feed.on_change("Flags:NewCheckout", Some("false")));
+    assert_eq!(config.get("Flags:NewCheckout").unwrap().as_str(), "true");
+}
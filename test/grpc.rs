@@ -0,0 +1,90 @@
+use config::{ext::*, *};
+use std::sync::{Arc, Mutex};
+
+#[derive(Clone, Default)]
+struct FakeClient {
+    snapshot: Vec<(String, String)>,
+    sinks: Arc<Mutex<Vec<ConfigurationUpdateSink>>>,
+}
+
+impl FakeClient {
+    fn new(pairs: &[(&str, &str)]) -> Self {
+        Self {
+            snapshot: pairs
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    fn push(&self, update: ConfigurationUpdate) -> bool {
+        self.sinks.lock().unwrap()[0].send(update)
+    }
+}
+
+impl ConfigurationServiceClient for FakeClient {
+    fn snapshot(&self) -> Result<Vec<(String, String)>, LoadError> {
+        Ok(self.snapshot.clone())
+    }
+
+    fn subscribe(&self, sink: ConfigurationUpdateSink) {
+        self.sinks.lock().unwrap().push(sink);
+    }
+}
+
+#[test]
+fn add_grpc_should_load_snapshot() {
+    // arrange
+    let client = FakeClient::new(&[("Logging:Level", "Info"), ("Feature:Beta", "false")]);
+
+    // act
+    let config = DefaultConfigurationBuilder::new()
+        .add_grpc(client.clone())
+        .build()
+        .unwrap();
+
+    // assert
+    assert_eq!(config.get("Logging:Level").unwrap().as_str(), "Info");
+    assert_eq!(config.section("Feature").children().len(), 1);
+    assert_eq!(client.sinks.lock().unwrap().len(), 1);
+}
+
+#[test]
+fn pushed_updates_should_change_values_and_signal_reload_token() {
+    // arrange
+    let client = FakeClient::new(&[("Logging:Level", "Info"), ("Feature:Beta", "false")]);
+    let config = DefaultConfigurationBuilder::new()
+        .add_grpc(client.clone())
+        .build()
+        .unwrap();
+    let token = config.reload_token();
+
+    // act
+    client.push(ConfigurationUpdate::Set(
+        "Logging:Level".into(),
+        "Debug".into(),
+    ));
+    client.push(ConfigurationUpdate::Remove("Feature:Beta".into()));
+
+    // assert
+    assert!(token.changed());
+    assert_eq!(config.get("Logging:Level").unwrap().as_str(), "Debug");
+    assert!(config.get("Feature:Beta").is_none());
+}
+
+#[test]
+fn send_should_return_false_after_provider_is_dropped() {
+    // arrange
+    let client = FakeClient::new(&[("Logging:Level", "Info")]);
+    let config = DefaultConfigurationBuilder::new()
+        .add_grpc(client.clone())
+        .build()
+        .unwrap();
+
+    // act
+    drop(config);
+
+    // assert
+    assert!(!client.push(ConfigurationUpdate::Snapshot(Vec::new())));
+}
@@ -0,0 +1,76 @@
+use config::{ext::*, *};
+use std::env::{remove_var, set_var, temp_dir};
+use std::fs::{create_dir_all, remove_dir_all, write};
+
+#[test]
+fn from_env_should_read_environment_name() {
+    // arrange
+    remove_var("APP_ENVIRONMENT");
+    set_var("RUST_ENV", "Staging");
+
+    // act
+    let staging = HostEnvironment::from_env();
+
+    set_var("APP_ENVIRONMENT", "Development");
+
+    let development = HostEnvironment::from_env();
+
+    remove_var("APP_ENVIRONMENT");
+    remove_var("RUST_ENV");
+
+    let production = HostEnvironment::from_env();
+
+    // assert
+    assert!(staging.is_staging());
+    assert!(development.is_development());
+    assert!(production.is_production());
+}
+
+#[test]
+fn use_environment_should_add_environment_to_properties() {
+    // arrange
+    let environment = HostEnvironment::new("Testing", temp_dir());
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    // act
+    builder.use_environment(environment.clone());
+
+    // assert
+    assert_eq!(HostEnvironment::of(&builder), Some(&environment));
+    assert!(HostEnvironment::of(&builder).unwrap().is("testing"));
+}
+
+#[test]
+fn add_json_file_for_env_should_override_base_file() {
+    // arrange
+    let root = temp_dir().join("host_environment");
+    create_dir_all(&root).unwrap();
+    write(
+        root.join("appsettings.json"),
+        r#"{"Name": "Base", "Port": 80}"#,
+    )
+    .unwrap();
+    write(
+        root.join("appsettings.Development.json"),
+        r#"{"Name": "Development"}"#,
+    )
+    .unwrap();
+    let development = HostEnvironment::new("Development", &root);
+    let production = HostEnvironment::new("Production", &root);
+
+    // act
+    let config = DefaultConfigurationBuilder::new()
+        .add_json_file_for_env("appsettings", &development)
+        .build()
+        .unwrap();
+    let fallback = DefaultConfigurationBuilder::new()
+        .add_json_file_for_env("appsettings", &production)
+        .build()
+        .unwrap();
+
+    // assert
+    remove_dir_all(&root).ok();
+    assert_eq!(config.get("Name").unwrap().as_str(), "Development");
+    assert_eq!(config.get("Port").unwrap().as_str(), "80");
+    assert_eq!(fallback.get("Name").unwrap().as_str(), "Base");
+}
@@ -34,6 +34,66 @@ fn add_ini_file_should_load_settings_from_file() {
     assert_eq!(value.unwrap().as_str(), "true");
 }
 
+#[test]
+fn add_ini_file_should_load_nested_keys_arrays_and_global_keys() {
+    // arrange
+    let path = temp_dir().join("test_settings_nested.ini");
+    let mut file = File::create(&path).unwrap();
+
+    file.write_all(b"Name=demo\n\n").unwrap();
+    file.write_all(b"[Logging]\n").unwrap();
+    file.write_all(b"LogLevel:Default=Information\n").unwrap();
+    file.write_all(b"Targets=console\n").unwrap();
+    file.write_all(b"Targets=file ; second\n").unwrap();
+    drop(file);
+
+    // act
+    let config = DefaultConfigurationBuilder::new()
+        .add_ini_file(&path)
+        .build()
+        .unwrap();
+
+    // assert
+    if path.exists() {
+        remove_file(&path).ok();
+    }
+
+    assert_eq!(config.get("Name").unwrap().as_str(), "demo");
+    assert_eq!(
+        config.get("Logging:LogLevel:Default").unwrap().as_str(),
+        "Information"
+    );
+    assert_eq!(config.get("Logging:Targets:0").unwrap().as_str(), "console");
+    assert_eq!(config.get("Logging:Targets:1").unwrap().as_str(), "file");
+    assert!(config.get("Logging:Targets").is_none());
+}
+
+#[test]
+fn add_ini_file_with_delimiter_should_convert_delimiter_to_key_delimiter() {
+    // arrange
+    let path = temp_dir().join("test_settings_delimiter.ini");
+    let mut file = File::create(&path).unwrap();
+
+    file.write_all(b"[Feature.Magic]\n").unwrap();
+    file.write_all(b"Options.Enabled=true\n").unwrap();
+    drop(file);
+
+    // act
+    let config = DefaultConfigurationBuilder::new()
+        .add_ini_file_with_delimiter(&path, ".")
+        .build()
+        .unwrap();
+
+    // assert
+    if path.exists() {
+        remove_file(&path).ok();
+    }
+
+    let value = config.get("Feature:Magic:Options:Enabled").unwrap();
+
+    assert_eq!(value.as_str(), "true");
+}
+
 #[test]
 fn add_ini_file_should_fail_if_file_does_not_exist() {
     // arrange
@@ -164,3 +224,83 @@ fn init_file_should_reload_when_changed() {
     assert_eq!(initial.as_str(), "true");
     assert_eq!(current.as_str(), "false");
 }
+
+#[test]
+fn save_should_write_ini_file() {
+    // arrange
+    let file = TempConfigFile::new(
+        "ini",
+        "Name=demo\n\n[Logging]\nLevel=Warning\nTargets=console\nTargets=file\n",
+    );
+    let mut provider = IniConfigurationProvider::new(file.path().to_path_buf().into());
+
+    provider.load().unwrap();
+    provider.set("Logging:Level", "Debug");
+    provider.set("Service:Enabled", "true");
+    remove_file(file.path()).unwrap();
+
+    // act
+    provider.save().unwrap();
+
+    // assert
+    let content = std::fs::read_to_string(file.path()).unwrap();
+
+    assert_eq!(
+        content,
+        "Name=demo\n\n[Logging]\nLevel=Debug\n\n[Logging:Targets]\n0=console\n1=file\n\n\
+         [Service]\nEnabled=true\n"
+    );
+}
+
+#[test]
+fn save_should_preserve_comments_and_formatting_of_ini_file() {
+    // arrange
+    let file = TempConfigFile::new(
+        "ini",
+        "; application settings\n\
+         Name = demo ; the name\n\
+         \n\
+         [Logging]\n\
+         # the minimum level\n\
+         Level = Warning\n\
+         Format=Json\n\
+         Targets=console\n\
+         Targets=file\n\
+         \n\
+         [Service]\n\
+         Enabled=false\n",
+    );
+    let mut provider = IniConfigurationProvider::new(file.path().to_path_buf().into());
+
+    provider.load().unwrap();
+    provider.set("Name", "updated");
+    provider.set("Logging:Level", "Debug");
+    provider.set("Logging:Color", "true");
+    provider.remove("Logging:Format");
+    provider.set("Cache:Size", "10");
+
+    // act
+    provider.save().unwrap();
+
+    // assert
+    let content = std::fs::read_to_string(file.path()).unwrap();
+
+    assert_eq!(
+        content,
+        "; application settings\n\
+         Name = updated ; the name\n\
+         \n\
+         [Logging]\n\
+         # the minimum level\n\
+         Level = Debug\n\
+         Targets=console\n\
+         Targets=file\n\
+         Color=true\n\
+         \n\
+         [Service]\n\
+         Enabled=false\n\
+         \n\
+         [Cache]\n\
+         Size=10\n"
+    );
+}
@@ -3,7 +3,8 @@ use serde_json::json;
 use std::env::temp_dir;
 use std::fs::{remove_file, File};
 use std::io::Write;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, Condvar, Mutex};
 use std::time::Duration;
 
@@ -367,3 +368,701 @@ fn json_file_should_reload_when_changed() {
     assert_eq!(initial.as_str(), "true");
     assert_eq!(current.as_str(), "false");
 }
+
+#[test]
+fn get_typed_should_preserve_json_value_types() {
+    // arrange
+    let json = json!({"server": {
+        "name": "web",
+        "port": 8080,
+        "ratio": 0.5,
+        "secure": true,
+        "proxy": null}
+    });
+    let path = temp_dir().join("test_settings_typed.json");
+    let mut file = File::create(&path).unwrap();
+
+    file.write_all(json.to_string().as_bytes()).unwrap();
+
+    let config = DefaultConfigurationBuilder::new()
+        .add_json_file(&path)
+        .build()
+        .unwrap();
+    let section = config.section("Server");
+
+    // act
+    let name = section.get_typed("Name").unwrap();
+    let port = section.get_typed("Port").unwrap();
+    let ratio = section.get_typed("Ratio").unwrap();
+    let secure = section.get_typed("Secure").unwrap();
+    let proxy = section.get_typed("Proxy").unwrap();
+
+    // assert
+    if path.exists() {
+        remove_file(&path).ok();
+    }
+
+    assert_eq!(name, ConfigValue::String(Value::new("web".into())));
+    assert_eq!(port, ConfigValue::Int(8080));
+    assert_eq!(ratio, ConfigValue::Float(0.5));
+    assert_eq!(secure, ConfigValue::Bool(true));
+    assert!(proxy.is_null());
+    assert_eq!(section.get("Port").unwrap().as_str(), "8080");
+}
+
+#[test]
+fn reify_should_bind_typed_json_values_without_parsing() {
+    // arrange
+    let json = json!({"port": 8080, "secure": true, "ratio": 2, "tags": {"build": 42}});
+    let path = temp_dir().join("test_settings_typed_bind.json");
+    let mut file = File::create(&path).unwrap();
+
+    file.write_all(json.to_string().as_bytes()).unwrap();
+
+    let config = DefaultConfigurationBuilder::new()
+        .add_json_file(&path)
+        .build()
+        .unwrap();
+
+    // act
+    let options: TypedOptions = config.reify();
+
+    // assert
+    if path.exists() {
+        remove_file(&path).ok();
+    }
+
+    assert_eq!(options.port, 8080);
+    assert!(options.secure);
+    assert_eq!(options.ratio, 2.0);
+    assert_eq!(options.tags["Build"], serde_json::Value::from(42));
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all(deserialize = "PascalCase"))]
+struct TypedOptions {
+    port: u16,
+    secure: bool,
+    ratio: f64,
+    tags: std::collections::HashMap<String, serde_json::Value>,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all(deserialize = "PascalCase"))]
+struct NullableOptions {
+    proxy: Option<String>,
+    name: Option<String>,
+}
+
+#[test]
+fn reify_should_bind_json_null_to_none() {
+    // arrange
+    let json = json!({"proxy": null, "name": ""});
+    let path = temp_dir().join("test_settings_null.json");
+    let mut file = File::create(&path).unwrap();
+
+    file.write_all(json.to_string().as_bytes()).unwrap();
+
+    let config = DefaultConfigurationBuilder::new()
+        .add_json_file(&path)
+        .build()
+        .unwrap();
+
+    // act
+    let options: NullableOptions = config.reify();
+
+    // assert
+    if path.exists() {
+        remove_file(&path).ok();
+    }
+
+    assert!(config.section("Proxy").is_null());
+    assert!(!config.section("Name").is_null());
+    assert!(!config.section("Missing").is_null());
+    assert_eq!(options.proxy, None);
+    assert_eq!(options.name, Some(String::new()));
+}
+
+#[test]
+fn json_should_reload_when_polled_file_changes() {
+    // arrange
+    let path = temp_dir().join("poll_settings_1.json");
+    let mut json = json!({"service": {"enabled": false}});
+    let mut file = File::create(&path).unwrap();
+    file.write_all(json.to_string().as_bytes()).unwrap();
+    drop(file);
+
+    let config = DefaultConfigurationBuilder::new()
+        .add_json_file(
+            path.is()
+                .poll_interval(Duration::from_millis(10))
+                .reload_delay(Duration::ZERO),
+        )
+        .build()
+        .unwrap();
+    let initial = config.get("Service:Enabled").unwrap();
+    let token = config.reload_token();
+    let state = Arc::new((Mutex::new(false), Condvar::new()));
+    let _unused = token.register(
+        Box::new(|s| {
+            let data = s.unwrap();
+            let (reloaded, event) = data.downcast_ref::<(Mutex<bool>, Condvar)>().unwrap();
+            *reloaded.lock().unwrap() = true;
+            event.notify_one();
+        }),
+        Some(state.clone()),
+    );
+
+    json = json!({"service": {"enabled": true}});
+    file = File::create(&path).unwrap();
+    file.write_all(json.to_string().as_bytes()).unwrap();
+    drop(file);
+
+    let (mutex, event) = &*state;
+    let mut reloaded = mutex.lock().unwrap();
+    let mut attempts = 0;
+
+    while !*reloaded && attempts < 10 {
+        reloaded = event
+            .wait_timeout(reloaded, Duration::from_millis(500))
+            .unwrap()
+            .0;
+        attempts += 1;
+    }
+
+    // act
+    let current = config.get("Service:Enabled").unwrap();
+
+    // assert
+    if path.exists() {
+        remove_file(&path).ok();
+    }
+
+    assert_eq!(initial.as_str(), "false");
+    assert_eq!(current.as_str(), "true");
+}
+
+#[test]
+fn json_should_reload_when_polled_file_changes_repeatedly_without_panicking() {
+    // arrange
+    static BACKGROUND_PANICS: AtomicUsize = AtomicUsize::new(0);
+
+    let previous = Arc::new(std::panic::take_hook());
+    let hook = previous.clone();
+
+    std::panic::set_hook(Box::new(move |info| {
+        // test threads are named; the polling threads are not
+        if std::thread::current().name().is_none() {
+            BACKGROUND_PANICS.fetch_add(1, Ordering::SeqCst);
+        }
+
+        hook(info);
+    }));
+
+    let file = TempConfigFile::new("json", r#"{"Level": "Warning"}"#);
+    let config = DefaultConfigurationBuilder::new()
+        .add_json_file(
+            file.path()
+                .is()
+                .poll_interval(Duration::from_millis(10))
+                .reload_delay(Duration::ZERO),
+        )
+        .build()
+        .unwrap();
+    let wait_for = |expected: &str| {
+        for _ in 0..100 {
+            if config.get("Level").unwrap().as_str() == expected {
+                break;
+            }
+
+            std::thread::sleep(Duration::from_millis(20));
+        }
+    };
+
+    // act
+    std::thread::sleep(Duration::from_millis(20));
+    file.write(r#"{"Level": "Info"}"#);
+    wait_for("Info");
+    std::thread::sleep(Duration::from_millis(20));
+    file.write(r#"{"Level": "Debug"}"#);
+    wait_for("Debug");
+    std::thread::sleep(Duration::from_millis(50));
+
+    let _ = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| previous(info)));
+
+    // assert
+    assert_eq!(config.get("Level").unwrap().as_str(), "Debug");
+    assert_eq!(BACKGROUND_PANICS.load(Ordering::SeqCst), 0);
+}
+
+#[test]
+fn json_should_reload_when_deleted_file_is_recreated_with_same_content() {
+    // arrange
+    let file = TempConfigFile::new("json", r#"{"Level": "Info"}"#);
+    let config = DefaultConfigurationBuilder::new()
+        .add_json_file(
+            file.path()
+                .is()
+                .poll_interval(Duration::from_millis(10))
+                .reload_delay(Duration::ZERO),
+        )
+        .build()
+        .unwrap();
+    let wait_for = |expected: Option<&str>| {
+        for _ in 0..100 {
+            if config.get("Level").as_ref().map(|value| value.as_str()) == expected {
+                break;
+            }
+
+            std::thread::sleep(Duration::from_millis(20));
+        }
+    };
+
+    // act
+    std::thread::sleep(Duration::from_millis(20));
+    remove_file(file.path()).unwrap();
+    wait_for(None);
+
+    let deleted = config.get("Level");
+
+    std::thread::sleep(Duration::from_millis(20));
+    file.write(r#"{"Level": "Info"}"#);
+    wait_for(Some("Info"));
+
+    // assert
+    assert!(deleted.is_none());
+    assert_eq!(config.get("Level").unwrap().as_str(), "Info");
+}
+
+#[test]
+fn json_should_not_signal_reload_when_polled_file_content_is_unchanged() {
+    // arrange
+    let path = temp_dir().join("poll_settings_2.json");
+    let json = json!({"service": {"enabled": false}});
+    let mut file = File::create(&path).unwrap();
+    file.write_all(json.to_string().as_bytes()).unwrap();
+    drop(file);
+
+    let config = DefaultConfigurationBuilder::new()
+        .add_json_file(
+            path.is()
+                .poll_interval(Duration::from_millis(10))
+                .reload_delay(Duration::from_millis(10)),
+        )
+        .build()
+        .unwrap();
+    let token = config.reload_token();
+
+    // act
+    file = File::create(&path).unwrap();
+    file.write_all(json.to_string().as_bytes()).unwrap();
+    drop(file);
+    std::thread::sleep(Duration::from_millis(250));
+
+    // assert
+    if path.exists() {
+        remove_file(&path).ok();
+    }
+
+    assert!(!token.changed());
+}
+
+#[test]
+fn json_should_retain_data_when_polled_file_becomes_invalid() {
+    // arrange
+    let path = temp_dir().join("poll_settings_3.json");
+    let json = json!({"service": {"enabled": true}});
+    let mut file = File::create(&path).unwrap();
+    file.write_all(json.to_string().as_bytes()).unwrap();
+    drop(file);
+
+    let state = Arc::new((Mutex::new(None), Condvar::new()));
+    let callback = state.clone();
+    let config = DefaultConfigurationBuilder::new()
+        .add_json_file(
+            path.is()
+                .poll_interval(Duration::from_millis(10))
+                .reload_delay(Duration::ZERO)
+                .on_reload_error(move |_, error| {
+                    let (failure, event) = &*callback;
+                    *failure.lock().unwrap() = Some(error);
+                    event.notify_one();
+                }),
+        )
+        .build()
+        .unwrap();
+
+    file = File::create(&path).unwrap();
+    file.write_all(b"{\"service\": {\"enabled\": fal").unwrap();
+    drop(file);
+
+    let (mutex, event) = &*state;
+    let mut failure = mutex.lock().unwrap();
+    let mut attempts = 0;
+
+    while failure.is_none() && attempts < 10 {
+        failure = event
+            .wait_timeout(failure, Duration::from_millis(500))
+            .unwrap()
+            .0;
+        attempts += 1;
+    }
+
+    // act
+    let enabled = config.get("Service:Enabled");
+
+    // assert
+    if path.exists() {
+        remove_file(&path).ok();
+    }
+
+    assert!(matches!(&*failure, Some(LoadError::File { .. })));
+    assert_eq!(enabled.unwrap().as_str(), "true");
+}
+
+#[test]
+fn json_should_report_reload_error_to_builder_callback() {
+    // arrange
+    let path = temp_dir().join("poll_settings_4.json");
+    let json = json!({"service": {"enabled": true}});
+    let mut file = File::create(&path).unwrap();
+    file.write_all(json.to_string().as_bytes()).unwrap();
+    drop(file);
+
+    let state = Arc::new((Mutex::new(None), Condvar::new()));
+    let callback = state.clone();
+    let config = DefaultConfigurationBuilder::new()
+        .on_reload_error(move |provider, _| {
+            let (failure, event) = &*callback;
+            *failure.lock().unwrap() = Some(provider.to_owned());
+            event.notify_one();
+        })
+        .add_json_file(
+            path.is()
+                .poll_interval(Duration::from_millis(10))
+                .reload_delay(Duration::ZERO),
+        )
+        .build()
+        .unwrap();
+
+    file = File::create(&path).unwrap();
+    file.write_all(b"[]").unwrap();
+    drop(file);
+
+    let (mutex, event) = &*state;
+    let mut failure = mutex.lock().unwrap();
+    let mut attempts = 0;
+
+    while failure.is_none() && attempts < 10 {
+        failure = event
+            .wait_timeout(failure, Duration::from_millis(500))
+            .unwrap()
+            .0;
+        attempts += 1;
+    }
+
+    // act
+    let enabled = config.get("Service:Enabled");
+
+    // assert
+    if path.exists() {
+        remove_file(&path).ok();
+    }
+
+    assert!(failure
+        .as_ref()
+        .unwrap()
+        .ends_with("JsonConfigurationProvider"));
+    assert_eq!(enabled.unwrap().as_str(), "true");
+}
+
+#[test]
+fn add_json_file_should_fail_if_file_is_invalid() {
+    // arrange
+    let path = temp_dir().join("invalid_settings.json");
+    let mut file = File::create(&path).unwrap();
+    file.write_all(b"{\n  \"service\": {\n    \"enabled\": @\n  }\n}")
+        .unwrap();
+    drop(file);
+
+    // act
+    let result = DefaultConfigurationBuilder::new()
+        .add_json_file(&path)
+        .build();
+
+    // assert
+    if path.exists() {
+        remove_file(&path).ok();
+    }
+
+    if let Err(ReloadError::Provider(errors)) = result {
+        let message = errors[0].1.message();
+        assert!(message.starts_with(&format!(
+            "The configuration file '{}' is invalid.",
+            path.display()
+        )));
+        assert!(message.ends_with("expected value at line 3 column 16"));
+    } else {
+        panic!("No error occurred.")
+    }
+}
+
+#[test]
+fn add_json_file_should_fail_if_top_level_element_is_not_an_object() {
+    // arrange
+    let path = temp_dir().join("array_settings.json");
+    let mut file = File::create(&path).unwrap();
+    file.write_all(b"[1, 2, 3]").unwrap();
+    drop(file);
+
+    // act
+    let result = DefaultConfigurationBuilder::new()
+        .add_json_file(&path)
+        .build();
+
+    // assert
+    if path.exists() {
+        remove_file(&path).ok();
+    }
+
+    if let Err(ReloadError::Provider(errors)) = result {
+        assert!(errors[0]
+            .1
+            .message()
+            .ends_with("Top-level JSON element must be an object. Instead, 'array' was found."));
+    } else {
+        panic!("No error occurred.")
+    }
+}
+
+#[test]
+fn add_json_file_should_report_position_of_invalid_content() {
+    // arrange
+    let path = temp_dir().join("invalid_position_settings.json");
+    let mut file = File::create(&path).unwrap();
+    file.write_all(b"{\n  \"service\": {\n    \"enabled\": @\n  }\n}")
+        .unwrap();
+    drop(file);
+
+    // act
+    let result = DefaultConfigurationBuilder::new()
+        .add_json_file(&path)
+        .build();
+
+    // assert
+    if path.exists() {
+        remove_file(&path).ok();
+    }
+
+    let error = result.unwrap_err();
+    let load_error = match &error {
+        ReloadError::Provider(errors) => &errors[0].1,
+        _ => panic!("{:?}", error),
+    };
+
+    assert_eq!(load_error.path(), Some(path.as_path()));
+    assert_eq!(load_error.line(), Some(3));
+    assert_eq!(load_error.column(), Some(16));
+    assert!(std::error::Error::source(load_error).is_some());
+    assert!(load_error
+        .to_string()
+        .starts_with(&format!("{}:3:16: ", path.display())));
+    assert!(std::error::Error::source(&error).is_some());
+}
+
+#[test]
+fn add_json_file_should_load_first_existing_fallback() {
+    // arrange
+    let missing = temp_dir().join("missing_settings.json");
+    let fallback = TempConfigFile::new("json", r#"{"Logging": {"Level": "Warning"}}"#);
+    let other = TempConfigFile::new("json", r#"{"Logging": {"Level": "Trace"}}"#);
+
+    // act
+    let config = DefaultConfigurationBuilder::new()
+        .add_json_file(missing.is().or_else(&fallback).or_else(&other))
+        .build()
+        .unwrap();
+
+    // assert
+    assert_eq!(config.get("Logging:Level").unwrap().as_str(), "Warning");
+}
+
+#[test]
+fn first_existing_should_choose_file_to_load() {
+    // arrange
+    let missing = temp_dir().join("missing_settings.json");
+    let file = TempConfigFile::new("json", r#"{"Logging": {"Level": "Warning"}}"#);
+    let paths = [missing.as_path(), file.path()];
+    let source = FileSourceBuilder::first_existing(&paths).build();
+
+    // act
+    let provider = FileConfigurationProvider::new(source.clone(), |_: &Path, _: &[u8]| {
+        Ok(util::ConfigurationData::default())
+    });
+
+    // assert
+    assert_eq!(source.resolve(), file.path());
+    assert_eq!(provider.path(), file.path());
+}
+
+fn file_name(file: &TempConfigFile) -> String {
+    file.path()
+        .file_name()
+        .unwrap()
+        .to_str()
+        .unwrap()
+        .to_owned()
+}
+
+#[test]
+fn add_json_file_with_includes_should_splice_included_files() {
+    // arrange
+    let logging = TempConfigFile::new("json", r#"{"Level": "Information", "Format": "json"}"#);
+    let shared = TempConfigFile::new(
+        "json",
+        r#"{"Service": {"Name": "shared", "Timeout": 30}, "Region": "us-east"}"#,
+    );
+    let settings = TempConfigFile::new(
+        "json",
+        &json!({
+            "$include": file_name(&shared),
+            "Service": {"Name": "orders"},
+            "Logging": {"$include": [file_name(&logging)], "Level": "Debug"}
+        })
+        .to_string(),
+    );
+
+    // act
+    let config = DefaultConfigurationBuilder::new()
+        .add_json_file_with_includes(settings.path())
+        .build()
+        .unwrap();
+
+    // assert
+    assert_config_eq!(
+        config,
+        [
+            ("Service:Name", "orders"),
+            ("Service:Timeout", "30"),
+            ("Region", "us-east"),
+            ("Logging:Level", "Debug"),
+            ("Logging:Format", "json"),
+        ]
+    );
+    assert!(config.get("$include").is_none());
+}
+
+#[test]
+fn add_json_file_with_includes_should_fail_if_include_is_recursive() {
+    // arrange
+    let other = TempConfigFile::new("json", "{}");
+    let settings = TempConfigFile::new("json", "{}");
+
+    other.write(&json!({ "$include": file_name(&settings) }).to_string());
+    settings.write(&json!({ "$include": file_name(&other) }).to_string());
+
+    // act
+    let result = DefaultConfigurationBuilder::new()
+        .add_json_file_with_includes(settings.path())
+        .build();
+
+    // assert
+    if let Err(ReloadError::Provider(errors)) = result {
+        assert!(errors[0].1.message().ends_with(&format!(
+            "The configuration file '{}' is included recursively.",
+            settings.path().canonicalize().unwrap().display()
+        )));
+    } else {
+        panic!("The recursive include was not detected.");
+    }
+}
+
+#[test]
+fn close_should_stop_watching_file() {
+    // arrange
+    let file = TempConfigFile::new("json", r#"{"Logging": {"Level": "Warning"}}"#);
+    let config = DefaultConfigurationBuilder::new()
+        .add_json_file(file.path().is().poll_interval(Duration::from_millis(10)))
+        .build()
+        .unwrap();
+
+    // act
+    config.close();
+    file.write(r#"{"Logging": {"Level": "Debug"}}"#);
+    std::thread::sleep(Duration::from_millis(200));
+
+    // assert
+    assert_eq!(config.get("Logging:Level").unwrap().as_str(), "Warning");
+}
+
+#[test]
+fn set_base_path_should_resolve_relative_json_file() {
+    // arrange
+    let file = TempConfigFile::new("json", r#"{"Logging": {"Level": "Warning"}}"#);
+    let directory = file.path().parent().unwrap();
+
+    // act
+    let config = DefaultConfigurationBuilder::new()
+        .add_json_file(file_name(&file))
+        .set_base_path(directory)
+        .build()
+        .unwrap();
+
+    // assert
+    assert_eq!(config.get("Logging:Level").unwrap().as_str(), "Warning");
+}
+
+#[test]
+fn save_should_write_json_file() {
+    // arrange
+    let file = TempConfigFile::new(
+        "json",
+        r#"{"Name": "App", "Enabled": true, "Port": 80, "Hosts": ["a", "b", "c"]}"#,
+    );
+    let mut provider = JsonConfigurationProvider::new(file.path().to_path_buf().into());
+
+    provider.load().unwrap();
+    provider.set("Name", "Updated");
+    provider.remove("Hosts:2");
+
+    // act
+    provider.save().unwrap();
+
+    // assert
+    let content = std::fs::read(file.path()).unwrap();
+    let saved: serde_json::Value = serde_json::from_slice(&content).unwrap();
+
+    assert_eq!(
+        saved,
+        json!({"Enabled": true, "Hosts": ["a", "b"], "Name": "Updated", "Port": 80})
+    );
+}
+
+#[test]
+fn save_should_fail_when_key_has_value_and_children() {
+    // arrange
+    let file = TempConfigFile::new("json", r#"{"Logging": {"Level": "Warning"}}"#);
+    let mut provider = JsonConfigurationProvider::new(file.path().to_path_buf().into());
+
+    provider.load().unwrap();
+    provider.set("Logging", "Verbose");
+
+    // act
+    let result = provider.save();
+
+    // assert
+    assert_eq!(result.unwrap_err().key(), Some("Logging"));
+}
+
+#[test]
+fn save_should_not_be_supported_by_default() {
+    // arrange
+    let provider = FakeConfigurationProvider::new(&[("Name", "App")]);
+
+    // act
+    let result = provider.save();
+
+    // assert
+    assert!(result.is_err());
+}
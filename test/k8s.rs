@@ -0,0 +1,200 @@
+use config::{ext::*, *};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+type Objects = HashMap<String, Vec<(String, String)>>;
+
+#[derive(Clone, Default)]
+struct FakeClient {
+    objects: Arc<Mutex<Objects>>,
+    watchers: Arc<Mutex<HashMap<String, KubernetesWatcher>>>,
+}
+
+impl FakeClient {
+    fn with(self, object: &KubernetesObject, data: &[(&str, &str)]) -> Self {
+        self.objects.lock().unwrap().insert(
+            object.name.clone(),
+            data.iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect(),
+        );
+        self
+    }
+
+    fn push(&self, name: &str, event: KubernetesWatchEvent) -> bool {
+        self.watchers.lock().unwrap()[name].send(event)
+    }
+}
+
+impl KubernetesClient for FakeClient {
+    fn get(
+        &self,
+        namespace: &str,
+        object: &KubernetesObject,
+    ) -> Result<Option<Vec<(String, String)>>, LoadError> {
+        assert_eq!(namespace, "apps");
+        Ok(self.objects.lock().unwrap().get(&object.name).cloned())
+    }
+
+    fn watch(&self, _namespace: &str, object: &KubernetesObject, watcher: KubernetesWatcher) {
+        self.watchers
+            .lock()
+            .unwrap()
+            .insert(object.name.clone(), watcher);
+    }
+}
+
+#[test]
+fn add_kubernetes_should_load_config_maps_and_secrets_into_sections() {
+    // arrange
+    let settings = KubernetesObject::config_map("settings");
+    let credentials = KubernetesObject::secret("credentials");
+    let client = FakeClient::default()
+        .with(&settings, &[("Logging__Level", "Info"), ("Db__Host", "db")])
+        .with(&credentials, &[("Db__Password", "czNjcjN0")]);
+
+    // act
+    let config = DefaultConfigurationBuilder::new()
+        .add_kubernetes(client.clone(), "apps", &[settings, credentials])
+        .build()
+        .unwrap();
+
+    // assert
+    assert_eq!(config.get("Logging:Level").unwrap().as_str(), "Info");
+    assert_eq!(config.get("Db:Host").unwrap().as_str(), "db");
+    assert_eq!(config.get("Db:Password").unwrap().as_str(), "s3cr3t");
+    assert_eq!(config.section("Db").children().len(), 2);
+    assert_eq!(client.watchers.lock().unwrap().len(), 2);
+}
+
+#[test]
+fn add_kubernetes_should_prefer_object_specified_last() {
+    // arrange
+    let defaults = KubernetesObject::config_map("defaults");
+    let overrides = KubernetesObject::config_map("overrides");
+    let client = FakeClient::default()
+        .with(&defaults, &[("Logging__Level", "Info")])
+        .with(&overrides, &[("Logging__Level", "Debug")]);
+
+    // act
+    let config = DefaultConfigurationBuilder::new()
+        .add_kubernetes(client, "apps", &[defaults, overrides])
+        .build()
+        .unwrap();
+
+    // assert
+    assert_eq!(config.get("Logging:Level").unwrap().as_str(), "Debug");
+}
+
+#[test]
+fn build_should_fail_when_object_is_missing() {
+    // arrange
+    let client = FakeClient::default();
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder.add_kubernetes(client, "apps", &[KubernetesObject::config_map("settings")]);
+
+    // act
+    let result = builder.build();
+
+    // assert
+    if let Err(ReloadError::Provider(errors)) = result {
+        assert_eq!(
+            errors[0].1.message(),
+            "The ConfigMap 'settings' was not found in the namespace 'apps' and is not optional."
+        );
+    } else {
+        panic!("No error occurred.")
+    }
+}
+
+#[test]
+fn build_should_succeed_when_optional_object_is_missing() {
+    // arrange
+    let client = FakeClient::default();
+    let source = KubernetesConfigurationSource::new(
+        client,
+        "apps",
+        &[KubernetesObject::config_map("settings")],
+    )
+    .optional(true);
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder.add(Box::new(source));
+
+    // act
+    let config = builder.build().unwrap();
+
+    // assert
+    assert!(config.children().is_empty());
+}
+
+#[test]
+fn build_should_fail_when_secret_is_not_base64() {
+    // arrange
+    let credentials = KubernetesObject::secret("credentials");
+    let client = FakeClient::default().with(&credentials, &[("Password", "not base64!")]);
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder.add_kubernetes(client, "apps", &[credentials]);
+
+    // act
+    let result = builder.build();
+
+    // assert
+    if let Err(ReloadError::Provider(errors)) = result {
+        assert_eq!(
+            errors[0].1.message(),
+            "The value of 'Password' in the Secret 'credentials' is not Base64 encoded text."
+        );
+    } else {
+        panic!("No error occurred.")
+    }
+}
+
+#[test]
+fn watch_events_should_change_values_and_signal_reload_token() {
+    // arrange
+    let defaults = KubernetesObject::config_map("defaults");
+    let overrides = KubernetesObject::config_map("overrides");
+    let client = FakeClient::default()
+        .with(
+            &defaults,
+            &[("Logging__Level", "Info"), ("Feature__Beta", "false")],
+        )
+        .with(&overrides, &[("Logging__Level", "Warn")]);
+    let config = DefaultConfigurationBuilder::new()
+        .add_kubernetes(client.clone(), "apps", &[defaults, overrides])
+        .build()
+        .unwrap();
+    let token = config.reload_token();
+
+    // act
+    client.push(
+        "defaults",
+        KubernetesWatchEvent::Applied(vec![("Feature__Beta".into(), "true".into())]),
+    );
+    client.push("overrides", KubernetesWatchEvent::Deleted);
+
+    // assert
+    assert!(token.changed());
+    assert_eq!(config.get("Feature:Beta").unwrap().as_str(), "true");
+    assert!(config.get("Logging:Level").is_none());
+}
+
+#[test]
+fn send_should_return_false_after_provider_is_dropped() {
+    // arrange
+    let settings = KubernetesObject::config_map("settings");
+    let client = FakeClient::default().with(&settings, &[("Logging__Level", "Info")]);
+    let config = DefaultConfigurationBuilder::new()
+        .add_kubernetes(client.clone(), "apps", &[settings])
+        .build()
+        .unwrap();
+
+    // act
+    drop(config);
+
+    // assert
+    assert!(!client.push("settings", KubernetesWatchEvent::Deleted));
+}
@@ -0,0 +1,79 @@
+use config::{ext::*, *};
+use std::collections::HashMap;
+
+struct FakeStore(HashMap<(String, String), String>);
+
+impl FakeStore {
+    fn new(secrets: &[(&str, &str, &str)]) -> Self {
+        Self(
+            secrets
+                .iter()
+                .map(|s| ((s.0.to_owned(), s.1.to_owned()), s.2.to_owned()))
+                .collect(),
+        )
+    }
+}
+
+impl CredentialStore for FakeStore {
+    fn get(&self, service: &str, account: &str) -> Result<Option<String>, LoadError> {
+        Ok(self
+            .0
+            .get(&(service.to_owned(), account.to_owned()))
+            .cloned())
+    }
+}
+
+#[test]
+fn get_should_return_secret_from_credential_store() {
+    // arrange
+    let store = FakeStore::new(&[
+        ("demo", "Database:Password", "p@ssw0rd"),
+        ("other", "Database:User", "admin"),
+    ]);
+    let source = KeyringConfigurationSource::new("demo", &["Database:Password", "Database:User"])
+        .with_store(Box::new(store));
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder.add_in_memory(&[("Database:Host", "localhost")]);
+    builder.add(Box::new(source));
+
+    let config = builder.build().unwrap();
+
+    // act
+    let password = config.section("Database").get("password");
+
+    // assert
+    assert_eq!(password.unwrap().as_str(), "p@ssw0rd");
+    assert_eq!(config.get("Database:Host").unwrap().as_str(), "localhost");
+    assert!(config.get("Database:User").is_none());
+}
+
+struct UnavailableStore;
+
+impl CredentialStore for UnavailableStore {
+    fn get(&self, _service: &str, _account: &str) -> Result<Option<String>, LoadError> {
+        Err(LoadError::Generic("The credential store is locked.".into()))
+    }
+}
+
+#[test]
+fn build_should_fail_when_credential_store_cannot_be_read() {
+    // arrange
+    let source = KeyringConfigurationSource::new("demo", &["Database:Password"])
+        .with_store(Box::new(UnavailableStore));
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder.add_in_memory(&[("Database:Password", "fallback")]);
+    builder.add(Box::new(source));
+
+    // act
+    let result = builder.build();
+
+    // assert
+    match result {
+        Err(ReloadError::Provider(errors)) => {
+            assert_eq!(errors[0].1.message(), "The credential store is locked.")
+        }
+        _ => panic!("expected a provider error"),
+    }
+}
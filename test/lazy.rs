@@ -0,0 +1,118 @@
+use config::{ext::*, *};
+use std::sync::{
+    atomic::{AtomicU8, Ordering},
+    Arc,
+};
+
+struct LazyProvider {
+    loads: Arc<AtomicU8>,
+    fail: bool,
+    loaded: bool,
+}
+
+impl ConfigurationProvider for LazyProvider {
+    fn get(&self, key: &str) -> Option<Value> {
+        if self.loaded && key.eq_ignore_ascii_case("Remote:Enabled") {
+            Some("true".to_owned().into())
+        } else {
+            None
+        }
+    }
+
+    fn load(&mut self) -> LoadResult {
+        self.loads.fetch_add(1, Ordering::SeqCst);
+
+        if self.fail {
+            Err(LoadError::Generic("The remote service is unavailable.".into()))
+        } else {
+            self.loaded = true;
+            Ok(())
+        }
+    }
+
+    fn child_keys(&self, earlier_keys: &mut Vec<String>, parent_path: Option<&str>) {
+        if self.loaded && parent_path.is_none() {
+            earlier_keys.push("Remote".into());
+        }
+    }
+}
+
+struct LazySource {
+    loads: Arc<AtomicU8>,
+    fail: bool,
+}
+
+impl LazySource {
+    fn new(fail: bool) -> Self {
+        Self {
+            loads: Default::default(),
+            fail,
+        }
+    }
+}
+
+impl ConfigurationSource for LazySource {
+    fn build(&self, _builder: &dyn ConfigurationBuilder) -> Box<dyn ConfigurationProvider> {
+        Box::new(LazyProvider {
+            loads: self.loads.clone(),
+            fail: self.fail,
+            loaded: false,
+        })
+    }
+}
+
+#[test]
+fn build_lazy_should_load_provider_on_first_access() {
+    // arrange
+    let source = LazySource::new(false);
+    let loads = source.loads.clone();
+    let mut builder = DefaultConfigurationBuilder::new();
+    builder.add(Box::new(source));
+    let config = builder.build_lazy();
+    let before = loads.load(Ordering::SeqCst);
+
+    // act
+    let enabled = config.get("Remote:Enabled");
+    let _ = config.get("Remote:Enabled");
+
+    // assert
+    assert_eq!(before, 0);
+    assert_eq!(enabled.unwrap().as_str(), "true");
+    assert_eq!(loads.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn build_lazy_should_not_load_provider_when_later_provider_has_value() {
+    // arrange
+    let source = LazySource::new(false);
+    let loads = source.loads.clone();
+    let mut builder = DefaultConfigurationBuilder::new();
+    builder.add(Box::new(source));
+    builder.add_in_memory(&[("Name", "Alpha")]);
+    let config = builder.build_lazy();
+
+    // act
+    let name = config.get("Name").unwrap();
+
+    // assert
+    assert_eq!(name.as_str(), "Alpha");
+    assert_eq!(loads.load(Ordering::SeqCst), 0);
+}
+
+#[test]
+fn ensure_loaded_should_report_provider_errors() {
+    // arrange
+    let source = LazySource::new(true);
+    let loads = source.loads.clone();
+    let mut builder = DefaultConfigurationBuilder::new();
+    builder.add(Box::new(source));
+    let config = builder.build_lazy();
+
+    // act
+    let result = config.ensure_loaded();
+
+    // assert
+    assert!(matches!(result, Err(ReloadError::Provider(errors)) if errors.len() == 1));
+    assert!(config.get("Remote:Enabled").is_none());
+    assert_eq!(loads.load(Ordering::SeqCst), 1);
+}
@@ -1,10 +1,45 @@
 #![cfg(test)]
 
+mod access;
+mod alias;
 mod binder;
+mod conditional;
+mod connection;
 mod de;
+mod derive;
+mod diff;
+mod decrypt;
 mod default;
 mod env;
+mod feed;
+mod grpc;
+mod host;
 mod ini;
 mod json;
+mod k8s;
+mod keyring;
+mod lazy;
+mod localized;
+mod mapped;
+mod named;
+mod overlay;
+mod parser;
+mod paths;
+mod prefix;
+mod priority;
+mod profiles;
+mod query;
+mod refresh;
 mod reload;
+mod required;
+mod schema;
+mod scoped;
+mod sensitive;
+mod stream;
+mod structure;
+mod tenant;
+mod testing;
+mod trace;
+mod unused;
+mod web;
 mod xml;
@@ -0,0 +1,50 @@
+use config::{ext::*, *};
+
+fn build() -> Box<dyn ConfigurationRoot> {
+    DefaultConfigurationBuilder::new()
+        .add_in_memory(&[
+            ("Greeting", "Hello"),
+            ("Greeting:fr", "Bonjour"),
+            ("Greeting:fr-CA", "Allô"),
+            ("Currency", "USD"),
+            ("Currency:en-GB", "GBP"),
+        ])
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn get_localized_should_prefer_most_specific_culture() {
+    // arrange
+    let config = build();
+
+    // act
+    let value = config.get_localized("Greeting", "fr-CA");
+
+    // assert
+    assert_eq!(value.unwrap().as_str(), "Allô");
+}
+
+#[test]
+fn get_localized_should_fall_back_to_neutral_culture() {
+    // arrange
+    let config = build();
+
+    // act
+    let value = config.get_localized("Greeting", "fr-FR");
+
+    // assert
+    assert_eq!(value.unwrap().as_str(), "Bonjour");
+}
+
+#[test]
+fn get_localized_should_fall_back_to_key() {
+    // arrange
+    let config = build();
+
+    // act
+    let value = config.get_localized("Currency", "de-DE");
+
+    // assert
+    assert_eq!(value.unwrap().as_str(), "USD");
+}
@@ -0,0 +1,190 @@
+use config::{ext::*, *};
+
+fn memory(data: &[(&str, &str)]) -> Box<dyn ConfigurationSource> {
+    Box::new(MemoryConfigurationSource::new(data))
+}
+
+#[test]
+fn map_keys_should_rename_keys() {
+    // arrange
+    let source = MappedConfigurationSource::new(memory(&[
+        ("LEGACY_DB_HOST", "localhost"),
+        ("LEGACY_DB_PORT", "5432"),
+    ]))
+    .map_keys(|key| Some(key.replace("LEGACY_DB_", "Database:")));
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder.add(Box::new(source));
+
+    let config = builder.build().unwrap();
+
+    // act
+    let host = config.section("Database").get("HOST");
+
+    // assert
+    assert_eq!(host.unwrap().as_str(), "localhost");
+    assert_eq!(config.get("Database:Port").unwrap().as_str(), "5432");
+    assert!(config.get("LEGACY_DB_HOST").is_none());
+}
+
+#[test]
+fn map_keys_should_remove_key_when_none_is_returned() {
+    // arrange
+    let source =
+        MappedConfigurationSource::new(memory(&[("App:Name", "demo"), ("Other:Name", "ignored")]))
+            .map_keys(|key| key.strip_prefix("App:").map(|k| k.to_owned()));
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder.add(Box::new(source));
+
+    let config = builder.build().unwrap();
+
+    // act
+    let children = config.children();
+
+    // assert
+    assert_eq!(children.len(), 1);
+    assert_eq!(config.get("Name").unwrap().as_str(), "demo");
+}
+
+#[test]
+fn map_values_should_transform_values() {
+    // arrange
+    let source = MappedConfigurationSource::new(memory(&[
+        ("Database:User", "admin"),
+        ("Database:Password", "p@ssw0rd"),
+    ]))
+    .map_values(|key, value| {
+        if key.ends_with("Password") {
+            "***".into()
+        } else {
+            value.to_uppercase()
+        }
+    });
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder.add(Box::new(source));
+
+    let config = builder.build().unwrap();
+
+    // act
+    let password = config.get("Database:Password");
+
+    // assert
+    assert_eq!(password.unwrap().as_str(), "***");
+    assert_eq!(config.get("Database:User").unwrap().as_str(), "ADMIN");
+}
+
+#[test]
+fn mapped_source_should_merge_with_other_sources() {
+    // arrange
+    let source = MappedConfigurationSource::new(memory(&[("logging:level", "Debug")]))
+        .map_keys(|key| Some(key.to_lowercase()));
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder.add_in_memory(&[("Logging:Level", "Warning"), ("Logging:Format", "json")]);
+    builder.add(Box::new(source));
+
+    let config = builder.build().unwrap();
+
+    // act
+    let level = config.get("Logging:Level");
+
+    // assert
+    assert_eq!(level.unwrap().as_str(), "Debug");
+    assert_eq!(config.get("Logging:Format").unwrap().as_str(), "json");
+}
+
+#[test]
+fn mapped_source_should_retain_null_values() {
+    // arrange
+    let file = TempConfigFile::new("json", r#"{"App": {"Name": null, "Title": "Demo"}}"#);
+    let source =
+        MappedConfigurationSource::new(Box::new(JsonConfigurationSource::new(file.path().into())))
+            .map_keys(|key| key.strip_prefix("App:").map(|k| k.to_owned()));
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder.add(Box::new(source));
+
+    let config = builder.build().unwrap();
+
+    // act
+    let name = config.get_typed("Name");
+
+    // assert
+    assert_eq!(name, Some(ConfigValue::Null));
+    assert_eq!(config.get("Title").unwrap().as_str(), "Demo");
+}
+
+#[test]
+fn mapped_source_should_retain_typed_values_unless_transformed() {
+    // arrange
+    let file = TempConfigFile::new("json", r#"{"Port": 8080, "Secure": true}"#);
+    let source =
+        MappedConfigurationSource::new(Box::new(JsonConfigurationSource::new(file.path().into())))
+            .map_keys(|key| Some(format!("Server:{}", key)))
+            .map_values(|key, value| {
+                if key.ends_with("Secure") {
+                    "yes".into()
+                } else {
+                    value.to_owned()
+                }
+            });
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder.add(Box::new(source));
+
+    let config = builder.build().unwrap();
+
+    // act
+    let port = config.get_typed("Server:Port");
+    let secure = config.get_typed("Server:Secure");
+
+    // assert
+    assert_eq!(port, Some(ConfigValue::Int(8080)));
+    assert_eq!(secure, Some(ConfigValue::String("yes".to_owned().into())));
+}
+
+#[test]
+fn mapped_source_should_remove_keys_of_preceding_sources() {
+    // arrange
+    let source = MappedConfigurationSource::new(Box::new(
+        MemoryConfigurationSource::new::<&str>(&[]).with_removals(&["Proxy"]),
+    ))
+    .map_keys(|key| Some(key.to_owned()));
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder.add_in_memory(&[("Proxy:Host", "localhost"), ("Proxy:Port", "8080")]);
+    builder.add(Box::new(source));
+
+    let config = builder.build().unwrap();
+
+    // act
+    let children = config.section("Proxy").children();
+
+    // assert
+    assert!(children.is_empty());
+    assert!(config.get("Proxy:Host").is_none());
+}
+
+#[test]
+fn save_should_save_inner_provider() {
+    // arrange
+    let file = TempConfigFile::new("json", r#"{"LEGACY_HOST":"localhost"}"#);
+    let mut provider =
+        MappedConfigurationSource::new(Box::new(JsonConfigurationSource::new(file.path().into())))
+            .map_keys(|key| Some(key.replace("LEGACY_", "Database:")))
+            .build(&DefaultConfigurationBuilder::new());
+
+    provider.load().unwrap();
+
+    // act
+    let result = provider.save();
+
+    // assert
+    assert!(result.is_ok());
+    assert_eq!(
+        std::fs::read_to_string(file.path()).unwrap(),
+        "{\n  \"LEGACY_HOST\": \"localhost\"\n}\n"
+    );
+}
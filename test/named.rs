@@ -0,0 +1,37 @@
+use config::{ext::*, *};
+use std::env::temp_dir;
+
+#[test]
+fn named_source_should_name_provider() {
+    // arrange
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder.add(Box::new(
+        MemoryConfigurationSource::new(&[("Name", "Base")]).named("base settings"),
+    ));
+
+    // act
+    let config = builder.build().unwrap();
+    let names: Vec<_> = config.providers().map(|p| p.name().to_owned()).collect();
+
+    // assert
+    assert_eq!(builder.sources[0].name(), "base settings");
+    assert_eq!(names, vec!["base settings"]);
+    assert_eq!(config.get("Name").unwrap().as_str(), "Base");
+}
+
+#[test]
+fn named_source_should_appear_in_reload_error() {
+    // arrange
+    let path = temp_dir().join("missing_named_settings.json");
+    let source = JsonConfigurationSource::new(FileSource::from(&path));
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder.add(Box::new(source.named("base settings")));
+
+    // act
+    let error = builder.build().unwrap_err();
+
+    // assert
+    assert!(error.to_string().ends_with("(base settings)"));
+}
@@ -0,0 +1,160 @@
+use config::{ext::*, *};
+use std::sync::{Arc, RwLock};
+
+fn build() -> Box<dyn ConfigurationRoot> {
+    DefaultConfigurationBuilder::new()
+        .add_in_memory(&[
+            ("Logging:Level", "Warning"),
+            ("Logging:Format", "Json"),
+            ("Name", "App"),
+        ])
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn overlay_set_should_take_precedence_over_providers() {
+    // arrange
+    let config = build();
+    let overlay = config.overlay().unwrap();
+    let token = config.reload_token();
+
+    // act
+    overlay.set("Logging:Level", "Debug");
+    overlay.set("Logging:Color", "true");
+
+    // assert
+    assert!(token.changed());
+    assert_eq!(config.get("Logging:Level").unwrap().as_str(), "Debug");
+    assert_eq!(
+        config.section("Logging").get("Color").unwrap().as_str(),
+        "true"
+    );
+    assert_eq!(config.section("Logging").children().len(), 3);
+}
+
+#[test]
+fn overlay_remove_should_mask_provider_values() {
+    // arrange
+    let config = build();
+    let overlay = config.overlay().unwrap();
+
+    // act
+    overlay.remove("Logging");
+
+    // assert
+    assert_eq!(config.get("Logging:Level"), None);
+    assert!(!config.section("Logging").exists());
+    assert_eq!(config.children().len(), 1);
+}
+
+#[test]
+fn overlay_clear_should_restore_provider_values() {
+    // arrange
+    let config = build();
+    let overlay = config.overlay().unwrap();
+
+    overlay.set("Name", "Override");
+    overlay.remove("Logging:Format");
+
+    // act
+    overlay.clear();
+
+    // assert
+    assert!(overlay.is_empty());
+    assert_eq!(config.get("Name").unwrap().as_str(), "App");
+    assert_eq!(config.get("Logging:Format").unwrap().as_str(), "Json");
+}
+
+#[test]
+fn section_set_should_write_relative_to_section_path() {
+    // arrange
+    let config = build();
+    let section = config.section("Logging");
+    let token = config.reload_token();
+
+    // act
+    let written = section.set("Level", "Trace");
+
+    // assert
+    assert!(written);
+    assert!(token.changed());
+    assert_eq!(config.get("Logging:Level").unwrap().as_str(), "Trace");
+    assert_eq!(section.get("Level").unwrap().as_str(), "Trace");
+}
+
+#[test]
+fn section_remove_should_mask_value_relative_to_section_path() {
+    // arrange
+    let config = build();
+    let section = config.section("Logging");
+
+    // act
+    section.remove("Format");
+
+    // assert
+    assert!(config.get("Logging:Format").is_none());
+    assert_eq!(section.children().len(), 1);
+}
+
+#[test]
+fn section_set_should_not_write_without_overlay() {
+    // arrange
+    let config = build().snapshot();
+    let section = config.section("Logging");
+
+    // act
+    let written = section.set("Level", "Trace");
+
+    // assert
+    assert!(!written);
+    assert_eq!(config.get("Logging:Level").unwrap().as_str(), "Warning");
+}
+
+#[test]
+fn transaction_should_apply_all_changes_before_notifying() {
+    // arrange
+    let config: Arc<dyn ConfigurationRoot> = build().into();
+    let observed = Arc::new(RwLock::new(Vec::<Option<String>>::new()));
+    let _unused = config.reload_token().register(
+        Box::new(|state| {
+            let state = state.unwrap();
+            let (config, observed) = state
+                .downcast_ref::<(Arc<dyn ConfigurationRoot>, Arc<RwLock<Vec<Option<String>>>>)>()
+                .unwrap();
+            let mut observed = observed.write().unwrap();
+
+            observed.push(config.get("Logging:Level").map(|v| v.to_string()));
+            observed.push(config.get("Logging:Format").map(|v| v.to_string()));
+            observed.push(config.get("Name").map(|v| v.to_string()));
+        }),
+        Some(Arc::new((config.clone(), observed.clone()))),
+    );
+
+    // act
+    let applied = config.transaction(|tx| {
+        tx.set("Logging:Level", "Debug")
+            .remove("Logging:Format")
+            .set("Name", "Override");
+    });
+
+    // assert
+    assert!(applied);
+    assert_eq!(
+        &*observed.read().unwrap(),
+        &[Some("Debug".to_owned()), None, Some("Override".to_owned())]
+    );
+}
+
+#[test]
+fn transaction_should_not_notify_without_changes() {
+    // arrange
+    let config = build();
+    let token = config.reload_token();
+
+    // act
+    config.transaction(|_| {});
+
+    // assert
+    assert!(!token.changed());
+}
@@ -0,0 +1,66 @@
+use config::{ext::*, util::ConfigurationData, *};
+use std::collections::HashMap;
+use std::path::Path;
+
+// parses lines in the form 'key -> value'
+fn parse_arrows(_path: &Path, content: &[u8]) -> Result<ConfigurationData, ParseError> {
+    let text = String::from_utf8_lossy(content);
+    let mut data = HashMap::new();
+
+    for (number, line) in (1..).zip(text.lines()) {
+        let (key, value) = line.split_once("->").ok_or_else(|| ParseError {
+            message: format!("Expected 'key -> value', but found '{}'.", line),
+            line: Some(number),
+            ..Default::default()
+        })?;
+        let key = key.trim().to_owned();
+
+        data.insert(key.to_uppercase(), (key, value.trim().to_owned().into()));
+    }
+
+    Ok(data.into())
+}
+
+#[test]
+fn add_file_should_load_settings_with_custom_parser() {
+    // arrange
+    let file = TempConfigFile::new("arrows", "Service:Name -> demo\nService:Enabled -> true");
+
+    // act
+    let config = DefaultConfigurationBuilder::new()
+        .add_file(file.path(), parse_arrows)
+        .build()
+        .unwrap();
+
+    // assert
+    assert_eq!(config.get("Service:Name").unwrap().as_str(), "demo");
+    assert_eq!(
+        config.section("Service").get("Enabled").unwrap().as_str(),
+        "true"
+    );
+}
+
+#[test]
+fn add_file_should_report_custom_parser_error() {
+    // arrange
+    let file = TempConfigFile::new("arrows", "Service:Name -> demo\nService:Enabled");
+
+    // act
+    let result = DefaultConfigurationBuilder::new()
+        .add_file(file.path(), parse_arrows)
+        .build();
+
+    // assert
+    if let Err(ReloadError::Provider(errors)) = result {
+        let error = &errors[0].1;
+
+        assert_eq!(
+            error.message(),
+            "Expected 'key -> value', but found 'Service:Enabled'."
+        );
+        assert_eq!(error.path(), Some(file.path()));
+        assert_eq!(error.line(), Some(2));
+    } else {
+        panic!("No error occurred.")
+    }
+}
@@ -0,0 +1,60 @@
+use config::{ext::*, paths::*, *};
+use std::path::PathBuf;
+
+#[test]
+fn config_file_should_be_in_config_dir_of_app() {
+    // arrange
+    let dirs = AppDirs::new("myapp");
+
+    // act
+    let path = dirs.config_file("settings.json");
+
+    // assert
+    assert!(path.ends_with(PathBuf::from("myapp").join("settings.json")));
+    assert_eq!(path.parent(), dirs.config_dir().as_deref());
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+#[test]
+fn config_files_should_precede_user_file_with_system_file() {
+    // arrange
+    let dirs = AppDirs::new("myapp");
+
+    // act
+    let paths = dirs.config_files("settings.json");
+
+    // assert
+    assert_eq!(paths[0], PathBuf::from("/etc/myapp/settings.json"));
+    assert_eq!(paths.last().unwrap(), &dirs.config_file("settings.json"));
+}
+
+#[test]
+fn first_config_file_should_be_optional_when_no_file_exists() {
+    // arrange
+    let dirs = AppDirs::new("more-config-tests-missing-app");
+
+    // act
+    let config = DefaultConfigurationBuilder::new()
+        .add_json_file(dirs.first_config_file("settings.json").optional())
+        .build()
+        .unwrap();
+
+    // assert
+    assert!(config.children().is_empty());
+}
+
+#[test]
+fn expand_home_should_replace_tilde_with_home_dir() {
+    // arrange
+    let path = PathBuf::from("~").join(".myapp.json");
+
+    // act
+    let expanded = expand_home(&path);
+
+    // assert
+    assert_eq!(expanded, home_dir().unwrap().join(".myapp.json"));
+    assert_eq!(
+        expand_home("./settings.json"),
+        PathBuf::from("./settings.json")
+    );
+}
@@ -0,0 +1,154 @@
+use config::{ext::*, *};
+
+#[test]
+fn mount_should_place_keys_under_section() {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[("Name", "app")])
+        .mount("Database", |builder| {
+            builder.add_in_memory(&[("Host", "localhost"), ("Port", "5432")]);
+        })
+        .build()
+        .unwrap();
+
+    // act
+    let host = config.get("Database:Host");
+    let port = config.section("database").get("port");
+
+    // assert
+    assert_eq!(host.unwrap().as_str(), "localhost");
+    assert_eq!(port.unwrap().as_str(), "5432");
+    assert!(config.get("Host").is_none());
+}
+
+#[test]
+fn mount_should_support_nested_prefix() {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .mount("Services:Billing", |builder| {
+            builder.add_in_memory(&[("Url", "https://tempuri.org"), ("Retry:Count", "3")]);
+        })
+        .build()
+        .unwrap();
+
+    // act
+    let url = config.section("Services").section("Billing").get("Url");
+    let count = config.get("Services:Billing:Retry:Count");
+
+    // assert
+    assert_eq!(url.unwrap().as_str(), "https://tempuri.org");
+    assert_eq!(count.unwrap().as_str(), "3");
+}
+
+#[test]
+fn try_iter_should_include_prefix_in_keys() {
+    // arrange
+    let provider = PrefixedConfigurationSource::new(
+        "Database",
+        Box::new(MemoryConfigurationSource::new(&[("Host", "localhost")])),
+    )
+    .build(&DefaultConfigurationBuilder::new());
+
+    // act
+    let pairs: Vec<_> = provider.try_iter().unwrap().collect();
+
+    // assert
+    assert_eq!(pairs.len(), 1);
+    assert_eq!(pairs[0].0, "Database:Host");
+    assert_eq!(pairs[0].1.as_str(), "localhost");
+}
+
+#[test]
+fn children_should_include_mounted_sections() {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[("Logging:Level", "Debug")])
+        .mount("Services:Billing", |builder| {
+            builder.add_in_memory(&[("Url", "https://tempuri.org"), ("Retry:Count", "3")]);
+        })
+        .build()
+        .unwrap();
+
+    // act
+    let mut root = config
+        .children()
+        .iter()
+        .map(|s| s.key().to_owned())
+        .collect::<Vec<_>>();
+    let services = config.section("Services").children();
+    let mut billing = config
+        .section("Services:Billing")
+        .children()
+        .iter()
+        .map(|s| s.key().to_owned())
+        .collect::<Vec<_>>();
+
+    root.sort();
+    billing.sort();
+
+    // assert
+    assert_eq!(root, vec!["Logging", "Services"]);
+    assert_eq!(services.len(), 1);
+    assert_eq!(services[0].key(), "Billing");
+    assert_eq!(billing, vec!["Retry", "Url"]);
+}
+
+#[test]
+fn mount_should_not_add_section_for_empty_source() {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .mount("Database", |builder| {
+            builder.add_in_memory::<&str>(&[]);
+        })
+        .build()
+        .unwrap();
+
+    // act
+    let children = config.children();
+
+    // assert
+    assert!(children.is_empty());
+}
+
+#[test]
+fn prefixed_source_should_wrap_any_source() {
+    // arrange
+    let inner = MemoryConfigurationSource::new(&[("Enabled", "true")]);
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder.add(Box::new(PrefixedConfigurationSource::new(
+        "Feature",
+        Box::new(inner),
+    )));
+
+    let config = builder.build().unwrap();
+
+    // act
+    let value = config.get("feature:enabled");
+
+    // assert
+    assert_eq!(value.unwrap().as_str(), "true");
+}
+
+#[test]
+fn save_should_save_inner_provider() {
+    // arrange
+    let file = TempConfigFile::new("json", r#"{"Host":"localhost"}"#);
+    let mut provider = PrefixedConfigurationSource::new(
+        "Database",
+        Box::new(JsonConfigurationSource::new(file.path().into())),
+    )
+    .build(&DefaultConfigurationBuilder::new());
+
+    provider.load().unwrap();
+
+    // act
+    let result = provider.save();
+
+    // assert
+    assert!(result.is_ok());
+    assert_eq!(
+        std::fs::read_to_string(file.path()).unwrap(),
+        "{\n  \"Host\": \"localhost\"\n}\n"
+    );
+}
@@ -0,0 +1,49 @@
+use config::{ext::*, *};
+
+#[test]
+fn higher_priority_source_should_take_precedence_regardless_of_order() {
+    // arrange
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder.add(Box::new(
+        MemoryConfigurationSource::new(&[("Name", "Plugin")]).with_priority(10),
+    ));
+    builder.add(Box::new(MemoryConfigurationSource::new(&[
+        ("Name", "App"),
+        ("Mode", "Default"),
+    ])));
+
+    // act
+    let config = builder.build().unwrap();
+
+    // assert
+    assert_eq!(config.get("Name").unwrap().as_str(), "Plugin");
+    assert_eq!(config.get("Mode").unwrap().as_str(), "Default");
+}
+
+#[test]
+fn sources_with_same_priority_should_keep_add_order() {
+    // arrange
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder.add(Box::new(
+        MemoryConfigurationSource::new(&[("Name", "First")])
+            .with_priority(-1)
+            .named("First"),
+    ));
+    builder.add(Box::new(
+        MemoryConfigurationSource::new(&[("Name", "Second")]).with_priority(-1),
+    ));
+    builder.add(Box::new(MemoryConfigurationSource::new(&[(
+        "Mode", "Default",
+    )])));
+
+    // act
+    let config = builder.build().unwrap();
+    let names: Vec<_> = config.providers().map(|p| p.name().to_owned()).collect();
+
+    // assert
+    assert_eq!(config.get("Name").unwrap().as_str(), "Second");
+    assert_eq!(names[0], "First");
+    assert_eq!(names.len(), 3);
+}
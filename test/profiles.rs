@@ -0,0 +1,120 @@
+use config::{ext::*, *};
+
+#[test]
+fn with_profiles_should_overlay_profile_of_host_environment() {
+    // arrange
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder.use_environment(HostEnvironment::new("Development", "."));
+
+    // act
+    let config = builder
+        .with_profiles(|builder| {
+            builder.add_in_memory(&[
+                ("Logging:Level", "Warning"),
+                ("Logging:Format", "json"),
+                ("Profiles:Development:Logging:Level", "Debug"),
+                ("Profiles:Development:Tracing:Enabled", "true"),
+                ("Profiles:Production:Logging:Level", "Error"),
+            ]);
+        })
+        .build()
+        .unwrap();
+
+    // assert
+    assert_config_eq!(
+        config,
+        [
+            ("Logging:Level", "Debug"),
+            ("Logging:Format", "json"),
+            ("Tracing:Enabled", "true"),
+        ]
+    );
+    assert_eq!(config.section("Logging").children().len(), 2);
+}
+
+#[test]
+fn with_profile_should_hide_profiles_section() {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .with_profile("Production", |builder| {
+            builder.add_in_memory(&[
+                ("Logging:Level", "Warning"),
+                ("Profiles:Development:Logging:Level", "Debug"),
+            ]);
+        })
+        .build()
+        .unwrap();
+
+    // act
+    let children = config.children();
+
+    // assert
+    assert_eq!(children.len(), 1);
+    assert_eq!(children[0].key(), "Logging");
+    assert_eq!(config.get("Logging:Level").unwrap().as_str(), "Warning");
+    assert!(config.get("Profiles:Development:Logging:Level").is_none());
+}
+
+#[test]
+fn later_source_should_override_profile() {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .with_profile("Staging", |builder| {
+            builder.add_in_memory(&[("Profiles:Staging:Url", "https://staging.tempuri.org")]);
+        })
+        .add_in_memory(&[("Url", "https://tempuri.org")])
+        .build()
+        .unwrap();
+
+    // act
+    let url = config.get("Url");
+
+    // assert
+    assert_eq!(url.unwrap().as_str(), "https://tempuri.org");
+}
+
+#[test]
+fn try_iter_should_apply_profile() {
+    // arrange
+    let provider = ProfiledConfigurationSource::new(
+        Some("Development"),
+        Box::new(MemoryConfigurationSource::new(&[
+            ("Logging:Level", "Warning"),
+            ("Profiles:Development:Logging:Level", "Debug"),
+            ("Profiles:Production:Logging:Level", "Error"),
+        ])),
+    )
+    .build(&DefaultConfigurationBuilder::new());
+
+    // act
+    let pairs: Vec<_> = provider.try_iter().unwrap().collect();
+
+    // assert
+    assert_eq!(pairs.len(), 1);
+    assert_eq!(pairs[0].0, "Logging:Level");
+    assert_eq!(pairs[0].1.as_str(), "Debug");
+}
+
+#[test]
+fn save_should_save_inner_provider() {
+    // arrange
+    let file = TempConfigFile::new("json", r#"{"Logging":{"Level":"Warning"}}"#);
+    let mut provider = ProfiledConfigurationSource::new(
+        Some("Development"),
+        Box::new(JsonConfigurationSource::new(file.path().into())),
+    )
+    .build(&DefaultConfigurationBuilder::new());
+
+    provider.load().unwrap();
+
+    // act
+    let result = provider.save();
+
+    // assert
+    assert!(result.is_ok());
+    assert_eq!(
+        std::fs::read_to_string(file.path()).unwrap(),
+        "{\n  \"Logging\": {\n    \"Level\": \"Warning\"\n  }\n}\n"
+    );
+}
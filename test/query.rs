@@ -0,0 +1,76 @@
+use config::{ext::*, *};
+
+fn build() -> Box<dyn ConfigurationRoot> {
+    DefaultConfigurationBuilder::new()
+        .add_in_memory(&[
+            ("Logging:Console:Level", "Debug"),
+            ("Logging:File:Level", "Warning"),
+            ("Logging:File:Path", "app.log"),
+            ("Clients:Web:Url", "https://web"),
+            ("Clients:Mobile:Url", "https://mobile"),
+            ("Clients:Timeout", "30"),
+        ])
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn find_should_return_values_matching_wildcard_segment() {
+    // arrange
+    let config = build();
+
+    // act
+    let mut values: Vec<_> = config
+        .find("logging:*:level")
+        .into_iter()
+        .map(|(key, value)| (key, value.to_string()))
+        .collect();
+
+    values.sort();
+
+    // assert
+    assert_eq!(
+        values,
+        vec![
+            ("Logging:Console:Level".to_owned(), "Debug".to_owned()),
+            ("Logging:File:Level".to_owned(), "Warning".to_owned()),
+        ]
+    );
+}
+
+#[test]
+fn find_should_match_partial_segment_relative_to_section() {
+    // arrange
+    let config = build();
+    let section = config.section("Clients");
+
+    // act
+    let values = section.find("M*:Url");
+
+    // assert
+    assert_eq!(values.len(), 1);
+    assert_eq!(values[0].0, "Mobile:Url");
+    assert_eq!(values[0].1.as_str(), "https://mobile");
+}
+
+#[test]
+fn sections_matching_should_return_existing_sections() {
+    // arrange
+    let config = build();
+
+    // act
+    let mut paths: Vec<_> = config
+        .sections_matching("Clients:*")
+        .iter()
+        .map(|section| section.path().to_owned())
+        .collect();
+
+    paths.sort();
+
+    // assert
+    assert_eq!(
+        paths,
+        vec!["Clients:Mobile", "Clients:Timeout", "Clients:Web"]
+    );
+    assert!(config.sections_matching("Missing:*").is_empty());
+}
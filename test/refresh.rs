@@ -0,0 +1,160 @@
+use config::{ext::*, *};
+use std::sync::{
+    atomic::{AtomicU8, Ordering},
+    Arc,
+};
+use std::thread::sleep;
+use std::time::Duration;
+
+struct CountingProvider {
+    key: &'static str,
+    loads: Arc<AtomicU8>,
+    value: Value,
+}
+
+impl ConfigurationProvider for CountingProvider {
+    fn get(&self, key: &str) -> Option<Value> {
+        if key.eq_ignore_ascii_case(self.key) {
+            Some(self.value.clone())
+        } else {
+            None
+        }
+    }
+
+    fn load(&mut self) -> LoadResult {
+        let loads = self.loads.fetch_add(1, Ordering::SeqCst) + 1;
+        self.value = loads.to_string().into();
+        Ok(())
+    }
+
+    fn child_keys(&self, earlier_keys: &mut Vec<String>, parent_path: Option<&str>) {
+        if parent_path.is_none() {
+            earlier_keys.push(self.key.into());
+        }
+    }
+}
+
+struct CountingSource {
+    key: &'static str,
+    loads: Arc<AtomicU8>,
+}
+
+impl CountingSource {
+    fn new(key: &'static str) -> Self {
+        Self {
+            key,
+            loads: Default::default(),
+        }
+    }
+}
+
+impl ConfigurationSource for CountingSource {
+    fn build(&self, _builder: &dyn ConfigurationBuilder) -> Box<dyn ConfigurationProvider> {
+        Box::new(CountingProvider {
+            key: self.key,
+            loads: self.loads.clone(),
+            value: Default::default(),
+        })
+    }
+}
+
+#[test]
+fn refresh_should_reload_only_refreshable_provider() {
+    // arrange
+    let refreshable = CountingSource::new("Refreshable");
+    let other = CountingSource::new("Other");
+    let refreshable_loads = refreshable.loads.clone();
+    let other_loads = other.loads.clone();
+    let mut builder = DefaultConfigurationBuilder::new();
+    let handle = builder.add_refreshable(Box::new(refreshable));
+    builder.add(Box::new(other));
+    let config = builder.build().unwrap();
+
+    // act
+    handle.refresh().unwrap();
+
+    // assert
+    assert_eq!(refreshable_loads.load(Ordering::SeqCst), 2);
+    assert_eq!(other_loads.load(Ordering::SeqCst), 1);
+    assert_eq!(config.get("Refreshable").unwrap().as_str(), "2");
+    assert_eq!(config.get("Other").unwrap().as_str(), "1");
+}
+
+#[test]
+fn refresh_should_signal_reload_token() {
+    // arrange
+    let mut builder = DefaultConfigurationBuilder::new();
+    let handle = builder.add_refreshable(Box::new(CountingSource::new("Test")));
+    let config = builder.build().unwrap();
+    let token = config.reload_token();
+
+    // act
+    handle.refresh().unwrap();
+
+    // assert
+    assert!(token.changed());
+}
+
+#[test]
+fn add_with_refresh_interval_should_reload_provider_periodically() {
+    // arrange
+    let source = CountingSource::new("Timed");
+    let loads = source.loads.clone();
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder.add_with_refresh_interval(Box::new(source), Duration::from_millis(10));
+
+    let config = builder.build().unwrap();
+    let token = config.reload_token();
+
+    // act
+    sleep(Duration::from_millis(200));
+
+    // assert
+    assert!(loads.load(Ordering::SeqCst) > 2);
+    assert!(token.changed());
+    assert_ne!(config.get("Timed").unwrap().as_str(), "1");
+}
+
+#[test]
+fn refresh_interval_should_stop_when_configuration_is_dropped() {
+    // arrange
+    let source = CountingSource::new("Timed");
+    let loads = source.loads.clone();
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder.add_with_refresh_interval(Box::new(source), Duration::from_millis(10));
+
+    let config = builder.build().unwrap();
+
+    // act
+    drop(config);
+
+    let expected = loads.load(Ordering::SeqCst);
+    sleep(Duration::from_millis(50));
+
+    // assert
+    assert_eq!(loads.load(Ordering::SeqCst), expected);
+}
+
+#[test]
+fn close_should_stop_refresh_interval() {
+    // arrange
+    let source = CountingSource::new("Timed");
+    let loads = source.loads.clone();
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder.add_with_refresh_interval(Box::new(source), Duration::from_millis(10));
+
+    let config = builder.build().unwrap();
+
+    // act
+    config.close();
+
+    let expected = loads.load(Ordering::SeqCst);
+    sleep(Duration::from_millis(50));
+
+    // assert
+    assert_eq!(loads.load(Ordering::SeqCst), expected);
+    assert_eq!(config.get("Timed").unwrap().as_str(), expected.to_string());
+}
@@ -1,19 +1,19 @@
-use config::*;
-use std::{
-    cell::RefCell,
-    rc::Rc,
-    sync::{
-        atomic::{AtomicU8, Ordering},
-        Arc,
-    },
+use config::{ext::*, *};
+use std::sync::{
+    atomic::{AtomicU8, Ordering},
+    Arc, RwLock,
 };
+#[cfg(not(feature = "async"))]
+use std::{cell::RefCell, rc::Rc};
 use tokens::{ChangeToken, SharedChangeToken, SingleChangeToken};
 
+#[cfg(not(feature = "async"))]
 #[derive(Default)]
 struct Trigger {
     token: RefCell<SharedChangeToken<SingleChangeToken>>,
 }
 
+#[cfg(not(feature = "async"))]
 impl Trigger {
     fn fire(&self) {
         let token = self.token.replace(SharedChangeToken::default());
@@ -21,12 +21,14 @@ impl Trigger {
     }
 }
 
+#[cfg(not(feature = "async"))]
 struct ReloadableConfigProvider {
     counter: u8,
     value: Value,
     trigger: Rc<Trigger>,
 }
 
+#[cfg(not(feature = "async"))]
 impl ReloadableConfigProvider {
     fn new(trigger: Rc<Trigger>) -> Self {
         Self {
@@ -37,6 +39,7 @@ impl ReloadableConfigProvider {
     }
 }
 
+#[cfg(not(feature = "async"))]
 impl ConfigurationProvider for ReloadableConfigProvider {
     fn get(&self, key: &str) -> Option<Value> {
         if key == "Test" {
@@ -61,23 +64,27 @@ impl ConfigurationProvider for ReloadableConfigProvider {
     }
 }
 
+#[cfg(not(feature = "async"))]
 #[derive(Default)]
 struct ReloadableConfigSource {
     trigger: Rc<Trigger>,
 }
 
+#[cfg(not(feature = "async"))]
 impl ReloadableConfigSource {
     fn new(trigger: Rc<Trigger>) -> Self {
         Self { trigger }
     }
 }
 
+#[cfg(not(feature = "async"))]
 impl ConfigurationSource for ReloadableConfigSource {
     fn build(&self, _builder: &dyn ConfigurationBuilder) -> Box<dyn ConfigurationProvider> {
         Box::new(ReloadableConfigProvider::new(self.trigger.clone()))
     }
 }
 
+#[cfg(not(feature = "async"))]
 #[test]
 fn reload_should_load_providers() {
     // arrange
@@ -96,6 +103,7 @@ fn reload_should_load_providers() {
     assert_eq!(root.get("Test").unwrap().as_str(), "2");
 }
 
+#[cfg(not(feature = "async"))]
 #[test]
 fn reload_token_should_indicate_change_after_reload() {
     // arrange
@@ -123,6 +131,7 @@ fn reload_token_should_indicate_change_after_reload() {
     assert_eq!(data.load(Ordering::SeqCst), 1);
 }
 
+#[cfg(not(feature = "async"))]
 #[test]
 fn reload_token_should_indicate_change_after_provider_change() {
     // arrange
@@ -150,3 +159,470 @@ fn reload_token_should_indicate_change_after_provider_change() {
     // assert
     assert_eq!(data.load(Ordering::SeqCst), 1);
 }
+
+#[cfg(not(feature = "async"))]
+struct SharedValueProvider {
+    value: Rc<RefCell<String>>,
+    trigger: Rc<Trigger>,
+}
+
+#[cfg(not(feature = "async"))]
+impl ConfigurationProvider for SharedValueProvider {
+    fn get(&self, key: &str) -> Option<Value> {
+        if key.eq_ignore_ascii_case("Section:Test") {
+            Some(self.value.borrow().clone().into())
+        } else {
+            None
+        }
+    }
+
+    fn reload_token(&self) -> Box<dyn ChangeToken> {
+        Box::new(self.trigger.token.borrow().clone())
+    }
+
+    fn child_keys(&self, earlier_keys: &mut Vec<String>, parent_path: Option<&str>) {
+        match parent_path {
+            None => earlier_keys.push("Section".into()),
+            Some(path) if path.eq_ignore_ascii_case("Section") => earlier_keys.push("Test".into()),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(not(feature = "async"))]
+struct SharedValueSource {
+    value: Rc<RefCell<String>>,
+    trigger: Rc<Trigger>,
+}
+
+#[cfg(not(feature = "async"))]
+impl ConfigurationSource for SharedValueSource {
+    fn build(&self, _builder: &dyn ConfigurationBuilder) -> Box<dyn ConfigurationProvider> {
+        Box::new(SharedValueProvider {
+            value: self.value.clone(),
+            trigger: self.trigger.clone(),
+        })
+    }
+}
+
+#[cfg(not(feature = "async"))]
+#[test]
+fn get_should_return_new_value_after_provider_change() {
+    // arrange
+    let value = Rc::new(RefCell::new(String::from("before")));
+    let trigger = Rc::new(Trigger::default());
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder.add(Box::new(SharedValueSource {
+        value: value.clone(),
+        trigger: trigger.clone(),
+    }));
+
+    let root = builder.build().unwrap();
+    let section = root.section("Section");
+
+    assert_eq!(section.get("Test").unwrap().as_str(), "before");
+    assert_eq!(section.children().len(), 1);
+
+    // act
+    *value.borrow_mut() = String::from("after");
+    trigger.fire();
+
+    // assert
+    assert_eq!(section.get("Test").unwrap().as_str(), "after");
+    assert_eq!(root.get("Section:Test").unwrap().as_str(), "after");
+}
+
+// providers must be Send and Sync when the configuration can be shared across threads, so the
+// same scenarios are exercised with thread-safe state
+#[cfg(feature = "async")]
+mod shared {
+
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Default)]
+    struct Trigger {
+        token: RwLock<SharedChangeToken<SingleChangeToken>>,
+    }
+
+    impl Trigger {
+        fn fire(&self) {
+            let token = std::mem::take(&mut *self.token.write().unwrap());
+            token.notify();
+        }
+    }
+
+    struct ReloadableConfigProvider {
+        counter: u8,
+        value: Value,
+        trigger: Arc<Trigger>,
+    }
+
+    impl ReloadableConfigProvider {
+        fn new(trigger: Arc<Trigger>) -> Self {
+            Self {
+                counter: 0,
+                value: Value::new("0".into()),
+                trigger,
+            }
+        }
+    }
+
+    impl ConfigurationProvider for ReloadableConfigProvider {
+        fn get(&self, key: &str) -> Option<Value> {
+            if key == "Test" {
+                Some(self.value.clone())
+            } else {
+                None
+            }
+        }
+
+        fn reload_token(&self) -> Box<dyn ChangeToken> {
+            Box::new(self.trigger.token.read().unwrap().clone())
+        }
+
+        fn load(&mut self) -> LoadResult {
+            self.counter += 1;
+            self.value = self.counter.to_string().into();
+            Ok(())
+        }
+
+        fn child_keys(&self, earlier_keys: &mut Vec<String>, _parent_path: Option<&str>) {
+            earlier_keys.push("Test".into());
+        }
+    }
+
+    #[derive(Default)]
+    struct ReloadableConfigSource {
+        trigger: Arc<Trigger>,
+    }
+
+    impl ReloadableConfigSource {
+        fn new(trigger: Arc<Trigger>) -> Self {
+            Self { trigger }
+        }
+    }
+
+    impl ConfigurationSource for ReloadableConfigSource {
+        fn build(&self, _builder: &dyn ConfigurationBuilder) -> Box<dyn ConfigurationProvider> {
+            Box::new(ReloadableConfigProvider::new(self.trigger.clone()))
+        }
+    }
+
+    #[test]
+    fn reload_should_load_providers() {
+        // arrange
+        let mut builder = DefaultConfigurationBuilder::new();
+
+        builder.add(Box::new(ReloadableConfigSource::default()));
+
+        let root = builder.build().unwrap();
+
+        assert_eq!(root.get("Test").unwrap().as_str(), "1");
+
+        // act
+        root.reload().ok();
+
+        // assert
+        assert_eq!(root.get("Test").unwrap().as_str(), "2");
+    }
+
+    #[test]
+    fn reload_token_should_indicate_change_after_reload() {
+        // arrange
+        let data = Arc::<AtomicU8>::default();
+        let mut builder = DefaultConfigurationBuilder::new();
+
+        builder.add(Box::new(ReloadableConfigSource::default()));
+
+        let root = builder.build().unwrap();
+        let _unused = root.reload_token().register(
+            Box::new(|state| {
+                state
+                    .unwrap()
+                    .downcast_ref::<AtomicU8>()
+                    .unwrap()
+                    .store(1, Ordering::SeqCst)
+            }),
+            Some(data.clone()),
+        );
+
+        // act
+        root.reload().ok();
+
+        // assert
+        assert_eq!(data.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn reload_token_should_indicate_change_after_provider_change() {
+        // arrange
+        let trigger = Arc::new(Trigger::default());
+        let data = Arc::<AtomicU8>::default();
+        let mut builder = DefaultConfigurationBuilder::new();
+
+        builder.add(Box::new(ReloadableConfigSource::new(trigger.clone())));
+
+        let root = builder.build().unwrap();
+        let _unused = root.reload_token().register(
+            Box::new(|state| {
+                state
+                    .unwrap()
+                    .downcast_ref::<AtomicU8>()
+                    .unwrap()
+                    .store(1, Ordering::SeqCst)
+            }),
+            Some(data.clone()),
+        );
+
+        // act
+        trigger.fire();
+
+        // assert
+        assert_eq!(data.load(Ordering::SeqCst), 1);
+    }
+
+    struct SharedValueProvider {
+        value: Arc<RwLock<String>>,
+        trigger: Arc<Trigger>,
+    }
+
+    impl ConfigurationProvider for SharedValueProvider {
+        fn get(&self, key: &str) -> Option<Value> {
+            if key.eq_ignore_ascii_case("Section:Test") {
+                Some(self.value.read().unwrap().clone().into())
+            } else {
+                None
+            }
+        }
+
+        fn reload_token(&self) -> Box<dyn ChangeToken> {
+            Box::new(self.trigger.token.read().unwrap().clone())
+        }
+
+        fn child_keys(&self, earlier_keys: &mut Vec<String>, parent_path: Option<&str>) {
+            match parent_path {
+                None => earlier_keys.push("Section".into()),
+                Some(path) if path.eq_ignore_ascii_case("Section") => {
+                    earlier_keys.push("Test".into())
+                }
+                _ => {}
+            }
+        }
+    }
+
+    struct SharedValueSource {
+        value: Arc<RwLock<String>>,
+        trigger: Arc<Trigger>,
+    }
+
+    impl ConfigurationSource for SharedValueSource {
+        fn build(&self, _builder: &dyn ConfigurationBuilder) -> Box<dyn ConfigurationProvider> {
+            Box::new(SharedValueProvider {
+                value: self.value.clone(),
+                trigger: self.trigger.clone(),
+            })
+        }
+    }
+
+    #[test]
+    fn get_should_return_new_value_after_provider_change() {
+        // arrange
+        let value = Arc::new(RwLock::new(String::from("before")));
+        let trigger = Arc::new(Trigger::default());
+        let mut builder = DefaultConfigurationBuilder::new();
+
+        builder.add(Box::new(SharedValueSource {
+            value: value.clone(),
+            trigger: trigger.clone(),
+        }));
+
+        let root = builder.build().unwrap();
+        let section = root.section("Section");
+
+        assert_eq!(section.get("Test").unwrap().as_str(), "before");
+        assert_eq!(section.children().len(), 1);
+
+        // act
+        *value.write().unwrap() = String::from("after");
+        trigger.fire();
+
+        // assert
+        assert_eq!(section.get("Test").unwrap().as_str(), "after");
+        assert_eq!(root.get("Section:Test").unwrap().as_str(), "after");
+    }
+
+    #[derive(Deserialize)]
+    #[serde(rename_all(deserialize = "PascalCase"))]
+    struct TestOptions {
+        test: String,
+    }
+
+    #[test]
+    fn reify_watched_should_bind_again_after_each_provider_change() {
+        // arrange
+        let value = Arc::new(RwLock::new(String::from("before")));
+        let trigger = Arc::new(Trigger::default());
+        let changes = Arc::<AtomicU8>::default();
+        let counter = changes.clone();
+        let mut builder = DefaultConfigurationBuilder::new();
+
+        builder.add(Box::new(SharedValueSource {
+            value: value.clone(),
+            trigger: trigger.clone(),
+        }));
+
+        let config = builder.build().unwrap().into_shared();
+        let watched = config
+            .reify_watched_at::<TestOptions>("Section")
+            .unwrap()
+            .on_change(move |_| {
+                counter.fetch_add(1, Ordering::SeqCst);
+            });
+
+        assert_eq!(watched.current().test, "before");
+
+        // act
+        *value.write().unwrap() = String::from("after");
+        trigger.fire();
+        let first = watched.current();
+
+        *value.write().unwrap() = String::from("again");
+        trigger.fire();
+        let second = watched.current();
+
+        // assert
+        assert_eq!(first.test, "after");
+        assert_eq!(second.test, "again");
+        assert_eq!(changes.load(Ordering::SeqCst), 2);
+    }
+}
+
+#[test]
+fn replace_sources_should_swap_providers_and_signal_reload_token() {
+    // arrange
+    let root = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[("Database:Host", "localhost"), ("Region", "west")])
+        .build()
+        .unwrap();
+    let token = root.reload_token();
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder
+        .set_default("Region", "east")
+        .add_in_memory(&[("Database:Host", "db.tempuri.org")])
+        .add_in_memory(&[("Cache:Host", "cache.tempuri.org")]);
+
+    // act
+    root.replace_sources(&builder).unwrap();
+
+    // assert
+    assert!(token.changed());
+    assert_eq!(root.providers().len(), 3);
+    assert_eq!(
+        root.get("Database:Host").unwrap().as_str(),
+        "db.tempuri.org"
+    );
+    assert_eq!(
+        root.get("Cache:Host").unwrap().as_str(),
+        "cache.tempuri.org"
+    );
+    assert_eq!(root.get("Region").unwrap().as_str(), "east");
+}
+
+#[test]
+fn replace_sources_should_keep_providers_if_replacement_fails_to_load() {
+    // arrange
+    let root = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[("Database:Host", "localhost")])
+        .build()
+        .unwrap();
+    let failing = FakeConfigurationProvider::default();
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    failing.set_load_error(Some(LoadError::Generic("Unavailable".into())));
+    builder
+        .add_in_memory(&[("Database:Host", "db.tempuri.org")])
+        .add(Box::new(failing));
+
+    // act
+    let result = root.replace_sources(&builder);
+
+    // assert
+    assert!(matches!(result, Err(ReloadError::Provider(_))));
+    assert_eq!(root.get("Database:Host").unwrap().as_str(), "localhost");
+}
+
+#[test]
+fn reload_should_not_fail_while_providers_are_borrowed() {
+    // arrange
+    let provider = FakeConfigurationProvider::new(&[("Name", "before")]);
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder.add(Box::new(provider.clone()));
+
+    let root = builder.build().unwrap();
+    let section = root.section("Name");
+    let mut providers = root.providers();
+    let first = providers.next().unwrap();
+
+    provider.set("Name", "after");
+
+    // act
+    let result = root.reload();
+
+    // assert
+    assert!(result.is_ok());
+    assert_eq!(first.get("Name").unwrap().as_str(), "after");
+    assert_eq!(section.value().as_str(), "after");
+}
+
+#[test]
+fn reload_should_renew_reload_token_of_cloned_configuration() {
+    // arrange
+    let root = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[("Name", "app")])
+        .build()
+        .unwrap();
+    let config = root.as_config();
+    let token = config.reload_token();
+
+    // act
+    root.reload().unwrap();
+
+    // assert
+    assert!(token.changed());
+    assert!(!config.reload_token().changed());
+}
+
+#[test]
+fn reload_should_not_block_callback_that_reads_configuration() {
+    // arrange
+    let file = TempConfigFile::new("json", r#"{"Level": "Info"}"#);
+    let root = DefaultConfigurationBuilder::new()
+        .add_json_file(file.path())
+        .build()
+        .unwrap();
+    let state = Arc::new((root.as_config(), RwLock::new(Vec::<String>::new())));
+    let _registration = root.reload_token().register(
+        Box::new(|state| {
+            let state = state.unwrap();
+            let (config, levels) = state
+                .downcast_ref::<(Box<dyn Configuration>, RwLock<Vec<String>>)>()
+                .unwrap();
+            let level = config.get("Level").unwrap().as_str().to_owned();
+
+            levels.write().unwrap().push(level);
+        }),
+        Some(state.clone()),
+    );
+
+    file.write(r#"{"Level": "Debug"}"#);
+
+    // act
+    root.reload().unwrap();
+
+    // assert
+    assert_eq!(*state.1.read().unwrap(), vec!["Debug".to_owned()]);
+}
@@ -0,0 +1,52 @@
+use config::{ext::*, *};
+
+#[test]
+fn get_required_should_return_existing_value() {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[("Service:Host", "localhost")])
+        .build()
+        .unwrap();
+
+    // act
+    let host = config.get_required("Service:Host").unwrap();
+
+    // assert
+    assert_eq!(host.as_str(), "localhost");
+}
+
+#[test]
+fn get_required_should_report_path_and_providers_when_missing() {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[("Service:Host", "localhost")])
+        .build()
+        .unwrap();
+
+    // act
+    let error = config.get_required("Service:Port").unwrap_err();
+
+    // assert
+    assert_eq!(error.path(), "Service:Port");
+    assert_eq!(
+        error.to_string(),
+        "The required configuration key 'Service:Port' was not found. \
+         Providers consulted: config::memory::MemoryConfigurationProvider."
+    );
+}
+
+#[test]
+fn required_section_should_report_full_path_when_missing() {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[("Service:Host", "localhost")])
+        .build()
+        .unwrap();
+    let service = config.required_section("Service").unwrap();
+
+    // act
+    let error = service.required_section("Retry").err().unwrap();
+
+    // assert
+    assert_eq!(error.path(), "Service:Retry");
+}
@@ -0,0 +1,179 @@
+use config::{ext::*, *};
+use serde::Deserialize;
+use std::time::Duration;
+
+#[derive(Deserialize)]
+#[allow(dead_code)]
+#[serde(rename_all(deserialize = "PascalCase"))]
+struct ServerOptions {
+    host: String,
+    port: u16,
+    #[serde(default)]
+    secure: bool,
+    timeout: Option<Duration>,
+    tags: Vec<String>,
+    mode: Mode,
+}
+
+#[derive(Deserialize)]
+#[allow(dead_code)]
+enum Mode {
+    Active,
+    Passive,
+}
+
+#[test]
+fn schema_should_be_extracted_from_bound_type() {
+    // arrange
+    let expected = SchemaType::Object(vec![
+        SchemaProperty {
+            name: "Host".into(),
+            kind: SchemaType::String,
+            required: true,
+        },
+        SchemaProperty {
+            name: "Port".into(),
+            kind: SchemaType::Integer,
+            required: true,
+        },
+        SchemaProperty {
+            name: "Secure".into(),
+            kind: SchemaType::Boolean,
+            required: false,
+        },
+        SchemaProperty {
+            name: "Timeout".into(),
+            kind: SchemaType::String,
+            required: false,
+        },
+        SchemaProperty {
+            name: "Tags".into(),
+            kind: SchemaType::Array(Box::new(SchemaType::String)),
+            required: true,
+        },
+        SchemaProperty {
+            name: "Mode".into(),
+            kind: SchemaType::Enum(vec!["Active".into(), "Passive".into()]),
+            required: true,
+        },
+    ]);
+
+    // act
+    let schema = ConfigurationSchema::of::<ServerOptions>();
+
+    // assert
+    assert_eq!(schema.root(), &expected);
+}
+
+#[test]
+fn validate_against_should_report_unknown_missing_and_mismatched_keys() {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[
+            ("Server:Host", "localhost"),
+            ("Server:Port", "eighty"),
+            ("Server:Timeout", "30s"),
+            ("Server:Mode", "Idle"),
+            ("Server:Verbose", "true"),
+        ])
+        .build()
+        .unwrap();
+    let section = config.section("Server");
+
+    // act
+    let report = section.validate_against::<ServerOptions>();
+
+    // assert
+    assert!(!report.is_valid());
+    assert_eq!(report.unknown_keys, vec!["Server:Verbose"]);
+    assert_eq!(report.missing_keys, vec!["Server:Tags"]);
+    assert_eq!(
+        report.mismatches,
+        vec![
+            TypeMismatch {
+                key: "Server:Port".into(),
+                expected: "integer".into(),
+                value: "eighty".into(),
+            },
+            TypeMismatch {
+                key: "Server:Mode".into(),
+                expected: "enum".into(),
+                value: "Idle".into(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn validate_against_should_succeed_for_valid_configuration() {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[
+            ("Host", "localhost"),
+            ("Port", "80"),
+            ("Tags:0", "web"),
+            ("Mode", "Active"),
+        ])
+        .build()
+        .unwrap();
+
+    // act
+    let report = config.validate_against::<ServerOptions>();
+
+    // assert
+    assert!(report.is_valid());
+}
+
+#[test]
+fn to_json_schema_should_describe_bound_type() {
+    // arrange
+    let schema = ConfigurationSchema::of::<ServerOptions>();
+
+    // act
+    let json: serde_json::Value = serde_json::from_str(&schema.to_json_schema()).unwrap();
+
+    // assert
+    assert_eq!(json["type"], "object");
+    assert_eq!(json["properties"]["Port"]["type"], "integer");
+    assert_eq!(json["properties"]["Tags"]["items"]["type"], "string");
+    assert_eq!(json["properties"]["Mode"]["enum"][1], "Passive");
+    assert_eq!(
+        json["required"],
+        serde_json::json!(["Host", "Port", "Tags", "Mode"])
+    );
+}
+
+#[test]
+fn from_json_schema_should_validate_configuration() {
+    // arrange
+    let schema = ConfigurationSchema::from_json_schema(
+        r#"{
+            "type": "object",
+            "properties": {
+                "Host": { "type": "string" },
+                "Port": { "type": ["integer", "null"] },
+                "Mode": { "enum": ["Active", "Passive"] },
+                "Labels": { "type": "object", "additionalProperties": { "type": "string" } }
+            },
+            "required": ["Host", "Mode"]
+        }"#,
+    )
+    .unwrap();
+    let config = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[
+            ("Port", "eighty"),
+            ("Labels:Team", "web"),
+            ("Secure", "true"),
+        ])
+        .build()
+        .unwrap();
+
+    // act
+    let report = schema.validate(config.as_config().as_ref());
+
+    // assert
+    assert_eq!(report.unknown_keys, vec!["Secure"]);
+    assert_eq!(report.missing_keys, vec!["Host", "Mode"]);
+    assert_eq!(report.mismatches[0].key, "Port");
+    assert_eq!(report.mismatches[0].expected, "integer");
+}
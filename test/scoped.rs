@@ -0,0 +1,117 @@
+use config::{ext::*, *};
+
+#[test]
+fn into_root_should_have_keys_relative_to_section() {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[
+            ("Name", "App"),
+            ("Database:Host", "localhost"),
+            ("Database:Pool:Size", "10"),
+        ])
+        .build()
+        .unwrap();
+
+    // act
+    let root = config.section("Database").into_root();
+    let pool = root.section("Pool");
+
+    // assert
+    assert_eq!(root.get("Host").unwrap().as_str(), "localhost");
+    assert_eq!(root.get("Pool:Size").unwrap().as_str(), "10");
+    assert_eq!(root.get("Name"), None);
+    assert_eq!(pool.path(), "Pool");
+    assert_eq!(root.children().len(), 2);
+}
+
+#[test]
+fn into_root_should_have_providers_view_of_section() {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[("Database:Host", "localhost")])
+        .add_in_memory(&[("Database:Port", "5432")])
+        .build()
+        .unwrap();
+
+    // act
+    let root = config.section("Database").into_root();
+    let names: Vec<_> = root.providers().map(|p| p.name().to_owned()).collect();
+
+    // assert
+    assert_eq!(names, vec!["Database"]);
+    assert_eq!(root.get("Port").unwrap().as_str(), "5432");
+}
+
+#[test]
+fn into_root_should_signal_reload_when_configuration_changes() {
+    // arrange
+    let mut source = MemoryConfigurationSource::new(&[("Database:Host", "localhost")]);
+    let handle = source.handle();
+    let mut builder = DefaultConfigurationBuilder::new();
+    builder.add(Box::new(source));
+    let config = builder.build().unwrap();
+    let root = config.section("Database").into_root();
+    let token = root.reload_token();
+
+    // act
+    handle.set("Database:Host", "remote");
+    handle.set("Database:Port", "5432");
+
+    // assert
+    assert!(token.changed());
+    assert_eq!(root.get("Host").unwrap().as_str(), "remote");
+    assert_eq!(root.children().len(), 2);
+}
+
+#[test]
+fn scoped_configuration_should_mask_values_until_popped() {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[("Name", "App"), ("Database:Host", "localhost")])
+        .build()
+        .unwrap();
+    let scoped = ScopedConfiguration::new(config.into());
+
+    // act
+    scoped.push_overrides(&[("Database:Host", "test"), ("Database:Port", "5432")]);
+    scoped.push_overrides(&[("Database:Host", "inner")]);
+
+    let inner = scoped.get("Database:Host").unwrap().to_string();
+
+    scoped.pop();
+
+    let outer = scoped.get("Database:Host").unwrap().to_string();
+    let children = scoped.section("Database").children().len();
+
+    scoped.pop();
+
+    // assert
+    assert_eq!(inner, "inner");
+    assert_eq!(outer, "test");
+    assert_eq!(children, 2);
+    assert_eq!(scoped.get("Database:Host").unwrap().as_str(), "localhost");
+    assert_eq!(scoped.get("Database:Port"), None);
+    assert_eq!(scoped.get("Name").unwrap().as_str(), "App");
+    assert!(!scoped.pop());
+}
+
+#[test]
+fn scoped_configuration_should_not_change_original_configuration() {
+    // arrange
+    let config: std::sync::Arc<dyn ConfigurationRoot> = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[("Name", "App")])
+        .build()
+        .unwrap()
+        .into();
+    let scoped = ScopedConfiguration::new(config.clone());
+    let token = scoped.reload_token();
+
+    // act
+    scoped.push_overrides(&[("Name", "Test")]);
+
+    // assert
+    assert!(token.changed());
+    assert_eq!(scoped.depth(), 1);
+    assert_eq!(scoped.get("Name").unwrap().as_str(), "Test");
+    assert_eq!(config.get("Name").unwrap().as_str(), "App");
+}
@@ -0,0 +1,79 @@
+use config::{ext::*, *};
+
+fn build() -> Box<dyn ConfigurationRoot> {
+    DefaultConfigurationBuilder::new()
+        .add_in_memory(&[
+            ("Database:Host", "localhost"),
+            ("Database:Password", "p@ssw0rd"),
+            ("ClientSecret", "s3cr3t"),
+        ])
+        .mark_sensitive(&["*:Password", "*secret*"])
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn debug_view_should_redact_sensitive_values() {
+    // arrange
+    let config = build();
+
+    // act
+    let view = format!("{:?}", config);
+
+    // assert
+    assert!(view.contains("ClientSecret=***"));
+    assert!(view.contains("Password=***"));
+    assert!(view.contains("Host=localhost"));
+    assert!(!view.contains("p@ssw0rd"));
+    assert!(!view.contains("s3cr3t"));
+}
+
+#[test]
+fn section_should_report_sensitive_keys() {
+    // arrange
+    let config = build();
+
+    // act
+    let section = config.section("Database");
+
+    // assert
+    assert!(section.is_sensitive("Password"));
+    assert!(!section.is_sensitive("Host"));
+    assert_eq!(section.get("Password").unwrap().as_str(), "p@ssw0rd");
+}
+
+#[test]
+fn snapshot_should_redact_sensitive_values() {
+    // arrange
+    let config = build();
+
+    // act
+    let view = format!("{:?}", config.snapshot());
+
+    // assert
+    assert!(view.contains("\"Database:Password\": \"***\""));
+    assert!(!view.contains("p@ssw0rd"));
+}
+
+#[test]
+fn diff_should_redact_sensitive_values() {
+    // arrange
+    let old = build();
+    let overlay = old.overlay().unwrap();
+    let snapshot = old.snapshot();
+
+    overlay.set("Database:Password", "n3w");
+
+    // act
+    let entries = diff(&snapshot, &**old);
+
+    // assert
+    assert_eq!(
+        entries,
+        vec![DiffEntry::Changed {
+            key: "Database:Password".into(),
+            old: REDACTED.into(),
+            new: REDACTED.into(),
+        }]
+    );
+}
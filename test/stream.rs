@@ -0,0 +1,173 @@
+use config::{ext::*, *};
+use std::io::{Cursor, Error, ErrorKind, Read, Result};
+use test_case::test_case;
+
+struct BrokenStream;
+
+impl Read for BrokenStream {
+    fn read(&mut self, _buf: &mut [u8]) -> Result<usize> {
+        Err(Error::new(ErrorKind::BrokenPipe, "broken pipe"))
+    }
+}
+
+#[test]
+fn add_json_stream_should_load_configuration_from_reader() {
+    // arrange
+    let json = r#"{"service": {"name": "demo", "ports": [80, 443]}}"#;
+    let config = DefaultConfigurationBuilder::new()
+        .add_json_stream(Cursor::new(json))
+        .build()
+        .unwrap();
+
+    // act
+    let name = config.get("Service:Name");
+
+    // assert
+    assert_eq!(name.unwrap().as_str(), "demo");
+    assert_eq!(config.get("Service:Ports:1").unwrap().as_str(), "443");
+}
+
+#[test]
+fn add_ini_stream_should_load_configuration_from_reader() {
+    // arrange
+    let ini = "[Service]\nName=demo\n";
+    let config = DefaultConfigurationBuilder::new()
+        .add_ini_stream(ini.as_bytes())
+        .build()
+        .unwrap();
+
+    // act
+    let name = config.get("Service:Name");
+
+    // assert
+    assert_eq!(name.unwrap().as_str(), "demo");
+}
+
+#[test]
+fn add_xml_stream_should_load_configuration_from_reader() {
+    // arrange
+    let xml = "<settings><Service><Name>demo</Name></Service></settings>";
+    let config = DefaultConfigurationBuilder::new()
+        .add_xml_stream(xml.as_bytes())
+        .build()
+        .unwrap();
+
+    // act
+    let name = config.get("Service:Name");
+
+    // assert
+    assert_eq!(name.unwrap().as_str(), "demo");
+}
+
+#[test]
+fn build_should_fail_when_stream_content_is_invalid() {
+    // arrange
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder.add_json_stream(Cursor::new("[1, 2, 3]"));
+
+    // act
+    let result = builder.build();
+
+    // assert
+    assert!(result.is_err());
+}
+
+#[test]
+fn build_should_fail_when_stream_cannot_be_read() {
+    // arrange
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder.add_json_stream(BrokenStream);
+
+    // act
+    let result = builder.build();
+
+    // assert
+    assert!(result.is_err());
+}
+
+#[test]
+fn stream_should_be_reused_when_built_more_than_once() {
+    // arrange
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder.add_json_stream(Cursor::new(r#"{"key": "value"}"#));
+
+    // act
+    let config1 = builder.build().unwrap();
+    let config2 = builder.build().unwrap();
+
+    // assert
+    assert_eq!(config1.get("Key").unwrap().as_str(), "value");
+    assert_eq!(config2.get("Key").unwrap().as_str(), "value");
+}
+
+#[test]
+fn add_embedded_json_should_layer_under_later_sources() {
+    // arrange
+    let defaults = r#"{"logging": {"level": "Information", "format": "text"}}"#;
+    let config = DefaultConfigurationBuilder::new()
+        .add_embedded_json(defaults)
+        .add_in_memory(&[("Logging:Level", "Debug")])
+        .build()
+        .unwrap();
+
+    // act
+    let level = config.get("Logging:Level");
+    let format = config.get("Logging:Format");
+
+    // assert
+    assert_eq!(level.unwrap().as_str(), "Debug");
+    assert_eq!(format.unwrap().as_str(), "text");
+}
+
+#[test]
+fn add_embedded_ini_should_load_configuration_from_text() {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .add_embedded_ini("[Logging]\nLevel=Warning\n")
+        .build()
+        .unwrap();
+
+    // act
+    let level = config.get("Logging:Level");
+
+    // assert
+    assert_eq!(level.unwrap().as_str(), "Warning");
+}
+
+#[test]
+fn add_embedded_xml_should_load_configuration_from_text() {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .add_embedded_xml("<settings><Logging Level=\"Error\" /></settings>")
+        .build()
+        .unwrap();
+
+    // act
+    let level = config.get("Logging:Level");
+
+    // assert
+    assert_eq!(level.unwrap().as_str(), "Error");
+}
+
+#[test_case(r#"{"Logging": {"Level": "Debug"}}"#, Format::Json ; "json")]
+#[test_case("[Logging]\nLevel=Debug", Format::Ini ; "ini")]
+#[test_case(r#"<settings><Logging Level="Debug" /></settings>"#, Format::Xml ; "xml")]
+#[test_case(r#"  {"Logging": {"Level": "Debug"}}"#, Format::Auto ; "auto json")]
+#[test_case("[Logging]\nLevel=Debug", Format::Auto ; "auto ini")]
+#[test_case(r#"<settings><Logging Level="Debug" /></settings>"#, Format::Auto ; "auto xml")]
+fn add_config_text_should_load_configuration_in_format(text: &str, format: Format) {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .add_config_text(text, format)
+        .build()
+        .unwrap();
+
+    // act
+    let level = config.get("Logging:Level");
+
+    // assert
+    assert_eq!(level.unwrap().as_str(), "Debug");
+}
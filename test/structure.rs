@@ -0,0 +1,148 @@
+use config::{ext::*, *};
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+#[derive(Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct Logging {
+    log_level: Level,
+    #[serde(skip)]
+    _ignored: bool,
+}
+
+#[derive(Serialize)]
+enum Level {
+    Warning,
+    Error,
+}
+
+#[derive(Serialize)]
+enum Store {
+    File(String),
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct Common {
+    retries: u8,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct Settings {
+    logging: Logging,
+    store: Store,
+    #[serde(flatten)]
+    common: Common,
+    servers: Vec<&'static str>,
+    tags: BTreeMap<&'static str, f64>,
+    proxy: Option<String>,
+}
+
+fn settings() -> Settings {
+    Settings {
+        logging: Logging {
+            log_level: Level::Warning,
+            _ignored: true,
+        },
+        store: Store::File("data.db".into()),
+        common: Common { retries: 3 },
+        servers: vec!["alpha", "beta"],
+        tags: vec![("weight", 0.5)].into_iter().collect(),
+        proxy: None,
+    }
+}
+
+#[test]
+fn add_struct_should_honor_serde_attributes() {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .add_struct(&settings())
+        .build()
+        .unwrap();
+
+    // act
+    let level = config.section("Logging").get("LogLevel");
+
+    // assert
+    assert_eq!(level.unwrap().as_str(), "Warning");
+    assert!(config.get("Logging:_ignored").is_none());
+    assert_eq!(config.get("Retries").unwrap().as_str(), "3");
+    assert!(config.get("Common:Retries").is_none());
+}
+
+#[test]
+fn add_struct_should_represent_collections_and_enum_variants() {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .add_struct(&settings())
+        .build()
+        .unwrap();
+
+    // act
+    let store = config.get("Store:File");
+
+    // assert
+    assert_eq!(store.unwrap().as_str(), "data.db");
+    assert_eq!(config.get("Servers:1").unwrap().as_str(), "beta");
+    assert_eq!(
+        config.get_typed("Tags:weight"),
+        Some(ConfigValue::Float(0.5))
+    );
+    assert!(config.section("Proxy").is_null());
+}
+
+#[test]
+fn add_struct_should_layer_under_later_sources() {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .add_struct(&settings())
+        .add_in_memory(&[("Logging:LogLevel", "Debug")])
+        .build()
+        .unwrap();
+
+    // act
+    let level = config.get("Logging:LogLevel");
+
+    // assert
+    assert_eq!(level.unwrap().as_str(), "Debug");
+}
+
+#[test]
+fn update_should_change_struct_configuration() {
+    // arrange
+    let source = StructConfigurationSource::new(&settings());
+    let handle = source.handle();
+    let mut builder = DefaultConfigurationBuilder::new();
+    builder.add(Box::new(source));
+    let config = builder.build().unwrap();
+    let token = config.reload_token();
+    let mut updated = settings();
+    updated.logging.log_level = Level::Error;
+
+    // act
+    handle.update(&updated).unwrap();
+
+    // assert
+    assert!(token.changed());
+    assert_eq!(config.get("Logging:LogLevel").unwrap().as_str(), "Error");
+}
+
+#[test]
+fn add_struct_defaults_should_be_overridden_by_other_sources() {
+    // arrange
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder
+        .add_in_memory(&[("Retries", "5")])
+        .add_struct_defaults(&settings());
+
+    let config = builder.build().unwrap();
+
+    // act
+    let retries = config.get("Retries").unwrap();
+
+    // assert
+    assert_eq!(retries.as_str(), "5");
+    assert_eq!(config.get("Logging:LogLevel").unwrap().as_str(), "Warning");
+}
@@ -0,0 +1,77 @@
+use config::{ext::*, *};
+use std::sync::Arc;
+
+fn build() -> Arc<dyn ConfigurationRoot> {
+    DefaultConfigurationBuilder::new()
+        .add_in_memory(&[
+            ("Logging:Level", "Warning"),
+            ("Logging:Format", "Json"),
+            ("Name", "App"),
+            ("Tenants:Contoso:Logging:Level", "Debug"),
+            ("Tenants:Contoso:Theme", "Dark"),
+            ("Tenants:Fabrikam:Name", "Fabrikam"),
+        ])
+        .build()
+        .unwrap()
+        .into()
+}
+
+#[test]
+fn tenant_should_override_shared_values() {
+    // arrange
+    let tenants = TenantConfiguration::new(build());
+
+    // act
+    let tenant = tenants.tenant("contoso");
+
+    // assert
+    assert_eq!(tenant.get("Logging:Level").unwrap().as_str(), "Debug");
+    assert_eq!(tenant.get("Logging:Format").unwrap().as_str(), "Json");
+    assert_eq!(tenant.get("Name").unwrap().as_str(), "App");
+    assert_eq!(tenant.get("Theme").unwrap().as_str(), "Dark");
+    assert_eq!(tenant.section("Logging").children().len(), 2);
+}
+
+#[test]
+fn tenant_should_not_observe_tenants_section() {
+    // arrange
+    let tenants = TenantConfiguration::new(build());
+
+    // act
+    let tenant = tenants.tenant("Fabrikam");
+    let mut keys: Vec<_> = tenant
+        .children()
+        .iter()
+        .map(|child| child.key().to_owned())
+        .collect();
+
+    keys.sort();
+
+    // assert
+    assert_eq!(keys, vec!["Logging", "Name"]);
+    assert_eq!(tenant.get("Tenants:Contoso:Theme"), None);
+    assert_eq!(tenants.tenants(), vec!["Contoso", "Fabrikam"]);
+}
+
+#[test]
+fn tenant_should_be_cached_until_reload() {
+    // arrange
+    let mut source = MemoryConfigurationSource::new(&[("Name", "App")]);
+    let handle = source.handle();
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder.add(Box::new(source));
+
+    let tenants = TenantConfiguration::new(builder.build().unwrap().into());
+    let before = tenants.tenant("Contoso");
+
+    // act
+    let cached = tenants.tenant("CONTOSO");
+    handle.set("Tenants:Contoso:Name", "Contoso");
+    let after = tenants.tenant("Contoso");
+
+    // assert
+    assert!(Arc::ptr_eq(&before, &cached));
+    assert!(!Arc::ptr_eq(&before, &after));
+    assert_eq!(after.get("Name").unwrap().as_str(), "Contoso");
+}
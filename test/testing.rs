@@ -0,0 +1,59 @@
+use config::{ext::*, *};
+
+#[test]
+fn fake_provider_should_signal_reload_when_triggered() {
+    // arrange
+    let fake = FakeConfigurationProvider::new(&[("Name", "Alpha")]);
+    let mut builder = DefaultConfigurationBuilder::new();
+    builder.add(Box::new(fake.clone()));
+    let config = builder.build().unwrap();
+    let token = config.reload_token();
+
+    fake.set("Name", "Beta");
+
+    // act
+    let before = token.changed();
+    fake.trigger();
+
+    // assert
+    assert!(!before);
+    assert!(token.changed());
+    assert_config_eq!(config, &[("Name", "Beta")]);
+}
+
+#[test]
+fn fake_provider_should_fail_load_when_scripted() {
+    // arrange
+    let fake = FakeConfigurationProvider::new(&[("Name", "Alpha")]);
+    let mut builder = DefaultConfigurationBuilder::new();
+    builder.add(Box::new(fake.clone()));
+    let config = builder.build().unwrap();
+
+    fake.set_load_error(Some(LoadError::Generic("Unavailable".into())));
+
+    // act
+    let result = config.reload();
+
+    // assert
+    assert!(result.is_err());
+    assert_eq!(fake.load_count(), 2);
+}
+
+#[test]
+fn temp_config_file_should_be_deleted_when_dropped() {
+    // arrange
+    let file = TempConfigFile::new("json", r#"{"Logging": {"Level": "Debug"}}"#);
+    let path = file.path().to_path_buf();
+
+    // act
+    let config = DefaultConfigurationBuilder::new()
+        .add_json_file(file.path())
+        .build()
+        .unwrap();
+
+    drop(file);
+
+    // assert
+    assert_config_eq!(config, &[("Logging:Level", "Debug")]);
+    assert!(!path.exists());
+}
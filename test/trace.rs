@@ -0,0 +1,97 @@
+use config::{ext::*, *};
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+
+// records the message and fields of each event as text
+#[derive(Clone, Default)]
+struct EventRecorder {
+    events: Arc<Mutex<Vec<String>>>,
+}
+
+struct EventVisitor<'a>(&'a mut String);
+
+impl Visit for EventVisitor<'_> {
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        if field.name() == "message" {
+            self.0.insert_str(0, &format!("{:?}", value));
+        } else {
+            self.0.push_str(&format!(" {}={:?}", field.name(), value));
+        }
+    }
+}
+
+impl Subscriber for EventRecorder {
+    fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, _span: &Attributes<'_>) -> Id {
+        Id::from_u64(1)
+    }
+
+    fn record(&self, _span: &Id, _values: &Record<'_>) {}
+
+    fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut text = String::new();
+        event.record(&mut EventVisitor(&mut text));
+        self.events.lock().unwrap().push(text);
+    }
+
+    fn enter(&self, _span: &Id) {}
+
+    fn exit(&self, _span: &Id) {}
+}
+
+#[test]
+fn load_should_emit_provider_name_and_key_count() {
+    // arrange
+    let recorder = EventRecorder::default();
+    let events = recorder.events.clone();
+
+    // act
+    tracing::subscriber::with_default(recorder, || {
+        DefaultConfigurationBuilder::new()
+            .add_in_memory(&[("Name", "App"), ("Logging:Level", "Debug")])
+            .build()
+            .unwrap()
+    });
+
+    // assert
+    let events = events.lock().unwrap();
+
+    assert_eq!(events.len(), 1);
+    assert!(events[0].starts_with("Configuration provider loaded"));
+    assert!(events[0].contains("keys=2"));
+}
+
+#[test]
+fn reload_should_emit_failed_providers() {
+    // arrange
+    let recorder = EventRecorder::default();
+    let events = recorder.events.clone();
+    let provider = FakeConfigurationProvider::new(&[("Name", "App")]);
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder.add(Box::new(provider.clone()));
+
+    let config = builder.build().unwrap();
+
+    provider.set_load_error(Some(LoadError::Generic("Unavailable".into())));
+
+    // act
+    let result = tracing::subscriber::with_default(recorder, || config.reload());
+
+    // assert
+    let events = events.lock().unwrap();
+
+    assert!(result.is_err());
+    assert!(events[0].starts_with("Configuration provider failed to load"));
+    assert!(events[0].contains("Unavailable"));
+    assert!(events[1].starts_with("Configuration failed to reload"));
+    assert!(events[1].contains("providers=1"));
+}
@@ -0,0 +1,83 @@
+use config::{ext::*, *};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+#[allow(dead_code)]
+#[serde(rename_all(deserialize = "PascalCase"))]
+struct DatabaseOptions {
+    url: String,
+    #[serde(default)]
+    timeout: u32,
+}
+
+#[test]
+fn unused_keys_should_report_values_that_were_never_read() {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[
+            ("Database:Url", "postgres://localhost"),
+            ("Databse:Timeout", "30"),
+            ("Logging:Level", "Debug"),
+        ])
+        .track_unused_keys()
+        .build()
+        .unwrap();
+
+    // act
+    let _: DatabaseOptions = config.section("Database").reify();
+    let _ = config.get("Logging:Level");
+
+    // assert
+    assert_eq!(config.unused_keys(), vec!["Databse:Timeout"]);
+}
+
+#[test]
+fn unused_keys_should_include_every_value_before_any_are_read() {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[("Service:Host", "localhost"), ("Service:Port", "80")])
+        .track_unused_keys()
+        .build()
+        .unwrap();
+
+    // act
+    let keys = config.unused_keys();
+
+    // assert
+    assert_eq!(keys, vec!["Service:Host", "Service:Port"]);
+}
+
+#[test]
+fn prewarm_should_not_read_values() {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[
+            ("Database:Url", "postgres://localhost"),
+            ("Logging:Level", "Debug"),
+        ])
+        .track_unused_keys()
+        .build()
+        .unwrap();
+
+    // act
+    config.prewarm();
+
+    // assert
+    assert_eq!(config.unused_keys(), vec!["Database:Url", "Logging:Level"]);
+    assert_eq!(config.get("Logging:Level").unwrap().as_str(), "Debug");
+}
+
+#[test]
+fn unused_keys_should_be_empty_when_not_tracked() {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[("Service:Host", "localhost"), ("Service:Port", "80")])
+        .build()
+        .unwrap();
+
+    // act
+    let keys = config.unused_keys();
+
+    // assert
+    assert!(keys.is_empty());
+}
@@ -0,0 +1,233 @@
+use config::{ext::*, *};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+#[derive(Clone, Default)]
+struct FakeClient {
+    resources: Arc<Mutex<HashMap<String, Vec<u8>>>>,
+    pollers: Arc<Mutex<HashMap<String, (Duration, WebPoller)>>>,
+}
+
+impl FakeClient {
+    fn with(self, url: &str, json: &str) -> Self {
+        self.resources
+            .lock()
+            .unwrap()
+            .insert(url.into(), json.as_bytes().to_vec());
+        self
+    }
+
+    fn push(&self, url: &str, json: Option<&str>) -> bool {
+        self.pollers.lock().unwrap()[url]
+            .1
+            .send(json.map(|json| json.as_bytes().to_vec()))
+    }
+}
+
+impl WebClient for FakeClient {
+    fn fetch(&self, url: &str) -> Result<Option<Vec<u8>>, LoadError> {
+        Ok(self.resources.lock().unwrap().get(url).cloned())
+    }
+
+    fn poll(&self, url: &str, interval: Duration, poller: WebPoller) {
+        self.pollers
+            .lock()
+            .unwrap()
+            .insert(url.into(), (interval, poller));
+    }
+}
+
+#[test]
+fn add_web_json_should_load_fetched_json_and_poll_for_changes() {
+    // arrange
+    let client = FakeClient::default().with(
+        "https://example.com/settings.json",
+        r#"{"Logging": {"Level": "Info"}, "Hosts": ["a", "b"]}"#,
+    );
+
+    // act
+    let config = DefaultConfigurationBuilder::new()
+        .add_web_json(
+            client.clone(),
+            "https://example.com/settings.json",
+            Duration::from_secs(30),
+        )
+        .build()
+        .unwrap();
+
+    // assert
+    assert_eq!(config.get("Logging:Level").unwrap().as_str(), "Info");
+    assert_eq!(config.get("Hosts:1").unwrap().as_str(), "b");
+    assert_eq!(
+        client.pollers.lock().unwrap()["https://example.com/settings.json"].0,
+        Duration::from_secs(30)
+    );
+}
+
+#[test]
+fn build_should_fail_when_content_is_missing() {
+    // arrange
+    let client = FakeClient::default();
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder.add_web_json(
+        client,
+        "https://example.com/settings.json",
+        Duration::from_secs(30),
+    );
+
+    // act
+    let result = builder.build();
+
+    // assert
+    if let Err(ReloadError::Provider(errors)) = result {
+        assert_eq!(
+            errors[0].1.message(),
+            "The configuration at 'https://example.com/settings.json' was not found and is not optional."
+        );
+    } else {
+        panic!("No error occurred.")
+    }
+}
+
+#[test]
+fn build_should_succeed_without_polling_when_optional_content_is_missing() {
+    // arrange
+    let client = FakeClient::default();
+    let source = WebConfigurationSource::new(client.clone(), "https://example.com/settings.json")
+        .optional(true);
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder.add(Box::new(source));
+
+    // act
+    let config = builder.build().unwrap();
+
+    // assert
+    assert!(config.children().is_empty());
+    assert!(client.pollers.lock().unwrap().is_empty());
+}
+
+#[test]
+fn build_should_fail_when_content_is_invalid() {
+    // arrange
+    let client = FakeClient::default().with("https://example.com/settings.json", "{");
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder.add_web_json(
+        client,
+        "https://example.com/settings.json",
+        Duration::from_secs(30),
+    );
+
+    // act
+    let result = builder.build();
+
+    // assert
+    if let Err(ReloadError::Provider(errors)) = result {
+        assert!(errors[0].1.message().starts_with(
+            "The configuration fetched from 'https://example.com/settings.json' is invalid."
+        ));
+    } else {
+        panic!("No error occurred.")
+    }
+}
+
+#[test]
+fn send_should_change_values_and_signal_reload_token() {
+    // arrange
+    let client = FakeClient::default().with(
+        "https://example.com/settings.json",
+        r#"{"Logging": {"Level": "Info"}}"#,
+    );
+    let config = DefaultConfigurationBuilder::new()
+        .add_web_json(
+            client.clone(),
+            "https://example.com/settings.json",
+            Duration::from_secs(30),
+        )
+        .build()
+        .unwrap();
+    let token = config.reload_token();
+
+    // act
+    client.push(
+        "https://example.com/settings.json",
+        Some(r#"{"Logging": {"Level": "Debug"}}"#),
+    );
+
+    // assert
+    assert!(token.changed());
+    assert_eq!(config.get("Logging:Level").unwrap().as_str(), "Debug");
+}
+
+#[test]
+fn send_should_not_signal_reload_token_when_content_is_unchanged_or_invalid() {
+    // arrange
+    let json = r#"{"Logging": {"Level": "Info"}}"#;
+    let client = FakeClient::default().with("https://example.com/settings.json", json);
+    let config = DefaultConfigurationBuilder::new()
+        .add_web_json(
+            client.clone(),
+            "https://example.com/settings.json",
+            Duration::from_secs(30),
+        )
+        .build()
+        .unwrap();
+    let token = config.reload_token();
+
+    // act
+    let unchanged = client.push("https://example.com/settings.json", Some(json));
+    let invalid = client.push("https://example.com/settings.json", Some("{"));
+
+    // assert
+    assert!(unchanged);
+    assert!(invalid);
+    assert!(!token.changed());
+    assert_eq!(config.get("Logging:Level").unwrap().as_str(), "Info");
+}
+
+#[test]
+fn send_should_clear_values_when_content_no_longer_exists() {
+    // arrange
+    let client = FakeClient::default().with(
+        "https://example.com/settings.json",
+        r#"{"Logging": {"Level": "Info"}}"#,
+    );
+    let config = DefaultConfigurationBuilder::new()
+        .add_web_json(
+            client.clone(),
+            "https://example.com/settings.json",
+            Duration::from_secs(30),
+        )
+        .build()
+        .unwrap();
+
+    // act
+    client.push("https://example.com/settings.json", None);
+
+    // assert
+    assert!(config.get("Logging:Level").is_none());
+}
+
+#[test]
+fn send_should_return_false_after_provider_is_dropped() {
+    // arrange
+    let json = r#"{"Logging": {"Level": "Info"}}"#;
+    let client = FakeClient::default().with("https://example.com/settings.json", json);
+    let config = DefaultConfigurationBuilder::new()
+        .add_web_json(
+            client.clone(),
+            "https://example.com/settings.json",
+            Duration::from_secs(30),
+        )
+        .build()
+        .unwrap();
+
+    // act
+    drop(config);
+
+    // assert
+    assert!(!client.push("https://example.com/settings.json", Some(json)));
+}
@@ -7,16 +7,6 @@ use std::sync::{Arc, Condvar, Mutex};
 use std::time::Duration;
 use test_case::test_case;
 
-struct TempFile(PathBuf);
-
-impl Drop for TempFile {
-    fn drop(&mut self) {
-        if self.0.exists() {
-            remove_file(&self.0).ok();
-        }
-    }
-}
-
 #[test]
 fn add_xml_file_should_load_settings_from_file() {
     // arrange
@@ -679,16 +669,11 @@ fn load_should_fail_when_xml_namespace_is_encountered() {
         " </MyNamespace:Data>\n",
         "</settings>"
     );
-    let path = temp_dir().join("test_settings_14.xml");
-    let mut file = File::create(&path).unwrap();
-
-    file.write_all(xml.to_string().as_bytes()).unwrap();
-
-    let _file = TempFile(path.clone());
+    let file = TempConfigFile::new("xml", xml);
 
     // act
     let result = DefaultConfigurationBuilder::new()
-        .add_xml_file(&path)
+        .add_xml_file(file.path())
         .build();
 
     // assert
@@ -719,16 +704,11 @@ fn load_should_fail_when_key_is_duplicated() {
         " <Data Name='DefaultConnection' ConnectionString='NewConnectionString' />\n",
         "</settings>"
     );
-    let path = temp_dir().join("test_settings_15.xml");
-    let mut file = File::create(&path).unwrap();
-
-    file.write_all(xml.to_string().as_bytes()).unwrap();
-
-    let _file = TempFile(path.clone());
+    let file = TempConfigFile::new("xml", xml);
 
     // act
     let result = DefaultConfigurationBuilder::new()
-        .add_xml_file(&path)
+        .add_xml_file(file.path())
         .build();
 
     // assert
@@ -818,3 +798,126 @@ fn xml_file_should_reload_when_changed() {
     assert_eq!(initial.as_str(), "3");
     assert_eq!(current.as_str(), "5");
 }
+
+#[test]
+fn load_should_report_duplicate_key_and_line() {
+    // arrange
+    let xml = concat!(
+        "<settings>\n",
+        " <Data>\n",
+        "  <DefaultConnection>\n",
+        "   <ConnectionString>TestConnectionString</ConnectionString>\n",
+        "  </DefaultConnection>\n",
+        " </Data>\n",
+        " <Data Name='DefaultConnection' ConnectionString='NewConnectionString' />\n",
+        "</settings>"
+    );
+    let file = TempConfigFile::new("xml", xml);
+
+    // act
+    let result = DefaultConfigurationBuilder::new()
+        .add_xml_file(file.path())
+        .build();
+
+    // assert
+    if let Err(ReloadError::Provider(errors)) = result {
+        let error = &errors[0].1;
+
+        assert_eq!(error.key(), Some("Data:DefaultConnection:ConnectionString"));
+        assert_eq!(error.line(), Some(7));
+        assert_eq!(error.column(), None);
+    } else {
+        panic!("No error occurred.")
+    }
+}
+
+#[test_case(XmlNamespaces::Strip, "Data:DefaultConnection:Provider" ; "strip")]
+#[test_case(XmlNamespaces::Prefix, "Data:DefaultConnection:db:Provider" ; "prefix")]
+fn load_should_apply_xml_namespace_option(namespaces: XmlNamespaces, key: &str) {
+    // arrange
+    let xml = concat!(
+        "<settings xmlns='urn:settings' xmlns:db='urn:db'>\n",
+        " <Data>\n",
+        "  <DefaultConnection>\n",
+        "   <db:Provider>SqlClient</db:Provider>\n",
+        "  </DefaultConnection>\n",
+        " </Data>\n",
+        "</settings>"
+    );
+    let file = TempConfigFile::new("xml", xml);
+    let options = XmlOptions {
+        namespaces,
+        ..Default::default()
+    };
+
+    // act
+    let config = DefaultConfigurationBuilder::new()
+        .add_xml_file_with_options(file.path(), options)
+        .build()
+        .unwrap();
+
+    // assert
+    assert_eq!(config.get(key).unwrap().as_str(), "SqlClient");
+}
+
+#[test_case(XmlDuplicateKeys::LastWins, &[("Data:DefaultConnection:ConnectionString", "NewConnectionString")] ; "last wins")]
+#[test_case(XmlDuplicateKeys::Array, &[("Data:DefaultConnection:ConnectionString:0", "TestConnectionString"), ("Data:DefaultConnection:ConnectionString:1", "NewConnectionString")] ; "array")]
+fn load_should_apply_duplicate_key_option(
+    duplicate_keys: XmlDuplicateKeys,
+    expected: &[(&str, &str)],
+) {
+    // arrange
+    let xml = concat!(
+        "<settings>\n",
+        " <Data>\n",
+        "  <DefaultConnection>\n",
+        "   <ConnectionString>TestConnectionString</ConnectionString>\n",
+        "  </DefaultConnection>\n",
+        " </Data>\n",
+        " <Data Name='DefaultConnection' ConnectionString='NewConnectionString' />\n",
+        "</settings>"
+    );
+    let file = TempConfigFile::new("xml", xml);
+    let options = XmlOptions {
+        duplicate_keys,
+        ..Default::default()
+    };
+
+    // act
+    let config = DefaultConfigurationBuilder::new()
+        .add_xml_file_with_options(file.path(), options)
+        .build()
+        .unwrap();
+
+    // assert
+    for (key, value) in expected {
+        assert_eq!(config.get(key).unwrap().as_str(), *value);
+    }
+}
+
+#[test]
+fn load_should_prefix_attributes_when_configured() {
+    // arrange
+    let xml = concat!(
+        "<settings>\n",
+        " <Server Port='8080'>\n",
+        "  <Port>9090</Port>\n",
+        " </Server>\n",
+        "</settings>"
+    );
+    let file = TempConfigFile::new("xml", xml);
+    let options = XmlOptions {
+        attribute_prefix: Some("@".into()),
+        ..Default::default()
+    };
+
+    // act
+    let config = DefaultConfigurationBuilder::new()
+        .add_xml_file_with_options(file.path(), options)
+        .build()
+        .unwrap();
+
+    // assert
+    assert_eq!(config.get("Server:@Port").unwrap().as_str(), "8080");
+    assert_eq!(config.get("Server:Port").unwrap().as_str(), "9090");
+}